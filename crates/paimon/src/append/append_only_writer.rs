@@ -0,0 +1,212 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+
+use crate::io::{DataFileWriter, FileIO};
+use crate::spec::{CoreOptions, DataFileMeta};
+use crate::table::FileNameGenerator;
+
+/// Writer of the data files of one bucket of an append-only table.
+///
+/// Rows are written into a new data file, which is closed when it reaches the target file size,
+/// or on [`AppendOnlyWriter::prepare_commit`]. The size is checked after each written batch.
+/// Append-only tables have no LSM tree, all data files are written at level 0.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/append/AppendOnlyWriter.java>
+pub struct AppendOnlyWriter {
+    file_io: FileIO,
+    bucket_path: String,
+    schema: SchemaRef,
+    schema_id: i64,
+    file_format: String,
+    target_file_size: u64,
+    file_name_generator: Arc<FileNameGenerator>,
+    /// sequence number of the next row
+    next_sequence_number: i64,
+    current: Option<DataFileWriter>,
+    new_files: Vec<DataFileMeta>,
+}
+
+impl AppendOnlyWriter {
+    /// Create a writer of the bucket at `bucket_path`, continuing after the largest sequence
+    /// number of the existing files of the bucket, `-1` if there is none.
+    pub fn new(
+        file_io: FileIO,
+        bucket_path: impl ToString,
+        schema: SchemaRef,
+        schema_id: i64,
+        file_format: impl ToString,
+        file_name_generator: Arc<FileNameGenerator>,
+        max_sequence_number: i64,
+    ) -> Self {
+        Self {
+            file_io,
+            bucket_path: bucket_path.to_string(),
+            schema,
+            schema_id,
+            file_format: file_format.to_string(),
+            target_file_size: CoreOptions::DEFAULT_TARGET_FILE_SIZE,
+            file_name_generator,
+            next_sequence_number: max_sequence_number + 1,
+            current: None,
+            new_files: Vec::new(),
+        }
+    }
+
+    /// Set the size at which the data file in progress is closed and a new one is started.
+    pub fn with_target_file_size(mut self, target_file_size: u64) -> Self {
+        self.target_file_size = target_file_size;
+        self
+    }
+
+    /// Write a batch, whose schema must be the schema of the writer.
+    pub async fn write(&mut self, batch: &RecordBatch) -> crate::Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+        let writer = match &mut self.current {
+            Some(writer) => writer,
+            None => self.current.insert(self.new_data_file_writer()?),
+        };
+        writer.write(batch)?;
+        self.next_sequence_number += batch.num_rows() as i64;
+
+        if writer.estimated_size() >= self.target_file_size {
+            self.roll().await?;
+        }
+        Ok(())
+    }
+
+    /// Close the data file in progress, if any.
+    async fn roll(&mut self) -> crate::Result<()> {
+        if let Some(writer) = self.current.take() {
+            self.new_files.push(writer.close().await?);
+        }
+        Ok(())
+    }
+
+    fn new_data_file_writer(&self) -> crate::Result<DataFileWriter> {
+        let file_name = self
+            .file_name_generator
+            .new_data_file_name(&self.file_format);
+        let path = format!("{}/{}", self.bucket_path, file_name);
+        Ok(DataFileWriter::new(
+            self.file_io.new_output(&path)?,
+            self.schema.clone(),
+            self.schema_id,
+            self.next_sequence_number,
+        )?
+        .with_level(0))
+    }
+
+    /// Close the data file in progress and return the files written since the last call.
+    pub async fn prepare_commit(&mut self) -> crate::Result<Vec<DataFileMeta>> {
+        self.roll().await?;
+        Ok(std::mem::take(&mut self.new_files))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Int32Array;
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::*;
+    use crate::io::FileIOBuilder;
+
+    #[tokio::test]
+    async fn test_append_only_writer() -> crate::Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let mut writer = AppendOnlyWriter::new(
+            file_io.clone(),
+            "memory:/tmp/test_append_only_writer/bucket-0",
+            schema,
+            0,
+            "parquet",
+            Arc::new(FileNameGenerator::with_uuid("abc")),
+            4,
+        );
+        assert!(writer.prepare_commit().await?.is_empty());
+
+        writer.write(&batch).await?;
+        writer.write(&batch).await?;
+        let files = writer.prepare_commit().await?;
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].file_name(), "data-abc-0.parquet");
+        assert_eq!(files[0].row_count(), 6);
+        assert_eq!(files[0].min_sequence_number(), 5);
+        assert_eq!(files[0].max_sequence_number(), 10);
+        assert_eq!(files[0].level(), 0);
+        assert!(
+            file_io
+                .exists("memory:/tmp/test_append_only_writer/bucket-0/data-abc-0.parquet")
+                .await?
+        );
+
+        // sequence numbers continue in the next file
+        writer.write(&batch).await?;
+        let files = writer.prepare_commit().await?;
+        assert_eq!(files[0].file_name(), "data-abc-1.parquet");
+        assert_eq!(files[0].min_sequence_number(), 11);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_roll_at_target_file_size() -> crate::Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from((0..1000).collect::<Vec<_>>()))],
+        )?;
+
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let mut writer = AppendOnlyWriter::new(
+            file_io,
+            "memory:/tmp/test_roll_at_target_file_size/bucket-0",
+            schema,
+            0,
+            "parquet",
+            Arc::new(FileNameGenerator::new()),
+            -1,
+        )
+        .with_target_file_size(1024);
+        for _ in 0..3 {
+            writer.write(&batch).await?;
+        }
+        let files = writer.prepare_commit().await?;
+        assert!(files.len() >= 2, "expected at least 2 files: {:?}", files);
+        assert_eq!(files.iter().map(|file| file.row_count()).sum::<i64>(), 3000);
+        assert_eq!(files[0].min_sequence_number(), 0);
+        for pair in files.windows(2) {
+            assert_eq!(
+                pair[0].max_sequence_number() + 1,
+                pair[1].min_sequence_number()
+            );
+        }
+        Ok(())
+    }
+}
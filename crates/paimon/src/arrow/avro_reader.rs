@@ -0,0 +1,458 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use arrow_array::{
+    ArrayRef, BinaryArray, BooleanArray, Date32Array, Decimal128Array, Float32Array, Float64Array,
+    Int16Array, Int32Array, Int64Array, Int8Array, RecordBatch, RecordBatchReader, StringArray,
+    Time32MillisecondArray, TimestampMicrosecondArray, TimestampMillisecondArray,
+    TimestampNanosecondArray, TimestampSecondArray,
+};
+use arrow_schema::{ArrowError, DataType as ArrowDataType, SchemaRef, TimeUnit};
+
+use crate::arrow::{storage_to_timestamp, timestamp_to_storage, to_arrow_schema, unit_precision};
+use crate::spec::{DataField, DataType};
+use crate::Error;
+
+/// Reader of the records of an avro data file as Arrow record batches.
+///
+/// The records are decoded with the fields given, usually the projection of the fields of the
+/// schema the file was written with. Fields are matched by name, and logical types are mapped
+/// to the Arrow type of the field: decimals, dates, times and timestamps of millisecond or
+/// microsecond precision.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-format/src/main/java/org/apache/paimon/format/avro/AvroRowDatumReader.java>
+#[derive(Debug)]
+pub struct AvroReader {
+    fields: Vec<DataField>,
+    schema: SchemaRef,
+    records: std::vec::IntoIter<Value>,
+    batch_size: usize,
+}
+
+impl AvroReader {
+    pub const DEFAULT_BATCH_SIZE: usize = 1024;
+
+    /// Decode the avro object container in `bytes`, reading the given fields.
+    pub fn new(bytes: &[u8], fields: Vec<DataField>) -> crate::Result<Self> {
+        let schema = Arc::new(to_arrow_schema(&fields)?);
+        let records = Reader::new(bytes)?.collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            fields,
+            schema,
+            records: records.into_iter(),
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+        })
+    }
+
+    /// Set the maximum number of rows of the batches read.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    fn next_batch(&mut self) -> crate::Result<Option<RecordBatch>> {
+        let records = self
+            .records
+            .by_ref()
+            .take(self.batch_size)
+            .collect::<Vec<_>>();
+        if records.is_empty() {
+            return Ok(None);
+        }
+
+        let columns = self
+            .fields
+            .iter()
+            .zip(self.schema.fields())
+            .map(|(field, arrow_field)| {
+                let values = records
+                    .iter()
+                    .map(|record| field_value(record, field.name()))
+                    .collect::<Vec<_>>();
+                to_array(&values, field, arrow_field.data_type())
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        Ok(Some(RecordBatch::try_new(self.schema.clone(), columns)?))
+    }
+}
+
+impl Iterator for AvroReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // `Error` is not `Send + Sync`, so only its message can be boxed into an `ArrowError`.
+        self.next_batch()
+            .map_err(|e| ArrowError::ExternalError(e.to_string().into()))
+            .transpose()
+    }
+}
+
+impl RecordBatchReader for AvroReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// Get the value of a field of a record, `None` if it is null or missing.
+fn field_value<'a>(record: &'a Value, name: &str) -> Option<&'a Value> {
+    let Value::Record(fields) = record else {
+        return None;
+    };
+    let mut value = &fields.iter().find(|(n, _)| n == name)?.1;
+    while let Value::Union(_, inner) = value {
+        value = inner;
+    }
+    (!matches!(value, Value::Null)).then_some(value)
+}
+
+/// Convert the values of a field, `None` for null, to an array of its Arrow type.
+fn to_array(
+    values: &[Option<&Value>],
+    field: &DataField,
+    arrow_type: &ArrowDataType,
+) -> crate::Result<ArrayRef> {
+    let array: ArrayRef = match (field.data_type(), arrow_type) {
+        (DataType::Boolean(_), _) => {
+            Arc::new(BooleanArray::from(collect(values, field, |v| match v {
+                Value::Boolean(v) => Some(*v),
+                _ => None,
+            })?))
+        }
+        (DataType::TinyInt(_), _) => Arc::new(Int8Array::from(collect(values, field, |v| {
+            int(v).and_then(|v| i8::try_from(v).ok())
+        })?)),
+        (DataType::SmallInt(_), _) => Arc::new(Int16Array::from(collect(values, field, |v| {
+            int(v).and_then(|v| i16::try_from(v).ok())
+        })?)),
+        (DataType::Int(_), _) => Arc::new(Int32Array::from(collect(values, field, int)?)),
+        (DataType::BigInt(_), _) => {
+            Arc::new(Int64Array::from(collect(values, field, |v| match v {
+                Value::Long(v) => Some(*v),
+                v => int(v).map(i64::from),
+            })?))
+        }
+        (DataType::Float(_), _) => {
+            Arc::new(Float32Array::from(collect(values, field, |v| match v {
+                Value::Float(v) => Some(*v),
+                _ => None,
+            })?))
+        }
+        (DataType::Double(_), _) => {
+            Arc::new(Float64Array::from(collect(values, field, |v| match v {
+                Value::Double(v) => Some(*v),
+                _ => None,
+            })?))
+        }
+        (DataType::Decimal(_), ArrowDataType::Decimal128(precision, scale)) => {
+            let unscaled = collect(values, field, |v| match v {
+                Value::Decimal(v) => Vec::<u8>::try_from(v).ok().and_then(|b| unscaled(&b)),
+                Value::Bytes(v) | Value::Fixed(_, v) => unscaled(v),
+                _ => None,
+            })?;
+            Arc::new(Decimal128Array::from(unscaled).with_precision_and_scale(*precision, *scale)?)
+        }
+        (DataType::Char(_) | DataType::VarChar(_), _) => {
+            Arc::new(StringArray::from(collect(values, field, |v| match v {
+                Value::String(v) => Some(v.as_str()),
+                _ => None,
+            })?))
+        }
+        (DataType::Binary(_) | DataType::VarBinary(_), _) => {
+            Arc::new(BinaryArray::from(collect(values, field, |v| match v {
+                Value::Bytes(v) | Value::Fixed(_, v) => Some(v.as_slice()),
+                _ => None,
+            })?))
+        }
+        (DataType::Date(_), _) => {
+            Arc::new(Date32Array::from(collect(values, field, |v| match v {
+                Value::Date(v) | Value::Int(v) => Some(*v),
+                _ => None,
+            })?))
+        }
+        (DataType::Time(_), _) => Arc::new(Time32MillisecondArray::from(collect(
+            values,
+            field,
+            |v| match v {
+                Value::TimeMillis(v) | Value::Int(v) => Some(*v),
+                Value::TimeMicros(v) => i32::try_from(v / 1_000).ok(),
+                _ => None,
+            },
+        )?)),
+        (
+            DataType::Timestamp(_) | DataType::LocalZonedTimestamp(_),
+            ArrowDataType::Timestamp(unit, time_zone),
+        ) => {
+            let values = collect(values, field, |v| {
+                let (value, source_unit) = match v {
+                    Value::TimestampMillis(v) | Value::LocalTimestampMillis(v) => {
+                        (*v, TimeUnit::Millisecond)
+                    }
+                    Value::TimestampMicros(v) | Value::LocalTimestampMicros(v) => {
+                        (*v, TimeUnit::Microsecond)
+                    }
+                    Value::Long(v) => (*v, *unit),
+                    _ => return None,
+                };
+                convert_time_unit(value, &source_unit, unit)
+            })?;
+            let time_zone = time_zone.clone();
+            match unit {
+                TimeUnit::Second => {
+                    Arc::new(TimestampSecondArray::from(values).with_timezone_opt(time_zone))
+                }
+                TimeUnit::Millisecond => {
+                    Arc::new(TimestampMillisecondArray::from(values).with_timezone_opt(time_zone))
+                }
+                TimeUnit::Microsecond => {
+                    Arc::new(TimestampMicrosecondArray::from(values).with_timezone_opt(time_zone))
+                }
+                TimeUnit::Nanosecond => {
+                    Arc::new(TimestampNanosecondArray::from(values).with_timezone_opt(time_zone))
+                }
+            }
+        }
+        (data_type, _) => {
+            return Err(Error::Unsupported {
+                message: format!(
+                    "Reading avro field {} of type {:?} is not supported",
+                    field.name(),
+                    data_type
+                ),
+            })
+        }
+    };
+    Ok(array)
+}
+
+/// Convert the non-null values with `f`, which returns `None` for a value of an unexpected type.
+fn collect<'a, T>(
+    values: &[Option<&'a Value>],
+    field: &DataField,
+    f: impl Fn(&'a Value) -> Option<T>,
+) -> crate::Result<Vec<Option<T>>> {
+    values
+        .iter()
+        .map(|value| match value {
+            None => Ok(None),
+            Some(value) => f(value).map(Some).ok_or_else(|| Error::DataTypeInvalid {
+                message: format!(
+                    "Avro value {:?} does not match field {} of type {:?}",
+                    value,
+                    field.name(),
+                    field.data_type()
+                ),
+            }),
+        })
+        .collect()
+}
+
+fn int(value: &Value) -> Option<i32> {
+    match value {
+        Value::Int(v) => Some(*v),
+        _ => None,
+    }
+}
+
+/// Decode the unscaled value of a decimal from its big-endian two's complement bytes.
+fn unscaled(bytes: &[u8]) -> Option<i128> {
+    if bytes.is_empty() || bytes.len() > 16 {
+        return None;
+    }
+    let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0 };
+    let mut buf = [fill; 16];
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    Some(i128::from_be_bytes(buf))
+}
+
+/// Convert an epoch value from a time unit to another, truncating finer digits.
+fn convert_time_unit(value: i64, from: &TimeUnit, to: &TimeUnit) -> Option<i64> {
+    let timestamp = storage_to_timestamp(value, unit_precision(from))?;
+    timestamp_to_storage(&timestamp, unit_precision(to))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{
+        Date32Type, Decimal128Type, Int32Type, Time32MillisecondType, TimestampMicrosecondType,
+        TimestampMillisecondType,
+    };
+    use arrow_array::Array;
+
+    use super::*;
+    use crate::spec::{
+        DateType, DecimalType, IntType, TimeType, TimestampType, VarBinaryType, VarCharType,
+    };
+
+    const FIXTURE: &str = "data-0f7a8a0e-4c1b-4d4e-9b1e-3d2a6c5f8b91-0.avro";
+
+    fn fixture_bytes() -> Vec<u8> {
+        let path = format!(
+            "{}/tests/fixtures/data/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            FIXTURE
+        );
+        std::fs::read(path).unwrap()
+    }
+
+    fn fixture_fields() -> Vec<DataField> {
+        vec![
+            DataField::new(
+                0,
+                "id".to_string(),
+                DataType::Int(IntType::with_nullable(false)),
+            ),
+            DataField::new(
+                1,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+            DataField::new(
+                2,
+                "price".to_string(),
+                DataType::Decimal(DecimalType::new(10, 2).unwrap()),
+            ),
+            DataField::new(3, "dt".to_string(), DataType::Date(DateType::new())),
+            DataField::new(
+                4,
+                "t".to_string(),
+                DataType::Time(TimeType::new(3).unwrap()),
+            ),
+            DataField::new(
+                5,
+                "ts".to_string(),
+                DataType::Timestamp(TimestampType::new(3).unwrap()),
+            ),
+            DataField::new(
+                6,
+                "ts_us".to_string(),
+                DataType::Timestamp(TimestampType::new(6).unwrap()),
+            ),
+            DataField::new(
+                7,
+                "payload".to_string(),
+                DataType::VarBinary(VarBinaryType::new(10).unwrap()),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_read_avro_data_file() -> crate::Result<()> {
+        let reader = AvroReader::new(&fixture_bytes(), fixture_fields())?;
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(batch.num_rows(), 3);
+
+        let ids = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(ids.values().to_vec(), vec![1, 2, 3]);
+        let names = batch.column(1).as_string::<i32>();
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            vec![Some("apple"), None, Some("cherry")]
+        );
+        let prices = batch.column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(
+            prices.iter().collect::<Vec<_>>(),
+            vec![Some(1234), Some(-5), Some(99999)]
+        );
+        assert_eq!(prices.value_as_string(0), "12.34");
+        let dates = batch.column(3).as_primitive::<Date32Type>();
+        assert_eq!(
+            dates.iter().collect::<Vec<_>>(),
+            vec![Some(19000), None, Some(19723)]
+        );
+        let times = batch.column(4).as_primitive::<Time32MillisecondType>();
+        assert_eq!(
+            times.iter().collect::<Vec<_>>(),
+            vec![Some(3_600_000), None, Some(0)]
+        );
+        let ts = batch.column(5).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(
+            ts.iter().collect::<Vec<_>>(),
+            vec![Some(1_641_038_400_123), None, Some(0)]
+        );
+        let ts_us = batch.column(6).as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(
+            ts_us.iter().collect::<Vec<_>>(),
+            vec![Some(1_641_038_400_123_456), None, Some(-1)]
+        );
+        let payloads = batch.column(7).as_binary::<i32>();
+        assert_eq!(
+            payloads.iter().collect::<Vec<_>>(),
+            vec![Some(&[1u8, 2][..]), None, Some(&[][..])]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_avro_projection_in_batches() -> crate::Result<()> {
+        let fields = fixture_fields();
+        // a timestamp of millisecond precision read as microseconds
+        let projection = vec![
+            fields[1].clone(),
+            fields[5]
+                .clone()
+                .with_data_type(DataType::Timestamp(TimestampType::new(6).unwrap())),
+        ];
+        let batches = AvroReader::new(&fixture_bytes(), projection)?
+            .with_batch_size(2)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        assert_eq!(batches[0].num_columns(), 2);
+        assert_eq!(batches[1].column(0).as_string::<i32>().value(0), "cherry");
+        let ts = batches[0]
+            .column(1)
+            .as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(ts.value(0), 1_641_038_400_123_000);
+        assert!(ts.is_null(1));
+
+        let mismatch = vec![fields[1]
+            .clone()
+            .with_data_type(DataType::Int(IntType::new()))];
+        let result = AvroReader::new(&fixture_bytes(), mismatch)?.next().unwrap();
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_time_unit() {
+        let (ms, us, ns) = (
+            TimeUnit::Millisecond,
+            TimeUnit::Microsecond,
+            TimeUnit::Nanosecond,
+        );
+        assert_eq!(convert_time_unit(1_001, &ms, &us), Some(1_001_000));
+        assert_eq!(convert_time_unit(1_001_999, &us, &ms), Some(1_001));
+        // finer digits are truncated towards the past
+        assert_eq!(convert_time_unit(-1, &us, &ms), Some(-1));
+        assert_eq!(
+            convert_time_unit(1_704_112_496_123_456_789, &ns, &ns),
+            Some(1_704_112_496_123_456_789)
+        );
+        assert_eq!(convert_time_unit(i64::MAX / 1_000, &ms, &ns), None);
+    }
+}
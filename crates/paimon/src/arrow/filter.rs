@@ -0,0 +1,319 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow_arith::boolean::{and_kleene, is_not_null, is_null, not, or_kleene};
+use arrow_array::{
+    new_null_array, Array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Decimal128Array,
+    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, RecordBatch, Scalar,
+    StringArray, Time32MillisecondArray, TimestampMicrosecondArray, TimestampMillisecondArray,
+    TimestampNanosecondArray, TimestampSecondArray,
+};
+use arrow_ord::cmp;
+use arrow_schema::{DataType as ArrowDataType, TimeUnit};
+use arrow_select::filter::{filter_record_batch, prep_null_mask_filter};
+
+use crate::arrow::{storage_to_timestamp, timestamp_to_storage, unit_precision};
+use crate::predicate::{LeafPredicate, Predicate, PredicateOperator};
+use crate::spec::{Literal, RowType};
+use crate::Error;
+
+/// Keep the rows of the batch matching the predicate.
+///
+/// The columns of `batch` are the fields of `row_type`, usually a projection of the table
+/// fields. The predicate is evaluated with Arrow kernels, comparisons with null are unknown and
+/// the rows are only kept if the result is true.
+///
+/// Returns [`Error::PredicateInvalid`] if the predicate references a field not in `row_type`, and
+/// [`Error::PredicateTypeMismatch`] if it compares a field with a literal of another type.
+pub fn apply_predicate_to_batch(
+    batch: &RecordBatch,
+    predicate: &Predicate,
+    row_type: &RowType,
+) -> crate::Result<RecordBatch> {
+    if batch.num_columns() != row_type.fields().len() {
+        return Err(Error::PredicateInvalid {
+            message: format!(
+                "Batch has {} columns, but the row type has {} fields",
+                batch.num_columns(),
+                row_type.fields().len()
+            ),
+        });
+    }
+    let mask = predicate_mask(batch, predicate)?;
+    Ok(filter_record_batch(batch, &mask)?)
+}
+
+/// Evaluate the predicate on the rows of the batch with Arrow kernels, the mask is true for the
+/// rows matching the predicate and false otherwise, including the unknown results.
+///
+/// Columns are looked up by field name, a field missing from the batch fails with
+/// [`Error::PredicateInvalid`].
+pub(crate) fn predicate_mask(
+    batch: &RecordBatch,
+    predicate: &Predicate,
+) -> crate::Result<BooleanArray> {
+    let mask = evaluate(batch, predicate)?;
+    if mask.null_count() == 0 {
+        Ok(mask)
+    } else {
+        Ok(prep_null_mask_filter(&mask))
+    }
+}
+
+fn evaluate(batch: &RecordBatch, predicate: &Predicate) -> crate::Result<BooleanArray> {
+    match predicate {
+        Predicate::Leaf(leaf) => {
+            let Some(column) = batch.column_by_name(leaf.field_name()) else {
+                return Err(Error::PredicateInvalid {
+                    message: format!(
+                        "Field '{}' of the predicate is not projected",
+                        leaf.field_name()
+                    ),
+                });
+            };
+            evaluate_leaf(column, leaf)
+        }
+        Predicate::And(children) => {
+            let mut result = BooleanArray::from(vec![true; batch.num_rows()]);
+            for child in children {
+                result = and_kleene(&result, &evaluate(batch, child)?)?;
+            }
+            Ok(result)
+        }
+        Predicate::Or(children) => {
+            let mut result = BooleanArray::from(vec![false; batch.num_rows()]);
+            for child in children {
+                result = or_kleene(&result, &evaluate(batch, child)?)?;
+            }
+            Ok(result)
+        }
+    }
+}
+
+fn evaluate_leaf(column: &ArrayRef, leaf: &LeafPredicate) -> crate::Result<BooleanArray> {
+    leaf.check_literal_types()?;
+    let scalar = |literal: &Literal| -> crate::Result<Scalar<ArrayRef>> {
+        Ok(Scalar::new(literal_array(
+            literal,
+            column.data_type(),
+            leaf.field_name(),
+        )?))
+    };
+    let literal = leaf.literals().first().unwrap_or(&Literal::Null);
+    let result = match leaf.op() {
+        PredicateOperator::IsNull => is_null(column)?,
+        PredicateOperator::IsNotNull => is_not_null(column)?,
+        PredicateOperator::Equal => cmp::eq(column, &scalar(literal)?)?,
+        PredicateOperator::NotEqual => cmp::neq(column, &scalar(literal)?)?,
+        PredicateOperator::LessThan => cmp::lt(column, &scalar(literal)?)?,
+        PredicateOperator::LessOrEqual => cmp::lt_eq(column, &scalar(literal)?)?,
+        PredicateOperator::GreaterThan => cmp::gt(column, &scalar(literal)?)?,
+        PredicateOperator::GreaterOrEqual => cmp::gt_eq(column, &scalar(literal)?)?,
+        PredicateOperator::In | PredicateOperator::NotIn => {
+            let mut found = BooleanArray::from(vec![false; column.len()]);
+            for literal in leaf.literals() {
+                found = or_kleene(&found, &cmp::eq(column, &scalar(literal)?)?)?;
+            }
+            if leaf.op() == PredicateOperator::In {
+                found
+            } else {
+                not(&found)?
+            }
+        }
+    };
+    Ok(result)
+}
+
+/// Build a single value array of the literal, in the Arrow type of the column it is compared to.
+pub(crate) fn literal_array(
+    literal: &Literal,
+    data_type: &ArrowDataType,
+    field_name: &str,
+) -> crate::Result<ArrayRef> {
+    let array: ArrayRef = match (literal, data_type) {
+        (Literal::Null, _) => new_null_array(data_type, 1),
+        (Literal::Boolean(v), ArrowDataType::Boolean) => Arc::new(BooleanArray::from(vec![*v])),
+        (Literal::TinyInt(v), ArrowDataType::Int8) => Arc::new(Int8Array::from(vec![*v])),
+        (Literal::SmallInt(v), ArrowDataType::Int16) => Arc::new(Int16Array::from(vec![*v])),
+        (Literal::Int(v), ArrowDataType::Int32) => Arc::new(Int32Array::from(vec![*v])),
+        (Literal::BigInt(v), ArrowDataType::Int64) => Arc::new(Int64Array::from(vec![*v])),
+        (Literal::Float(v), ArrowDataType::Float32) => Arc::new(Float32Array::from(vec![*v])),
+        (Literal::Double(v), ArrowDataType::Float64) => Arc::new(Float64Array::from(vec![*v])),
+        (
+            Literal::Decimal {
+                unscaled, scale, ..
+            },
+            ArrowDataType::Decimal128(precision, column_scale),
+        ) => {
+            let unscaled = rescale(*unscaled, *scale as i32, *column_scale as i32)
+                .ok_or_else(|| mismatch(literal, data_type, field_name))?;
+            Arc::new(
+                Decimal128Array::from(vec![unscaled])
+                    .with_precision_and_scale(*precision, *column_scale)?,
+            )
+        }
+        (Literal::String(v), ArrowDataType::Utf8) => Arc::new(StringArray::from(vec![v.as_str()])),
+        (Literal::Binary(v), ArrowDataType::Binary) => {
+            Arc::new(BinaryArray::from(vec![v.as_slice()]))
+        }
+        (Literal::Date(v), ArrowDataType::Date32) => Arc::new(Date32Array::from(vec![*v])),
+        (Literal::Time(v), ArrowDataType::Time32(TimeUnit::Millisecond)) => {
+            Arc::new(Time32MillisecondArray::from(vec![*v]))
+        }
+        (Literal::Timestamp { millis, nanos }, ArrowDataType::Timestamp(unit, None))
+        | (
+            Literal::LocalZonedTimestamp { millis, nanos },
+            ArrowDataType::Timestamp(unit, Some(_)),
+        ) => {
+            // the literal must be exactly representable in the unit of the column
+            let precision = unit_precision(unit);
+            let value = Literal::timestamp(*millis, *nanos)
+                .and_then(|ts| {
+                    let value = timestamp_to_storage(&ts, precision)?;
+                    (storage_to_timestamp(value, precision)? == ts).then_some(value)
+                })
+                .ok_or_else(|| mismatch(literal, data_type, field_name))?;
+            let ArrowDataType::Timestamp(_, time_zone) = data_type else {
+                unreachable!()
+            };
+            match unit {
+                TimeUnit::Second => Arc::new(
+                    TimestampSecondArray::from(vec![value]).with_timezone_opt(time_zone.clone()),
+                ),
+                TimeUnit::Millisecond => Arc::new(
+                    TimestampMillisecondArray::from(vec![value])
+                        .with_timezone_opt(time_zone.clone()),
+                ),
+                TimeUnit::Microsecond => Arc::new(
+                    TimestampMicrosecondArray::from(vec![value])
+                        .with_timezone_opt(time_zone.clone()),
+                ),
+                TimeUnit::Nanosecond => Arc::new(
+                    TimestampNanosecondArray::from(vec![value])
+                        .with_timezone_opt(time_zone.clone()),
+                ),
+            }
+        }
+        _ => return Err(mismatch(literal, data_type, field_name)),
+    };
+    Ok(array)
+}
+
+/// Rescale an unscaled decimal value, `None` if digits would be lost or it overflows.
+fn rescale(unscaled: i128, scale: i32, target_scale: i32) -> Option<i128> {
+    if target_scale >= scale {
+        unscaled.checked_mul(10i128.checked_pow((target_scale - scale) as u32)?)
+    } else {
+        let divisor = 10i128.checked_pow((scale - target_scale) as u32)?;
+        (unscaled % divisor == 0).then(|| unscaled / divisor)
+    }
+}
+
+fn mismatch(literal: &Literal, data_type: &ArrowDataType, field_name: &str) -> Error {
+    Error::PredicateInvalid {
+        message: format!(
+            "Literal {:?} cannot be compared with field '{}' of Arrow type {}",
+            literal, field_name, data_type
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::predicate::PredicateBuilder;
+    use crate::spec::{DataField, DataType, IntType, VarCharType};
+
+    fn row_type() -> RowType {
+        RowType::new(vec![
+            DataField::new(0, "id".to_string(), DataType::Int(IntType::new())),
+            DataField::new(
+                1,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+        ])
+    }
+
+    fn batch() -> RecordBatch {
+        let ids: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(5),
+            None,
+            Some(7),
+            Some(10),
+        ]));
+        let names: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e"]));
+        RecordBatch::try_from_iter([("id", ids), ("name", names)]).unwrap()
+    }
+
+    #[test]
+    fn test_apply_range_predicate() -> crate::Result<()> {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let predicate = PredicateBuilder::and(vec![
+            builder.greater_or_equal(0, Literal::Int(5)),
+            builder.less_than(0, Literal::Int(10)),
+        ]);
+        let filtered = apply_predicate_to_batch(&batch(), &predicate, &row_type())?;
+        assert_eq!(
+            filtered.column(0).as_ref(),
+            &Int32Array::from(vec![5, 7]) as &dyn Array
+        );
+        assert_eq!(
+            filtered.column(1).as_ref(),
+            &StringArray::from(vec!["b", "d"]) as &dyn Array
+        );
+
+        // the null id is unknown, but the OR is true on the name
+        let predicate = PredicateBuilder::or(vec![
+            builder.is_in(0, vec![Literal::Int(1), Literal::Int(10)]),
+            builder.equal(1, Literal::String("c".to_string())),
+        ]);
+        let filtered = apply_predicate_to_batch(&batch(), &predicate, &row_type())?;
+        assert_eq!(
+            filtered.column(1).as_ref(),
+            &StringArray::from(vec!["a", "c", "e"]) as &dyn Array
+        );
+
+        let predicate = builder.is_not_in(0, vec![Literal::Int(1), Literal::Null]);
+        let filtered = apply_predicate_to_batch(&batch(), &predicate, &row_type())?;
+        assert_eq!(filtered.num_rows(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_invalid_predicate() {
+        let projected = RowType::new(row_type().fields()[..1].to_vec());
+        let ids: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let batch = RecordBatch::try_from_iter([("id", ids)]).unwrap();
+        let builder = PredicateBuilder::new(row_type().fields());
+
+        let predicate = builder.equal(1, Literal::String("a".to_string()));
+        assert!(matches!(
+            apply_predicate_to_batch(&batch, &predicate, &projected),
+            Err(Error::PredicateInvalid { message }) if message.contains("'name'")
+        ));
+        let predicate = builder.equal(0, Literal::BigInt(1));
+        assert!(matches!(
+            apply_predicate_to_batch(&batch, &predicate, &projected),
+            Err(Error::PredicateTypeMismatch { field, .. }) if field == "id"
+        ));
+    }
+}
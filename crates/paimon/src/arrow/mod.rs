@@ -0,0 +1,325 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of Paimon types and predicates to Arrow, used to read and write data files.
+
+mod avro_reader;
+pub use avro_reader::*;
+
+mod filter;
+pub use filter::*;
+
+mod row_position_filter;
+pub use row_position_filter::*;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Date32Type, Decimal128Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    Int8Type, Time32MillisecondType, TimestampMicrosecondType, TimestampMillisecondType,
+    TimestampNanosecondType, TimestampSecondType,
+};
+use arrow_array::Array;
+use arrow_schema::{DataType as ArrowDataType, Field, Schema, TimeUnit};
+use chrono::{DateTime, NaiveDateTime, Timelike};
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+
+use crate::spec::{DataField, DataType, Literal};
+use crate::Error;
+
+/// Time zone of the Arrow timestamps converted from `TIMESTAMP WITH LOCAL TIME ZONE` values.
+const LOCAL_ZONED_TIMESTAMP_TIME_ZONE: &str = "UTC";
+
+/// Convert a Paimon data type to an Arrow data type.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-arrow/src/main/java/org/apache/paimon/arrow/ArrowUtils.java>
+pub fn to_arrow_type(data_type: &DataType) -> crate::Result<ArrowDataType> {
+    let arrow_type = match data_type {
+        DataType::Boolean(_) => ArrowDataType::Boolean,
+        DataType::TinyInt(_) => ArrowDataType::Int8,
+        DataType::SmallInt(_) => ArrowDataType::Int16,
+        DataType::Int(_) => ArrowDataType::Int32,
+        DataType::BigInt(_) => ArrowDataType::Int64,
+        DataType::Float(_) => ArrowDataType::Float32,
+        DataType::Double(_) => ArrowDataType::Float64,
+        DataType::Decimal(v) => ArrowDataType::Decimal128(v.precision() as u8, v.scale() as i8),
+        DataType::Char(_) | DataType::VarChar(_) => ArrowDataType::Utf8,
+        DataType::Binary(_) | DataType::VarBinary(_) => ArrowDataType::Binary,
+        DataType::Date(_) => ArrowDataType::Date32,
+        DataType::Time(_) => ArrowDataType::Time32(TimeUnit::Millisecond),
+        DataType::Timestamp(v) => ArrowDataType::Timestamp(time_unit(v.precision()), None),
+        DataType::LocalZonedTimestamp(v) => ArrowDataType::Timestamp(
+            time_unit(v.precision()),
+            Some(LOCAL_ZONED_TIMESTAMP_TIME_ZONE.into()),
+        ),
+        DataType::Array(_) | DataType::Map(_) | DataType::Multiset(_) | DataType::Row(_) => {
+            return Err(Error::DataTypeInvalid {
+                message: format!(
+                    "Converting {:?} to an Arrow type is not supported",
+                    data_type
+                ),
+            })
+        }
+    };
+    Ok(arrow_type)
+}
+
+fn time_unit(precision: u32) -> TimeUnit {
+    match precision {
+        0 => TimeUnit::Second,
+        1..=3 => TimeUnit::Millisecond,
+        4..=6 => TimeUnit::Microsecond,
+        _ => TimeUnit::Nanosecond,
+    }
+}
+
+/// Get the highest precision of timestamps stored in a time unit.
+fn unit_precision(unit: &TimeUnit) -> u32 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 3,
+        TimeUnit::Microsecond => 6,
+        TimeUnit::Nanosecond => 9,
+    }
+}
+
+/// Convert a timestamp to the epoch value stored in data files for the given precision: seconds,
+/// milliseconds, microseconds or nanoseconds, finer digits than the precision are truncated.
+///
+/// Returns `None` if the value overflows, which only happens with nanoseconds outside of the
+/// years 1677 to 2262.
+pub fn timestamp_to_storage(dt: &NaiveDateTime, precision: u32) -> Option<i64> {
+    let dt = dt.and_utc();
+    match time_unit(precision) {
+        TimeUnit::Second => Some(dt.timestamp()),
+        TimeUnit::Millisecond => Some(dt.timestamp_millis()),
+        TimeUnit::Microsecond => Some(dt.timestamp_micros()),
+        TimeUnit::Nanosecond => dt.timestamp_nanos_opt(),
+    }
+}
+
+/// Convert an epoch value stored in data files for the given precision back to a timestamp.
+///
+/// Returns `None` if the value is out of the range of timestamps.
+pub fn storage_to_timestamp(value: i64, precision: u32) -> Option<NaiveDateTime> {
+    let dt = match time_unit(precision) {
+        TimeUnit::Second => DateTime::from_timestamp(value, 0),
+        TimeUnit::Millisecond => DateTime::from_timestamp_millis(value),
+        TimeUnit::Microsecond => DateTime::from_timestamp_micros(value),
+        TimeUnit::Nanosecond => Some(DateTime::from_timestamp_nanos(value)),
+    };
+    dt.map(|dt| dt.naive_utc())
+}
+
+/// Convert the fields of a table to an Arrow schema.
+///
+/// The id of each field is kept in the `PARQUET:field_id` metadata of the Arrow field, so that
+/// it is written into parquet files.
+pub fn to_arrow_schema(fields: &[DataField]) -> crate::Result<Schema> {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let arrow_field = Field::new(
+                field.name(),
+                to_arrow_type(field.data_type())?,
+                field.data_type().is_nullable(),
+            );
+            Ok(arrow_field.with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                field.id().to_string(),
+            )])))
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
+    Ok(Schema::new(
+        fields.into_iter().map(Arc::new).collect::<Vec<_>>(),
+    ))
+}
+
+/// Get the value at `row` of an Arrow array as a [`Literal`].
+///
+/// Only the Arrow types converted from Paimon types by [`to_arrow_type`] are supported.
+pub fn to_literal(array: &dyn Array, row: usize) -> crate::Result<Literal> {
+    if array.is_null(row) {
+        return Ok(Literal::Null);
+    }
+
+    let literal = match array.data_type() {
+        ArrowDataType::Boolean => Literal::Boolean(array.as_boolean().value(row)),
+        ArrowDataType::Int8 => Literal::TinyInt(array.as_primitive::<Int8Type>().value(row)),
+        ArrowDataType::Int16 => Literal::SmallInt(array.as_primitive::<Int16Type>().value(row)),
+        ArrowDataType::Int32 => Literal::Int(array.as_primitive::<Int32Type>().value(row)),
+        ArrowDataType::Int64 => Literal::BigInt(array.as_primitive::<Int64Type>().value(row)),
+        ArrowDataType::Float32 => Literal::Float(array.as_primitive::<Float32Type>().value(row)),
+        ArrowDataType::Float64 => Literal::Double(array.as_primitive::<Float64Type>().value(row)),
+        ArrowDataType::Decimal128(precision, scale) => Literal::Decimal {
+            unscaled: array.as_primitive::<Decimal128Type>().value(row),
+            precision: *precision as u32,
+            scale: (*scale).max(0) as u32,
+        },
+        ArrowDataType::Utf8 => Literal::String(array.as_string::<i32>().value(row).to_string()),
+        ArrowDataType::Binary => Literal::Binary(array.as_binary::<i32>().value(row).to_vec()),
+        ArrowDataType::Date32 => Literal::Date(array.as_primitive::<Date32Type>().value(row)),
+        ArrowDataType::Time32(TimeUnit::Millisecond) => {
+            Literal::Time(array.as_primitive::<Time32MillisecondType>().value(row))
+        }
+        ArrowDataType::Timestamp(unit, time_zone) => {
+            let value = match unit {
+                TimeUnit::Second => array.as_primitive::<TimestampSecondType>().value(row),
+                TimeUnit::Millisecond => {
+                    array.as_primitive::<TimestampMillisecondType>().value(row)
+                }
+                TimeUnit::Microsecond => {
+                    array.as_primitive::<TimestampMicrosecondType>().value(row)
+                }
+                TimeUnit::Nanosecond => array.as_primitive::<TimestampNanosecondType>().value(row),
+            };
+            let ts = storage_to_timestamp(value, unit_precision(unit)).ok_or_else(|| {
+                Error::DataTypeInvalid {
+                    message: format!("Timestamp {} in {:?} is out of range", value, unit),
+                }
+            })?;
+            let millis = ts.and_utc().timestamp_millis();
+            let nanos = (ts.nanosecond() % 1_000_000) as i32;
+            match time_zone {
+                None => Literal::Timestamp { millis, nanos },
+                Some(_) => Literal::LocalZonedTimestamp { millis, nanos },
+            }
+        }
+        other => {
+            return Err(Error::DataTypeInvalid {
+                message: format!("Converting Arrow {} values is not supported", other),
+            })
+        }
+    };
+    Ok(literal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{ArrayType, IntType, LocalZonedTimestampType, TimestampType, VarCharType};
+
+    #[test]
+    fn test_to_arrow_schema() {
+        let fields = vec![
+            DataField::new(
+                0,
+                "id".to_string(),
+                DataType::Int(IntType::with_nullable(false)),
+            ),
+            DataField::new(
+                3,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+            DataField::new(
+                4,
+                "ts".to_string(),
+                DataType::Timestamp(TimestampType::new(6).unwrap()),
+            ),
+            DataField::new(
+                5,
+                "lts".to_string(),
+                DataType::LocalZonedTimestamp(LocalZonedTimestampType::new(3).unwrap()),
+            ),
+        ];
+        let schema = to_arrow_schema(&fields).unwrap();
+
+        assert_eq!(schema.fields().len(), 4);
+        assert_eq!(schema.field(0).data_type(), &ArrowDataType::Int32);
+        assert!(!schema.field(0).is_nullable());
+        assert_eq!(
+            schema.field(1).metadata().get(PARQUET_FIELD_ID_META_KEY),
+            Some(&"3".to_string())
+        );
+        assert!(schema.field(1).is_nullable());
+        assert_eq!(
+            schema.field(2).data_type(),
+            &ArrowDataType::Timestamp(TimeUnit::Microsecond, None)
+        );
+        assert_eq!(
+            schema.field(3).data_type(),
+            &ArrowDataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()))
+        );
+
+        let array = DataType::Array(ArrayType::new(DataType::Int(IntType::new())));
+        assert!(matches!(
+            to_arrow_type(&array),
+            Err(Error::DataTypeInvalid { .. })
+        ));
+    }
+
+    #[test]
+    fn test_to_literal() {
+        use arrow_array::{Int32Array, StringArray, TimestampMicrosecondArray};
+
+        let ints = Int32Array::from(vec![Some(1), None]);
+        assert_eq!(to_literal(&ints, 0).unwrap(), Literal::Int(1));
+        assert_eq!(to_literal(&ints, 1).unwrap(), Literal::Null);
+
+        let strings = StringArray::from(vec!["abc"]);
+        assert_eq!(
+            to_literal(&strings, 0).unwrap(),
+            Literal::String("abc".to_string())
+        );
+
+        let timestamps = TimestampMicrosecondArray::from(vec![-1, 1_001_002]);
+        assert_eq!(
+            to_literal(&timestamps, 0).unwrap(),
+            Literal::Timestamp {
+                millis: -1,
+                nanos: 999_000
+            }
+        );
+        assert_eq!(
+            to_literal(&timestamps, 1).unwrap(),
+            Literal::Timestamp {
+                millis: 1_001,
+                nanos: 2_000
+            }
+        );
+    }
+
+    #[test]
+    fn test_timestamp_storage_round_trip() {
+        let nanos =
+            NaiveDateTime::parse_from_str("2024-01-01 12:34:56.123456789", "%Y-%m-%d %H:%M:%S%.f")
+                .unwrap();
+        let value = timestamp_to_storage(&nanos, 9).unwrap();
+        assert_eq!(value, 1_704_112_496_123_456_789);
+        assert_eq!(storage_to_timestamp(value, 9), Some(nanos));
+
+        // digits beyond the precision are truncated
+        assert_eq!(timestamp_to_storage(&nanos, 6), Some(1_704_112_496_123_456));
+        let millis = timestamp_to_storage(&nanos, 3).unwrap();
+        assert_eq!(millis, 1_704_112_496_123);
+        assert_eq!(
+            storage_to_timestamp(millis, 3),
+            Some(nanos.with_nanosecond(123_000_000).unwrap())
+        );
+
+        let far =
+            NaiveDateTime::parse_from_str("2300-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(timestamp_to_storage(&far, 9), None);
+        assert_eq!(
+            storage_to_timestamp(timestamp_to_storage(&far, 3).unwrap(), 3),
+            Some(far)
+        );
+    }
+}
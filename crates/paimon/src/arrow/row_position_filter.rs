@@ -0,0 +1,104 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_array::builder::BooleanBufferBuilder;
+use arrow_array::BooleanArray;
+use roaring::{RoaringBitmap, RoaringTreemap};
+
+/// Set of row positions in a data file, like the deleted rows of a
+/// [`DeletionVector`](crate::deletion_vector::DeletionVector) or the rows selected by a
+/// [`BitmapFileIndexReader`](crate::file_index::BitmapFileIndexReader), converted to Arrow masks
+/// to filter the rows read.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RowPositionFilter {
+    positions: RoaringTreemap,
+}
+
+impl RowPositionFilter {
+    pub fn new(positions: RoaringTreemap) -> Self {
+        Self { positions }
+    }
+
+    /// Get the row positions in the set.
+    #[inline]
+    pub fn positions(&self) -> &RoaringTreemap {
+        &self.positions
+    }
+
+    /// Mask of the first `total_rows` rows, true for the rows not in the set, to keep the rows
+    /// not deleted.
+    pub fn keep_mask(&self, total_rows: u64) -> BooleanArray {
+        self.mask(total_rows, false)
+    }
+
+    /// Mask of the first `total_rows` rows, true for the rows in the set, to keep the rows
+    /// selected.
+    pub fn selected_mask(&self, total_rows: u64) -> BooleanArray {
+        self.mask(total_rows, true)
+    }
+
+    fn mask(&self, total_rows: u64, in_set: bool) -> BooleanArray {
+        let mut builder = BooleanBufferBuilder::new(total_rows as usize);
+        builder.append_n(total_rows as usize, !in_set);
+        for position in self.positions.iter().take_while(|p| *p < total_rows) {
+            builder.set_bit(position as usize, in_set);
+        }
+        BooleanArray::new(builder.finish(), None)
+    }
+}
+
+impl From<RoaringTreemap> for RowPositionFilter {
+    fn from(positions: RoaringTreemap) -> Self {
+        Self::new(positions)
+    }
+}
+
+impl From<RoaringBitmap> for RowPositionFilter {
+    fn from(positions: RoaringBitmap) -> Self {
+        Self::new(RoaringTreemap::from_bitmaps([(0, positions)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masks() {
+        let filter = RowPositionFilter::new(RoaringTreemap::from_iter([1, 3, 4, 9]));
+        assert_eq!(
+            filter.keep_mask(6),
+            BooleanArray::from(vec![true, false, true, false, false, true])
+        );
+        assert_eq!(
+            filter.selected_mask(6),
+            BooleanArray::from(vec![false, true, false, true, true, false])
+        );
+        assert_eq!(filter.keep_mask(0).len(), 0);
+
+        let filter = RowPositionFilter::from(RoaringBitmap::from_iter([0, 2]));
+        assert_eq!(filter.positions().len(), 2);
+        assert_eq!(
+            filter.keep_mask(3),
+            BooleanArray::from(vec![false, true, false])
+        );
+        assert_eq!(
+            RowPositionFilter::default().selected_mask(2),
+            BooleanArray::from(vec![false, false])
+        );
+    }
+}
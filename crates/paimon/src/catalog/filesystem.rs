@@ -0,0 +1,322 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+
+use crate::catalog::{Catalog, Identifier};
+use crate::io::{FileIO, FileStatus};
+use crate::table::{FileStoreTable, PathFactory, SchemaCache};
+use crate::Error;
+
+/// Catalog storing the databases and the tables as directories of a warehouse, a database
+/// `my_db` being the directory `my_db.db` and its table `my_table` the directory
+/// `my_db.db/my_table`.
+///
+/// The tables got from the catalog have the options of their schema, overridden by the table
+/// options of the catalog, see [`FileSystemCatalog::with_table_options`].
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/catalog/FileSystemCatalog.java>
+#[derive(Debug, Clone)]
+pub struct FileSystemCatalog {
+    file_io: FileIO,
+    warehouse: String,
+    table_options: HashMap<String, String>,
+}
+
+impl FileSystemCatalog {
+    /// Suffix of the directory of a database.
+    pub const DB_SUFFIX: &'static str = ".db";
+
+    pub fn new(file_io: FileIO, warehouse: impl ToString) -> Self {
+        Self {
+            file_io,
+            warehouse: warehouse.to_string().trim_end_matches('/').to_string(),
+            table_options: HashMap::new(),
+        }
+    }
+
+    /// Set options applied to all tables got from the catalog, overriding the options of their
+    /// schema with the same keys. The schema files are not changed.
+    pub fn with_table_options(mut self, table_options: HashMap<String, String>) -> Self {
+        self.table_options = table_options;
+        self
+    }
+
+    /// Get the root directory of the warehouse.
+    pub fn warehouse(&self) -> &str {
+        &self.warehouse
+    }
+
+    /// Get the directory of the database with the given name.
+    pub fn database_path(&self, database: &str) -> String {
+        format!("{}/{}{}", self.warehouse, database, Self::DB_SUFFIX)
+    }
+
+    /// Get the directory of the table with the given identifier.
+    pub fn table_path(&self, identifier: &Identifier) -> String {
+        format!(
+            "{}/{}",
+            self.database_path(identifier.database_name()),
+            identifier.table_name()
+        )
+    }
+
+    /// Get the name of a listed directory, without its trailing `/`.
+    fn dir_name(status: &FileStatus) -> Option<&str> {
+        if !status.is_dir {
+            return None;
+        }
+        status.path.trim_end_matches('/').rsplit('/').next()
+    }
+
+    async fn check_database_exists(&self, database: &str) -> crate::Result<()> {
+        if !self.database_exists(database).await? {
+            return Err(Error::DatabaseNotExist {
+                message: format!("Database '{}' does not exist", database),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Catalog for FileSystemCatalog {
+    async fn list_databases(&self) -> crate::Result<Vec<String>> {
+        let dir = format!("{}/", self.warehouse);
+        if !self.file_io.exists(&dir).await? {
+            return Ok(vec![]);
+        }
+        let mut databases = self
+            .file_io
+            .list_status(&dir)
+            .await?
+            .iter()
+            .filter_map(|status| Self::dir_name(status)?.strip_suffix(Self::DB_SUFFIX))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        databases.sort_unstable();
+        Ok(databases)
+    }
+
+    async fn database_exists(&self, database: &str) -> crate::Result<bool> {
+        self.file_io
+            .exists(&format!("{}/", self.database_path(database)))
+            .await
+    }
+
+    async fn list_tables(&self, database: &str) -> crate::Result<Vec<String>> {
+        let tables = self.list_tables_stream(database).await?;
+        let mut tables: Vec<String> = tables.try_collect().await?;
+        tables.sort_unstable();
+        Ok(tables)
+    }
+
+    /// The directories of the database are listed as they are consumed, each one being a table
+    /// if it holds a schema directory.
+    async fn list_tables_stream(
+        &self,
+        database: &str,
+    ) -> crate::Result<BoxStream<'static, crate::Result<String>>> {
+        self.check_database_exists(database).await?;
+        let file_io = self.file_io.clone();
+        let tables = self
+            .file_io
+            .list_status_stream(&format!("{}/", self.database_path(database)))
+            .await?
+            .try_filter_map(move |status| {
+                let file_io = file_io.clone();
+                async move {
+                    let Some(name) = Self::dir_name(&status) else {
+                        return Ok(None);
+                    };
+                    let schema_dir =
+                        PathFactory::new(status.path.trim_end_matches('/')).schema_dir();
+                    if file_io.exists(&format!("{}/", schema_dir)).await? {
+                        Ok(Some(name.to_string()))
+                    } else {
+                        Ok(None)
+                    }
+                }
+            });
+        Ok(tables.boxed())
+    }
+
+    async fn get_table(&self, identifier: &Identifier) -> crate::Result<FileStoreTable> {
+        let table_path = self.table_path(identifier);
+        let schema = SchemaCache::new(self.file_io.clone(), &table_path)
+            .latest_schema()
+            .await?
+            .ok_or_else(|| Error::TableNotExist {
+                message: format!("Table '{}' does not exist", identifier.full_name()),
+            })?;
+        let table = FileStoreTable::new(self.file_io.clone(), table_path, schema.as_ref().clone());
+        Ok(table.copy(self.table_options.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::spec::{DataField, DataType, IntType, TableSchema};
+
+    async fn create_table(catalog: &FileSystemCatalog, identifier: &Identifier) {
+        create_table_with_options(catalog, identifier, HashMap::new()).await
+    }
+
+    async fn create_table_with_options(
+        catalog: &FileSystemCatalog,
+        identifier: &Identifier,
+        options: HashMap<String, String>,
+    ) {
+        let fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        let schema = TableSchema::new(0, fields, 0, vec![], vec![], options, None);
+        let path = PathFactory::new(catalog.table_path(identifier)).schema_path(0);
+        catalog
+            .file_io
+            .new_output(&path)
+            .unwrap()
+            .write(Bytes::from(serde_json::to_vec(&schema).unwrap()))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_tables_stream() -> crate::Result<()> {
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let catalog = FileSystemCatalog::new(file_io, "memory:/tmp/test_list_tables_stream/");
+        for i in 0..500 {
+            create_table(&catalog, &Identifier::new("big", format!("t{:03}", i))).await;
+        }
+        create_table(&catalog, &Identifier::new("small", "t")).await;
+        // a directory without schema is not a table
+        let stray = format!("{}/stray/data-0.orc", catalog.database_path("big"));
+        catalog
+            .file_io
+            .new_output(&stray)?
+            .write(Bytes::new())
+            .await?;
+
+        assert_eq!(catalog.list_databases().await?, vec!["big", "small"]);
+
+        let mut tables = catalog
+            .list_tables_stream("big")
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        tables.sort_unstable();
+        let expected = (0..500).map(|i| format!("t{:03}", i)).collect::<Vec<_>>();
+        assert_eq!(tables, expected);
+        assert_eq!(catalog.list_tables("big").await?, expected);
+        assert_eq!(catalog.list_tables("small").await?, vec!["t"]);
+
+        let result = catalog.list_tables_stream("missing").await;
+        assert!(matches!(result, Err(Error::DatabaseNotExist { .. })));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_table() -> crate::Result<()> {
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let catalog = FileSystemCatalog::new(file_io, "memory:/tmp/test_get_table");
+        let identifier = Identifier::new("db", "t");
+        create_table(&catalog, &identifier).await;
+
+        let table = catalog.get_table(&identifier).await?;
+        assert_eq!(table.location(), "memory:/tmp/test_get_table/db.db/t");
+        assert_eq!(table.schema().fields().len(), 1);
+
+        let result = catalog.get_table(&Identifier::new("db", "missing")).await;
+        assert!(result.unwrap_err().is_not_found());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_table_options_precedence() -> crate::Result<()> {
+        let options = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<_, _>>()
+        };
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let catalog = FileSystemCatalog::new(file_io, "memory:/tmp/test_table_options_precedence")
+            .with_table_options(options(&[
+                ("file.format", "parquet"),
+                ("bucket", "2"),
+                ("catalog-only", "c"),
+            ]));
+        let identifier = Identifier::new("db", "t");
+        create_table_with_options(
+            &catalog,
+            &identifier,
+            options(&[
+                ("file.format", "orc"),
+                ("bucket", "1"),
+                ("schema-only", "s"),
+            ]),
+        )
+        .await;
+
+        // catalog > schema
+        let table = catalog.get_table(&identifier).await?;
+        assert_eq!(
+            table.schema().options(),
+            &options(&[
+                ("file.format", "parquet"),
+                ("bucket", "2"),
+                ("catalog-only", "c"),
+                ("schema-only", "s"),
+            ])
+        );
+
+        // dynamic > catalog > schema
+        let table = table.copy(options(&[("bucket", "4"), ("schema-only", "d")]));
+        let core_options = table.options();
+        assert_eq!(core_options.bucket()?, 4);
+        assert_eq!(core_options.file_format(), "parquet");
+        assert_eq!(
+            table.schema().options(),
+            &options(&[
+                ("file.format", "parquet"),
+                ("bucket", "4"),
+                ("catalog-only", "c"),
+                ("schema-only", "d"),
+            ])
+        );
+
+        // the schema file is unchanged
+        let schema = SchemaCache::new(catalog.file_io.clone(), catalog.table_path(&identifier))
+            .latest_schema()
+            .await?
+            .unwrap();
+        assert_eq!(schema.options().get("bucket").unwrap(), "1");
+        Ok(())
+    }
+}
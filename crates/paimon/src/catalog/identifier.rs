@@ -0,0 +1,279 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::{Display, Formatter};
+
+use crate::catalog::SYSTEM_DATABASE_NAME;
+use crate::Error;
+
+/// Splitter between a table name and a system table name, like `my_table$snapshots`.
+pub const SYSTEM_TABLE_SPLITTER: &str = "$";
+
+/// Prefix of a branch name in an object name, like `my_table$branch_b1`.
+pub const SYSTEM_BRANCH_PREFIX: &str = "branch_";
+
+/// Database name used when an identifier is created without a database.
+pub const UNKNOWN_DATABASE: &str = "unknown";
+
+/// Default char quoting the parts of an escaped full name.
+pub const DEFAULT_ESCAPE_CHAR: char = '`';
+
+/// Identifies an object like a table or a view in a catalog.
+///
+/// The object name may carry a branch and a system table name after the table name, separated
+/// by [`SYSTEM_TABLE_SPLITTER`]: `my_table$branch_b1$files`.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/catalog/Identifier.java>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Identifier {
+    database: String,
+    object: String,
+}
+
+impl Identifier {
+    pub fn new(database: impl ToString, object: impl ToString) -> Self {
+        Self {
+            database: database.to_string(),
+            object: object.to_string(),
+        }
+    }
+
+    /// Create the identifier of a system table of a table, like `my_db.my_table$snapshots`.
+    pub fn system_table(database: impl ToString, table: &str, system_table: &str) -> Self {
+        Self::new(
+            database,
+            format!("{}{}{}", table, SYSTEM_TABLE_SPLITTER, system_table),
+        )
+    }
+
+    /// Parse an identifier from its full name, like `my_db.my_table`.
+    ///
+    /// The full name may also be escaped with [`DEFAULT_ESCAPE_CHAR`], like
+    /// `` `my.db`.`my_table` ``, for names containing dots.
+    pub fn from_string(full_name: &str) -> crate::Result<Self> {
+        if full_name.starts_with(DEFAULT_ESCAPE_CHAR) {
+            return Self::from_escaped_string(full_name, DEFAULT_ESCAPE_CHAR);
+        }
+        match full_name.split_once('.') {
+            Some((database, object))
+                if !database.is_empty() && !object.is_empty() && !object.contains('.') =>
+            {
+                Ok(Self::new(database, object))
+            }
+            _ => Err(Self::invalid(full_name)),
+        }
+    }
+
+    /// Parse an identifier from a full name whose both parts are quoted with `escape`, an escape
+    /// char inside a part being doubled.
+    fn from_escaped_string(full_name: &str, escape: char) -> crate::Result<Self> {
+        let mut parts = Vec::new();
+        let mut chars = full_name.chars().peekable();
+        while chars.next() == Some(escape) {
+            let mut part = String::new();
+            loop {
+                match chars.next() {
+                    Some(c) if c == escape && chars.peek() == Some(&escape) => {
+                        chars.next();
+                        part.push(escape);
+                    }
+                    Some(c) if c == escape => break,
+                    Some(c) => part.push(c),
+                    None => return Err(Self::invalid(full_name)),
+                }
+            }
+            parts.push(part);
+            match chars.next() {
+                Some('.') => continue,
+                None => break,
+                Some(_) => return Err(Self::invalid(full_name)),
+            }
+        }
+        match parts.as_slice() {
+            [database, object] if !database.is_empty() && !object.is_empty() => {
+                Ok(Self::new(database, object))
+            }
+            _ => Err(Self::invalid(full_name)),
+        }
+    }
+
+    fn invalid(full_name: &str) -> Error {
+        Error::IdentifierInvalid {
+            message: format!(
+                "Cannot get splits from '{}' to get database and object",
+                full_name
+            ),
+        }
+    }
+
+    /// Get the name of the database.
+    #[inline]
+    pub fn database_name(&self) -> &str {
+        &self.database
+    }
+
+    /// Get the name of the object, including the branch and the system table name.
+    #[inline]
+    pub fn object_name(&self) -> &str {
+        &self.object
+    }
+
+    /// Get the full name of the object, like `my_db.my_table$files`.
+    pub fn full_name(&self) -> String {
+        format!("{}.{}", self.database, self.object)
+    }
+
+    /// Get the full name quoted with [`DEFAULT_ESCAPE_CHAR`], like `` `my_db`.`my_table` ``.
+    pub fn escaped_full_name(&self) -> String {
+        self.escaped_full_name_with_char(DEFAULT_ESCAPE_CHAR)
+    }
+
+    /// Get the full name with the database and object names quoted with `escape`, as SQL quotes
+    /// identifiers: an `escape` inside a name is doubled, so `` a`b `` is quoted as
+    /// `` `a``b` ``.
+    pub fn escaped_full_name_with_char(&self, escape: char) -> String {
+        let quote = |name: &str| {
+            let doubled = format!("{escape}{escape}");
+            format!("{escape}{}{escape}", name.replace(escape, &doubled))
+        };
+        format!("{}.{}", quote(&self.database), quote(&self.object))
+    }
+
+    /// Get the name of the table, without the branch and the system table name.
+    pub fn table_name(&self) -> &str {
+        self.object
+            .split(SYSTEM_TABLE_SPLITTER)
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// Get the name of the branch, if any.
+    pub fn branch_name(&self) -> Option<&str> {
+        self.object
+            .split(SYSTEM_TABLE_SPLITTER)
+            .nth(1)
+            .and_then(|name| name.strip_prefix(SYSTEM_BRANCH_PREFIX))
+    }
+
+    /// Get the name of the system table, if this identifier refers to one.
+    pub fn system_table_name(&self) -> Option<&str> {
+        let mut splits = self.object.split(SYSTEM_TABLE_SPLITTER).skip(1);
+        match (splits.next(), splits.next()) {
+            (Some(name), None) if !name.starts_with(SYSTEM_BRANCH_PREFIX) => Some(name),
+            (Some(_), Some(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Check whether this identifier refers to a system table.
+    pub fn is_system_table(&self) -> bool {
+        self.system_table_name().is_some()
+    }
+
+    /// Check whether this identifier is in the [`SYSTEM_DATABASE_NAME`] database.
+    pub fn is_system_database(&self) -> bool {
+        self.database == SYSTEM_DATABASE_NAME
+    }
+}
+
+impl Display for Identifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.full_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_string() {
+        let identifier = Identifier::from_string("my_db.my_table").unwrap();
+        assert_eq!(identifier.database_name(), "my_db");
+        assert_eq!(identifier.object_name(), "my_table");
+        assert_eq!(identifier.to_string(), "my_db.my_table");
+
+        assert!(Identifier::from_string("my_table").is_err());
+        assert!(Identifier::from_string("a.b.c").is_err());
+        assert!(Identifier::from_string(".b").is_err());
+    }
+
+    #[test]
+    fn test_escaped_full_name() {
+        let identifier = Identifier::new("my_db", "my_table");
+        assert_eq!(identifier.escaped_full_name(), "`my_db`.`my_table`");
+        assert_eq!(
+            identifier.escaped_full_name_with_char('"'),
+            "\"my_db\".\"my_table\""
+        );
+
+        let identifier = Identifier::new("my.db", "a`b");
+        assert_eq!(identifier.escaped_full_name(), "`my.db`.`a``b`");
+        assert_eq!(
+            Identifier::from_string(&identifier.escaped_full_name()).unwrap(),
+            identifier
+        );
+
+        assert_eq!(
+            Identifier::from_string("`my_db`.`my.table`").unwrap(),
+            Identifier::new("my_db", "my.table")
+        );
+        assert!(Identifier::from_string("`my_db`").is_err());
+        assert!(Identifier::from_string("`my_db`.`t").is_err());
+        assert!(Identifier::from_string("`my_db`x`t`").is_err());
+        assert!(Identifier::from_string("`a`.`b`.`c`").is_err());
+    }
+
+    #[test]
+    fn test_system_table_name() {
+        let identifier = Identifier::new("db", "t");
+        assert_eq!(identifier.table_name(), "t");
+        assert_eq!(identifier.system_table_name(), None);
+
+        let identifier = Identifier::new("db", "t$partitions");
+        assert_eq!(identifier.table_name(), "t");
+        assert_eq!(identifier.system_table_name(), Some("partitions"));
+        assert!(identifier.is_system_table());
+
+        let identifier = Identifier::new("db", "t$branch_b1");
+        assert_eq!(identifier.branch_name(), Some("b1"));
+        assert_eq!(identifier.system_table_name(), None);
+
+        let identifier = Identifier::new("db", "t$branch_b1$files");
+        assert_eq!(identifier.table_name(), "t");
+        assert_eq!(identifier.branch_name(), Some("b1"));
+        assert_eq!(identifier.system_table_name(), Some("files"));
+    }
+
+    #[test]
+    fn test_system_table_round_trip() {
+        let identifier = Identifier::system_table("db", "t", "snapshots");
+        assert_eq!(identifier.full_name(), "db.t$snapshots");
+        assert!(identifier.is_system_table());
+        assert!(!identifier.is_system_database());
+
+        let parsed = Identifier::from_string(&identifier.full_name()).unwrap();
+        assert_eq!(parsed, identifier);
+        assert_eq!(parsed.table_name(), "t");
+        assert_eq!(parsed.system_table_name(), Some("snapshots"));
+
+        let identifier = Identifier::from_string("sys.all_table_options").unwrap();
+        assert!(identifier.is_system_database());
+        assert!(!identifier.is_system_table());
+        assert!(!Identifier::new(crate::catalog::DEFAULT_DATABASE, "t").is_system_database());
+    }
+}
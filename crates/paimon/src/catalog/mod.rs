@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Catalog module for paimon.
+
+mod filesystem;
+pub use filesystem::*;
+
+mod identifier;
+pub use identifier::*;
+
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream};
+use futures::StreamExt;
+
+use crate::table::FileStoreTable;
+
+/// Name of the database created by default in a catalog.
+pub const DEFAULT_DATABASE: &str = "default";
+
+/// Name of the database holding the global system tables of a catalog.
+pub const SYSTEM_DATABASE_NAME: &str = "sys";
+
+/// Catalog of the databases and the tables of a warehouse.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/catalog/Catalog.java>
+#[async_trait]
+pub trait Catalog: Send + Sync {
+    /// List the names of all databases, sorted.
+    async fn list_databases(&self) -> crate::Result<Vec<String>>;
+
+    /// Whether the database with the given name exists.
+    async fn database_exists(&self, database: &str) -> crate::Result<bool>;
+
+    /// List the names of the tables of a database, sorted.
+    ///
+    /// Fails with [`Error::DatabaseNotExist`](crate::Error::DatabaseNotExist) if there is no
+    /// such database.
+    async fn list_tables(&self, database: &str) -> crate::Result<Vec<String>>;
+
+    /// List the names of the tables of a database as a stream, in no particular order, for
+    /// databases with too many tables to list at once.
+    ///
+    /// Wraps [`Catalog::list_tables`] by default, catalogs able to fetch the tables
+    /// progressively override it.
+    async fn list_tables_stream(
+        &self,
+        database: &str,
+    ) -> crate::Result<BoxStream<'static, crate::Result<String>>> {
+        let tables = self.list_tables(database).await?;
+        Ok(stream::iter(tables.into_iter().map(Ok)).boxed())
+    }
+
+    /// Get the table with the given identifier, with its latest schema.
+    ///
+    /// Fails with [`Error::TableNotExist`](crate::Error::TableNotExist) if there is no such
+    /// table.
+    async fn get_table(&self, identifier: &Identifier) -> crate::Result<FileStoreTable>;
+}
@@ -0,0 +1,314 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+
+use crate::mergetree::MergeTreeLevels;
+use crate::spec::{CoreOptions, DataFileMeta};
+
+/// Files selected to be compacted together into `output_level`.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/compact/CompactUnit.java>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactionTask {
+    files: Vec<DataFileMeta>,
+    output_level: i32,
+}
+
+impl CompactionTask {
+    #[inline]
+    pub fn files(&self) -> &[DataFileMeta] {
+        &self.files
+    }
+
+    #[inline]
+    pub fn output_level(&self) -> i32 {
+        self.output_level
+    }
+}
+
+/// A sorted run of a bucket: one level-0 file, or all files of a higher level.
+#[derive(Debug)]
+struct LevelSortedRun<'a> {
+    level: i32,
+    files: Vec<&'a DataFileMeta>,
+    total_size: i64,
+}
+
+/// Planner of the universal compaction of the LSM tree of a bucket.
+///
+/// Sorted runs are picked from the newest to the oldest, in this order of checks:
+///
+/// 1. size amplification: all runs are compacted when the runs newer than the oldest are larger
+///    than `compaction.max-size-amplification-percent` of the oldest run.
+/// 2. size ratio: newer runs are picked while the next run is not larger than the picked ones by
+///    more than `compaction.size-ratio` percent.
+/// 3. number of runs: the oldest runs are forced into the compaction when there are more than
+///    `num-sorted-run.compaction-trigger` runs.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/mergetree/compact/UniversalCompaction.java>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactionPlanner {
+    max_size_amp: u64,
+    size_ratio: u64,
+    num_run_compaction_trigger: usize,
+    max_level: i32,
+}
+
+impl CompactionPlanner {
+    pub fn new(options: &CoreOptions) -> crate::Result<Self> {
+        Ok(Self {
+            max_size_amp: options.max_size_amplification_percent()?,
+            size_ratio: options.sorted_run_size_ratio()?,
+            num_run_compaction_trigger: options.num_sorted_runs_compaction_trigger()?,
+            max_level: MergeTreeLevels::new(options)?.max_level(),
+        })
+    }
+
+    /// Plan the compaction of the given files of a bucket, `None` if no compaction is needed.
+    pub fn plan(&self, files: &[DataFileMeta]) -> Option<CompactionTask> {
+        let runs = Self::level_sorted_runs(files);
+
+        self.pick_for_size_amp(&runs)
+            .or_else(|| self.pick_for_size_ratio(&runs))
+            .or_else(|| {
+                (runs.len() > self.num_run_compaction_trigger).then(|| {
+                    let candidate_count = runs.len() - self.num_run_compaction_trigger + 1;
+                    self.pick_for_size_ratio_from(&runs, candidate_count, true)
+                })?
+            })
+    }
+
+    /// Sort the files into runs: level-0 files from the newest, then the levels from the lowest.
+    fn level_sorted_runs(files: &[DataFileMeta]) -> Vec<LevelSortedRun<'_>> {
+        let mut level0: Vec<&DataFileMeta> = files.iter().filter(|f| f.level() == 0).collect();
+        level0.sort_by(|a, b| {
+            b.max_sequence_number()
+                .cmp(&a.max_sequence_number())
+                .then_with(|| a.min_sequence_number().cmp(&b.min_sequence_number()))
+                .then_with(|| a.creation_time().cmp(&b.creation_time()))
+                .then_with(|| a.file_name().cmp(b.file_name()))
+        });
+
+        let mut runs: Vec<LevelSortedRun> = level0
+            .into_iter()
+            .map(|file| LevelSortedRun {
+                level: 0,
+                files: vec![file],
+                total_size: file.file_size(),
+            })
+            .collect();
+
+        let mut higher: Vec<&DataFileMeta> = files.iter().filter(|f| f.level() > 0).collect();
+        higher.sort_by(|a, b| match a.level().cmp(&b.level()) {
+            Ordering::Equal => a.file_name().cmp(b.file_name()),
+            other => other,
+        });
+        for file in higher {
+            match runs.last_mut() {
+                Some(run) if run.level == file.level() => {
+                    run.files.push(file);
+                    run.total_size += file.file_size();
+                }
+                _ => runs.push(LevelSortedRun {
+                    level: file.level(),
+                    files: vec![file],
+                    total_size: file.file_size(),
+                }),
+            }
+        }
+        runs
+    }
+
+    fn pick_for_size_amp(&self, runs: &[LevelSortedRun]) -> Option<CompactionTask> {
+        if runs.len() < self.num_run_compaction_trigger {
+            return None;
+        }
+
+        let (earliest, candidates) = runs.split_last()?;
+        let candidate_size: i64 = candidates.iter().map(|run| run.total_size).sum();
+        if candidate_size as u128 * 100 > self.max_size_amp as u128 * earliest.total_size as u128 {
+            return Some(Self::task(runs, self.max_level));
+        }
+        None
+    }
+
+    fn pick_for_size_ratio(&self, runs: &[LevelSortedRun]) -> Option<CompactionTask> {
+        if runs.len() < self.num_run_compaction_trigger {
+            return None;
+        }
+        self.pick_for_size_ratio_from(runs, 1, false)
+    }
+
+    fn pick_for_size_ratio_from(
+        &self,
+        runs: &[LevelSortedRun],
+        mut candidate_count: usize,
+        force_pick: bool,
+    ) -> Option<CompactionTask> {
+        let mut candidate_size: i64 = runs[..candidate_count]
+            .iter()
+            .map(|run| run.total_size)
+            .sum();
+        for next in &runs[candidate_count..] {
+            if (candidate_size as f64) * (100.0 + self.size_ratio as f64) / 100.0
+                < next.total_size as f64
+            {
+                break;
+            }
+            candidate_size += next.total_size;
+            candidate_count += 1;
+        }
+
+        if force_pick || candidate_count > 1 {
+            return Some(self.create_task(runs, candidate_count));
+        }
+        None
+    }
+
+    fn create_task(&self, runs: &[LevelSortedRun], mut run_count: usize) -> CompactionTask {
+        let mut output_level = if run_count == runs.len() {
+            self.max_level
+        } else {
+            // level of next run - 1
+            (runs[run_count].level - 1).max(0)
+        };
+
+        if output_level == 0 {
+            // do not output level 0
+            for next in &runs[run_count..] {
+                run_count += 1;
+                if next.level != 0 {
+                    output_level = next.level;
+                    break;
+                }
+            }
+        }
+
+        if run_count == runs.len() {
+            output_level = self.max_level;
+        }
+        Self::task(&runs[..run_count], output_level)
+    }
+
+    fn task(runs: &[LevelSortedRun], output_level: i32) -> CompactionTask {
+        CompactionTask {
+            files: runs
+                .iter()
+                .flat_map(|run| run.files.iter().map(|file| (*file).clone()))
+                .collect(),
+            output_level,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+    use std::collections::HashMap;
+
+    fn file(name: &str, level: i32, size: i64, max_sequence_number: i64) -> DataFileMeta {
+        DataFileMeta::builder()
+            .file_name(name.to_string())
+            .file_size(size)
+            .row_count(1)
+            .min_sequence_number(max_sequence_number)
+            .max_sequence_number(max_sequence_number)
+            .schema_id(0)
+            .level(level)
+            .creation_time(DateTime::from_timestamp_millis(0).unwrap())
+            .build()
+    }
+
+    fn planner(trigger: usize) -> CompactionPlanner {
+        CompactionPlanner::new(&CoreOptions::new(HashMap::from([(
+            CoreOptions::NUM_SORTED_RUNS_COMPACTION_TRIGGER.to_string(),
+            trigger.to_string(),
+        )])))
+        .unwrap()
+    }
+
+    fn names(task: &CompactionTask) -> Vec<&str> {
+        task.files().iter().map(|f| f.file_name()).collect()
+    }
+
+    #[test]
+    fn test_no_compaction_below_trigger() {
+        let files = vec![file("f1", 0, 100, 1), file("f2", 0, 100, 2)];
+        assert_eq!(planner(3).plan(&files), None);
+        assert_eq!(planner(3).plan(&[]), None);
+    }
+
+    #[test]
+    fn test_size_amplification() {
+        // runs newer than the oldest are 3 times as large as it
+        let files = vec![
+            file("f1", 0, 100, 1),
+            file("f2", 0, 100, 2),
+            file("f3", 0, 100, 3),
+            file("f4", 0, 100, 4),
+        ];
+        let task = planner(4).plan(&files).unwrap();
+        assert_eq!(names(&task), vec!["f4", "f3", "f2", "f1"]);
+        assert_eq!(task.output_level(), 4);
+    }
+
+    #[test]
+    fn test_size_ratio() {
+        let files = vec![
+            file("f1", 0, 1, 1),
+            file("f2", 0, 1, 2),
+            file("f3", 0, 1, 3),
+            file("big", 3, 1000, 0),
+        ];
+        let task = planner(3).plan(&files).unwrap();
+        assert_eq!(names(&task), vec!["f3", "f2", "f1"]);
+        // the next run is at level 3
+        assert_eq!(task.output_level(), 2);
+    }
+
+    #[test]
+    fn test_file_num() {
+        // growing sizes never satisfy the size ratio
+        let files = vec![
+            file("f1", 0, 10, 1),
+            file("f2", 0, 1, 2),
+            file("mid", 2, 100, 0),
+            file("big", 3, 10_000, 0),
+        ];
+        let task = planner(3).plan(&files).unwrap();
+        // 4 runs with a trigger of 3 force the 2 newest runs
+        assert_eq!(names(&task), vec!["f2", "f1"]);
+        assert_eq!(task.output_level(), 1);
+    }
+
+    #[test]
+    fn test_never_output_level0() {
+        let files = vec![
+            file("f1", 0, 1000, 1),
+            file("f2", 0, 100, 2),
+            file("f3", 0, 10, 3),
+            file("f4", 0, 1, 4),
+            file("big", 3, 100_000, 0),
+        ];
+        let task = planner(4).plan(&files).unwrap();
+        // the forced runs are followed by level-0 runs, which are all picked
+        assert_eq!(names(&task), vec!["f4", "f3", "f2", "f1", "big"]);
+        assert_eq!(task.output_level(), 4);
+    }
+}
@@ -0,0 +1,327 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Buf;
+use roaring::RoaringBitmap;
+
+use crate::{
+    arrow::RowPositionFilter,
+    io::{FileRead, InputFile},
+    Error,
+};
+
+/// Magic number marking a bitmap-based deletion vector.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/deletionvectors/BitmapDeletionVector.java#L35>
+pub const BITMAP_DELETION_VECTOR_MAGIC_NUMBER: i32 = 1581511376;
+
+/// Serialization version of a deletion vector index file.
+///
+/// The version is stored as the first byte of the index file.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/deletionvectors/DeletionVectorsIndexFile.java#L48>
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeletionVectorVersion {
+    V1 = 1,
+}
+
+impl TryFrom<u8> for DeletionVectorVersion {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(DeletionVectorVersion::V1),
+            _ => Err(Error::DeletionVectorInvalid {
+                message: format!(
+                    "Unsupported deletion vector version: expected {}, but found: {}",
+                    DeletionVectorVersion::V1 as u8,
+                    value
+                ),
+            }),
+        }
+    }
+}
+
+/// Metadata of a deletion vector, parsed before the bitmap itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeletionVectorMeta {
+    /// serialization version of the index file holding the deletion vector
+    version: u8,
+    /// number of deleted rows
+    cardinality: u64,
+}
+
+impl DeletionVectorMeta {
+    /// Get the serialization version.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Get the number of deleted rows.
+    #[inline]
+    pub fn cardinality(&self) -> u64 {
+        self.cardinality
+    }
+}
+
+/// A deletion vector marking the deleted row positions of a data file.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/deletionvectors/BitmapDeletionVector.java>
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeletionVector {
+    meta: DeletionVectorMeta,
+    bitmap: RoaringBitmap,
+}
+
+impl DeletionVector {
+    /// Get the metadata of this deletion vector.
+    #[inline]
+    pub fn meta(&self) -> &DeletionVectorMeta {
+        &self.meta
+    }
+
+    /// Get the underlying bitmap of deleted row positions.
+    #[inline]
+    pub fn bitmap(&self) -> &RoaringBitmap {
+        &self.bitmap
+    }
+
+    /// Filter of the deleted rows, see [`RowPositionFilter::keep_mask`].
+    pub fn row_position_filter(&self) -> RowPositionFilter {
+        self.bitmap.clone().into()
+    }
+
+    /// Check whether the row at the given position is deleted.
+    pub fn is_deleted(&self, position: u32) -> bool {
+        self.bitmap.contains(position)
+    }
+
+    /// Get the number of deleted rows.
+    #[inline]
+    pub fn cardinality(&self) -> u64 {
+        self.meta.cardinality
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bitmap.is_empty()
+    }
+}
+
+/// Reader of deletion vector index files.
+///
+/// ```text
+/// ｜ version (1 byte) ｜ deletion vector 1 ｜ deletion vector 2 ｜ ... ｜
+///
+/// each deletion vector:
+/// ｜ size (4 bytes) ｜ magic number (4 bytes) ｜ bitmap ｜ checksum (4 bytes) ｜
+/// ```
+///
+/// - `size`: the length of magic number and bitmap
+/// - `bitmap`: a `RoaringBitmap` in the portable serialization format
+/// - `checksum`: CRC32 of magic number and bitmap
+///
+/// All integers are big-endian, as written by Java's `DataOutputStream`.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/deletionvectors/DeletionVectorsIndexFile.java>
+pub struct DeletionVectorIndexReader {
+    reader: Box<dyn FileRead>,
+    path: String,
+    version: DeletionVectorVersion,
+}
+
+impl DeletionVectorIndexReader {
+    /// Open a deletion vector index file and check its version.
+    pub async fn new(input_file: InputFile) -> crate::Result<Self> {
+        let reader = input_file.reader().await?;
+        let header = reader.read(0..1).await?;
+        let Some(version) = header.first() else {
+            return Err(Error::DeletionVectorInvalid {
+                message: format!(
+                    "Deletion vector index file '{}' is empty",
+                    input_file.location()
+                ),
+            });
+        };
+
+        Ok(Self {
+            reader: Box::new(reader),
+            path: input_file.location().to_string(),
+            version: DeletionVectorVersion::try_from(*version)?,
+        })
+    }
+
+    pub fn version(&self) -> DeletionVectorVersion {
+        self.version
+    }
+
+    /// Read the deletion vector stored at `offset` whose serialized bitmap is `length` bytes long.
+    ///
+    /// The `offset` and `length` come from the deletion vector ranges of the index file meta.
+    pub async fn read_deletion_vector(
+        &self,
+        offset: u64,
+        length: u64,
+    ) -> crate::Result<DeletionVector> {
+        // size (4 bytes) + bytes + checksum (4 bytes)
+        let mut buffer = self.reader.read(offset..offset + length + 8).await?;
+        if buffer.len() as u64 != length + 8 {
+            return Err(Error::DeletionVectorInvalid {
+                message: format!(
+                    "Expected to read {} bytes at offset {}, but found only {}, file path: {}",
+                    length + 8,
+                    offset,
+                    buffer.len(),
+                    self.path
+                ),
+            });
+        }
+
+        let actual_size = buffer.get_i32() as u64;
+        if actual_size != length {
+            return Err(Error::DeletionVectorInvalid {
+                message: format!(
+                    "Size not match, actual size: {}, expected size: {}, file path: {}",
+                    actual_size, length, self.path
+                ),
+            });
+        }
+
+        let bytes = buffer.split_to(length as usize);
+        let checksum = buffer.get_i32();
+        if checksum != crc32fast::hash(&bytes) as i32 {
+            return Err(Error::DeletionVectorInvalid {
+                message: format!(
+                    "Checksum not match, actual checksum: {}, expected checksum: {}, file path: {}",
+                    checksum,
+                    crc32fast::hash(&bytes) as i32,
+                    self.path
+                ),
+            });
+        }
+
+        self.deserialize(bytes)
+    }
+
+    fn deserialize(&self, mut bytes: bytes::Bytes) -> crate::Result<DeletionVector> {
+        if bytes.len() < 4 {
+            return Err(Error::DeletionVectorInvalid {
+                message: format!("Deletion vector is too short, file path: {}", self.path),
+            });
+        }
+
+        let magic = bytes.get_i32();
+        if magic != BITMAP_DELETION_VECTOR_MAGIC_NUMBER {
+            return Err(Error::DeletionVectorInvalid {
+                message: format!(
+                    "Invalid magic number: expected {}, but found: {}",
+                    BITMAP_DELETION_VECTOR_MAGIC_NUMBER, magic
+                ),
+            });
+        }
+
+        let bitmap = RoaringBitmap::deserialize_from(bytes.reader()).map_err(|e| {
+            Error::DeletionVectorInvalid {
+                message: format!("Failed to deserialize bitmap: {}", e),
+            }
+        })?;
+
+        Ok(DeletionVector {
+            meta: DeletionVectorMeta {
+                version: self.version as u8,
+                cardinality: bitmap.len(),
+            },
+            bitmap,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::FileIO;
+    use bytes::{BufMut, Bytes, BytesMut};
+
+    fn serialize_deletion_vector(buffer: &mut BytesMut, positions: &[u32]) -> (u64, u64) {
+        let bitmap: RoaringBitmap = positions.iter().collect();
+        let mut value = BytesMut::new();
+        value.put_i32(BITMAP_DELETION_VECTOR_MAGIC_NUMBER);
+        let mut writer = value.writer();
+        bitmap.serialize_into(&mut writer).unwrap();
+        let value = writer.into_inner();
+
+        let offset = buffer.len() as u64;
+        buffer.put_i32(value.len() as i32);
+        buffer.put_slice(&value);
+        buffer.put_i32(crc32fast::hash(&value) as i32);
+        (offset, value.len() as u64)
+    }
+
+    async fn write_file(path: &str, bytes: Bytes) -> InputFile {
+        let file_io = FileIO::from_url(path).unwrap().build().unwrap();
+        let output = file_io.new_output(path).unwrap();
+        output.write(bytes).await.unwrap();
+        output.to_input_file()
+    }
+
+    #[tokio::test]
+    async fn test_read_v1_deletion_vector() -> crate::Result<()> {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(DeletionVectorVersion::V1 as u8);
+        let (offset1, length1) = serialize_deletion_vector(&mut buffer, &[1, 3, 5]);
+        let (offset2, length2) = serialize_deletion_vector(&mut buffer, &[100_000]);
+
+        let input = write_file("memory:/tmp/test_read_v1_deletion_vector", buffer.freeze()).await;
+        let reader = DeletionVectorIndexReader::new(input).await?;
+        assert_eq!(reader.version(), DeletionVectorVersion::V1);
+
+        let dv = reader.read_deletion_vector(offset1, length1).await?;
+        assert_eq!(dv.meta().version(), 1);
+        assert_eq!(dv.meta().cardinality(), 3);
+        assert!(dv.is_deleted(3));
+        assert!(!dv.is_deleted(2));
+        assert_eq!(
+            dv.row_position_filter().keep_mask(5),
+            arrow_array::BooleanArray::from(vec![true, false, true, false, true])
+        );
+
+        let dv = reader.read_deletion_vector(offset2, length2).await?;
+        assert_eq!(dv.cardinality(), 1);
+        assert!(dv.is_deleted(100_000));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unknown_deletion_vector_version() {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(2);
+        serialize_deletion_vector(&mut buffer, &[1]);
+
+        let input = write_file(
+            "memory:/tmp/test_unknown_deletion_vector_version",
+            buffer.freeze(),
+        )
+        .await;
+        let result = DeletionVectorIndexReader::new(input).await;
+        assert!(matches!(
+            result,
+            Err(Error::DeletionVectorInvalid { message }) if message.contains("found: 2")
+        ));
+    }
+}
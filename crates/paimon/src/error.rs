@@ -65,6 +65,203 @@ pub enum Error {
         display("Paimon hitting invalid file index format: {}", message)
     )]
     FileIndexFormatInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid deletion vector: {}", message)
+    )]
+    DeletionVectorInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid metadata {}: {}", message, source)
+    )]
+    MetadataInvalid {
+        message: String,
+        source: serde_json::Error,
+    },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting unsupported manifest version: {}", message)
+    )]
+    UnsupportedManifestVersion { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid binary row: {}", message)
+    )]
+    BinaryRowInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid identifier: {}", message)
+    )]
+    IdentifierInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting unexpected arrow error {}: {:?}", message, source)
+    )]
+    ArrowUnexpected {
+        message: String,
+        source: arrow_schema::ArrowError,
+    },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting unexpected parquet error {}: {:?}", message, source)
+    )]
+    ParquetUnexpected {
+        message: String,
+        source: parquet::errors::ParquetError,
+    },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting unsupported operation: {}", message)
+    )]
+    Unsupported { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting commit conflict: {}", message)
+    )]
+    CommitConflict { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid predicate: {}", message)
+    )]
+    PredicateInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting snapshot not exist: {}", message)
+    )]
+    SnapshotNotExist { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid tag: {}", message)
+    )]
+    TagInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting column not exist: {}", message)
+    )]
+    ColumnNotExist { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid schema change: {}", message)
+    )]
+    SchemaChangeInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting path not exist: {}", message)
+    )]
+    PathNotExist { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid literal: {}", message)
+    )]
+    LiteralInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid stats: {}", message)
+    )]
+    StatsInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid schema: {}", message)
+    )]
+    SchemaInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid record batch: {}", message)
+    )]
+    RecordBatchInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display(
+            "Paimon hitting predicate type mismatch: field '{}' of type {} compared with {}",
+            field,
+            expected,
+            actual
+        )
+    )]
+    PredicateTypeMismatch {
+        field: String,
+        expected: String,
+        actual: String,
+    },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid index file: {}", message)
+    )]
+    IndexFileInvalid { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting database not exist: {}", message)
+    )]
+    DatabaseNotExist { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting table not exist: {}", message)
+    )]
+    TableNotExist { message: String },
+    #[snafu(
+        visibility(pub(crate)),
+        display(
+            "Paimon hitting objects file mismatch: '{}' is {}, not {}",
+            path,
+            actual,
+            expected
+        )
+    )]
+    ObjectsFileMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid utf-8 {}: {:?}", message, source)
+    )]
+    Utf8Invalid {
+        message: String,
+        source: std::string::FromUtf8Error,
+    },
+    #[snafu(
+        visibility(pub(crate)),
+        display("Paimon hitting invalid key value: {}", message)
+    )]
+    KeyValueInvalid { message: String },
+}
+
+impl Error {
+    /// Whether the error is caused by a file or an object which does not exist.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::IoUnexpected { source, .. } => source.kind() == opendal::ErrorKind::NotFound,
+            Error::SnapshotNotExist { .. }
+            | Error::PathNotExist { .. }
+            | Error::DatabaseNotExist { .. }
+            | Error::TableNotExist { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the failed operation may succeed if retried: a temporary storage failure, or a
+    /// commit conflicting with a concurrent one.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::IoUnexpected { source, .. } => {
+                source.is_temporary() || source.kind() == opendal::ErrorKind::RateLimited
+            }
+            Error::CommitConflict { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the error is caused by an invalid configuration, of the table or of the storage.
+    pub fn is_config_error(&self) -> bool {
+        match self {
+            Error::ConfigInvalid { .. } => true,
+            Error::IoUnexpected { source, .. } => {
+                source.kind() == opendal::ErrorKind::ConfigInvalid
+            }
+            _ => false,
+        }
+    }
 }
 
 impl From<opendal::Error> for Error {
@@ -85,3 +282,74 @@ impl From<apache_avro::Error> for Error {
         }
     }
 }
+
+impl From<arrow_schema::ArrowError> for Error {
+    fn from(source: arrow_schema::ArrowError) -> Self {
+        Error::ArrowUnexpected {
+            message: "".to_string(),
+            source,
+        }
+    }
+}
+
+impl From<parquet::errors::ParquetError> for Error {
+    fn from(source: parquet::errors::ParquetError) -> Self {
+        Error::ParquetUnexpected {
+            message: "".to_string(),
+            source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opendal::ErrorKind;
+
+    #[test]
+    fn test_is_not_found() {
+        assert!(Error::from(opendal::Error::new(ErrorKind::NotFound, "missing")).is_not_found());
+        assert!(!Error::from(opendal::Error::new(ErrorKind::Unexpected, "failed")).is_not_found());
+        assert!(Error::SnapshotNotExist {
+            message: "snapshot 1".to_string()
+        }
+        .is_not_found());
+        assert!(!Error::ConfigInvalid {
+            message: "bad".to_string()
+        }
+        .is_not_found());
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let temporary = opendal::Error::new(ErrorKind::Unexpected, "timeout").set_temporary();
+        assert!(Error::from(temporary).is_retryable());
+        assert!(
+            Error::from(opendal::Error::new(ErrorKind::RateLimited, "slow down")).is_retryable()
+        );
+        assert!(Error::CommitConflict {
+            message: "snapshot 2 exists".to_string()
+        }
+        .is_retryable());
+        assert!(!Error::from(opendal::Error::new(ErrorKind::NotFound, "missing")).is_retryable());
+        assert!(!Error::DataTypeInvalid {
+            message: "bad".to_string()
+        }
+        .is_retryable());
+    }
+
+    #[test]
+    fn test_is_config_error() {
+        assert!(Error::ConfigInvalid {
+            message: "bad".to_string()
+        }
+        .is_config_error());
+        assert!(
+            Error::from(opendal::Error::new(ErrorKind::ConfigInvalid, "no root")).is_config_error()
+        );
+        assert!(!Error::Unsupported {
+            message: "orc".to_string()
+        }
+        .is_config_error());
+    }
+}
@@ -0,0 +1,359 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{BTreeMap, HashMap};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use roaring::RoaringBitmap;
+
+use crate::arrow::RowPositionFilter;
+use crate::spec::DataType;
+use crate::{file_index::FileIndexWriter, Error};
+
+/// Index type name of the bitmap file index.
+pub const BITMAP_INDEX_TYPE: &str = "bitmap";
+
+const VERSION_1: u8 = 1;
+
+/// Serialization of the distinct values of a column in the meta of the index, chosen by the type
+/// of the column.
+///
+/// Keys of string and binary columns are their bytes, keys of other columns are the
+/// little-endian bytes of their value as stored in a `BinaryRow`, timestamps are epoch
+/// milliseconds. Values are serialized big-endian, strings and binaries with their length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueCodec {
+    Bytes,
+    Fixed(usize),
+}
+
+impl ValueCodec {
+    fn for_type(data_type: &DataType) -> crate::Result<Self> {
+        match data_type {
+            DataType::Char(_)
+            | DataType::VarChar(_)
+            | DataType::Binary(_)
+            | DataType::VarBinary(_) => Ok(ValueCodec::Bytes),
+            DataType::Boolean(_) | DataType::TinyInt(_) => Ok(ValueCodec::Fixed(1)),
+            DataType::SmallInt(_) => Ok(ValueCodec::Fixed(2)),
+            DataType::Int(_) | DataType::Float(_) | DataType::Date(_) | DataType::Time(_) => {
+                Ok(ValueCodec::Fixed(4))
+            }
+            DataType::BigInt(_)
+            | DataType::Double(_)
+            | DataType::Timestamp(_)
+            | DataType::LocalZonedTimestamp(_) => Ok(ValueCodec::Fixed(8)),
+            _ => Err(Error::Unsupported {
+                message: format!("Bitmap index does not support type {:?}", data_type),
+            }),
+        }
+    }
+
+    /// Serialize the value of a key.
+    fn encode(&self, key: &[u8]) -> Vec<u8> {
+        match self {
+            ValueCodec::Bytes => {
+                let mut value = Vec::with_capacity(4 + key.len());
+                value.put_i32(key.len() as i32);
+                value.put_slice(key);
+                value
+            }
+            ValueCodec::Fixed(width) => {
+                // sign-extend or truncate the little-endian key to the width of the value
+                let negative = key.last().is_some_and(|b| *b & 0x80 != 0);
+                let mut value = vec![if negative { 0xff } else { 0 }; *width];
+                let len = key.len().min(*width);
+                value[..len].copy_from_slice(&key[..len]);
+                value.reverse();
+                value
+            }
+        }
+    }
+
+    /// Split a serialized value off the front of `bytes`.
+    fn decode(&self, bytes: &mut Bytes) -> crate::Result<Vec<u8>> {
+        let length = match self {
+            ValueCodec::Bytes if bytes.len() >= 4 => 4 + (&bytes[..4]).get_i32().max(0) as usize,
+            ValueCodec::Bytes => 4,
+            ValueCodec::Fixed(width) => *width,
+        };
+        if bytes.len() < length {
+            return Err(Error::FileIndexFormatInvalid {
+                message: "Unexpected end of bitmap index meta".to_string(),
+            });
+        }
+        Ok(bytes.split_to(length).to_vec())
+    }
+}
+
+/// Writer of the bitmap file index, compatible with the one of Paimon and serialized as:
+///
+/// ```text
+/// ｜ version (1 byte) ｜ row count (4 bytes) ｜ non-null value bitmap number (4 bytes) ｜
+/// ｜ has null value (1 byte) ｜ null value offset (4 bytes, if has null value) ｜
+/// ｜ value 1 ｜ offset 1 (4 bytes) ｜ value 2 ｜ offset 2 (4 bytes) ｜ ... ｜
+/// ｜ bitmap 1 ｜ bitmap 2 ｜ ... ｜
+/// ```
+///
+/// Integers are big-endian, see `ValueCodec` for the values. Bitmaps use the portable
+/// `RoaringBitmap` serialization and offsets are relative to the first bitmap. A value found in a
+/// single row has no bitmap, its offset is `-1 - row`.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.9.0/paimon-common/src/main/java/org/apache/paimon/fileindex/bitmap/BitmapFileIndex.java>
+pub struct BitmapFileIndexWriter {
+    codec: ValueCodec,
+    row_count: u32,
+    null_bitmap: RoaringBitmap,
+    value_bitmaps: BTreeMap<Vec<u8>, RoaringBitmap>,
+}
+
+impl BitmapFileIndexWriter {
+    /// Create a writer of a column of type `data_type`.
+    ///
+    /// Fails for decimal and nested types.
+    pub fn new(data_type: &DataType) -> crate::Result<Self> {
+        Ok(Self {
+            codec: ValueCodec::for_type(data_type)?,
+            row_count: 0,
+            null_bitmap: RoaringBitmap::new(),
+            value_bitmaps: BTreeMap::new(),
+        })
+    }
+}
+
+impl FileIndexWriter for BitmapFileIndexWriter {
+    fn index_type(&self) -> &str {
+        BITMAP_INDEX_TYPE
+    }
+
+    fn write(&mut self, key: Option<&[u8]>) {
+        match key {
+            Some(key) => {
+                self.value_bitmaps
+                    .entry(self.codec.encode(key))
+                    .or_default()
+                    .insert(self.row_count);
+            }
+            None => {
+                self.null_bitmap.insert(self.row_count);
+            }
+        }
+        self.row_count += 1;
+    }
+
+    fn serialized_bytes(&self) -> crate::Result<Bytes> {
+        let mut body = Vec::new();
+        // Serialize a bitmap into the body, unless it has a single row, and return its offset.
+        let mut serialize_bitmap = |bitmap: &RoaringBitmap| -> crate::Result<i32> {
+            if bitmap.len() == 1 {
+                return Ok(-1 - bitmap.min().unwrap() as i32);
+            }
+            let offset = body.len() as i32;
+            bitmap
+                .serialize_into(&mut body)
+                .map_err(|e| Error::FileIndexFormatInvalid {
+                    message: format!("Failed to serialize bitmap: {}", e),
+                })?;
+            Ok(offset)
+        };
+
+        let mut head = BytesMut::new();
+        head.put_u8(VERSION_1);
+        head.put_i32(self.row_count as i32);
+        head.put_i32(self.value_bitmaps.len() as i32);
+        head.put_u8(!self.null_bitmap.is_empty() as u8);
+        if !self.null_bitmap.is_empty() {
+            head.put_i32(serialize_bitmap(&self.null_bitmap)?);
+        }
+        for (value, bitmap) in &self.value_bitmaps {
+            head.put_slice(value);
+            head.put_i32(serialize_bitmap(bitmap)?);
+        }
+        head.put_slice(&body);
+        Ok(head.freeze())
+    }
+}
+
+/// Reader of the bitmap file index.
+pub struct BitmapFileIndexReader {
+    codec: ValueCodec,
+    row_count: u32,
+    null_offset: Option<i32>,
+    value_offsets: HashMap<Vec<u8>, i32>,
+    body: Bytes,
+}
+
+impl BitmapFileIndexReader {
+    /// Create a reader of the index of a column of type `data_type`.
+    pub fn new(data_type: &DataType, mut bytes: Bytes) -> crate::Result<Self> {
+        let codec = ValueCodec::for_type(data_type)?;
+        if bytes.len() < 10 {
+            return Err(Error::FileIndexFormatInvalid {
+                message: format!("Invalid bitmap index length: {}", bytes.len()),
+            });
+        }
+        let version = bytes.get_u8();
+        if version != VERSION_1 {
+            return Err(Error::FileIndexFormatInvalid {
+                message: format!("Unsupported bitmap index version: {}", version),
+            });
+        }
+        let row_count = bytes.get_i32() as u32;
+        let value_count = bytes.get_i32();
+        let null_offset = match bytes.get_u8() {
+            0 => None,
+            _ if bytes.len() >= 4 => Some(bytes.get_i32()),
+            _ => {
+                return Err(Error::FileIndexFormatInvalid {
+                    message: "Unexpected end of bitmap index meta".to_string(),
+                })
+            }
+        };
+        let mut value_offsets = HashMap::with_capacity(value_count.max(0) as usize);
+        for _ in 0..value_count {
+            let value = codec.decode(&mut bytes)?;
+            if bytes.len() < 4 {
+                return Err(Error::FileIndexFormatInvalid {
+                    message: "Unexpected end of bitmap index meta".to_string(),
+                });
+            }
+            value_offsets.insert(value, bytes.get_i32());
+        }
+        Ok(Self {
+            codec,
+            row_count,
+            null_offset,
+            value_offsets,
+            body: bytes,
+        })
+    }
+
+    /// Number of rows covered by this index.
+    pub fn row_count(&self) -> u32 {
+        self.row_count
+    }
+
+    /// Filter of the rows whose value equals `key`, or of the null rows if `key` is `None`, see
+    /// [`RowPositionFilter::selected_mask`].
+    pub fn row_position_filter(&self, key: Option<&[u8]>) -> crate::Result<RowPositionFilter> {
+        Ok(self.positions(key)?.into())
+    }
+
+    /// Row positions whose value equals `key`, or the null rows if `key` is `None`.
+    pub fn positions(&self, key: Option<&[u8]>) -> crate::Result<RoaringBitmap> {
+        let offset = match key {
+            Some(key) => self.value_offsets.get(&self.codec.encode(key)).copied(),
+            None => self.null_offset,
+        };
+        match offset {
+            None => Ok(RoaringBitmap::new()),
+            Some(offset) if offset < 0 => Ok(RoaringBitmap::from_iter([(-1 - offset) as u32])),
+            Some(offset) if offset as usize <= self.body.len() => {
+                RoaringBitmap::deserialize_from(&self.body[offset as usize..]).map_err(|e| {
+                    Error::FileIndexFormatInvalid {
+                        message: format!("Failed to deserialize bitmap: {}", e),
+                    }
+                })
+            }
+            Some(offset) => Err(Error::FileIndexFormatInvalid {
+                message: format!(
+                    "Bitmap offset {} exceeds the index body of {} bytes",
+                    offset,
+                    self.body.len()
+                ),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::BooleanArray;
+
+    use super::*;
+    use crate::spec::{IntType, VarCharType};
+
+    #[test]
+    fn test_bitmap_round_trip() -> crate::Result<()> {
+        let data_type = DataType::VarChar(VarCharType::default());
+        let mut writer = BitmapFileIndexWriter::new(&data_type)?;
+        for key in [Some("a"), Some("b"), None, Some("a"), Some("c")] {
+            writer.write(key.map(str::as_bytes));
+        }
+
+        let reader = BitmapFileIndexReader::new(&data_type, writer.serialized_bytes()?)?;
+        assert_eq!(reader.row_count(), 5);
+        assert_eq!(
+            reader.positions(Some(b"a"))?,
+            RoaringBitmap::from_iter([0, 3])
+        );
+        assert_eq!(reader.positions(Some(b"c"))?, RoaringBitmap::from_iter([4]));
+        assert_eq!(reader.positions(None)?, RoaringBitmap::from_iter([2]));
+        assert!(reader.positions(Some(b"d"))?.is_empty());
+        assert_eq!(
+            reader.row_position_filter(Some(b"a"))?.selected_mask(5),
+            BooleanArray::from(vec![true, false, false, true, false])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_paimon_bitmap_format() -> crate::Result<()> {
+        // int column [1, 2, null, 1, null], in the layout of Paimon's BitmapFileIndex
+        #[rustfmt::skip]
+        let bytes = Bytes::from_static(&[
+            // version, row count, non-null value number, has null, null value offset
+            1, 0, 0, 0, 5, 0, 0, 0, 2, 1, 0, 0, 0, 0,
+            // value 1 at offset 20, value 2 only in row 1
+            0, 0, 0, 1, 0, 0, 0, 20,
+            0, 0, 0, 2, 0xff, 0xff, 0xff, 0xfe,
+            // bitmap {2, 4}
+            0x3a, 0x30, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 16, 0, 0, 0, 2, 0, 4, 0,
+            // bitmap {0, 3}
+            0x3a, 0x30, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 16, 0, 0, 0, 0, 0, 3, 0,
+        ]);
+        let data_type = DataType::Int(IntType::new());
+        let reader = BitmapFileIndexReader::new(&data_type, bytes.clone())?;
+        assert_eq!(reader.row_count(), 5);
+        assert_eq!(
+            reader.positions(Some(&1i32.to_le_bytes()))?,
+            RoaringBitmap::from_iter([0, 3])
+        );
+        assert_eq!(
+            reader.positions(Some(&2i32.to_le_bytes()))?,
+            RoaringBitmap::from_iter([1])
+        );
+        assert_eq!(reader.positions(None)?, RoaringBitmap::from_iter([2, 4]));
+
+        let mut writer = BitmapFileIndexWriter::new(&data_type)?;
+        for key in [Some(1i32), Some(2), None, Some(1), None] {
+            writer.write(key.map(i32::to_le_bytes).as_ref().map(|k| &k[..]));
+        }
+        assert_eq!(writer.serialized_bytes()?, bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_bitmap_index() {
+        let data_type = DataType::Int(IntType::new());
+        let version_2 = Bytes::from_static(&[2, 0, 0, 0, 1, 0, 0, 0, 0, 0]);
+        assert!(BitmapFileIndexReader::new(&data_type, version_2).is_err());
+        let truncated = Bytes::from_static(&[1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0]);
+        assert!(BitmapFileIndexReader::new(&data_type, truncated).is_err());
+    }
+}
@@ -0,0 +1,355 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::spec::{BinaryRow, DataType};
+use crate::{file_index::FileIndexWriter, Error};
+
+/// Index type name of the bloom filter file index.
+pub const BLOOM_FILTER_INDEX_TYPE: &str = "bloom-filter";
+
+const DEFAULT_ITEMS: usize = 1_000_000;
+const DEFAULT_FPP: f64 = 0.1;
+
+/// Hash function of the keys of a column, chosen by the type of the column.
+///
+/// Keys of string and binary columns are their bytes, keys of numeric and datetime columns are
+/// the little-endian bytes of their value as stored in a `BinaryRow`: timestamps are epoch
+/// milliseconds, decimals are unscaled longs and floating point numbers are their bits.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/fileindex/bloomfilter/FastHash.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FastHash {
+    /// Murmur hash of the bytes, as `BinaryString#hashCode`.
+    Bytes,
+    /// Hash of the long value.
+    Long,
+}
+
+impl FastHash {
+    fn for_type(data_type: &DataType) -> crate::Result<Self> {
+        match data_type {
+            DataType::Char(_)
+            | DataType::VarChar(_)
+            | DataType::Binary(_)
+            | DataType::VarBinary(_) => Ok(FastHash::Bytes),
+            DataType::TinyInt(_)
+            | DataType::SmallInt(_)
+            | DataType::Int(_)
+            | DataType::BigInt(_)
+            | DataType::Float(_)
+            | DataType::Double(_)
+            | DataType::Date(_)
+            | DataType::Time(_)
+            | DataType::Timestamp(_)
+            | DataType::LocalZonedTimestamp(_) => Ok(FastHash::Long),
+            DataType::Decimal(v) if v.precision() <= BinaryRow::MAX_COMPACT_DECIMAL_PRECISION => {
+                Ok(FastHash::Long)
+            }
+            _ => Err(Error::Unsupported {
+                message: format!("Bloom filter index does not support type {:?}", data_type),
+            }),
+        }
+    }
+
+    fn hash(&self, key: &[u8]) -> i64 {
+        match self {
+            FastHash::Bytes => long_hash(murmur_hash(key) as i64),
+            FastHash::Long => {
+                // sign-extend the little-endian value to 8 bytes
+                let negative = key.last().is_some_and(|b| *b & 0x80 != 0);
+                let mut buf = [if negative { 0xff } else { 0 }; 8];
+                let len = key.len().min(8);
+                buf[..len].copy_from_slice(&key[..len]);
+                long_hash(i64::from_le_bytes(buf))
+            }
+        }
+    }
+}
+
+/// Thomas Wang's 64-bit integer hash.
+fn long_hash(mut key: i64) -> i64 {
+    key = (!key).wrapping_add(key << 21);
+    key ^= key >> 24;
+    key = key.wrapping_add(key << 3).wrapping_add(key << 8);
+    key ^= key >> 14;
+    key = key.wrapping_add(key << 2).wrapping_add(key << 4);
+    key ^= key >> 28;
+    key.wrapping_add(key << 31)
+}
+
+/// 32-bit murmur hash of Paimon with the seed 42, whose tail bytes are mixed one by one as
+/// signed integers.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/utils/MurmurHashUtils.java>
+fn murmur_hash(bytes: &[u8]) -> i32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+    fn mix_k1(k1: u32) -> u32 {
+        k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2)
+    }
+    fn mix_h1(h1: u32, k1: u32) -> u32 {
+        (h1 ^ k1)
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe654_6b64)
+    }
+
+    let aligned = bytes.len() - bytes.len() % 4;
+    let mut h1 = bytes[..aligned].chunks_exact(4).fold(42u32, |h1, word| {
+        mix_h1(h1, mix_k1(u32::from_le_bytes(word.try_into().unwrap())))
+    });
+    for b in &bytes[aligned..] {
+        h1 = mix_h1(h1, mix_k1(*b as i8 as i32 as u32));
+    }
+    h1 ^= bytes.len() as u32;
+    h1 ^= h1 >> 16;
+    h1 = h1.wrapping_mul(0x85eb_ca6b);
+    h1 ^= h1 >> 13;
+    h1 = h1.wrapping_mul(0xc2b2_ae35);
+    h1 ^= h1 >> 16;
+    h1 as i32
+}
+
+/// Bloom filter of a column, serialized as:
+///
+/// ```text
+/// ｜ hash function number (4 bytes, big-endian) ｜ bit set ｜
+/// ```
+///
+/// Bit `i` of the bit set is the bit `i % 8` of its byte `i / 8`, from the lowest.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/utils/BloomFilter64.java>
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BloomFilter64 {
+    num_hash_functions: i32,
+    bits: Vec<u8>,
+}
+
+impl BloomFilter64 {
+    fn with_items(items: usize, fpp: f64) -> Self {
+        let items = items.max(1) as f64;
+        let num_bits = (-items * fpp.ln() / (2f64.ln() * 2f64.ln())) as usize;
+        let num_bits = num_bits + (8 - num_bits % 8);
+        let num_hash_functions = ((num_bits as f64 / items) * 2f64.ln()).round().max(1.0) as i32;
+        Self {
+            num_hash_functions,
+            bits: vec![0; num_bits / 8],
+        }
+    }
+
+    fn num_bits(&self) -> i32 {
+        self.bits.len() as i32 * 8
+    }
+
+    /// Bit positions of a hash, using double hashing over its two halves.
+    fn positions(&self, hash: i64) -> impl Iterator<Item = usize> {
+        let hash1 = hash as i32;
+        let hash2 = (hash as u64 >> 32) as i32;
+        let num_bits = self.num_bits();
+        (1..=self.num_hash_functions).map(move |i| {
+            let combined = hash1.wrapping_add(i.wrapping_mul(hash2));
+            // flip all the bits of a negative hash
+            let combined = if combined < 0 { !combined } else { combined };
+            (combined % num_bits) as usize
+        })
+    }
+
+    fn add_hash(&mut self, hash: i64) {
+        for pos in self.positions(hash).collect::<Vec<_>>() {
+            self.bits[pos / 8] |= 1 << (pos % 8);
+        }
+    }
+
+    fn test_hash(&self, hash: i64) -> bool {
+        self.positions(hash)
+            .all(|pos| self.bits[pos / 8] & (1 << (pos % 8)) != 0)
+    }
+
+    fn serialize(&self) -> Bytes {
+        let mut buffer = BytesMut::with_capacity(4 + self.bits.len());
+        buffer.put_i32(self.num_hash_functions);
+        buffer.put_slice(&self.bits);
+        buffer.freeze()
+    }
+
+    fn deserialize(mut bytes: Bytes) -> crate::Result<Self> {
+        if bytes.len() <= 4 {
+            return Err(Error::FileIndexFormatInvalid {
+                message: format!("Invalid bloom filter length: {}", bytes.len()),
+            });
+        }
+        let num_hash_functions = bytes.get_i32();
+        if num_hash_functions <= 0 {
+            return Err(Error::FileIndexFormatInvalid {
+                message: format!("Invalid bloom filter hash function number: {num_hash_functions}"),
+            });
+        }
+        Ok(Self {
+            num_hash_functions,
+            bits: bytes.to_vec(),
+        })
+    }
+}
+
+/// Writer of the bloom filter file index, null values are skipped.
+///
+/// The index is compatible with the one of Paimon: string and binary keys are hashed with the
+/// murmur hash of `BinaryString#hashCode`, keys of numeric and temporal types with Thomas Wang's
+/// hash of their value as a long.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/fileindex/bloomfilter/BloomFilterFileIndex.java>
+pub struct BloomFilterFileIndexWriter {
+    filter: BloomFilter64,
+    hash: FastHash,
+}
+
+impl BloomFilterFileIndexWriter {
+    /// Create a writer of a column of type `data_type`, sized for `items` distinct keys with the
+    /// false positive probability `fpp`.
+    ///
+    /// Fails for boolean, nested and non-compact decimal types.
+    pub fn new(data_type: &DataType, items: usize, fpp: f64) -> crate::Result<Self> {
+        Ok(Self {
+            filter: BloomFilter64::with_items(items, fpp),
+            hash: FastHash::for_type(data_type)?,
+        })
+    }
+
+    /// Create a writer of a column of type `data_type` with the default size of Paimon.
+    pub fn with_default_size(data_type: &DataType) -> crate::Result<Self> {
+        Self::new(data_type, DEFAULT_ITEMS, DEFAULT_FPP)
+    }
+}
+
+impl FileIndexWriter for BloomFilterFileIndexWriter {
+    fn index_type(&self) -> &str {
+        BLOOM_FILTER_INDEX_TYPE
+    }
+
+    fn write(&mut self, key: Option<&[u8]>) {
+        if let Some(key) = key {
+            self.filter.add_hash(self.hash.hash(key));
+        }
+    }
+
+    fn serialized_bytes(&self) -> crate::Result<Bytes> {
+        Ok(self.filter.serialize())
+    }
+}
+
+/// Reader of the bloom filter file index.
+pub struct BloomFilterFileIndexReader {
+    filter: BloomFilter64,
+    hash: FastHash,
+}
+
+impl BloomFilterFileIndexReader {
+    /// Create a reader of the index of a column of type `data_type`.
+    pub fn new(data_type: &DataType, bytes: Bytes) -> crate::Result<Self> {
+        Ok(Self {
+            filter: BloomFilter64::deserialize(bytes)?,
+            hash: FastHash::for_type(data_type)?,
+        })
+    }
+
+    /// Returns `false` only if the key is definitely absent.
+    pub fn might_contain(&self, key: &[u8]) -> bool {
+        self.filter.test_hash(self.hash.hash(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{BooleanType, IntType, VarCharType};
+
+    #[test]
+    fn test_bloom_filter_round_trip() -> crate::Result<()> {
+        let data_type = DataType::VarChar(VarCharType::default());
+        let mut writer = BloomFilterFileIndexWriter::new(&data_type, 100, 0.01)?;
+        for i in 0..100 {
+            writer.write(Some(format!("key-{}", i).as_bytes()));
+        }
+        writer.write(None);
+
+        let reader = BloomFilterFileIndexReader::new(&data_type, writer.serialized_bytes()?)?;
+        for i in 0..100 {
+            assert!(reader.might_contain(format!("key-{}", i).as_bytes()));
+        }
+        let false_positives = (100..1100)
+            .filter(|i| reader.might_contain(format!("key-{}", i).as_bytes()))
+            .count();
+        assert!(
+            false_positives < 50,
+            "too many false positives: {}",
+            false_positives
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_paimon_bloom_filter_format() -> crate::Result<()> {
+        assert_eq!(murmur_hash(b""), 142593372);
+        assert_eq!(murmur_hash(b"hello"), -1008564952);
+        assert_eq!(long_hash(1), 6614235796240398542);
+
+        // filters of 10 items with a fpp of 0.1 in the layout of Paimon's BloomFilter64: 3 hash
+        // functions over 48 bits
+        let varchar = DataType::VarChar(VarCharType::default());
+        let strings = Bytes::from_static(&[0, 0, 0, 3, 225, 0, 1, 0, 130, 32]);
+        let int = DataType::Int(IntType::new());
+        let ints = Bytes::from_static(&[0, 0, 0, 3, 137, 32, 1, 80, 64, 0]);
+
+        let mut writer = BloomFilterFileIndexWriter::new(&varchar, 10, 0.1)?;
+        for key in ["a", "b", "c"] {
+            writer.write(Some(key.as_bytes()));
+        }
+        assert_eq!(writer.serialized_bytes()?, strings);
+        let mut writer = BloomFilterFileIndexWriter::new(&int, 10, 0.1)?;
+        for key in [1i32, 2, 3] {
+            writer.write(Some(&key.to_le_bytes()));
+        }
+        assert_eq!(writer.serialized_bytes()?, ints);
+
+        let reader = BloomFilterFileIndexReader::new(&varchar, strings)?;
+        assert!(["a", "b", "c"]
+            .iter()
+            .all(|k| reader.might_contain(k.as_bytes())));
+        let reader = BloomFilterFileIndexReader::new(&int, ints)?;
+        assert!([1i32, 2, 3]
+            .iter()
+            .all(|k| reader.might_contain(&k.to_le_bytes())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_bloom_filter() {
+        let data_type = DataType::VarChar(VarCharType::default());
+        for bytes in [&[0, 0, 0, 1][..], &[0, 0, 0, 0, 2]] {
+            assert!(
+                BloomFilterFileIndexReader::new(&data_type, Bytes::copy_from_slice(bytes)).is_err()
+            );
+        }
+        assert!(matches!(
+            BloomFilterFileIndexWriter::with_default_size(&DataType::Boolean(BooleanType::new())),
+            Err(Error::Unsupported { .. })
+        ));
+    }
+}
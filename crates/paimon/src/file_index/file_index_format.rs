@@ -20,6 +20,7 @@ use std::collections::HashMap;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 
 use crate::{
+    file_index::FileIndexWriter,
     io::{FileIO, FileRead, FileStatus, InputFile, OutputFile},
     Error,
 };
@@ -187,6 +188,32 @@ pub async fn write_column_indexes(
     Ok(output)
 }
 
+/// Serialize the index writers of each column, keyed by column name and then by index type.
+///
+/// The result is ready to be passed to [`write_column_indexes`].
+pub fn build_column_indexes(
+    columns: &[(String, Vec<Box<dyn FileIndexWriter>>)],
+) -> crate::Result<HashMap<String, HashMap<String, Bytes>>> {
+    let mut indexes = HashMap::with_capacity(columns.len());
+    for (column_name, writers) in columns {
+        let column_indexes: &mut HashMap<String, Bytes> =
+            indexes.entry(column_name.clone()).or_default();
+        for writer in writers {
+            let index_type = writer.index_type();
+            if column_indexes.contains_key(index_type) {
+                return Err(Error::FileIndexFormatInvalid {
+                    message: format!(
+                        "Duplicate index type '{}' for column '{}'",
+                        index_type, column_name
+                    ),
+                });
+            }
+            column_indexes.insert(index_type.to_string(), writer.serialized_bytes()?);
+        }
+    }
+    Ok(indexes)
+}
+
 fn calculate_head_length(
     body_info: &HashMap<String, HashMap<String, IndexInfo>>,
 ) -> crate::Result<usize> {
@@ -213,10 +240,17 @@ fn calculate_head_length(
 
 pub struct FileIndex {
     reader: Box<dyn FileRead>,
-    header: HashMap<String, HashMap<String, IndexInfo>>,
+    header: Header,
+    lossy_names: Vec<String>,
 }
 
 impl FileIndex {
+    /// Get the column and index names of the header which were not valid UTF-8, as decoded with
+    /// replacement characters. Always empty for a file read with strict UTF-8.
+    pub fn lossy_names(&self) -> &[String] {
+        &self.lossy_names
+    }
+
     pub async fn get_column_index(
         &self,
         column_name: &str,
@@ -264,40 +298,57 @@ impl FileIndex {
 pub struct FileIndexFormatReader {
     reader: Box<dyn FileRead>,
     stat: FileStatus,
+    strict_utf8: bool,
 }
 
 impl FileIndexFormatReader {
+    /// Read the header of a file index, failing on a column or index name which is not valid
+    /// UTF-8.
     pub async fn get_file_index(input_file: InputFile) -> crate::Result<FileIndex> {
+        Self::get_file_index_with_strict_utf8(input_file, true).await
+    }
+
+    /// Read the header of a file index, decoding the names which are not valid UTF-8 with
+    /// replacement characters unless `strict_utf8`, to inspect a corrupt file.
+    ///
+    /// The names decoded so are reported by [`FileIndex::lossy_names`], and logged as warnings
+    /// with the `tracing` feature.
+    pub async fn get_file_index_with_strict_utf8(
+        input_file: InputFile,
+        strict_utf8: bool,
+    ) -> crate::Result<FileIndex> {
         let reader = input_file.reader().await?;
         let mut file_reader = Self {
             reader: Box::new(reader),
             stat: input_file.metadata().await?,
+            strict_utf8,
         };
-        let header = file_reader.read_header().await?;
+        let (header, lossy_names) = file_reader.read_header().await?;
         Ok(FileIndex {
             header,
             reader: file_reader.reader,
+            lossy_names,
         })
     }
 
-    async fn read_header(&mut self) -> crate::Result<HashMap<String, HashMap<String, IndexInfo>>> {
-        let read_size = if self.stat.size < READ_BLOCK_SIZE {
-            self.stat.size
-        } else {
-            READ_BLOCK_SIZE
-        };
+    /// Read the whole header, in a single read when it fits in the first [`READ_BLOCK_SIZE`]
+    /// bytes, otherwise read again from the start of the file up to the head length.
+    ///
+    /// Returns the header and the names decoded lossily.
+    async fn read_header(&mut self) -> crate::Result<(Header, Vec<String>)> {
+        let read_size = self.stat.size.min(READ_BLOCK_SIZE);
         let mut buffer = self.reader.read(0..read_size).await?;
+        // Magic (8 bytes) + Version (4 bytes) + Head Length (4 bytes)
+        let mut reader = HeaderReader::new(buffer.clone(), self.strict_utf8);
 
-        // Magic (8 bytes)
-        let magic = buffer.get_u64_le();
+        let magic = reader.get_u64_le("magic")?;
         if magic != MAGIC {
             return Err(Error::FileIndexFormatInvalid {
                 message: format!("Expected MAGIC: {}, but found: {}", MAGIC, magic),
             });
         }
 
-        // Version (4 bytes)
-        let version = buffer.get_i32_le();
+        let version = reader.get_i32_le("version")?;
         if version != Version::V1 as i32 {
             return Err(Error::FileIndexFormatInvalid {
                 message: format!(
@@ -308,89 +359,122 @@ impl FileIndexFormatReader {
             });
         }
 
-        // Head Length (4 bytes)
-        let head_length = buffer.get_i32_le() as usize;
-
-        // Ensure the header is fully contained in the buffer
+        let head_length = reader.get_i32_le("head length")?;
+        if head_length < reader.offset as i32 || head_length as u64 > self.stat.size {
+            return Err(Error::FileIndexFormatInvalid {
+                message: format!(
+                    "Head length {} is out of the file of {} bytes",
+                    head_length, self.stat.size
+                ),
+            });
+        }
+        let head_length = head_length as usize;
         if buffer.len() < head_length {
-            let remaining = head_length - buffer.len();
-            let mut remaining_head_buffer = BytesMut::with_capacity(remaining);
-            let additional_data = self
-                .reader
-                .read(buffer.len() as u64..buffer.len() as u64 + remaining as u64)
-                .await?;
-            remaining_head_buffer.extend_from_slice(&additional_data);
-            buffer = Bytes::from(
-                [buffer.slice(0..), remaining_head_buffer.freeze().slice(0..)].concat(),
-            );
+            buffer = self.reader.read(0..head_length as u64).await?;
         }
+        reader.buffer = buffer.slice(reader.offset..head_length);
 
-        // Column Number (4 bytes)
-        let column_number = buffer.get_i32_le();
-
-        let mut current_offset = 20;
+        let column_number = reader.get_i32_le("column number")?;
         let mut header = HashMap::new();
-
         for _ in 0..column_number {
-            // Column Name Length (2 bytes)
-            let column_name_len = buffer.get_u16_le();
-            current_offset += 2;
-
-            // Column Name (variable-length UTF-8 string)
-            let column_name = String::from_utf8(buffer.split_to(column_name_len as usize).to_vec())
-                .map_err(|e| Error::FileIndexFormatInvalid {
-                    message: format!("Invalid UTF-8 sequence in column name: {}", e),
-                })?;
-            current_offset += column_name_len as u64;
-
-            // Index Number (4 bytes)
-            let index_number = buffer.get_i32_le();
-            current_offset += 4;
+            let column_name = reader.get_string("column name")?;
+            let index_number = reader.get_i32_le("index number")?;
 
             let mut index_info_map = HashMap::new();
             for _ in 0..index_number {
-                // Index Name Length (2 bytes)
-                let index_name_len = buffer.get_u16_le();
-                current_offset += 2;
+                let index_name = reader.get_string("index name")?;
+                let start_pos = reader.get_i64_le("start pos")?;
+                let length = reader.get_i64_le("length")?;
+                index_info_map.insert(index_name, IndexInfo { start_pos, length });
+            }
 
-                // Index Name (variable-length UTF-8 string)
-                let index_name =
-                    String::from_utf8(buffer.split_to(index_name_len as usize).to_vec()).unwrap();
-                current_offset += index_name_len as u64;
+            header.insert(column_name, index_info_map);
+        }
 
-                // Start Pos (8 bytes)
-                let start_pos = buffer.get_i64_le();
-                current_offset += 4;
+        let redundant_length = reader.get_i32_le("redundant length")?;
+        if redundant_length > 0 {
+            reader.split_to(redundant_length as usize, "redundant bytes")?;
+        }
 
-                // Length (8 bytes)
-                let length = buffer.get_i64_le();
-                current_offset += 4;
+        Ok((header, reader.lossy_names))
+    }
+}
 
-                index_info_map.insert(index_name, IndexInfo { start_pos, length });
-            }
+/// Index infos by index name, by column name.
+type Header = HashMap<String, HashMap<String, IndexInfo>>;
+
+/// Parser of the header bytes, tracking the offset in the file to report where the header is
+/// truncated.
+struct HeaderReader {
+    /// offset in the file of the first byte of `buffer`
+    offset: usize,
+    buffer: Bytes,
+    strict_utf8: bool,
+    /// names which are not valid UTF-8, decoded with replacement characters
+    lossy_names: Vec<String>,
+}
 
-            header.insert(column_name, index_info_map);
+impl HeaderReader {
+    fn new(buffer: Bytes, strict_utf8: bool) -> Self {
+        Self {
+            offset: 0,
+            buffer,
+            strict_utf8,
+            lossy_names: Vec::new(),
         }
+    }
 
-        let redundant_length = buffer.get_i32_le() as u64;
-        current_offset += 4;
+    fn split_to(&mut self, len: usize, field: &str) -> crate::Result<Bytes> {
+        if self.buffer.len() < len {
+            return Err(Error::FileIndexFormatInvalid {
+                message: format!(
+                    "Expected to read {} bytes of {}, but found only {}, on offset {}",
+                    len,
+                    field,
+                    self.buffer.len(),
+                    self.offset
+                ),
+            });
+        }
+        self.offset += len;
+        Ok(self.buffer.split_to(len))
+    }
 
-        if redundant_length > 0 {
-            let redundant_bytes = buffer.split_to(redundant_length as usize);
+    fn get_u64_le(&mut self, field: &str) -> crate::Result<u64> {
+        Ok(self.split_to(8, field)?.get_u64_le())
+    }
 
-            if redundant_bytes.len() as u64 != redundant_length {
-                return Err(Error::FileIndexFormatInvalid {
-                    message: format!(
-                        "Expected to read {} redundant bytes, but found only {}, on offset {}",
-                        redundant_length,
-                        redundant_bytes.len(),
-                        current_offset
-                    ),
-                });
+    fn get_i64_le(&mut self, field: &str) -> crate::Result<i64> {
+        Ok(self.split_to(8, field)?.get_i64_le())
+    }
+
+    fn get_i32_le(&mut self, field: &str) -> crate::Result<i32> {
+        Ok(self.split_to(4, field)?.get_i32_le())
+    }
+
+    /// Read a UTF-8 string prefixed by its 2-byte length.
+    fn get_string(&mut self, field: &str) -> crate::Result<String> {
+        let len = self.split_to(2, field)?.get_u16_le() as usize;
+        let bytes = self.split_to(len, field)?;
+        match String::from_utf8(bytes.to_vec()) {
+            Ok(string) => Ok(string),
+            Err(e) if self.strict_utf8 => Err(Error::FileIndexFormatInvalid {
+                message: format!("Invalid UTF-8 sequence in {}: {}", field, e),
+            }),
+            Err(_e) => {
+                let string = String::from_utf8_lossy(&bytes).into_owned();
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    "Invalid UTF-8 sequence in {} at offset {}, decoded as '{}': {}",
+                    field,
+                    self.offset - len,
+                    string,
+                    _e
+                );
+                self.lossy_names.push(string.clone());
+                Ok(string)
             }
         }
-
-        Ok(header)
     }
 }
 
@@ -398,6 +482,11 @@ impl FileIndexFormatReader {
 mod file_index_format_tests {
 
     use super::*;
+    use crate::file_index::{
+        BitmapFileIndexReader, BitmapFileIndexWriter, BloomFilterFileIndexReader,
+        BloomFilterFileIndexWriter, BITMAP_INDEX_TYPE, BLOOM_FILTER_INDEX_TYPE,
+    };
+    use crate::spec::{DataType, VarCharType};
     use bytes::Bytes;
     use std::collections::HashMap;
 
@@ -509,6 +598,165 @@ mod file_index_format_tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_header_larger_than_read_block() -> crate::Result<()> {
+        let path = "memory:/tmp/test_header_larger_than_read_block";
+
+        // long column and index names make a header of more than 1MB, read in two parts
+        let mut indexes = HashMap::new();
+        for col_num in 0..20 {
+            let mut index_map = HashMap::new();
+            for idx_num in 0..20 {
+                index_map.insert(
+                    format!("index{}-{}", idx_num, "i".repeat(2000)),
+                    random_bytes(10 + idx_num),
+                );
+            }
+            indexes.insert(
+                format!("column{}-{}", col_num, "c".repeat(60000)),
+                index_map,
+            );
+        }
+
+        let input = write_column_indexes(path, indexes.clone())
+            .await?
+            .to_input_file();
+        let bytes = input.read().await?;
+        let head_length = i32::from_le_bytes(bytes[12..16].try_into().unwrap());
+        assert!(head_length as u64 > READ_BLOCK_SIZE);
+
+        let reader = FileIndexFormatReader::get_file_index(input).await?;
+        assert_eq!(reader.header.len(), 20);
+        assert_eq!(reader.get_index().await?, indexes);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_truncated_header() -> crate::Result<()> {
+        let path = "memory:/tmp/test_truncated_header";
+        let mut index_map = HashMap::new();
+        index_map.insert("index1".to_string(), Bytes::from("sample_data"));
+        let indexes = HashMap::from([("column1".to_string(), index_map)]);
+        let output = write_column_indexes(path, indexes).await?;
+
+        // keep the head length but cut the file in the middle of the header
+        let bytes = output.to_input_file().read().await?;
+        let truncated = "memory:/tmp/test_truncated_header/truncated";
+        let file_io = FileIO::from_url(truncated)?.build()?;
+        let output = file_io.new_output(truncated)?;
+        output.write(bytes.slice(0..30)).await?;
+        let result = FileIndexFormatReader::get_file_index(output.to_input_file()).await;
+        assert!(matches!(result, Err(Error::FileIndexFormatInvalid { .. })));
+
+        // keep the whole file but end the header in the middle of the column name
+        let mut corrupt = bytes.to_vec();
+        corrupt[12..16].copy_from_slice(&24i32.to_le_bytes());
+        let output = file_io.new_output(truncated)?;
+        output.write(Bytes::from(corrupt)).await?;
+        let result = FileIndexFormatReader::get_file_index(output.to_input_file()).await;
+        assert!(matches!(
+            result,
+            Err(Error::FileIndexFormatInvalid { message }) if message.contains("column name")
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalid_utf8_column_name() -> crate::Result<()> {
+        let path = "memory:/tmp/test_invalid_utf8_column_name";
+        let mut index_map = HashMap::new();
+        index_map.insert("index1".to_string(), Bytes::from("sample_data"));
+        let indexes = HashMap::from([("col\u{e9}".to_string(), index_map)]);
+        let bytes = write_column_indexes(path, indexes)
+            .await?
+            .to_input_file()
+            .read()
+            .await?;
+
+        // replace the 2-byte é of the column name by an invalid UTF-8 sequence
+        let mut corrupt = bytes.to_vec();
+        let position = corrupt
+            .windows(2)
+            .position(|window| window == "\u{e9}".as_bytes())
+            .unwrap();
+        corrupt[position..position + 2].copy_from_slice(&[0xff, 0xfe]);
+        let corrupt_path = "memory:/tmp/test_invalid_utf8_column_name/corrupt";
+        let file_io = FileIO::from_url(corrupt_path)?.build()?;
+        let output = file_io.new_output(corrupt_path)?;
+        output.write(Bytes::from(corrupt)).await?;
+
+        let result = FileIndexFormatReader::get_file_index(output.to_input_file()).await;
+        assert!(matches!(result, Err(Error::FileIndexFormatInvalid { .. })));
+
+        let file_index = FileIndexFormatReader::get_file_index_with_strict_utf8(
+            file_io.new_input(corrupt_path)?,
+            false,
+        )
+        .await?;
+        let column_name = "col\u{fffd}\u{fffd}";
+        assert_eq!(file_index.lossy_names(), [column_name.to_string()]);
+        let column_index = file_index.get_column_index(column_name).await?;
+        assert_eq!(column_index["index1"], Bytes::from("sample_data"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_build_multiple_indexes_for_one_column() -> crate::Result<()> {
+        let path = "memory:/tmp/test_build_multiple_indexes_for_one_column";
+
+        let data_type = DataType::VarChar(VarCharType::default());
+        let mut bloom_filter = BloomFilterFileIndexWriter::new(&data_type, 100, 0.01)?;
+        let mut bitmap = BitmapFileIndexWriter::new(&data_type)?;
+        for key in ["a", "b", "a", "c"] {
+            bloom_filter.write(Some(key.as_bytes()));
+            bitmap.write(Some(key.as_bytes()));
+        }
+        let columns: Vec<(String, Vec<Box<dyn FileIndexWriter>>)> = vec![(
+            "name".to_string(),
+            vec![Box::new(bloom_filter), Box::new(bitmap)],
+        )];
+
+        let indexes = build_column_indexes(&columns)?;
+        let output = write_column_indexes(path, indexes).await?;
+
+        let reader = FileIndexFormatReader::get_file_index(output.to_input_file()).await?;
+        let column_data = reader.get_column_index("name").await?;
+        assert_eq!(column_data.len(), 2);
+
+        let bloom_filter = BloomFilterFileIndexReader::new(
+            &data_type,
+            column_data[BLOOM_FILTER_INDEX_TYPE].clone(),
+        )?;
+        assert!(bloom_filter.might_contain(b"a"));
+        assert!(bloom_filter.might_contain(b"c"));
+
+        let bitmap =
+            BitmapFileIndexReader::new(&data_type, column_data[BITMAP_INDEX_TYPE].clone())?;
+        assert_eq!(
+            bitmap.positions(Some(b"a"))?,
+            roaring::RoaringBitmap::from_iter([0, 2])
+        );
+        assert_eq!(
+            bitmap.positions(Some(b"b"))?,
+            roaring::RoaringBitmap::from_iter([1])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_duplicate_index_type() {
+        let data_type = DataType::VarChar(VarCharType::default());
+        let columns: Vec<(String, Vec<Box<dyn FileIndexWriter>>)> = vec![(
+            "name".to_string(),
+            vec![
+                Box::new(BitmapFileIndexWriter::new(&data_type).unwrap()),
+                Box::new(BitmapFileIndexWriter::new(&data_type).unwrap()),
+            ],
+        )];
+        assert!(build_column_indexes(&columns).is_err());
+    }
+
     fn random_bytes(len: usize) -> Bytes {
         use rand::RngCore;
         let mut rng = rand::thread_rng();
@@ -0,0 +1,32 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Bytes;
+
+/// Writer of a single file index for one column.
+///
+/// Keys are written in row order, `None` stands for a null value.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/fileindex/FileIndexWriter.java>
+pub trait FileIndexWriter: Send {
+    /// The index type name, e.g. `bloom-filter`, used as the index name in the index file.
+    fn index_type(&self) -> &str;
+
+    fn write(&mut self, key: Option<&[u8]>);
+
+    fn serialized_bytes(&self) -> crate::Result<Bytes>;
+}
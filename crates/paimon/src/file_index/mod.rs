@@ -15,5 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod bitmap_file_index;
+pub use bitmap_file_index::*;
+
+mod bloom_filter_file_index;
+pub use bloom_filter_file_index::*;
+
 mod file_index_format;
 pub use file_index_format::*;
+
+mod file_index_writer;
+pub use file_index_writer::*;
@@ -0,0 +1,217 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use bytes::Buf;
+
+use crate::io::FileIO;
+use crate::spec::{FileKind, IndexManifestEntry, HASH_INDEX};
+use crate::table::PathFactory;
+use crate::Error;
+
+/// Reader of the hash index files of a dynamic bucket table, mapping the hashes of the keys to
+/// their buckets.
+///
+/// Each hash index file belongs to a bucket, given by its index manifest entry, and lists the
+/// hashes of the keys of the bucket:
+///
+/// ```text
+/// ｜ key hash 1 (4 bytes) ｜ key hash 2 (4 bytes) ｜ ... ｜
+/// ```
+///
+/// All integers are big-endian, as written by Java's `DataOutputStream`.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/index/HashIndexFile.java>
+#[derive(Debug, Clone)]
+pub struct HashBucketIndexReader {
+    file_io: FileIO,
+    path_factory: PathFactory,
+    buckets: HashMap<i32, i32>,
+}
+
+impl HashBucketIndexReader {
+    pub fn new(file_io: FileIO, table_path: impl ToString) -> Self {
+        Self {
+            file_io,
+            path_factory: PathFactory::new(table_path),
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Read the hash index file of an entry, mapping its key hashes to the bucket of the entry.
+    ///
+    /// Deleted entries are skipped. Fails if the entry is not a [`HASH_INDEX`] entry or if the
+    /// file does not hold the number of hashes recorded in the entry.
+    pub async fn read_index(&mut self, entry: &IndexManifestEntry) -> crate::Result<()> {
+        let index_file = entry.index_file();
+        if index_file.index_type() != HASH_INDEX {
+            return Err(Error::IndexFileInvalid {
+                message: format!(
+                    "Index file '{}' of type {} is not a {} index",
+                    index_file.file_name(),
+                    index_file.index_type(),
+                    HASH_INDEX
+                ),
+            });
+        }
+        if *entry.kind() == FileKind::Delete {
+            return Ok(());
+        }
+
+        let path = self.path_factory.index_path(index_file.file_name());
+        let mut bytes = self.file_io.new_input(&path)?.read().await?;
+        if bytes.len() as i64 != index_file.row_count() * 4 {
+            return Err(Error::IndexFileInvalid {
+                message: format!(
+                    "Hash index file '{}' of {} bytes does not hold {} key hashes",
+                    path,
+                    bytes.len(),
+                    index_file.row_count()
+                ),
+            });
+        }
+        while bytes.has_remaining() {
+            self.buckets.insert(bytes.get_i32(), entry.bucket());
+        }
+        Ok(())
+    }
+
+    /// Get the bucket of the key with the given hash, `None` if the key is in no index read.
+    pub fn bucket_for(&self, key_hash: i32) -> Option<i32> {
+        self.buckets.get(&key_hash).copied()
+    }
+
+    /// Get the number of key hashes read.
+    pub fn len(&self) -> usize {
+        self.buckets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{BufMut, BytesMut};
+
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::spec::{IndexFileMeta, IndexManifestEntries, IndexManifestFile};
+
+    const TABLE_PATH: &str = "memory:/tmp/test_hash_bucket_index_reader";
+
+    async fn write_hashes(file_io: &FileIO, file_name: &str, hashes: &[i32]) {
+        let mut buffer = BytesMut::new();
+        for hash in hashes {
+            buffer.put_i32(*hash);
+        }
+        let path = PathFactory::new(TABLE_PATH).index_path(file_name);
+        file_io
+            .new_output(&path)
+            .unwrap()
+            .write(buffer.freeze())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_read_hash_index() -> crate::Result<()> {
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let index_manifest = "memory:/tmp/test_hash_bucket_index_reader/index-manifest";
+        let fixture = std::fs::read(
+            "tests/fixtures/manifest/index-manifest-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-0",
+        )
+        .unwrap();
+        file_io
+            .new_output(index_manifest)?
+            .write(fixture.into())
+            .await?;
+        let entries = IndexManifestEntries::new(
+            IndexManifestFile::new(file_io.clone())
+                .read(index_manifest)
+                .await?,
+        );
+
+        // the fixture hash index of bucket 1 holds 4 key hashes in 16 bytes
+        let hash_entry = entries.hash_indexes()[0];
+        assert_eq!(hash_entry.index_file().file_size(), 16);
+        write_hashes(
+            &file_io,
+            hash_entry.index_file().file_name(),
+            &[7, -42, 1024, i32::MIN],
+        )
+        .await;
+        let other_bucket = IndexManifestEntry::new(
+            FileKind::Add,
+            hash_entry.partition().clone(),
+            2,
+            IndexFileMeta::new(
+                HASH_INDEX.to_string(),
+                "index-other-0".to_string(),
+                8,
+                2,
+                None,
+            ),
+            IndexManifestEntry::VERSION,
+        );
+        write_hashes(&file_io, "index-other-0", &[3, 5]).await;
+
+        let mut reader = HashBucketIndexReader::new(file_io, TABLE_PATH);
+        reader.read_index(hash_entry).await?;
+        reader.read_index(&other_bucket).await?;
+        assert_eq!(reader.len(), 6);
+        for hash in [7, -42, 1024, i32::MIN] {
+            assert_eq!(reader.bucket_for(hash), Some(1));
+        }
+        assert_eq!(reader.bucket_for(5), Some(2));
+        assert_eq!(reader.bucket_for(8), None);
+
+        // a deletion vectors index is refused
+        let result = reader
+            .read_index(entries.deletion_vector_indexes()[0])
+            .await;
+        assert!(matches!(result, Err(Error::IndexFileInvalid { .. })));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_hash_index_with_wrong_row_count() -> crate::Result<()> {
+        let file_io = FileIOBuilder::new("memory").build()?;
+        write_hashes(&file_io, "index-short-0", &[1, 2, 3]).await;
+        let entry = IndexManifestEntry::new(
+            FileKind::Add,
+            vec![],
+            0,
+            IndexFileMeta::new(
+                HASH_INDEX.to_string(),
+                "index-short-0".to_string(),
+                16,
+                4,
+                None,
+            ),
+            IndexManifestEntry::VERSION,
+        );
+        let mut reader = HashBucketIndexReader::new(file_io, TABLE_PATH);
+        let result = reader.read_index(&entry).await;
+        assert!(
+            matches!(result, Err(Error::IndexFileInvalid { message }) if message.contains("4 key hashes"))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+use bytes::Bytes;
+use chrono::Utc;
+use parquet::arrow::ArrowWriter;
+
+use crate::io::OutputFile;
+use crate::spec::{BinaryTableStats, DataFileMeta, EMPTY_BINARY_ROW};
+
+/// Writer of a single parquet data file, producing its [`DataFileMeta`] on close.
+///
+/// The file is encoded in memory and written to the storage on [`DataFileWriter::close`].
+/// Sequence numbers are assigned to the rows in order, starting from the given one. Files are
+/// written at level 0 unless set otherwise with [`DataFileWriter::with_level`].
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/io/RowDataFileWriter.java>
+pub struct DataFileWriter {
+    output: OutputFile,
+    schema_id: i64,
+    writer: ArrowWriter<Vec<u8>>,
+    min_sequence_number: i64,
+    level: i32,
+    row_count: i64,
+}
+
+impl DataFileWriter {
+    pub fn new(
+        output: OutputFile,
+        schema: SchemaRef,
+        schema_id: i64,
+        min_sequence_number: i64,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            output,
+            schema_id,
+            writer: ArrowWriter::try_new(Vec::new(), schema, None)?,
+            min_sequence_number,
+            level: 0,
+            row_count: 0,
+        })
+    }
+
+    /// Set the level of the file in the LSM tree of its bucket, see
+    /// [`MergeTreeLevels`](crate::mergetree::MergeTreeLevels) for primary key tables.
+    pub fn with_level(mut self, level: i32) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Get the number of rows written so far.
+    #[inline]
+    pub fn row_count(&self) -> i64 {
+        self.row_count
+    }
+
+    /// Get the estimated size of the file: the bytes flushed so far and the encoded bytes
+    /// buffered in memory.
+    pub fn estimated_size(&self) -> u64 {
+        (self.writer.bytes_written() + self.writer.in_progress_size()) as u64
+    }
+
+    /// Write a batch, whose schema must be the schema of the file.
+    pub fn write(&mut self, batch: &RecordBatch) -> crate::Result<()> {
+        self.writer.write(batch)?;
+        self.row_count += batch.num_rows() as i64;
+        Ok(())
+    }
+
+    /// Finish the file, write it to the storage and return its meta.
+    ///
+    /// Stats are not collected yet, the meta carries empty keys and stats.
+    pub async fn close(self) -> crate::Result<DataFileMeta> {
+        let bytes = self.writer.into_inner()?;
+        let file_size = bytes.len() as i64;
+        self.output.write(Bytes::from(bytes)).await?;
+
+        let location = self.output.location();
+        let file_name = location.rsplit('/').next().unwrap_or(location);
+        let empty_row = EMPTY_BINARY_ROW.to_bytes();
+        let empty_stats = BinaryTableStats::new(empty_row.clone(), empty_row.clone(), vec![]);
+        Ok(DataFileMeta::builder()
+            .file_name(file_name.to_string())
+            .file_size(file_size)
+            .row_count(self.row_count)
+            .min_key(empty_row.clone())
+            .max_key(empty_row)
+            .key_stats(empty_stats.clone())
+            .value_stats(empty_stats)
+            .min_sequence_number(self.min_sequence_number)
+            .max_sequence_number(self.min_sequence_number + self.row_count - 1)
+            .schema_id(self.schema_id)
+            .level(self.level)
+            .creation_time(Utc::now())
+            .delete_row_count(Some(0))
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::mergetree::MergeTreeLevels;
+    use crate::spec::CoreOptions;
+
+    #[tokio::test]
+    async fn test_write_data_file() -> crate::Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+            ],
+        )?;
+
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let path = "memory:/tmp/test_write_data_file/bucket-0/data-0.parquet";
+        let mut writer = DataFileWriter::new(file_io.new_output(path)?, schema, 3, 10)?;
+        writer.write(&batch)?;
+        writer.write(&batch.slice(0, 1))?;
+        assert_eq!(writer.row_count(), 4);
+        let meta = writer.close().await?;
+
+        assert_eq!(meta.file_name(), "data-0.parquet");
+        assert_eq!(meta.row_count(), 4);
+        assert_eq!(meta.min_sequence_number(), 10);
+        assert_eq!(meta.max_sequence_number(), 13);
+        assert_eq!(meta.schema_id(), 3);
+        assert_eq!(meta.level(), 0);
+
+        // files of primary key tables are flushed at level 0 and compacted up to the last level
+        let levels = MergeTreeLevels::new(&CoreOptions::new(HashMap::from([(
+            CoreOptions::NUM_LEVELS.to_string(),
+            "3".to_string(),
+        )])))?;
+        for (i, level) in [MergeTreeLevels::FLUSH_LEVEL, levels.max_level()]
+            .into_iter()
+            .enumerate()
+        {
+            let path = format!(
+                "memory:/tmp/test_write_data_file/bucket-0/data-kv-{}.parquet",
+                i
+            );
+            let mut writer =
+                DataFileWriter::new(file_io.new_output(&path)?, batch.schema(), 3, 14)?
+                    .with_level(level);
+            writer.write(&batch)?;
+            assert_eq!(writer.close().await?.level(), level);
+        }
+
+        let bytes = file_io.new_input(path)?.read().await?;
+        assert_eq!(meta.file_size(), bytes.len() as i64);
+        let rows: usize = ParquetRecordBatchReaderBuilder::try_new(bytes)?
+            .build()?
+            .map(|batch| batch.unwrap().num_rows())
+            .sum();
+        assert_eq!(rows, 4);
+        Ok(())
+    }
+}
@@ -22,6 +22,8 @@ use std::sync::Arc;
 
 use bytes::Bytes;
 use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use futures::{future, StreamExt, TryStreamExt};
 use opendal::Operator;
 use snafu::ResultExt;
 use url::Url;
@@ -93,28 +95,42 @@ impl FileIO {
     /// List the statuses of the files/directories in the given path if the path is a directory.
     ///
     /// References: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/fs/FileIO.java#L105>
-    ///
-    /// FIXME: how to handle large dir? Better to return a stream instead?
     pub async fn list_status(&self, path: &str) -> Result<Vec<FileStatus>> {
+        let statuses = self.list_status_stream(path).await?;
+        statuses.try_collect().await
+    }
+
+    /// List the statuses of the files/directories in the given path as a stream, fetching them
+    /// from the storage as they are consumed rather than all at once, for large directories.
+    pub async fn list_status_stream(
+        &self,
+        path: &str,
+    ) -> Result<BoxStream<'static, Result<FileStatus>>> {
         let (op, relative_path) = self.storage.create(path)?;
 
-        let entries = op.list(relative_path).await.context(IoUnexpectedSnafu {
+        let lister = op.lister(relative_path).await.context(IoUnexpectedSnafu {
             message: format!("Failed to list files in '{}'", path),
         })?;
 
-        let mut statuses = Vec::new();
-
-        for entry in entries {
-            let meta = entry.metadata();
-            statuses.push(FileStatus {
-                size: meta.content_length(),
-                is_dir: meta.is_dir(),
-                path: path.to_string(),
-                last_modified: meta.last_modified(),
+        let prefix = path[..path.len() - relative_path.len()].to_string();
+        let relative_path = relative_path.to_string();
+        let path = path.to_string();
+        let statuses = lister
+            // opendal lists the directory itself as well.
+            .try_filter(move |entry| future::ready(entry.path() != relative_path))
+            .map(move |entry| {
+                let entry = entry.context(IoUnexpectedSnafu {
+                    message: format!("Failed to list files in '{}'", path),
+                })?;
+                let meta = entry.metadata();
+                Ok(FileStatus {
+                    size: meta.content_length(),
+                    is_dir: meta.is_dir(),
+                    path: format!("{}{}", prefix, entry.path()),
+                    last_modified: meta.last_modified(),
+                })
             });
-        }
-
-        Ok(statuses)
+        Ok(statuses.boxed())
     }
 
     /// Check if exists.
@@ -128,6 +144,17 @@ impl FileIO {
         })
     }
 
+    /// Read a whole file as a UTF-8 string, like the JSON of a schema or a snapshot.
+    ///
+    /// A file which is not valid UTF-8 is reported as [`Error::Utf8Invalid`].
+    pub async fn read_to_string(&self, path: &str) -> Result<String> {
+        let input = self.new_input(path)?;
+        let bytes = input.read().await?;
+        String::from_utf8(bytes.to_vec()).context(Utf8InvalidSnafu {
+            message: format!("File '{}' is not valid UTF-8", path),
+        })
+    }
+
     /// Delete a file.
     ///
     /// Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/fs/FileIO.java#L139>
@@ -160,8 +187,14 @@ impl FileIO {
     ///
     /// Has the semantics of Unix 'mkdir -p'. Existence of the directory hierarchy is not an error.
     ///
+    /// This is a no-op on storages without real directories, like object stores. Writing a file
+    /// never requires creating its parent directories first.
+    ///
     /// Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/fs/FileIO.java#L150>
     pub async fn mkdirs(&self, path: &str) -> Result<()> {
+        if !self.storage.has_directories() {
+            return Ok(());
+        }
         let (op, relative_path) = self.storage.create(path)?;
 
         op.create_dir(relative_path)
@@ -173,6 +206,31 @@ impl FileIO {
         Ok(())
     }
 
+    /// Check that the path can be accessed with the configured credentials, with a single
+    /// `stat` of the path, to fail fast on a misconfigured storage.
+    ///
+    /// The error tells whether the access was denied, the path does not exist, the storage is
+    /// misconfigured or hit a temporary failure, like a network error.
+    pub async fn check_access(&self, path: &str) -> Result<()> {
+        let (op, relative_path) = self.storage.create(path)?;
+
+        op.stat(relative_path).await.map_err(|source| {
+            let reason = match source.kind() {
+                opendal::ErrorKind::PermissionDenied => "permission denied",
+                opendal::ErrorKind::NotFound => "path does not exist",
+                opendal::ErrorKind::ConfigInvalid => "invalid storage config",
+                _ if source.is_temporary() => "temporary failure, the storage may be unreachable",
+                _ => "unexpected failure",
+            };
+            Error::IoUnexpected {
+                message: format!("Failed to access '{}': {}", path, reason),
+                source,
+            }
+        })?;
+
+        Ok(())
+    }
+
     /// Renames the file/directory src to dst.
     ///
     /// Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/fs/FileIO.java#L159>
@@ -360,7 +418,9 @@ mod file_action_test {
     use bytes::Bytes;
 
     fn setup_memory_file_io() -> FileIO {
-        let storage = Storage::Memory;
+        let storage = Storage::Memory {
+            op: crate::io::memory_config_build().unwrap(),
+        };
         FileIO {
             storage: Arc::new(storage),
         }
@@ -464,6 +524,92 @@ mod file_action_test {
         common_test_mkdirs(&file_io, "file:/tmp/test_fs_dir/").await;
     }
 
+    #[tokio::test]
+    async fn test_check_access_memory() {
+        let file_io = setup_memory_file_io();
+        file_io
+            .new_output("memory:/test_check_access/a/file")
+            .unwrap()
+            .write(Bytes::from("hello world"))
+            .await
+            .unwrap();
+        file_io
+            .check_access("memory:/test_check_access/a/")
+            .await
+            .unwrap();
+        file_io
+            .check_access("memory:/test_check_access/a/file")
+            .await
+            .unwrap();
+
+        let err = file_io
+            .check_access("memory:/test_check_access/missing")
+            .await
+            .unwrap_err();
+        assert!(err.is_not_found(), "{err}");
+        assert!(err.to_string().contains("path does not exist"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_mkdirs_memory() {
+        let file_io = setup_memory_file_io();
+        file_io.mkdirs("memory:/test_mkdirs_mem/a/").await.unwrap();
+    }
+
+    async fn common_test_write_deep_path(file_io: &FileIO, path: &str) {
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(Bytes::from("hello world"))
+            .await
+            .unwrap();
+
+        assert!(file_io.exists(path).await.unwrap());
+        let content = file_io.new_input(path).unwrap().read().await.unwrap();
+        assert_eq!(&content[..], b"hello world");
+        file_io.delete_file(path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_read_to_string() {
+        let file_io = setup_memory_file_io();
+        let path = "memory:/test_read_to_string/text";
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(Bytes::from("{\"id\": 1, \"name\": \"caf\u{e9}\"}"))
+            .await
+            .unwrap();
+        assert_eq!(
+            file_io.read_to_string(path).await.unwrap(),
+            "{\"id\": 1, \"name\": \"caf\u{e9}\"}"
+        );
+
+        let path = "memory:/test_read_to_string/binary";
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(Bytes::from_static(b"caf\xe9"))
+            .await
+            .unwrap();
+        let err = file_io.read_to_string(path).await.unwrap_err();
+        assert!(matches!(err, Error::Utf8Invalid { .. }));
+        assert!(err.to_string().contains("is not valid UTF-8"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_write_deep_path_memory() {
+        let file_io = setup_memory_file_io();
+        common_test_write_deep_path(&file_io, "memory:/test_deep_mem/a/b/c/file").await;
+    }
+
+    #[tokio::test]
+    async fn test_write_deep_path_fs() {
+        let file_io = setup_fs_file_io();
+        common_test_write_deep_path(&file_io, "file:/tmp/test_deep_fs/a/b/c/file").await;
+        let _ = fs::remove_dir_all("/tmp/test_deep_fs");
+    }
+
     #[tokio::test]
     async fn test_rename_fs() {
         let file_io = setup_fs_file_io();
@@ -482,7 +628,9 @@ mod input_output_test {
     use bytes::Bytes;
 
     fn setup_memory_file_io() -> FileIO {
-        let storage = Storage::Memory;
+        let storage = Storage::Memory {
+            op: crate::io::memory_config_build().unwrap(),
+        };
         FileIO {
             storage: Arc::new(storage),
         }
@@ -15,6 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod data_file_writer;
+pub use data_file_writer::*;
+
 mod file_io;
 pub use file_io::*;
 
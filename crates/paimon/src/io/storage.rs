@@ -25,7 +25,7 @@ use super::FileIOBuilder;
 #[derive(Debug)]
 pub enum Storage {
     #[cfg(feature = "storage-memory")]
-    Memory,
+    Memory { op: Operator },
     #[cfg(feature = "storage-fs")]
     LocalFs,
 }
@@ -37,7 +37,9 @@ impl Storage {
 
         match scheme {
             #[cfg(feature = "storage-memory")]
-            Scheme::Memory => Ok(Self::Memory),
+            Scheme::Memory => Ok(Self::Memory {
+                op: super::memory_config_build()?,
+            }),
             #[cfg(feature = "storage-fs")]
             Scheme::Fs => Ok(Self::LocalFs),
             _ => Err(error::Error::IoUnsupported {
@@ -49,8 +51,8 @@ impl Storage {
     pub(crate) fn create<'a>(&self, path: &'a str) -> crate::Result<(Operator, &'a str)> {
         match self {
             #[cfg(feature = "storage-memory")]
-            Storage::Memory => {
-                let op = super::memory_config_build()?;
+            Storage::Memory { op } => {
+                let op = op.clone();
 
                 if let Some(stripped) = path.strip_prefix("memory:/") {
                     Ok((op, stripped))
@@ -71,6 +73,17 @@ impl Storage {
         }
     }
 
+    /// Whether the storage has real directories, which must be created. Object stores and the
+    /// memory storage only have files, whose parents exist implicitly.
+    pub(crate) fn has_directories(&self) -> bool {
+        match self {
+            #[cfg(feature = "storage-memory")]
+            Storage::Memory { .. } => false,
+            #[cfg(feature = "storage-fs")]
+            Storage::LocalFs => true,
+        }
+    }
+
     fn parse_scheme(scheme: &str) -> crate::Result<Scheme> {
         match scheme {
             "memory" => Ok(Scheme::Memory),
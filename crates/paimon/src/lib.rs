@@ -15,10 +15,33 @@
 // specific language governing permissions and limitations
 // under the License.
 
+//! The rust implementation of Apache Paimon.
+//!
+//! ## Async runtime
+//!
+//! IO goes through [opendal](https://docs.rs/opendal) and concurrency is built on the `futures`
+//! crate, so the crate itself does not spawn tasks on a specific async runtime. The storage
+//! services may need one though: the `fs` service of the `storage-fs` feature, enabled by
+//! default, runs on tokio. To drive the futures with another executor, like async-std or smol,
+//! disable the default features and enable only storages which do not need tokio, like
+//! `storage-memory`.
+
 mod error;
 pub use error::Error;
 pub use error::Result;
 
+pub mod append;
+pub mod arrow;
+pub mod catalog;
+pub mod compaction;
+pub mod deletion_vector;
 pub mod file_index;
+pub mod index;
 pub mod io;
+pub mod mergetree;
+pub mod predicate;
 pub mod spec;
+pub mod table;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+pub(crate) mod trace;
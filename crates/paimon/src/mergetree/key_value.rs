@@ -0,0 +1,146 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::spec::{BigIntType, BinaryRow, DataField, DataType, RowKind, TinyIntType};
+use crate::Error;
+
+/// A record of a primary key table: the key, the sequence number ordering the changes of the
+/// key, the kind of the change and the value.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/KeyValue.java>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyValue {
+    key: BinaryRow,
+    sequence_number: i64,
+    value_kind: RowKind,
+    value: BinaryRow,
+}
+
+impl KeyValue {
+    /// Prefix of the names of the key fields in data files.
+    pub const KEY_FIELD_PREFIX: &'static str = "_KEY_";
+    /// Offset of the ids of the key fields in data files.
+    pub const KEY_FIELD_ID_START: i32 = i32::MAX / 2;
+    pub const SEQUENCE_NUMBER: &'static str = "_SEQUENCE_NUMBER";
+    pub const VALUE_KIND: &'static str = "_VALUE_KIND";
+
+    /// Get the fields of the data files of a primary key table: the key fields renamed with
+    /// [`KeyValue::KEY_FIELD_PREFIX`], the sequence number, the value kind, and the value fields.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/KeyValue.java#L140>
+    pub fn fields(key_fields: &[DataField], value_fields: &[DataField]) -> Vec<DataField> {
+        let mut fields = Vec::with_capacity(key_fields.len() + 2 + value_fields.len());
+        fields.extend(key_fields.iter().map(|field| {
+            field
+                .clone()
+                .with_id(Self::KEY_FIELD_ID_START + field.id())
+                .with_name(format!("{}{}", Self::KEY_FIELD_PREFIX, field.name()))
+        }));
+        fields.push(DataField::new(
+            i32::MAX - 1,
+            Self::SEQUENCE_NUMBER.to_string(),
+            DataType::BigInt(BigIntType::with_nullable(false)),
+        ));
+        fields.push(DataField::new(
+            i32::MAX - 2,
+            Self::VALUE_KIND.to_string(),
+            DataType::TinyInt(TinyIntType::with_nullable(false)),
+        ));
+        fields.extend(value_fields.iter().cloned());
+        fields
+    }
+
+    pub fn new(
+        key: BinaryRow,
+        sequence_number: i64,
+        value_kind: RowKind,
+        value: BinaryRow,
+    ) -> Self {
+        Self {
+            key,
+            sequence_number,
+            value_kind,
+            value,
+        }
+    }
+
+    pub fn key(&self) -> &BinaryRow {
+        &self.key
+    }
+
+    pub fn sequence_number(&self) -> i64 {
+        self.sequence_number
+    }
+
+    pub fn value_kind(&self) -> RowKind {
+        self.value_kind
+    }
+
+    pub fn value(&self) -> &BinaryRow {
+        &self.value
+    }
+
+    /// Estimate the memory used by the record.
+    pub(crate) fn estimated_size(&self) -> usize {
+        // arities, sequence number and kind
+        4 + 4 + 8 + 1 + self.key.data().len() + self.value.data().len()
+    }
+
+    /// Serialize the record for spilling: the length prefixed key, the sequence number, the
+    /// kind, and the length prefixed value.
+    pub(crate) fn serialize_into(&self, buffer: &mut BytesMut) {
+        let key = self.key.to_bytes();
+        buffer.put_u32(key.len() as u32);
+        buffer.put_slice(&key);
+        buffer.put_i64(self.sequence_number);
+        buffer.put_u8(self.value_kind.to_byte_value());
+        let value = self.value.to_bytes();
+        buffer.put_u32(value.len() as u32);
+        buffer.put_slice(&value);
+    }
+
+    /// Deserialize a record written by [`KeyValue::serialize_into`] from the head of `buffer`.
+    pub(crate) fn deserialize_from(buffer: &mut Bytes) -> crate::Result<Self> {
+        let key = BinaryRow::from_bytes(&read_sized(buffer)?)?;
+        if buffer.remaining() < 9 {
+            return Err(truncated());
+        }
+        let sequence_number = buffer.get_i64();
+        let value_kind = RowKind::from_byte_value(buffer.get_u8())?;
+        let value = BinaryRow::from_bytes(&read_sized(buffer)?)?;
+        Ok(Self::new(key, sequence_number, value_kind, value))
+    }
+}
+
+fn read_sized(buffer: &mut Bytes) -> crate::Result<Bytes> {
+    if buffer.remaining() < 4 {
+        return Err(truncated());
+    }
+    let len = buffer.get_u32() as usize;
+    if buffer.remaining() < len {
+        return Err(truncated());
+    }
+    Ok(buffer.split_to(len))
+}
+
+fn truncated() -> Error {
+    Error::BinaryRowInvalid {
+        message: "Spilled key value is truncated".to_string(),
+    }
+}
@@ -0,0 +1,313 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Int64Type, Int8Type};
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_schema::DataType as ArrowDataType;
+
+use crate::arrow::to_literal;
+use crate::error::ColumnNotExistSnafu;
+use crate::mergetree::KeyValue;
+use crate::spec::{BinaryRow, BinaryRowWriter, DataType, RowKind, TableSchema};
+use crate::Error;
+
+/// Reader of the records of a data file of a primary key table, see [`KeyValue::fields`] for
+/// the layout of the file.
+///
+/// The key is made of the trimmed primary keys of the schema, the value of all its fields. The
+/// columns are looked up by name in the batches of the wrapped reader.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/io/KeyValueDataFileRecordReader.java>
+pub struct KeyValueReader<R: RecordBatchReader> {
+    reader: R,
+    /// position in the file and type of each key field
+    key_fields: Vec<(usize, DataType)>,
+    sequence_number_pos: usize,
+    value_kind_pos: usize,
+    /// position in the file and type of each value field
+    value_fields: Vec<(usize, DataType)>,
+    batch: Option<RecordBatch>,
+    /// position of the next row in `batch`
+    row: usize,
+}
+
+impl<R: RecordBatchReader> KeyValueReader<R> {
+    pub fn new(reader: R, schema: &TableSchema) -> crate::Result<Self> {
+        let file_schema = reader.schema();
+        let position = |name: &str| match file_schema.index_of(name) {
+            Ok(pos) => Ok(pos),
+            Err(_) => ColumnNotExistSnafu {
+                message: format!("Column '{}' does not exist in the key value file", name),
+            }
+            .fail(),
+        };
+
+        let key_fields = schema
+            .logical_trimmed_primary_keys_type()
+            .fields()
+            .iter()
+            .map(|field| {
+                let name = format!("{}{}", KeyValue::KEY_FIELD_PREFIX, field.name());
+                Ok((position(&name)?, field.data_type().clone()))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        let value_fields = schema
+            .fields()
+            .iter()
+            .map(|field| Ok((position(field.name())?, field.data_type().clone())))
+            .collect::<crate::Result<Vec<_>>>()?;
+        let system_column = |name: &str, data_type: ArrowDataType| {
+            let pos = position(name)?;
+            let actual = file_schema.field(pos).data_type();
+            if actual != &data_type {
+                return Err(Error::KeyValueInvalid {
+                    message: format!(
+                        "Column '{}' of the key value file has type {} instead of {}",
+                        name, actual, data_type
+                    ),
+                });
+            }
+            Ok(pos)
+        };
+        Ok(Self {
+            key_fields,
+            sequence_number_pos: system_column(KeyValue::SEQUENCE_NUMBER, ArrowDataType::Int64)?,
+            value_kind_pos: system_column(KeyValue::VALUE_KIND, ArrowDataType::Int8)?,
+            value_fields,
+            reader,
+            batch: None,
+            row: 0,
+        })
+    }
+
+    /// Read the record at `row` of the batch.
+    fn key_value(&self, batch: &RecordBatch, row: usize) -> crate::Result<KeyValue> {
+        let sequence_number = batch
+            .column(self.sequence_number_pos)
+            .as_primitive::<Int64Type>()
+            .value(row);
+        let value_kind = batch
+            .column(self.value_kind_pos)
+            .as_primitive::<Int8Type>()
+            .value(row);
+        Ok(KeyValue::new(
+            Self::row(batch, row, &self.key_fields)?,
+            sequence_number,
+            RowKind::from_byte_value(value_kind as u8)?,
+            Self::row(batch, row, &self.value_fields)?,
+        ))
+    }
+
+    fn row(
+        batch: &RecordBatch,
+        row: usize,
+        fields: &[(usize, DataType)],
+    ) -> crate::Result<BinaryRow> {
+        let mut writer = BinaryRowWriter::new(fields.len() as i32);
+        for (i, (pos, data_type)) in fields.iter().enumerate() {
+            let literal = to_literal(batch.column(*pos).as_ref(), row)?;
+            writer.write_literal(i, &literal, data_type)?;
+        }
+        Ok(writer.build())
+    }
+}
+
+impl<R: RecordBatchReader> Iterator for KeyValueReader<R> {
+    type Item = crate::Result<KeyValue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(batch) = &self.batch {
+                if self.row < batch.num_rows() {
+                    let result = self.key_value(batch, self.row);
+                    self.row += 1;
+                    return Some(result);
+                }
+            }
+            match self.reader.next()? {
+                Ok(batch) => {
+                    self.batch = Some(batch);
+                    self.row = 0;
+                }
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, Int64Array, Int8Array, StringArray};
+    use bytes::Bytes;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::arrow::ArrowWriter;
+
+    use super::*;
+    use crate::arrow::to_arrow_schema;
+    use crate::spec::{DataField, IntType, VarCharType};
+
+    fn table_schema() -> TableSchema {
+        let fields = vec![
+            DataField::new(
+                0,
+                "dt".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+            DataField::new(
+                1,
+                "id".to_string(),
+                DataType::Int(IntType::with_nullable(false)),
+            ),
+            DataField::new(
+                2,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+        ];
+        TableSchema::new(
+            0,
+            fields,
+            2,
+            vec!["dt".to_string()],
+            vec!["dt".to_string(), "id".to_string()],
+            HashMap::new(),
+            None,
+        )
+    }
+
+    /// Write a parquet data file of a primary key table, as Paimon lays it out.
+    fn key_value_file(schema: &TableSchema) -> Bytes {
+        let key_fields = schema.logical_trimmed_primary_keys_type().fields().to_vec();
+        let fields = KeyValue::fields(&key_fields, schema.fields());
+        assert_eq!(
+            fields.iter().map(|f| f.name()).collect::<Vec<_>>(),
+            vec![
+                "_KEY_id",
+                "_SEQUENCE_NUMBER",
+                "_VALUE_KIND",
+                "dt",
+                "id",
+                "name"
+            ]
+        );
+        assert_eq!(fields[0].id(), KeyValue::KEY_FIELD_ID_START + 1);
+
+        let arrow_schema = Arc::new(to_arrow_schema(&fields).unwrap());
+        let batch = RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(Int64Array::from(vec![7, 5, 6])),
+                Arc::new(Int8Array::from(vec![0, 2, 3])),
+                Arc::new(StringArray::from(vec!["2024-01-01"; 3])),
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("a"), Some("b"), None])),
+            ],
+        )
+        .unwrap();
+        let mut writer = ArrowWriter::try_new(Vec::new(), arrow_schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        Bytes::from(writer.into_inner().unwrap())
+    }
+
+    #[test]
+    fn test_read_key_values() -> crate::Result<()> {
+        let schema = table_schema();
+        // batches of two rows, to read across batches
+        let reader = ParquetRecordBatchReaderBuilder::try_new(key_value_file(&schema))?
+            .with_batch_size(2)
+            .build()?;
+        let records = KeyValueReader::new(reader, &schema)?.collect::<crate::Result<Vec<_>>>()?;
+        assert_eq!(records.len(), 3);
+
+        let keys = records
+            .iter()
+            .map(|kv| kv.key().get_int(0))
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec![1, 2, 3]);
+        assert!(records.iter().all(|kv| kv.key().arity() == 1));
+        let sequence_numbers = records
+            .iter()
+            .map(KeyValue::sequence_number)
+            .collect::<Vec<_>>();
+        assert_eq!(sequence_numbers, vec![7, 5, 6]);
+        let kinds = records.iter().map(KeyValue::value_kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![RowKind::Insert, RowKind::UpdateAfter, RowKind::Delete]
+        );
+
+        let value = records[1].value();
+        assert_eq!(value.arity(), 3);
+        assert_eq!(value.get_string(0)?, "2024-01-01");
+        assert_eq!(value.get_int(1), 2);
+        assert_eq!(value.get_string(2)?, "b");
+        assert!(records[2].value().is_null_at(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_system_column_type() {
+        let schema = table_schema();
+        let key_fields = schema.logical_trimmed_primary_keys_type().fields().to_vec();
+        let fields = KeyValue::fields(&key_fields, schema.fields());
+        let arrow_schema = to_arrow_schema(&fields).unwrap();
+        for invalid in [KeyValue::SEQUENCE_NUMBER, KeyValue::VALUE_KIND] {
+            let fields = arrow_schema
+                .fields()
+                .iter()
+                .map(|field| {
+                    if field.name() == invalid {
+                        Arc::new(field.as_ref().clone().with_data_type(ArrowDataType::Int32))
+                    } else {
+                        field.clone()
+                    }
+                })
+                .collect::<Vec<_>>();
+            let reader = arrow_array::RecordBatchIterator::new(
+                vec![],
+                Arc::new(arrow_schema::Schema::new(fields)),
+            );
+            assert!(
+                matches!(
+                    KeyValueReader::new(reader, &schema),
+                    Err(crate::Error::KeyValueInvalid { .. })
+                ),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_system_column() -> crate::Result<()> {
+        let schema = table_schema();
+        let bytes = key_value_file(&schema);
+        // a file of an append-only table has no key and system columns
+        let reader = ParquetRecordBatchReaderBuilder::try_new(bytes)?;
+        let mask = parquet::arrow::ProjectionMask::roots(reader.parquet_schema(), [3, 4, 5]);
+        let reader = reader.with_projection(mask).build()?;
+        assert!(matches!(
+            KeyValueReader::new(reader, &schema),
+            Err(crate::Error::ColumnNotExist { .. })
+        ));
+        Ok(())
+    }
+}
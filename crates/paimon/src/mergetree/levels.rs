@@ -0,0 +1,87 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::error::ConfigInvalidSnafu;
+use crate::spec::CoreOptions;
+
+/// Levels of the LSM tree of a bucket of a primary key table, `num-levels` in total.
+///
+/// Written records are flushed into new files at level 0, whose files may overlap, while
+/// compactions rewrite sorted runs into a higher level, up to [`MergeTreeLevels::max_level`].
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/mergetree/Levels.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergeTreeLevels {
+    num_levels: i32,
+}
+
+impl MergeTreeLevels {
+    /// Level of the files flushed from the write buffer.
+    pub const FLUSH_LEVEL: i32 = 0;
+
+    pub fn new(options: &CoreOptions) -> crate::Result<Self> {
+        let num_levels = options.num_levels()? as i32;
+        if num_levels < 2 {
+            return ConfigInvalidSnafu {
+                message: format!(
+                    "Option '{}' must be at least 2, but is {}",
+                    CoreOptions::NUM_LEVELS,
+                    num_levels
+                ),
+            }
+            .fail();
+        }
+        Ok(Self { num_levels })
+    }
+
+    #[inline]
+    pub fn num_levels(&self) -> i32 {
+        self.num_levels
+    }
+
+    /// Get the highest level, holding the oldest records.
+    #[inline]
+    pub fn max_level(&self) -> i32 {
+        self.num_levels - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        let levels = MergeTreeLevels::new(&CoreOptions::new(HashMap::new())).unwrap();
+        assert_eq!(levels.num_levels(), 6);
+        assert_eq!(levels.max_level(), 5);
+
+        let options = |num_levels: &str| {
+            CoreOptions::new(HashMap::from([(
+                CoreOptions::NUM_LEVELS.to_string(),
+                num_levels.to_string(),
+            )]))
+        };
+        assert_eq!(MergeTreeLevels::new(&options("3")).unwrap().max_level(), 2);
+        assert!(matches!(
+            MergeTreeLevels::new(&options("1")),
+            Err(crate::Error::ConfigInvalid { .. })
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::mergetree::KeyValue;
+
+/// Merge the records of a key, added in sequence order, into the result.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/mergetree/compact/MergeFunction.java>
+pub trait MergeFunction: Send {
+    /// Reset the function to merge the records of another key.
+    fn reset(&mut self);
+
+    /// Add the next record of the key.
+    fn add(&mut self, kv: KeyValue) -> crate::Result<()>;
+
+    /// Get the merged record of the key, `None` if there is none.
+    fn result(&mut self) -> crate::Result<Option<KeyValue>>;
+}
+
+/// Keep the record with the highest sequence number, the default merge engine.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/mergetree/compact/DeduplicateMergeFunction.java>
+#[derive(Debug, Default)]
+pub struct DeduplicateMergeFunction {
+    latest: Option<KeyValue>,
+}
+
+impl DeduplicateMergeFunction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl MergeFunction for DeduplicateMergeFunction {
+    fn reset(&mut self) {
+        self.latest = None;
+    }
+
+    fn add(&mut self, kv: KeyValue) -> crate::Result<()> {
+        self.latest = Some(kv);
+        Ok(())
+    }
+
+    fn result(&mut self) -> crate::Result<Option<KeyValue>> {
+        Ok(self.latest.take())
+    }
+}
@@ -0,0 +1,35 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Merge tree of primary key tables: the records of a key in sorted runs are merged into one.
+//!
+//! Impl Reference: <https://github.com/apache/paimon/tree/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/mergetree>
+
+mod key_value;
+pub use key_value::*;
+
+mod key_value_reader;
+pub use key_value_reader::*;
+
+mod levels;
+pub use levels::*;
+
+mod merge_function;
+pub use merge_function::*;
+
+mod sort_merge_reader;
+pub use sort_merge_reader::*;
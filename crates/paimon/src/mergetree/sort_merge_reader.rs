@@ -0,0 +1,435 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+
+use bytes::BytesMut;
+
+use crate::error::ColumnNotExistSnafu;
+use crate::io::FileIO;
+use crate::mergetree::{KeyValue, MergeFunction};
+use crate::spec::{BinaryRow, CoreOptions, DataType, RowType};
+use crate::Error;
+
+/// Iterator over the records of a sorted run, ordered by key.
+pub type KeyValueIterator = Box<dyn Iterator<Item = crate::Result<KeyValue>> + Send>;
+
+/// Reader merging sorted runs: the records of each key, from all runs, are merged by the
+/// [`MergeFunction`] into one.
+///
+/// The records of a key are buffered in sequence order until all of them are read, then merged.
+/// With [`SortMergeReader::with_sequence_fields`], the records of a key are ordered by the
+/// values of the sequence fields first, see [`CoreOptions::sequence_field`].
+/// Once the buffered records exceed the buffer limit, they are spilled to a temporary file if a
+/// spill directory is set with [`SortMergeReader::with_spill`], and read back when merging.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/mergetree/compact/SortMergeReaderWithMinHeap.java>
+pub struct SortMergeReader<M: MergeFunction> {
+    readers: Vec<KeyValueIterator>,
+    /// the next record of each reader, `None` once the reader is exhausted
+    heads: Vec<Option<KeyValue>>,
+    initialized: bool,
+    key_type: RowType,
+    /// position and type in the value of each sequence field
+    sequence_fields: Vec<(usize, DataType)>,
+    merge_function: M,
+    buffer_limit: u64,
+    spill: Option<(FileIO, String)>,
+    spill_count: usize,
+}
+
+impl<M: MergeFunction> SortMergeReader<M> {
+    pub fn new(readers: Vec<KeyValueIterator>, key_type: RowType, merge_function: M) -> Self {
+        Self {
+            readers,
+            heads: vec![],
+            initialized: false,
+            key_type,
+            sequence_fields: vec![],
+            merge_function,
+            buffer_limit: CoreOptions::DEFAULT_SORT_SPILL_BUFFER_SIZE,
+            spill: None,
+            spill_count: 0,
+        }
+    }
+
+    /// Order the records of a key by the given fields of their values, nulls first, and only then
+    /// by their sequence numbers.
+    pub fn with_sequence_fields(
+        mut self,
+        value_type: &RowType,
+        sequence_fields: &[String],
+    ) -> crate::Result<Self> {
+        self.sequence_fields = sequence_fields
+            .iter()
+            .map(|name| {
+                match value_type
+                    .fields()
+                    .iter()
+                    .position(|field| field.name() == name)
+                {
+                    Some(pos) => Ok((pos, value_type.fields()[pos].data_type().clone())),
+                    None => ColumnNotExistSnafu {
+                        message: format!("Sequence field '{}' does not exist", name),
+                    }
+                    .fail(),
+                }
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        Ok(self)
+    }
+
+    /// Set the size in bytes of the records of a key buffered in memory before spilling, see
+    /// [`CoreOptions::sort_spill_buffer_size`].
+    pub fn with_buffer_limit(mut self, buffer_limit: u64) -> Self {
+        self.buffer_limit = buffer_limit;
+        self
+    }
+
+    /// Spill the buffered records into temporary files under `temp_dir`, which are deleted once
+    /// merged. Without it, all records of a key are kept in memory.
+    pub fn with_spill(mut self, file_io: FileIO, temp_dir: impl ToString) -> Self {
+        self.spill = Some((file_io, temp_dir.to_string()));
+        self
+    }
+
+    /// Get the number of spill files written so far.
+    pub fn spill_count(&self) -> usize {
+        self.spill_count
+    }
+
+    /// Read the merged record of the next key, `None` once all readers are exhausted.
+    ///
+    /// Keys merged into no record are skipped.
+    pub async fn next(&mut self) -> crate::Result<Option<KeyValue>> {
+        if !self.initialized {
+            for reader in self.readers.iter_mut() {
+                self.heads.push(reader.next().transpose()?);
+            }
+            self.initialized = true;
+        }
+
+        while let Some(pos) = self.min_head()? {
+            let key = self.heads[pos].as_ref().unwrap().key().clone();
+            let mut buffer = Vec::new();
+            let mut buffered_size = 0;
+            let mut spilled = Vec::new();
+            while let Some(pos) = self.min_head()? {
+                let head = self.heads[pos].as_ref().unwrap();
+                if compare_rows(head.key(), &key, &self.key_type)? != Ordering::Equal {
+                    break;
+                }
+                let kv = self.advance(pos)?;
+                buffered_size += kv.estimated_size() as u64;
+                buffer.push(kv);
+                if buffered_size > self.buffer_limit && self.spill.is_some() {
+                    spilled.push(self.spill_buffer(&buffer).await?);
+                    buffer.clear();
+                    buffered_size = 0;
+                }
+            }
+
+            self.merge_function.reset();
+            for path in spilled {
+                let file_io = &self.spill.as_ref().unwrap().0;
+                let mut bytes = file_io.new_input(&path)?.read().await?;
+                while !bytes.is_empty() {
+                    self.merge_function
+                        .add(KeyValue::deserialize_from(&mut bytes)?)?;
+                }
+                file_io.delete_file(&path).await?;
+            }
+            for kv in buffer {
+                self.merge_function.add(kv)?;
+            }
+            if let Some(result) = self.merge_function.result()? {
+                return Ok(Some(result));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the reader whose next record comes first, by key, then by sequence fields and then
+    /// by sequence number.
+    fn min_head(&self) -> crate::Result<Option<usize>> {
+        let mut min: Option<(usize, &KeyValue)> = None;
+        for (pos, head) in self.heads.iter().enumerate() {
+            let Some(head) = head else {
+                continue;
+            };
+            let is_less = match min {
+                None => true,
+                Some((_, current)) => {
+                    match compare_rows(head.key(), current.key(), &self.key_type)? {
+                        Ordering::Less => true,
+                        Ordering::Equal => {
+                            let sequence_fields = self
+                                .sequence_fields
+                                .iter()
+                                .map(|(pos, data_type)| (*pos, data_type));
+                            match compare_fields(head.value(), current.value(), sequence_fields)? {
+                                Ordering::Equal => {
+                                    head.sequence_number() < current.sequence_number()
+                                }
+                                ordering => ordering == Ordering::Less,
+                            }
+                        }
+                        Ordering::Greater => false,
+                    }
+                }
+            };
+            if is_less {
+                min = Some((pos, head));
+            }
+        }
+        Ok(min.map(|(pos, _)| pos))
+    }
+
+    /// Take the next record of a reader, reading the following one.
+    fn advance(&mut self, pos: usize) -> crate::Result<KeyValue> {
+        let next = self.readers[pos].next().transpose()?;
+        Ok(std::mem::replace(&mut self.heads[pos], next).unwrap())
+    }
+
+    async fn spill_buffer(&mut self, buffer: &[KeyValue]) -> crate::Result<String> {
+        let (file_io, temp_dir) = self.spill.as_ref().unwrap();
+        let mut bytes = BytesMut::new();
+        for kv in buffer {
+            kv.serialize_into(&mut bytes);
+        }
+        let path = format!(
+            "{}/sort-merge-spill-{}-{}",
+            temp_dir.trim_end_matches('/'),
+            uuid::Uuid::new_v4(),
+            self.spill_count
+        );
+        file_io.new_output(&path)?.write(bytes.freeze()).await?;
+        self.spill_count += 1;
+        Ok(path)
+    }
+}
+
+/// Compare two rows of `row_type` field by field, nulls first.
+fn compare_rows(a: &BinaryRow, b: &BinaryRow, row_type: &RowType) -> crate::Result<Ordering> {
+    let fields = row_type
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(pos, field)| (pos, field.data_type()));
+    compare_fields(a, b, fields)
+}
+
+/// Compare two rows by the fields at the given positions, in order, nulls first.
+///
+/// Values which can't be ordered, such as a NaN float, are reported as invalid data.
+fn compare_fields<'a>(
+    a: &BinaryRow,
+    b: &BinaryRow,
+    fields: impl IntoIterator<Item = (usize, &'a DataType)>,
+) -> crate::Result<Ordering> {
+    for (pos, data_type) in fields {
+        let ordering = match (a.is_null_at(pos), b.is_null_at(pos)) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => {
+                let (a, b) = (
+                    a.get_literal(pos, data_type)?,
+                    b.get_literal(pos, data_type)?,
+                );
+                a.compare(&b).ok_or_else(|| Error::KeyValueInvalid {
+                    message: format!("{a} and {b} of sort field {pos} are not comparable"),
+                })?
+            }
+        };
+        if ordering != Ordering::Equal {
+            return Ok(ordering);
+        }
+    }
+    Ok(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::mergetree::DeduplicateMergeFunction;
+    use crate::spec::{BinaryRowWriter, DataField, DataType, DoubleType, IntType, RowKind};
+
+    fn int_row(value: i32) -> BinaryRow {
+        let mut writer = BinaryRowWriter::new(1);
+        writer.write_int(0, value);
+        writer.build()
+    }
+
+    fn kv(key: i32, sequence_number: i64, kind: RowKind, value: i32) -> KeyValue {
+        KeyValue::new(int_row(key), sequence_number, kind, int_row(value))
+    }
+
+    fn readers() -> Vec<KeyValueIterator> {
+        let runs = vec![
+            vec![
+                kv(1, 1, RowKind::Insert, 10),
+                kv(2, 2, RowKind::Insert, 20),
+                kv(3, 3, RowKind::Insert, 30),
+            ],
+            vec![kv(1, 5, RowKind::Insert, 11), kv(3, 6, RowKind::Delete, 30)],
+            vec![
+                kv(1, 7, RowKind::UpdateAfter, 12),
+                kv(2, 4, RowKind::Insert, 21),
+            ],
+            vec![],
+        ];
+        runs.into_iter()
+            .map(|run| Box::new(run.into_iter().map(Ok)) as KeyValueIterator)
+            .collect()
+    }
+
+    fn key_type() -> RowType {
+        RowType::new(vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )])
+    }
+
+    async fn read_all(
+        mut reader: SortMergeReader<DeduplicateMergeFunction>,
+    ) -> crate::Result<(Vec<KeyValue>, usize)> {
+        let mut result = Vec::new();
+        while let Some(kv) = reader.next().await? {
+            result.push(kv);
+        }
+        Ok((result, reader.spill_count()))
+    }
+
+    #[tokio::test]
+    async fn test_merge() -> crate::Result<()> {
+        let reader = SortMergeReader::new(readers(), key_type(), DeduplicateMergeFunction::new());
+        let (result, spill_count) = read_all(reader).await?;
+        assert_eq!(
+            result,
+            vec![
+                kv(1, 7, RowKind::UpdateAfter, 12),
+                kv(2, 4, RowKind::Insert, 21),
+                kv(3, 6, RowKind::Delete, 30),
+            ]
+        );
+        assert_eq!(spill_count, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_merge_with_spill() -> crate::Result<()> {
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let temp_dir = "memory:/tmp/test_sort_merge_reader_spill";
+        let reader = SortMergeReader::new(readers(), key_type(), DeduplicateMergeFunction::new())
+            .with_buffer_limit(1)
+            .with_spill(file_io.clone(), temp_dir);
+        let (result, spill_count) = read_all(reader).await?;
+        assert_eq!(
+            result,
+            vec![
+                kv(1, 7, RowKind::UpdateAfter, 12),
+                kv(2, 4, RowKind::Insert, 21),
+                kv(3, 6, RowKind::Delete, 30),
+            ]
+        );
+        // every record exceeds the limit
+        assert_eq!(spill_count, 7);
+        assert!(
+            !file_io.exists(&format!("{}/", temp_dir)).await?
+                || file_io.list_status(temp_dir).await?.is_empty()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_merge_with_sequence_field() -> crate::Result<()> {
+        let value = |ts: i32, v: i32| {
+            let mut writer = BinaryRowWriter::new(2);
+            writer.write_int(0, ts);
+            writer.write_int(1, v);
+            writer.build()
+        };
+        let value_type = RowType::new(vec![
+            DataField::new(1, "ts".to_string(), DataType::Int(IntType::new())),
+            DataField::new(2, "v".to_string(), DataType::Int(IntType::new())),
+        ]);
+        // the record written later carries an older event time
+        let readers = || -> Vec<KeyValueIterator> {
+            let runs = vec![
+                vec![KeyValue::new(int_row(1), 2, RowKind::Insert, value(3, 20))],
+                vec![KeyValue::new(int_row(1), 5, RowKind::Insert, value(1, 10))],
+            ];
+            runs.into_iter()
+                .map(|run| Box::new(run.into_iter().map(Ok)) as KeyValueIterator)
+                .collect()
+        };
+
+        let reader = SortMergeReader::new(readers(), key_type(), DeduplicateMergeFunction::new());
+        let (result, _) = read_all(reader).await?;
+        assert_eq!(result[0].value(), &value(1, 10));
+
+        let options = CoreOptions::new(HashMap::from([(
+            CoreOptions::SEQUENCE_FIELD.to_string(),
+            "ts".to_string(),
+        )]));
+        let reader = SortMergeReader::new(readers(), key_type(), DeduplicateMergeFunction::new())
+            .with_sequence_fields(&value_type, &options.sequence_field())?;
+        let (result, _) = read_all(reader).await?;
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].sequence_number(), 2);
+        assert_eq!(result[0].value(), &value(3, 20));
+
+        let unknown = SortMergeReader::new(readers(), key_type(), DeduplicateMergeFunction::new())
+            .with_sequence_fields(&value_type, &["event_time".to_string()]);
+        assert!(matches!(unknown, Err(crate::Error::ColumnNotExist { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_incomparable_values() {
+        let nan = || {
+            let mut writer = BinaryRowWriter::new(1);
+            writer.write_double(0, f64::NAN);
+            writer.build()
+        };
+        let row_type = RowType::new(vec![DataField::new(
+            0,
+            "k".to_string(),
+            DataType::Double(DoubleType::new()),
+        )]);
+        let result = compare_rows(&nan(), &nan(), &row_type);
+        assert!(matches!(result, Err(crate::Error::KeyValueInvalid { .. })));
+    }
+
+    #[test]
+    fn test_key_value_serialization() {
+        let kv = kv(1, 7, RowKind::UpdateBefore, 12);
+        let mut buffer = BytesMut::new();
+        kv.serialize_into(&mut buffer);
+        kv.serialize_into(&mut buffer);
+        let mut bytes = buffer.freeze();
+        assert_eq!(KeyValue::deserialize_from(&mut bytes).unwrap(), kv);
+        assert_eq!(KeyValue::deserialize_from(&mut bytes).unwrap(), kv);
+        assert!(bytes.is_empty());
+        assert!(KeyValue::deserialize_from(&mut bytes).is_err());
+    }
+}
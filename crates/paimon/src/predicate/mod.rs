@@ -0,0 +1,989 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Predicates on the fields of a table, used to skip partitions and data files when scanning.
+//!
+//! Impl Reference: <https://github.com/apache/paimon/tree/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/predicate>
+
+mod predicate_builder;
+pub use predicate_builder::*;
+
+use std::cmp::Ordering;
+
+use arrow_array::{BooleanArray, RecordBatch};
+
+use crate::arrow::predicate_mask;
+use crate::spec::{BinaryRow, DataType, Literal, RowType};
+use crate::Error;
+
+/// The function of a [`LeafPredicate`].
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/predicate/LeafFunction.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredicateOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessOrEqual,
+    GreaterThan,
+    GreaterOrEqual,
+    IsNull,
+    IsNotNull,
+    In,
+    NotIn,
+}
+
+/// A predicate on a single field, comparing its value with the literals.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/predicate/LeafPredicate.java>
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeafPredicate {
+    op: PredicateOperator,
+    field_name: String,
+    data_type: DataType,
+    literals: Vec<Literal>,
+}
+
+impl LeafPredicate {
+    pub fn new(
+        op: PredicateOperator,
+        field_name: String,
+        data_type: DataType,
+        literals: Vec<Literal>,
+    ) -> Self {
+        Self {
+            op,
+            field_name,
+            data_type,
+            literals,
+        }
+    }
+
+    pub fn op(&self) -> PredicateOperator {
+        self.op
+    }
+
+    pub fn field_name(&self) -> &str {
+        &self.field_name
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    pub fn literals(&self) -> &[Literal] {
+        &self.literals
+    }
+
+    /// Check that the literals are values of the type of the field, failing with
+    /// [`Error::PredicateTypeMismatch`] otherwise.
+    pub(crate) fn check_literal_types(&self) -> crate::Result<()> {
+        match self
+            .literals
+            .iter()
+            .find(|literal| !literal.is_of_type(&self.data_type))
+        {
+            Some(literal) => Err(Error::PredicateTypeMismatch {
+                field: self.field_name.clone(),
+                expected: self.data_type.to_sql_string(),
+                actual: literal.type_name().to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the field may match, given its statistics within `row_count` rows.
+    ///
+    /// `min` and `max` are null if unknown, and so is `null_count` if `None`. Values which cannot
+    /// be compared are assumed to match.
+    fn test_stats(
+        &self,
+        row_count: i64,
+        min: &Literal,
+        max: &Literal,
+        null_count: Option<i64>,
+    ) -> bool {
+        match self.op {
+            PredicateOperator::IsNull => return !matches!(null_count, Some(n) if n <= 0),
+            PredicateOperator::IsNotNull => return !matches!(null_count, Some(n) if n >= row_count),
+            _ => {}
+        }
+        if null_count == Some(row_count) {
+            // all values are null, no comparison holds
+            return false;
+        }
+        if min.is_null() || max.is_null() {
+            return true;
+        }
+
+        let literal = self.literals.first().unwrap_or(&Literal::Null);
+        if literal.is_null() && self.op != PredicateOperator::In {
+            return false;
+        }
+        match self.op {
+            PredicateOperator::Equal => in_range(min, max, literal),
+            PredicateOperator::NotEqual => !(equal(min, literal) && equal(max, literal)),
+            PredicateOperator::LessThan => may_be(min, literal, &[Ordering::Less]),
+            PredicateOperator::LessOrEqual => {
+                may_be(min, literal, &[Ordering::Less, Ordering::Equal])
+            }
+            PredicateOperator::GreaterThan => may_be(max, literal, &[Ordering::Greater]),
+            PredicateOperator::GreaterOrEqual => {
+                may_be(max, literal, &[Ordering::Greater, Ordering::Equal])
+            }
+            PredicateOperator::In => self
+                .literals
+                .iter()
+                .any(|literal| !literal.is_null() && in_range(min, max, literal)),
+            PredicateOperator::NotIn => {
+                !self.literals.iter().any(Literal::is_null)
+                    && !self
+                        .literals
+                        .iter()
+                        .any(|literal| equal(min, literal) && equal(max, literal))
+            }
+            PredicateOperator::IsNull | PredicateOperator::IsNotNull => unreachable!(),
+        }
+    }
+}
+
+impl LeafPredicate {
+    /// Evaluate the predicate on a value of the field with three-valued logic: `None` is unknown,
+    /// which is the result of comparing with null.
+    fn test_value(&self, value: &Literal) -> Option<bool> {
+        match self.op {
+            PredicateOperator::IsNull => return Some(value.is_null()),
+            PredicateOperator::IsNotNull => return Some(!value.is_null()),
+            _ => {}
+        }
+        if value.is_null() {
+            return None;
+        }
+
+        let compare = |literal: &Literal, orderings: &[Ordering]| -> Option<bool> {
+            if literal.is_null() {
+                return None;
+            }
+            Some(matches!(value.compare(literal), Some(ordering) if orderings.contains(&ordering)))
+        };
+        let literal = self.literals.first().unwrap_or(&Literal::Null);
+        match self.op {
+            PredicateOperator::Equal => compare(literal, &[Ordering::Equal]),
+            PredicateOperator::NotEqual => compare(literal, &[Ordering::Less, Ordering::Greater]),
+            PredicateOperator::LessThan => compare(literal, &[Ordering::Less]),
+            PredicateOperator::LessOrEqual => compare(literal, &[Ordering::Less, Ordering::Equal]),
+            PredicateOperator::GreaterThan => compare(literal, &[Ordering::Greater]),
+            PredicateOperator::GreaterOrEqual => {
+                compare(literal, &[Ordering::Greater, Ordering::Equal])
+            }
+            PredicateOperator::In => self.test_in(value),
+            PredicateOperator::NotIn => self.test_in(value).map(|found| !found),
+            PredicateOperator::IsNull | PredicateOperator::IsNotNull => unreachable!(),
+        }
+    }
+
+    /// Whether the predicate never holds: a comparison with null, or `IN` null literals only.
+    fn is_always_false(&self) -> bool {
+        match self.op {
+            PredicateOperator::IsNull | PredicateOperator::IsNotNull | PredicateOperator::NotIn => {
+                false
+            }
+            PredicateOperator::In => self.literals.iter().all(Literal::is_null),
+            _ => self.literals.first().unwrap_or(&Literal::Null).is_null(),
+        }
+    }
+
+    /// Whether the predicate is a lower bound of its field, like `x > 5`, or an upper bound, like
+    /// `x <= 5`, `None` if it is not a range bound.
+    fn is_lower_bound(&self) -> Option<bool> {
+        match self.literals.as_slice() {
+            [literal] if !literal.is_null() => match self.op {
+                PredicateOperator::GreaterThan | PredicateOperator::GreaterOrEqual => Some(true),
+                PredicateOperator::LessThan | PredicateOperator::LessOrEqual => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Merge two bounds on the same side of the same field into the tighter one if `tighter`,
+    /// otherwise the looser one. `None` if they cannot be merged.
+    fn merge_bound(&self, other: &LeafPredicate, tighter: bool) -> Option<LeafPredicate> {
+        let lower = self.is_lower_bound()?;
+        if other.is_lower_bound()? != lower
+            || self.field_name != other.field_name
+            || self.data_type != other.data_type
+        {
+            return None;
+        }
+        let strict = |leaf: &LeafPredicate| {
+            matches!(
+                leaf.op,
+                PredicateOperator::GreaterThan | PredicateOperator::LessThan
+            )
+        };
+        // how much more restrictive this bound is than the other one
+        let ordering = self.literals[0].compare(&other.literals[0])?;
+        let ordering = if lower { ordering } else { ordering.reverse() };
+        let ordering = ordering.then(strict(self).cmp(&strict(other)));
+        let keep_self = if tighter {
+            ordering.is_ge()
+        } else {
+            ordering.is_le()
+        };
+        Some(if keep_self { self } else { other }.clone())
+    }
+
+    /// `value IN (literals)`: true if any literal equals, otherwise unknown if any is null.
+    fn test_in(&self, value: &Literal) -> Option<bool> {
+        if self
+            .literals
+            .iter()
+            .any(|literal| value.compare(literal) == Some(Ordering::Equal))
+        {
+            Some(true)
+        } else if self.literals.iter().any(Literal::is_null) {
+            None
+        } else {
+            Some(false)
+        }
+    }
+}
+
+/// Whether `value` compared to `literal` may have one of the `orderings`.
+fn may_be(value: &Literal, literal: &Literal, orderings: &[Ordering]) -> bool {
+    match value.compare(literal) {
+        Some(ordering) => orderings.contains(&ordering),
+        None => true,
+    }
+}
+
+fn in_range(min: &Literal, max: &Literal, literal: &Literal) -> bool {
+    may_be(min, literal, &[Ordering::Less, Ordering::Equal])
+        && may_be(max, literal, &[Ordering::Greater, Ordering::Equal])
+}
+
+fn equal(value: &Literal, literal: &Literal) -> bool {
+    value.compare(literal) == Some(Ordering::Equal)
+}
+
+/// A predicate on the fields of a row.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/predicate/Predicate.java>
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    Leaf(LeafPredicate),
+    /// All of the children hold, true if there are no children.
+    And(Vec<Predicate>),
+    /// Any of the children holds, false if there are no children.
+    Or(Vec<Predicate>),
+}
+
+impl Predicate {
+    /// Get the names of the fields referenced by the predicate, in order of first appearance.
+    pub fn field_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.collect_field_names(&mut names);
+        names
+    }
+
+    fn collect_field_names<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Predicate::Leaf(leaf) => {
+                if !names.contains(&leaf.field_name()) {
+                    names.push(leaf.field_name());
+                }
+            }
+            Predicate::And(children) | Predicate::Or(children) => {
+                for child in children {
+                    child.collect_field_names(names);
+                }
+            }
+        }
+    }
+
+    /// Get the conjuncts of the predicate: the children of a top level `AND`, or itself.
+    pub fn conjuncts(&self) -> Vec<&Predicate> {
+        match self {
+            Predicate::And(children) => children.iter().flat_map(Predicate::conjuncts).collect(),
+            other => vec![other],
+        }
+    }
+
+    /// Whether the row matches the predicate.
+    ///
+    /// Comparisons with null are unknown, and so are `AND` and `OR` following three-valued
+    /// logic, the row only matches if the result is true. Fields missing from `row_type` are null.
+    /// Literals which are not values of the type of their field fail with
+    /// [`Error::PredicateTypeMismatch`].
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/predicate/Predicate.java#L41>
+    pub fn test(&self, row: &BinaryRow, row_type: &RowType) -> crate::Result<bool> {
+        let value_of = |name: &str| match row_type
+            .fields()
+            .iter()
+            .position(|field| field.name() == name)
+        {
+            Some(pos) if pos < row.arity() as usize => {
+                row.get_literal(pos, row_type.fields()[pos].data_type())
+            }
+            _ => Ok(Literal::Null),
+        };
+        Ok(self.evaluate(&value_of)? == Some(true))
+    }
+
+    /// Evaluate the predicate on the rows of the batch with Arrow kernels, with the same
+    /// three-valued logic as [`Predicate::test`]: the mask is only true for the rows where the
+    /// result is true.
+    ///
+    /// The returned mask can be passed to `arrow_select::filter::filter_record_batch`. Columns are
+    /// looked up by field name, unlike [`Predicate::test`] a field missing from the batch fails
+    /// with [`Error::PredicateInvalid`], as the batch is expected to hold the read fields.
+    pub fn test_batch(&self, batch: &RecordBatch) -> crate::Result<BooleanArray> {
+        predicate_mask(batch, self)
+    }
+
+    /// Evaluate the predicate with three-valued logic on the values given by `value_of`.
+    fn evaluate(
+        &self,
+        value_of: &impl Fn(&str) -> crate::Result<Literal>,
+    ) -> crate::Result<Option<bool>> {
+        match self {
+            Predicate::Leaf(leaf) => {
+                leaf.check_literal_types()?;
+                Ok(leaf.test_value(&value_of(leaf.field_name())?))
+            }
+            Predicate::And(children) => {
+                let mut result = Some(true);
+                for child in children {
+                    match child.evaluate(value_of)? {
+                        Some(false) => return Ok(Some(false)),
+                        None => result = None,
+                        Some(true) => {}
+                    }
+                }
+                Ok(result)
+            }
+            Predicate::Or(children) => {
+                let mut result = Some(false);
+                for child in children {
+                    match child.evaluate(value_of)? {
+                        Some(true) => return Ok(Some(true)),
+                        None => result = None,
+                        Some(false) => {}
+                    }
+                }
+                Ok(result)
+            }
+        }
+    }
+
+    /// Split the predicate into the conjuncts referencing only `partition_fields`, and the others,
+    /// each combined with `AND`, `None` if there is no such conjunct.
+    ///
+    /// An `OR` is never split: if it mixes partition and other fields, it goes to the second part
+    /// as a whole. The predicate holds if and only if both parts hold.
+    pub fn split_by_fields(
+        &self,
+        partition_fields: &[String],
+    ) -> (Option<Predicate>, Option<Predicate>) {
+        let (partition, others): (Vec<&Predicate>, Vec<&Predicate>) =
+            self.conjuncts().into_iter().partition(|conjunct| {
+                conjunct
+                    .field_names()
+                    .iter()
+                    .all(|name| partition_fields.iter().any(|field| field == name))
+            });
+        let combine = |conjuncts: Vec<&Predicate>| {
+            (!conjuncts.is_empty())
+                .then(|| PredicateBuilder::and(conjuncts.into_iter().cloned().collect()))
+        };
+        (combine(partition), combine(others))
+    }
+
+    /// Simplify the predicate, without changing the rows it matches:
+    ///
+    /// - nested `AND`s and `OR`s are flattened, and a single child replaces its parent,
+    /// - duplicate children are removed, keeping the first one,
+    /// - comparisons with null, which never hold, are folded to false: an empty `OR`,
+    /// - an `AND` with a false child is false, and an `OR` with a true child, an empty `AND`, is
+    ///   true,
+    /// - range bounds on the same field are merged, `x > 5 AND x >= 3` into `x > 5` and
+    ///   `x > 5 OR x >= 3` into `x >= 3`.
+    ///
+    /// Contradictions like `x > 5 AND x < 3` are kept as is.
+    pub fn simplify(&self) -> Predicate {
+        match self {
+            Predicate::Leaf(leaf) if leaf.is_always_false() => Predicate::Or(vec![]),
+            Predicate::Leaf(_) => self.clone(),
+            Predicate::And(children) => Self::simplify_children(children, true),
+            Predicate::Or(children) => Self::simplify_children(children, false),
+        }
+    }
+
+    /// Simplify the children of an `AND` if `is_and`, otherwise of an `OR`.
+    fn simplify_children(children: &[Predicate], is_and: bool) -> Predicate {
+        let mut flattened = Vec::with_capacity(children.len());
+        for child in children {
+            match child.simplify() {
+                Predicate::And(nested) if is_and => flattened.extend(nested),
+                Predicate::Or(nested) if !is_and => flattened.extend(nested),
+                other => flattened.push(other),
+            }
+        }
+
+        // false in an `AND` or true in an `OR` decides the result
+        let decisive = flattened.iter().any(|child| match child {
+            Predicate::Or(nested) if is_and => nested.is_empty(),
+            Predicate::And(nested) if !is_and => nested.is_empty(),
+            _ => false,
+        });
+        if decisive {
+            return if is_and {
+                Predicate::Or(vec![])
+            } else {
+                Predicate::And(vec![])
+            };
+        }
+
+        let mut simplified: Vec<Predicate> = Vec::with_capacity(flattened.len());
+        'children: for child in flattened {
+            if simplified.contains(&child) {
+                continue;
+            }
+            if let Predicate::Leaf(leaf) = &child {
+                for previous in simplified.iter_mut() {
+                    if let Predicate::Leaf(previous_leaf) = previous {
+                        if let Some(merged) = previous_leaf.merge_bound(leaf, is_and) {
+                            *previous_leaf = merged;
+                            continue 'children;
+                        }
+                    }
+                }
+            }
+            simplified.push(child);
+        }
+
+        match (simplified.len(), is_and) {
+            (1, _) => simplified.pop().unwrap(),
+            (_, true) => Predicate::And(simplified),
+            (_, false) => Predicate::Or(simplified),
+        }
+    }
+
+    /// Whether rows with the given statistics may match the predicate.
+    ///
+    /// `min_values` and `max_values` hold the minimum and maximum of the fields of `row_type`,
+    /// and `null_counts` their number of nulls. Fields missing from the statistics are assumed
+    /// to match, so the result is only `false` if no row can match. Literals which are not values
+    /// of the type of their field fail with [`Error::PredicateTypeMismatch`].
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/predicate/Predicate.java#L45>
+    pub fn test_stats(
+        &self,
+        row_type: &RowType,
+        row_count: i64,
+        min_values: &BinaryRow,
+        max_values: &BinaryRow,
+        null_counts: &[i64],
+    ) -> crate::Result<bool> {
+        match self {
+            Predicate::Leaf(leaf) => {
+                leaf.check_literal_types()?;
+                let Some(pos) = row_type
+                    .fields()
+                    .iter()
+                    .position(|field| field.name() == leaf.field_name())
+                else {
+                    return Ok(true);
+                };
+                if pos >= min_values.arity() as usize || pos >= max_values.arity() as usize {
+                    return Ok(true);
+                }
+                let data_type = row_type.fields()[pos].data_type();
+                Ok(leaf.test_stats(
+                    row_count,
+                    &min_values.get_literal(pos, data_type)?,
+                    &max_values.get_literal(pos, data_type)?,
+                    null_counts.get(pos).copied(),
+                ))
+            }
+            Predicate::And(children) => {
+                for child in children {
+                    if !child.test_stats(
+                        row_type,
+                        row_count,
+                        min_values,
+                        max_values,
+                        null_counts,
+                    )? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Predicate::Or(children) => {
+                for child in children {
+                    if child.test_stats(row_type, row_count, min_values, max_values, null_counts)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{BinaryRowWriter, DataField, IntType, VarCharType};
+
+    fn row_type() -> RowType {
+        RowType::new(vec![
+            DataField::new(0, "id".to_string(), DataType::Int(IntType::new())),
+            DataField::new(
+                1,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+        ])
+    }
+
+    fn stats_row(id: Option<i32>, name: &str) -> BinaryRow {
+        let mut writer = BinaryRowWriter::new(2);
+        match id {
+            Some(id) => writer.write_int(0, id),
+            None => writer.set_null_at(0),
+        }
+        writer.write_string(1, name);
+        writer.build()
+    }
+
+    #[test]
+    fn test_stats() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let min = stats_row(Some(10), "b");
+        let max = stats_row(Some(20), "d");
+        let test = |predicate: Predicate| {
+            predicate
+                .test_stats(&row_type(), 5, &min, &max, &[0, 1])
+                .unwrap()
+        };
+
+        assert!(test(builder.equal(0, Literal::Int(10))));
+        assert!(!test(builder.equal(0, Literal::Int(21))));
+        assert!(!test(builder.equal(0, Literal::Null)));
+        assert!(test(builder.not_equal(0, Literal::Int(10))));
+        assert!(!test(builder.less_than(0, Literal::Int(10))));
+        assert!(test(builder.less_or_equal(0, Literal::Int(10))));
+        assert!(!test(builder.greater_than(0, Literal::Int(20))));
+        assert!(test(builder.greater_or_equal(0, Literal::Int(20))));
+        assert!(test(
+            builder.is_in(0, vec![Literal::Int(1), Literal::Int(15)])
+        ));
+        assert!(!test(
+            builder.is_in(0, vec![Literal::Int(1), Literal::Int(25)])
+        ));
+        assert!(test(builder.is_not_in(0, vec![Literal::Int(10)])));
+        assert!(!test(builder.is_null(0)));
+        assert!(test(builder.is_null(1)));
+        assert!(test(builder.is_not_null(1)));
+        assert!(!test(builder.equal(1, Literal::String("a".to_string()))));
+        assert!(test(builder.equal(1, Literal::String("c".to_string()))));
+
+        assert!(!test(PredicateBuilder::and(vec![
+            builder.equal(0, Literal::Int(15)),
+            builder.equal(1, Literal::String("e".to_string())),
+        ])));
+        assert!(test(PredicateBuilder::or(vec![
+            builder.equal(0, Literal::Int(15)),
+            builder.equal(1, Literal::String("e".to_string())),
+        ])));
+
+        // single value
+        let test = |predicate: Predicate| {
+            predicate
+                .test_stats(&row_type(), 5, &min, &min, &[0, 0])
+                .unwrap()
+        };
+        assert!(!test(builder.not_equal(0, Literal::Int(10))));
+        assert!(!test(
+            builder.is_not_in(0, vec![Literal::Int(10), Literal::Int(11)])
+        ));
+        assert!(!test(
+            builder.is_not_in(0, vec![Literal::Int(11), Literal::Null])
+        ));
+    }
+
+    #[test]
+    fn test_stats_with_nulls_and_missing_fields() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let all_null = stats_row(None, "a");
+        let test = |predicate: Predicate, null_counts: &[i64]| {
+            predicate
+                .test_stats(&row_type(), 3, &all_null, &all_null, null_counts)
+                .unwrap()
+        };
+        assert!(!test(builder.equal(0, Literal::Int(1)), &[3, 0]));
+        assert!(test(builder.is_null(0), &[3, 0]));
+        assert!(!test(builder.is_not_null(0), &[3, 0]));
+        // unknown null counts
+        assert!(test(builder.equal(0, Literal::Int(1)), &[]));
+        assert!(test(builder.is_not_null(0), &[]));
+
+        // stats written before a field was added
+        let old_type = RowType::new(row_type().fields()[..1].to_vec());
+        let empty = BinaryRow::new(0);
+        assert!(builder
+            .equal(1, Literal::String("x".to_string()))
+            .test_stats(&old_type, 3, &empty, &empty, &[])
+            .unwrap());
+    }
+
+    fn row(id: Option<i32>, name: Option<&str>) -> BinaryRow {
+        let mut writer = BinaryRowWriter::new(2);
+        match id {
+            Some(id) => writer.write_int(0, id),
+            None => writer.set_null_at(0),
+        }
+        match name {
+            Some(name) => writer.write_string(1, name),
+            None => writer.set_null_at(1),
+        }
+        writer.build()
+    }
+
+    #[test]
+    fn test_row() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let test = |predicate: &Predicate, id: Option<i32>, name: Option<&str>| {
+            predicate.test(&row(id, name), &row_type()).unwrap()
+        };
+
+        // equality and range
+        let equal = builder.equal(0, Literal::Int(5));
+        assert!(test(&equal, Some(5), None));
+        assert!(!test(&equal, Some(6), None));
+        assert!(test(&builder.not_equal(0, Literal::Int(5)), Some(6), None));
+        let range = PredicateBuilder::and(vec![
+            builder.greater_or_equal(0, Literal::Int(1)),
+            builder.less_than(0, Literal::Int(10)),
+        ]);
+        assert!(test(&range, Some(1), None));
+        assert!(!test(&range, Some(10), None));
+        assert!(test(
+            &builder.less_or_equal(1, Literal::String("b".to_string())),
+            None,
+            Some("ab")
+        ));
+        assert!(!test(
+            &builder.greater_than(1, Literal::String("b".to_string())),
+            None,
+            Some("ab")
+        ));
+
+        // IN
+        let is_in = builder.is_in(0, vec![Literal::Int(1), Literal::Int(3)]);
+        assert!(test(&is_in, Some(3), None));
+        assert!(!test(&is_in, Some(2), None));
+        assert!(test(
+            &builder.is_not_in(0, vec![Literal::Int(1), Literal::Int(3)]),
+            Some(2),
+            None
+        ));
+
+        // AND / OR
+        let name_a = builder.equal(1, Literal::String("a".to_string()));
+        let and = PredicateBuilder::and(vec![equal.clone(), name_a.clone()]);
+        assert!(test(&and, Some(5), Some("a")));
+        assert!(!test(&and, Some(5), Some("b")));
+        let or = PredicateBuilder::or(vec![equal.clone(), name_a.clone()]);
+        assert!(test(&or, Some(6), Some("a")));
+        assert!(!test(&or, Some(6), Some("b")));
+    }
+
+    #[test]
+    fn test_row_with_nulls() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let test = |predicate: &Predicate, id: Option<i32>| {
+            predicate.test(&row(id, Some("a")), &row_type()).unwrap()
+        };
+        let equal = builder.equal(0, Literal::Int(5));
+        let not_equal = builder.not_equal(0, Literal::Int(5));
+
+        assert!(!test(&equal, None));
+        assert!(!test(&not_equal, None));
+        assert!(test(&builder.is_null(0), None));
+        assert!(!test(&builder.is_not_null(0), None));
+        assert!(!test(&builder.equal(0, Literal::Null), Some(5)));
+
+        // unknown OR true is true, unknown AND true is unknown
+        let name_a = builder.equal(1, Literal::String("a".to_string()));
+        assert!(test(
+            &PredicateBuilder::or(vec![equal.clone(), name_a.clone()]),
+            None
+        ));
+        assert!(!test(
+            &PredicateBuilder::and(vec![equal.clone(), name_a]),
+            None
+        ));
+
+        // NOT IN with a null literal is never true
+        let not_in = builder.is_not_in(0, vec![Literal::Int(1), Literal::Null]);
+        assert!(!test(&not_in, Some(2)));
+        assert!(test(
+            &builder.is_in(0, vec![Literal::Int(2), Literal::Null]),
+            Some(2)
+        ));
+
+        // fields missing from the row type are null
+        let old_type = RowType::new(row_type().fields()[1..].to_vec());
+        assert!(builder
+            .is_null(0)
+            .test(&row(Some(1), Some("a")), &old_type)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_batch() {
+        use arrow_array::{ArrayRef, Int32Array};
+        use std::sync::Arc;
+
+        let ids: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)]));
+        let batch = RecordBatch::try_from_iter([("id", ids)]).unwrap();
+        let builder = PredicateBuilder::new(row_type().fields());
+
+        let mask = builder
+            .is_in(0, vec![Literal::Int(3), Literal::Int(4)])
+            .test_batch(&batch)
+            .unwrap();
+        assert_eq!(mask, BooleanArray::from(vec![false, false, true, true]));
+
+        // the null id is unknown, so not matched
+        let mask = PredicateBuilder::or(vec![
+            builder.less_than(0, Literal::Int(2)),
+            builder.greater_than(0, Literal::Int(3)),
+        ])
+        .test_batch(&batch)
+        .unwrap();
+        assert_eq!(mask, BooleanArray::from(vec![true, false, false, true]));
+
+        assert!(matches!(
+            builder.is_null(1).test_batch(&batch),
+            Err(Error::PredicateInvalid { .. })
+        ));
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let mismatched = builder.equal(0, Literal::String("abc".to_string()));
+        let row = row(Some(1), Some("a"));
+        let assert_mismatch = |result: crate::Result<bool>, mismatched_field: &str| match result {
+            Err(Error::PredicateTypeMismatch { field, .. }) => assert_eq!(field, mismatched_field),
+            other => panic!("expected a type mismatch, got {:?}", other),
+        };
+
+        let err = mismatched.test(&row, &row_type()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Paimon hitting predicate type mismatch: field 'id' of type INT compared with STRING"
+        );
+        // nested, and after a child which does not short-circuit
+        let nested = PredicateBuilder::and(vec![builder.is_not_null(1), mismatched.clone()]);
+        assert_mismatch(nested.test(&row, &row_type()), "id");
+        let min = stats_row(Some(10), "b");
+        let max = stats_row(Some(20), "d");
+        assert_mismatch(
+            mismatched.test_stats(&row_type(), 5, &min, &max, &[0, 0]),
+            "id",
+        );
+        assert_mismatch(
+            builder
+                .is_in(1, vec![Literal::String("a".to_string()), Literal::Int(1)])
+                .test(&row, &row_type()),
+            "name",
+        );
+
+        // null is a value of any type
+        assert!(!builder
+            .equal(0, Literal::Null)
+            .test(&row, &row_type())
+            .unwrap());
+    }
+
+    #[test]
+    fn test_split_by_fields() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let partition_fields = vec!["id".to_string()];
+        let on_partition = builder.equal(0, Literal::Int(1));
+        let on_name = builder.equal(1, Literal::String("a".to_string()));
+
+        // pure partition filter
+        let predicate = PredicateBuilder::or(vec![on_partition.clone(), builder.is_null(0)]);
+        assert_eq!(
+            predicate.split_by_fields(&partition_fields),
+            (Some(predicate.clone()), None)
+        );
+
+        // mixed AND
+        let predicate = PredicateBuilder::and(vec![
+            on_name.clone(),
+            on_partition.clone(),
+            builder.greater_than(0, Literal::Int(0)),
+        ]);
+        assert_eq!(
+            predicate.split_by_fields(&partition_fields),
+            (
+                Some(PredicateBuilder::and(vec![
+                    on_partition.clone(),
+                    builder.greater_than(0, Literal::Int(0)),
+                ])),
+                Some(on_name.clone())
+            )
+        );
+
+        // mixed OR is kept as a whole
+        let mixed_or = PredicateBuilder::or(vec![on_partition.clone(), on_name.clone()]);
+        assert_eq!(
+            mixed_or.split_by_fields(&partition_fields),
+            (None, Some(mixed_or.clone()))
+        );
+        let predicate = PredicateBuilder::and(vec![mixed_or.clone(), on_partition.clone()]);
+        assert_eq!(
+            predicate.split_by_fields(&partition_fields),
+            (Some(on_partition), Some(mixed_or))
+        );
+
+        assert_eq!(on_name.split_by_fields(&[]), (None, Some(on_name.clone())));
+    }
+
+    #[test]
+    fn test_field_names_and_conjuncts() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let predicate = PredicateBuilder::and(vec![
+            builder.equal(1, Literal::String("a".to_string())),
+            PredicateBuilder::or(vec![builder.is_null(0), builder.is_null(1)]),
+        ]);
+        assert_eq!(predicate.field_names(), vec!["name", "id"]);
+        assert_eq!(predicate.conjuncts().len(), 2);
+        assert_eq!(builder.is_null(0).conjuncts(), vec![&builder.is_null(0)]);
+    }
+
+    #[test]
+    fn test_simplify_flattens_and_removes_duplicates() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let name_a = builder.equal(1, Literal::String("a".to_string()));
+        let id_null = builder.is_null(0);
+        let predicate = Predicate::And(vec![
+            name_a.clone(),
+            Predicate::And(vec![id_null.clone(), Predicate::And(vec![name_a.clone()])]),
+            Predicate::Or(vec![Predicate::Or(vec![id_null.clone()]), id_null.clone()]),
+        ]);
+        assert_eq!(
+            predicate.simplify(),
+            Predicate::And(vec![name_a.clone(), id_null.clone()])
+        );
+        assert_eq!(Predicate::Or(vec![name_a.clone()]).simplify(), name_a);
+        assert_eq!(name_a.simplify(), name_a);
+    }
+
+    #[test]
+    fn test_simplify_folds_constants() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let name_a = builder.equal(1, Literal::String("a".to_string()));
+        let id_is_null = builder.equal(0, Literal::Null);
+        let always_true = Predicate::And(vec![]);
+        let always_false = Predicate::Or(vec![]);
+
+        assert_eq!(id_is_null.simplify(), always_false);
+        assert_eq!(
+            builder.is_in(0, vec![Literal::Null]).simplify(),
+            always_false
+        );
+        assert_eq!(
+            PredicateBuilder::and(vec![name_a.clone(), id_is_null.clone()]).simplify(),
+            always_false
+        );
+        assert_eq!(
+            PredicateBuilder::or(vec![name_a.clone(), id_is_null.clone()]).simplify(),
+            name_a
+        );
+        assert_eq!(
+            Predicate::And(vec![name_a.clone(), always_true.clone()]).simplify(),
+            name_a
+        );
+        assert_eq!(
+            Predicate::Or(vec![name_a.clone(), always_true.clone()]).simplify(),
+            always_true
+        );
+        // `NOT IN` is unknown for null values, never folded
+        let not_in = builder.is_not_in(0, vec![]);
+        assert_eq!(not_in.simplify(), not_in);
+    }
+
+    #[test]
+    fn test_simplify_merges_ranges() {
+        let builder = PredicateBuilder::new(row_type().fields());
+        let predicate = PredicateBuilder::and(vec![
+            builder.greater_than(0, Literal::Int(5)),
+            builder.less_or_equal(0, Literal::Int(20)),
+            builder.greater_or_equal(0, Literal::Int(3)),
+            builder.less_than(0, Literal::Int(20)),
+            builder.greater_or_equal(0, Literal::Int(5)),
+        ]);
+        assert_eq!(
+            predicate.simplify(),
+            PredicateBuilder::and(vec![
+                builder.greater_than(0, Literal::Int(5)),
+                builder.less_than(0, Literal::Int(20)),
+            ])
+        );
+
+        let predicate = PredicateBuilder::or(vec![
+            builder.greater_than(0, Literal::Int(5)),
+            builder.greater_or_equal(0, Literal::Int(5)),
+            builder.greater_than(0, Literal::Int(7)),
+        ]);
+        assert_eq!(
+            predicate.simplify(),
+            builder.greater_or_equal(0, Literal::Int(5))
+        );
+
+        // bounds on different fields and contradictions are kept
+        let predicate = PredicateBuilder::and(vec![
+            builder.greater_than(0, Literal::Int(5)),
+            builder.less_than(0, Literal::Int(3)),
+            builder.greater_than(1, Literal::String("a".to_string())),
+        ]);
+        assert_eq!(predicate.simplify(), predicate);
+
+        // the simplified predicate matches the same rows
+        let predicate = PredicateBuilder::and(vec![
+            builder.greater_than(0, Literal::Int(5)),
+            builder.greater_or_equal(0, Literal::Int(3)),
+        ]);
+        for id in [Some(3), Some(5), Some(6), None] {
+            let row = stats_row(id, "a");
+            assert_eq!(
+                predicate.simplify().test(&row, &row_type()).unwrap(),
+                predicate.test(&row, &row_type()).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,128 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::predicate::{LeafPredicate, Predicate, PredicateOperator};
+use crate::spec::{DataField, Literal};
+
+/// Builder of predicates on the fields of a row, addressed by their positions.
+///
+/// # Panics
+///
+/// The leaf predicate methods panic if the position is out of the fields.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/predicate/PredicateBuilder.java>
+#[derive(Debug, Clone)]
+pub struct PredicateBuilder {
+    fields: Vec<DataField>,
+}
+
+impl PredicateBuilder {
+    pub fn new(fields: &[DataField]) -> Self {
+        Self {
+            fields: fields.to_vec(),
+        }
+    }
+
+    /// Get the position of the field named `field_name`.
+    pub fn index_of(&self, field_name: &str) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|field| field.name() == field_name)
+    }
+
+    fn leaf(&self, op: PredicateOperator, idx: usize, literals: Vec<Literal>) -> Predicate {
+        let field = &self.fields[idx];
+        Predicate::Leaf(LeafPredicate::new(
+            op,
+            field.name().to_string(),
+            field.data_type().clone(),
+            literals,
+        ))
+    }
+
+    pub fn equal(&self, idx: usize, literal: Literal) -> Predicate {
+        self.leaf(PredicateOperator::Equal, idx, vec![literal])
+    }
+
+    pub fn not_equal(&self, idx: usize, literal: Literal) -> Predicate {
+        self.leaf(PredicateOperator::NotEqual, idx, vec![literal])
+    }
+
+    pub fn less_than(&self, idx: usize, literal: Literal) -> Predicate {
+        self.leaf(PredicateOperator::LessThan, idx, vec![literal])
+    }
+
+    pub fn less_or_equal(&self, idx: usize, literal: Literal) -> Predicate {
+        self.leaf(PredicateOperator::LessOrEqual, idx, vec![literal])
+    }
+
+    pub fn greater_than(&self, idx: usize, literal: Literal) -> Predicate {
+        self.leaf(PredicateOperator::GreaterThan, idx, vec![literal])
+    }
+
+    pub fn greater_or_equal(&self, idx: usize, literal: Literal) -> Predicate {
+        self.leaf(PredicateOperator::GreaterOrEqual, idx, vec![literal])
+    }
+
+    pub fn is_null(&self, idx: usize) -> Predicate {
+        self.leaf(PredicateOperator::IsNull, idx, vec![])
+    }
+
+    pub fn is_not_null(&self, idx: usize) -> Predicate {
+        self.leaf(PredicateOperator::IsNotNull, idx, vec![])
+    }
+
+    pub fn is_in(&self, idx: usize, literals: Vec<Literal>) -> Predicate {
+        self.leaf(PredicateOperator::In, idx, literals)
+    }
+
+    pub fn is_not_in(&self, idx: usize, literals: Vec<Literal>) -> Predicate {
+        self.leaf(PredicateOperator::NotIn, idx, literals)
+    }
+
+    /// Combine the predicates with `AND`, flattening nested `AND`s.
+    pub fn and(predicates: Vec<Predicate>) -> Predicate {
+        let mut children = Vec::with_capacity(predicates.len());
+        for predicate in predicates {
+            match predicate {
+                Predicate::And(nested) => children.extend(nested),
+                other => children.push(other),
+            }
+        }
+        if children.len() == 1 {
+            children.pop().unwrap()
+        } else {
+            Predicate::And(children)
+        }
+    }
+
+    /// Combine the predicates with `OR`, flattening nested `OR`s.
+    pub fn or(predicates: Vec<Predicate>) -> Predicate {
+        let mut children = Vec::with_capacity(predicates.len());
+        for predicate in predicates {
+            match predicate {
+                Predicate::Or(nested) => children.extend(nested),
+                other => children.push(other),
+            }
+        }
+        if children.len() == 1 {
+            children.pop().unwrap()
+        } else {
+            Predicate::Or(children)
+        }
+    }
+}
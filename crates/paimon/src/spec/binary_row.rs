@@ -0,0 +1,518 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::spec::{DataType, Literal, RowType};
+use crate::Error;
+
+pub const EMPTY_BINARY_ROW: BinaryRow = BinaryRow::new(0);
+
+/// An implementation of InternalRow.
+///
+/// The row is stored as a fixed-length part followed by a variable-length part:
+///
+/// ```text
+/// | header (1 byte) + null bits | 8 bytes per field | variable-length values |
+/// ```
+///
+/// Fixed-length values are stored in their 8 bytes slot in little-endian. A variable-length
+/// value either fits in its slot (at most 7 bytes, marked by the highest bit of the slot), or
+/// the slot holds its offset and length in the variable-length part.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/data/BinaryRow.java>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BinaryRow {
+    arity: i32,
+    /// fixed-length part followed by the variable-length part
+    data: Vec<u8>,
+}
+
+impl BinaryRow {
+    pub const HEADER_SIZE_IN_BYTES: i32 = 8;
+
+    /// Mark of a variable-length value stored in its fixed-length slot.
+    const HIGHEST_FIRST_BIT: u64 = 0x80 << 56;
+    /// Length of a variable-length value stored in its fixed-length slot.
+    const HIGHEST_SECOND_TO_EIGHTH_BIT: u64 = 0x7F << 56;
+    /// Max precision of a decimal stored as a long in its fixed-length slot.
+    pub const MAX_COMPACT_DECIMAL_PRECISION: u32 = 18;
+    /// Max precision of a timestamp stored as epoch millis in its fixed-length slot.
+    pub const MAX_COMPACT_TIMESTAMP_PRECISION: u32 = 3;
+
+    pub const fn cal_bit_set_width_in_bytes(arity: i32) -> i32 {
+        ((arity + 63 + Self::HEADER_SIZE_IN_BYTES) / 64) * 8
+    }
+    pub const fn cal_fix_part_size_in_bytes(arity: i32) -> i32 {
+        Self::cal_bit_set_width_in_bytes(arity) + 8 * arity
+    }
+    pub const fn new(arity: i32) -> Self {
+        Self {
+            arity,
+            data: Vec::new(),
+        }
+    }
+
+    /// Decode a row serialized as Paimon does in manifests: the arity as a big-endian `i32`
+    /// followed by the row bytes.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/utils/SerializationUtils.java#L92>
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let Some((arity, data)) = bytes.split_first_chunk::<4>() else {
+            return Err(Error::BinaryRowInvalid {
+                message: format!(
+                    "Serialized row must contain at least 4 bytes, but found {}",
+                    bytes.len()
+                ),
+            });
+        };
+        let arity = i32::from_be_bytes(*arity);
+        if arity < 0 {
+            return Err(Error::BinaryRowInvalid {
+                message: format!("Arity of a row must not be negative, but found {}", arity),
+            });
+        }
+
+        // An empty row may be serialized without its fixed-length part.
+        let fix_part_size = Self::cal_fix_part_size_in_bytes(arity) as usize;
+        if arity > 0 && data.len() < fix_part_size {
+            return Err(Error::BinaryRowInvalid {
+                message: format!(
+                    "Row with arity {} must contain at least {} bytes, but found {}",
+                    arity,
+                    fix_part_size,
+                    data.len()
+                ),
+            });
+        }
+
+        Ok(Self {
+            data: data.to_vec(),
+            ..Self::new(arity)
+        })
+    }
+
+    /// Serialize this row as Paimon does in manifests, the inverse of [`BinaryRow::from_bytes`].
+    ///
+    /// A row without bytes, like [`EMPTY_BINARY_ROW`], is serialized with a zeroed fixed-length
+    /// part.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/utils/SerializationUtils.java#L80>
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.arity.to_be_bytes().to_vec();
+        if self.data.is_empty() {
+            bytes.resize(4 + Self::cal_fix_part_size_in_bytes(self.arity) as usize, 0);
+        } else {
+            bytes.extend_from_slice(&self.data);
+        }
+        bytes
+    }
+
+    /// Get the hash code of this row, as Java's `BinaryRow#hashCode`: the murmur3 hash of the row
+    /// bytes read as little-endian 4-byte words.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/utils/MurmurHashUtils.java>
+    pub fn hash_code(&self) -> i32 {
+        let bytes = self.to_bytes();
+        murmur_hash_by_words(&bytes[4..], 42)
+    }
+
+    /// Get the number of fields of this row.
+    #[inline]
+    pub fn arity(&self) -> i32 {
+        self.arity
+    }
+
+    /// Get the row bytes, without the arity prefix.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn field_offset(&self, pos: usize) -> usize {
+        debug_assert!(pos < self.arity as usize, "field {} out of bounds", pos);
+        Self::cal_bit_set_width_in_bytes(self.arity) as usize + pos * 8
+    }
+
+    fn read_slot<const N: usize>(&self, pos: usize) -> [u8; N] {
+        let offset = self.field_offset(pos);
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(&self.data[offset..offset + N]);
+        buf
+    }
+
+    pub fn is_null_at(&self, pos: usize) -> bool {
+        let index = pos + Self::HEADER_SIZE_IN_BYTES as usize;
+        self.data[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn get_boolean(&self, pos: usize) -> bool {
+        self.data[self.field_offset(pos)] != 0
+    }
+
+    pub fn get_byte(&self, pos: usize) -> i8 {
+        self.data[self.field_offset(pos)] as i8
+    }
+
+    pub fn get_short(&self, pos: usize) -> i16 {
+        i16::from_le_bytes(self.read_slot(pos))
+    }
+
+    pub fn get_int(&self, pos: usize) -> i32 {
+        i32::from_le_bytes(self.read_slot(pos))
+    }
+
+    pub fn get_long(&self, pos: usize) -> i64 {
+        i64::from_le_bytes(self.read_slot(pos))
+    }
+
+    pub fn get_float(&self, pos: usize) -> f32 {
+        f32::from_le_bytes(self.read_slot(pos))
+    }
+
+    pub fn get_double(&self, pos: usize) -> f64 {
+        f64::from_le_bytes(self.read_slot(pos))
+    }
+
+    fn var_part(&self, offset: usize, len: usize) -> crate::Result<&[u8]> {
+        self.data
+            .get(offset..offset + len)
+            .ok_or_else(|| Error::BinaryRowInvalid {
+                message: format!(
+                    "Variable-length value at offset {} with length {} exceeds row size {}",
+                    offset,
+                    len,
+                    self.data.len()
+                ),
+            })
+    }
+
+    pub fn get_binary(&self, pos: usize) -> crate::Result<&[u8]> {
+        let offset_and_len = self.get_long(pos) as u64;
+        if offset_and_len & Self::HIGHEST_FIRST_BIT == 0 {
+            let offset = (offset_and_len >> 32) as usize;
+            let len = offset_and_len as u32 as usize;
+            self.var_part(offset, len)
+        } else {
+            let len = ((offset_and_len & Self::HIGHEST_SECOND_TO_EIGHTH_BIT) >> 56) as usize;
+            let offset = self.field_offset(pos);
+            Ok(&self.data[offset..offset + len.min(7)])
+        }
+    }
+
+    pub fn get_string(&self, pos: usize) -> crate::Result<&str> {
+        std::str::from_utf8(self.get_binary(pos)?).map_err(|e| Error::BinaryRowInvalid {
+            message: format!("String field {} is not valid UTF-8: {}", pos, e),
+        })
+    }
+
+    /// Get the unscaled value of a decimal with the given precision.
+    pub fn get_decimal(&self, pos: usize, precision: u32) -> crate::Result<i128> {
+        if precision <= Self::MAX_COMPACT_DECIMAL_PRECISION {
+            return Ok(self.get_long(pos) as i128);
+        }
+
+        // Java's `BigInteger#toByteArray`: big-endian two's complement.
+        let offset_and_len = self.get_long(pos) as u64;
+        let bytes = self.var_part(
+            (offset_and_len >> 32) as usize,
+            offset_and_len as u32 as usize,
+        )?;
+        if bytes.is_empty() || bytes.len() > 16 {
+            return Err(Error::BinaryRowInvalid {
+                message: format!(
+                    "Decimal field {} must contain 1 to 16 bytes, but found {}",
+                    pos,
+                    bytes.len()
+                ),
+            });
+        }
+        let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0 };
+        let mut buf = [fill; 16];
+        buf[16 - bytes.len()..].copy_from_slice(bytes);
+        Ok(i128::from_be_bytes(buf))
+    }
+
+    /// Get a timestamp with the given precision as epoch millis and nanos of the millisecond.
+    pub fn get_timestamp(&self, pos: usize, precision: u32) -> crate::Result<(i64, i32)> {
+        if precision <= Self::MAX_COMPACT_TIMESTAMP_PRECISION {
+            return Ok((self.get_long(pos), 0));
+        }
+
+        let offset_and_nanos = self.get_long(pos) as u64;
+        let millis = self.var_part((offset_and_nanos >> 32) as usize, 8)?;
+        Ok((
+            i64::from_le_bytes(millis.try_into().unwrap()),
+            offset_and_nanos as u32 as i32,
+        ))
+    }
+
+    /// Get the field at `pos` as a [`Literal`] of the given type.
+    pub fn get_literal(&self, pos: usize, data_type: &DataType) -> crate::Result<Literal> {
+        if pos >= self.arity as usize {
+            return Err(Error::BinaryRowInvalid {
+                message: format!("Field {} out of bounds of arity {}", pos, self.arity),
+            });
+        }
+        if self.is_null_at(pos) {
+            return Ok(Literal::Null);
+        }
+
+        let literal = match data_type {
+            DataType::Boolean(_) => Literal::Boolean(self.get_boolean(pos)),
+            DataType::TinyInt(_) => Literal::TinyInt(self.get_byte(pos)),
+            DataType::SmallInt(_) => Literal::SmallInt(self.get_short(pos)),
+            DataType::Int(_) => Literal::Int(self.get_int(pos)),
+            DataType::BigInt(_) => Literal::BigInt(self.get_long(pos)),
+            DataType::Float(_) => Literal::Float(self.get_float(pos)),
+            DataType::Double(_) => Literal::Double(self.get_double(pos)),
+            DataType::Decimal(t) => Literal::Decimal {
+                unscaled: self.get_decimal(pos, t.precision())?,
+                precision: t.precision(),
+                scale: t.scale(),
+            },
+            DataType::Char(_) | DataType::VarChar(_) => {
+                Literal::String(self.get_string(pos)?.to_string())
+            }
+            DataType::Binary(_) | DataType::VarBinary(_) => {
+                Literal::Binary(self.get_binary(pos)?.to_vec())
+            }
+            DataType::Date(_) => Literal::Date(self.get_int(pos)),
+            DataType::Time(_) => Literal::Time(self.get_int(pos)),
+            DataType::Timestamp(t) => {
+                let (millis, nanos) = self.get_timestamp(pos, t.precision())?;
+                Literal::Timestamp { millis, nanos }
+            }
+            DataType::LocalZonedTimestamp(t) => {
+                let (millis, nanos) = self.get_timestamp(pos, t.precision())?;
+                Literal::LocalZonedTimestamp { millis, nanos }
+            }
+            DataType::Array(_) | DataType::Map(_) | DataType::Multiset(_) | DataType::Row(_) => {
+                return Err(Error::BinaryRowInvalid {
+                    message: format!("Unsupported field type of field {}: {:?}", pos, data_type),
+                })
+            }
+        };
+        Ok(literal)
+    }
+}
+
+/// Decode a serialized partition, like the `_PARTITION` of a manifest entry, into the values of
+/// its fields, [`Literal::Null`] for a null value.
+///
+/// Fails if the row does not have one field per field of `partition_type`.
+pub fn decode_partition(bytes: &[u8], partition_type: &RowType) -> crate::Result<Vec<Literal>> {
+    let row = BinaryRow::from_bytes(bytes)?;
+    let fields = partition_type.fields();
+    if row.arity() as usize != fields.len() {
+        return Err(Error::BinaryRowInvalid {
+            message: format!(
+                "Partition with arity {} does not match the {} partition keys",
+                row.arity(),
+                fields.len()
+            ),
+        });
+    }
+    fields
+        .iter()
+        .enumerate()
+        .map(|(pos, field)| row.get_literal(pos, field.data_type()))
+        .collect()
+}
+
+/// Murmur3 32-bit hash of bytes whose length is a multiple of 4.
+fn murmur_hash_by_words(data: &[u8], seed: u32) -> i32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    let mut h1 = seed;
+    for word in data.chunks_exact(4) {
+        let k1 = u32::from_le_bytes(word.try_into().unwrap())
+            .wrapping_mul(C1)
+            .rotate_left(15)
+            .wrapping_mul(C2);
+        h1 = (h1 ^ k1)
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe654_6b64);
+    }
+
+    h1 ^= data.len() as u32;
+    h1 ^= h1 >> 16;
+    h1 = h1.wrapping_mul(0x85eb_ca6b);
+    h1 ^= h1 >> 13;
+    h1 = h1.wrapping_mul(0xc2b2_ae35);
+    h1 ^= h1 >> 16;
+    h1 as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::spec::{
+        DataField, DecimalType, IndexManifestFile, IntType, TimestampType, VarCharType,
+    };
+
+    /// Build a serialized row of the given slots and variable-length part.
+    fn serialize(null_bits: u64, slots: &[u64], var_part: &[u8]) -> Vec<u8> {
+        let mut bytes = (slots.len() as i32).to_be_bytes().to_vec();
+        bytes.extend_from_slice(&null_bits.to_le_bytes());
+        for slot in slots {
+            bytes.extend_from_slice(&slot.to_le_bytes());
+        }
+        bytes.extend_from_slice(var_part);
+        bytes
+    }
+
+    #[test]
+    fn test_get_fields() {
+        let fix_part_size = BinaryRow::cal_fix_part_size_in_bytes(5) as u64;
+        // "a-long-string" in the variable-length part
+        let long_string = (fix_part_size << 32) | 13;
+        // "abc" inlined into the slot
+        let short_string = 0x83 << 56 | u64::from_le_bytes(*b"abc\0\0\0\0\0");
+        let mut var_part = b"a-long-string".to_vec();
+        var_part.extend_from_slice(&[0; 3]);
+        let bytes = serialize(
+            1 << (8 + 4),
+            &[42, long_string, short_string, (-7i64) as u64, 0],
+            &var_part,
+        );
+
+        let row = BinaryRow::from_bytes(&bytes).unwrap();
+        assert_eq!(row.arity(), 5);
+        assert_eq!(row.get_int(0), 42);
+        assert_eq!(row.get_string(1).unwrap(), "a-long-string");
+        assert_eq!(row.get_string(2).unwrap(), "abc");
+        assert_eq!(row.get_long(3), -7);
+        assert!(!row.is_null_at(3));
+        assert!(row.is_null_at(4));
+
+        let int = DataType::Int(IntType::new());
+        assert_eq!(row.get_literal(0, &int).unwrap(), Literal::Int(42));
+        assert_eq!(row.get_literal(4, &int).unwrap(), Literal::Null);
+        assert_eq!(
+            row.get_literal(2, &DataType::VarChar(VarCharType::default()))
+                .unwrap(),
+            Literal::String("abc".to_string())
+        );
+        assert_eq!(
+            row.get_literal(3, &DataType::Decimal(DecimalType::new(10, 2).unwrap()))
+                .unwrap(),
+            Literal::Decimal {
+                unscaled: -7,
+                precision: 10,
+                scale: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_non_compact_fields() {
+        let fix_part_size = BinaryRow::cal_fix_part_size_in_bytes(2) as u64;
+        // -1 as a BigInteger byte array
+        let decimal = (fix_part_size << 32) | 1;
+        let timestamp = ((fix_part_size + 8) << 32) | 999;
+        let mut var_part = vec![0xFF, 0, 0, 0, 0, 0, 0, 0];
+        var_part.extend_from_slice(&1_000i64.to_le_bytes());
+        let bytes = serialize(0, &[decimal, timestamp], &var_part);
+
+        let row = BinaryRow::from_bytes(&bytes).unwrap();
+        assert_eq!(row.get_decimal(0, 20).unwrap(), -1);
+        assert_eq!(
+            row.get_literal(1, &DataType::Timestamp(TimestampType::new(9).unwrap()))
+                .unwrap(),
+            Literal::Timestamp {
+                millis: 1_000,
+                nanos: 999
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_invalid_bytes() {
+        assert!(BinaryRow::from_bytes(&[0, 0]).is_err());
+        assert!(BinaryRow::from_bytes(&[0, 0, 0, 1, 0]).is_err());
+        assert_eq!(BinaryRow::from_bytes(&[0, 0, 0, 0]).unwrap().arity(), 0);
+
+        // variable-length value out of the row
+        let bytes = serialize(0, &[(100 << 32) | 4], &[]);
+        let row = BinaryRow::from_bytes(&bytes).unwrap();
+        assert!(row.get_string(0).is_err());
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let bytes = serialize(1 << 8, &[0, 42], &[]);
+        assert_eq!(BinaryRow::from_bytes(&bytes).unwrap().to_bytes(), bytes);
+        assert_eq!(
+            EMPTY_BINARY_ROW.to_bytes(),
+            vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_murmur_hash_by_words() {
+        // reference values of murmur3_x86_32
+        assert_eq!(murmur_hash_by_words(&[], 1), 0x514E28B7);
+        assert_eq!(murmur_hash_by_words(&[0, 0, 0, 0], 0), 0x2362F9DE);
+        assert_eq!(
+            murmur_hash_by_words(&[0x21, 0x43, 0x65, 0x87], 0),
+            0xF55B516Bu32 as i32
+        );
+        assert_eq!(
+            EMPTY_BINARY_ROW.hash_code(),
+            murmur_hash_by_words(&[0; 8], 42)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decode_partition() {
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        let path = "memory:/tmp/test_decode_partition/index-manifest";
+        let fixture = std::fs::read(
+            "tests/fixtures/manifest/index-manifest-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-0",
+        )
+        .unwrap();
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(fixture.into())
+            .await
+            .unwrap();
+        let entries = IndexManifestFile::new(file_io).read(path).await.unwrap();
+        let partition_type = RowType::new(vec![DataField::new(
+            0,
+            "p".to_string(),
+            DataType::Int(IntType::new()),
+        )]);
+        assert_eq!(
+            decode_partition(entries[0].partition(), &partition_type).unwrap(),
+            vec![Literal::Int(1)]
+        );
+
+        // the partition of an unpartitioned table
+        let zero = [0; 12];
+        assert_eq!(
+            decode_partition(&zero, &RowType::new(vec![])).unwrap(),
+            vec![]
+        );
+        assert!(matches!(
+            decode_partition(&zero, &partition_type),
+            Err(Error::BinaryRowInvalid { .. })
+        ));
+    }
+}
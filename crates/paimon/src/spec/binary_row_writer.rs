@@ -0,0 +1,321 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::spec::{BinaryRow, DataType, Literal};
+use crate::Error;
+
+/// Writer of a [`BinaryRow`], laying out the fields as Paimon does.
+///
+/// Each field must be written once, in any order. Variable-length values of more than 7 bytes
+/// are appended to the variable-length part, padded to a multiple of 8 bytes.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/data/BinaryRowWriter.java>
+#[derive(Debug, Clone)]
+pub struct BinaryRowWriter {
+    arity: i32,
+    /// fixed-length part followed by the variable-length part
+    data: Vec<u8>,
+}
+
+impl BinaryRowWriter {
+    pub fn new(arity: i32) -> Self {
+        Self {
+            arity,
+            data: vec![0; BinaryRow::cal_fix_part_size_in_bytes(arity) as usize],
+        }
+    }
+
+    fn field_offset(&self, pos: usize) -> usize {
+        assert!(pos < self.arity as usize, "field {} out of bounds", pos);
+        BinaryRow::cal_bit_set_width_in_bytes(self.arity) as usize + pos * 8
+    }
+
+    fn write_slot(&mut self, pos: usize, bytes: &[u8]) {
+        let offset = self.field_offset(pos);
+        self.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    pub fn set_null_at(&mut self, pos: usize) {
+        let index = pos + BinaryRow::HEADER_SIZE_IN_BYTES as usize;
+        self.data[index / 8] |= 1 << (index % 8);
+        self.write_slot(pos, &[0; 8]);
+    }
+
+    pub fn write_boolean(&mut self, pos: usize, value: bool) {
+        self.write_slot(pos, &[value as u8]);
+    }
+
+    pub fn write_byte(&mut self, pos: usize, value: i8) {
+        self.write_slot(pos, &value.to_le_bytes());
+    }
+
+    pub fn write_short(&mut self, pos: usize, value: i16) {
+        self.write_slot(pos, &value.to_le_bytes());
+    }
+
+    pub fn write_int(&mut self, pos: usize, value: i32) {
+        self.write_slot(pos, &value.to_le_bytes());
+    }
+
+    pub fn write_long(&mut self, pos: usize, value: i64) {
+        self.write_slot(pos, &value.to_le_bytes());
+    }
+
+    pub fn write_float(&mut self, pos: usize, value: f32) {
+        self.write_slot(pos, &value.to_le_bytes());
+    }
+
+    pub fn write_double(&mut self, pos: usize, value: f64) {
+        self.write_slot(pos, &value.to_le_bytes());
+    }
+
+    /// Append `len` zeroed bytes, rounded up to a multiple of 8, to the variable-length part
+    /// and return their offset.
+    fn reserve_var_part(&mut self, len: usize) -> usize {
+        let offset = self.data.len();
+        self.data.resize(offset + len.div_ceil(8) * 8, 0);
+        offset
+    }
+
+    fn set_offset_and_size(&mut self, pos: usize, offset: usize, size: u32) {
+        self.write_long(pos, ((offset as i64) << 32) | size as i64);
+    }
+
+    pub fn write_binary(&mut self, pos: usize, value: &[u8]) {
+        if value.len() <= 7 {
+            let mut slot = [0u8; 8];
+            slot[..value.len()].copy_from_slice(value);
+            slot[7] = 0x80 | value.len() as u8;
+            self.write_slot(pos, &slot);
+        } else {
+            let offset = self.reserve_var_part(value.len());
+            self.data[offset..offset + value.len()].copy_from_slice(value);
+            self.set_offset_and_size(pos, offset, value.len() as u32);
+        }
+    }
+
+    pub fn write_string(&mut self, pos: usize, value: &str) {
+        self.write_binary(pos, value.as_bytes());
+    }
+
+    /// Write the unscaled value of a decimal with the given precision, `None` for null.
+    ///
+    /// A non-compact decimal reserves 16 bytes in the variable-length part even if null, so that
+    /// it can be updated in place.
+    pub fn write_decimal(&mut self, pos: usize, unscaled: Option<i128>, precision: u32) {
+        if precision <= BinaryRow::MAX_COMPACT_DECIMAL_PRECISION {
+            match unscaled {
+                Some(unscaled) => self.write_long(pos, unscaled as i64),
+                None => self.set_null_at(pos),
+            }
+            return;
+        }
+
+        let offset = self.reserve_var_part(16);
+        match unscaled {
+            Some(unscaled) => {
+                let bytes = big_integer_bytes(unscaled);
+                self.data[offset..offset + bytes.len()].copy_from_slice(&bytes);
+                self.set_offset_and_size(pos, offset, bytes.len() as u32);
+            }
+            None => {
+                self.set_null_at(pos);
+                self.set_offset_and_size(pos, offset, 0);
+            }
+        }
+    }
+
+    /// Write a timestamp with the given precision as epoch millis and nanos of the millisecond,
+    /// `None` for null.
+    ///
+    /// A non-compact timestamp reserves 8 bytes in the variable-length part even if null, so that
+    /// it can be updated in place.
+    pub fn write_timestamp(&mut self, pos: usize, value: Option<(i64, i32)>, precision: u32) {
+        if precision <= BinaryRow::MAX_COMPACT_TIMESTAMP_PRECISION {
+            match value {
+                Some((millis, _)) => self.write_long(pos, millis),
+                None => self.set_null_at(pos),
+            }
+            return;
+        }
+
+        let offset = self.reserve_var_part(8);
+        match value {
+            Some((millis, nanos)) => {
+                self.data[offset..offset + 8].copy_from_slice(&millis.to_le_bytes());
+                self.set_offset_and_size(pos, offset, nanos as u32);
+            }
+            None => {
+                self.set_null_at(pos);
+                self.set_offset_and_size(pos, offset, 0);
+            }
+        }
+    }
+
+    /// Write a [`Literal`] as a field of the given type.
+    pub fn write_literal(
+        &mut self,
+        pos: usize,
+        literal: &Literal,
+        data_type: &DataType,
+    ) -> crate::Result<()> {
+        match (literal, data_type) {
+            // only the variable-length decimals and timestamps reserve their variable part
+            (Literal::Null, data_type) if data_type.is_fixed_length() => self.set_null_at(pos),
+            (Literal::Null, DataType::Decimal(t)) => self.write_decimal(pos, None, t.precision()),
+            (Literal::Null, DataType::Timestamp(t)) => {
+                self.write_timestamp(pos, None, t.precision())
+            }
+            (Literal::Null, DataType::LocalZonedTimestamp(t)) => {
+                self.write_timestamp(pos, None, t.precision())
+            }
+            (Literal::Null, _) => self.set_null_at(pos),
+            (Literal::Boolean(v), DataType::Boolean(_)) => self.write_boolean(pos, *v),
+            (Literal::TinyInt(v), DataType::TinyInt(_)) => self.write_byte(pos, *v),
+            (Literal::SmallInt(v), DataType::SmallInt(_)) => self.write_short(pos, *v),
+            (Literal::Int(v), DataType::Int(_)) => self.write_int(pos, *v),
+            (Literal::BigInt(v), DataType::BigInt(_)) => self.write_long(pos, *v),
+            (Literal::Float(v), DataType::Float(_)) => self.write_float(pos, *v),
+            (Literal::Double(v), DataType::Double(_)) => self.write_double(pos, *v),
+            (Literal::Decimal { unscaled, .. }, DataType::Decimal(t)) => {
+                self.write_decimal(pos, Some(*unscaled), t.precision())
+            }
+            (Literal::String(v), DataType::Char(_) | DataType::VarChar(_)) => {
+                self.write_string(pos, v)
+            }
+            (Literal::Binary(v), DataType::Binary(_) | DataType::VarBinary(_)) => {
+                self.write_binary(pos, v)
+            }
+            (Literal::Date(v), DataType::Date(_)) | (Literal::Time(v), DataType::Time(_)) => {
+                self.write_int(pos, *v)
+            }
+            (Literal::Timestamp { millis, nanos }, DataType::Timestamp(t)) => {
+                self.write_timestamp(pos, Some((*millis, *nanos)), t.precision())
+            }
+            (Literal::LocalZonedTimestamp { millis, nanos }, DataType::LocalZonedTimestamp(t)) => {
+                self.write_timestamp(pos, Some((*millis, *nanos)), t.precision())
+            }
+            _ => {
+                return Err(Error::BinaryRowInvalid {
+                    message: format!(
+                        "Can not write {:?} as field {} of type {:?}",
+                        literal, pos, data_type
+                    ),
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Finish the row.
+    pub fn build(self) -> BinaryRow {
+        let mut bytes = self.arity.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&self.data);
+        BinaryRow::from_bytes(&bytes).expect("written row must be valid")
+    }
+}
+
+/// Java's `BigInteger#toByteArray`: the minimal big-endian two's complement bytes.
+fn big_integer_bytes(value: i128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let sign = if value < 0 { 0xFF } else { 0 };
+    let mut start = 0;
+    while start < bytes.len() - 1
+        && bytes[start] == sign
+        && (bytes[start + 1] & 0x80) == (sign & 0x80)
+    {
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{DecimalType, IntType, TimestampType, VarCharType};
+
+    #[test]
+    fn test_write_and_read_fields() {
+        let mut writer = BinaryRowWriter::new(6);
+        writer.write_int(0, 42);
+        writer.write_string(1, "abc");
+        writer.write_string(2, "a-long-string");
+        writer.set_null_at(3);
+        writer.write_decimal(4, Some(-12345), 20);
+        writer.write_timestamp(5, Some((1_000, 999)), 9);
+        let row = writer.build();
+
+        assert_eq!(row.arity(), 6);
+        // 8 bytes of null bits, 6 slots, 16 bytes for the long string, 16 for the decimal
+        // and 8 for the timestamp
+        assert_eq!(row.data().len(), 8 + 6 * 8 + 16 + 16 + 8);
+        assert_eq!(row.get_int(0), 42);
+        assert_eq!(row.get_string(1).unwrap(), "abc");
+        assert_eq!(row.get_string(2).unwrap(), "a-long-string");
+        assert!(row.is_null_at(3));
+        assert!(!row.is_null_at(4));
+        assert_eq!(row.get_decimal(4, 20).unwrap(), -12345);
+        assert_eq!(row.get_timestamp(5, 9).unwrap(), (1_000, 999));
+        assert_eq!(BinaryRow::from_bytes(&row.to_bytes()).unwrap(), row);
+    }
+
+    #[test]
+    fn test_write_literals() {
+        let types = [
+            DataType::Int(IntType::new()),
+            DataType::VarChar(VarCharType::default()),
+            DataType::Decimal(DecimalType::new(30, 2).unwrap()),
+            DataType::Timestamp(TimestampType::new(6).unwrap()),
+            DataType::Decimal(DecimalType::new(10, 2).unwrap()),
+        ];
+        let literals = [
+            Literal::Int(7),
+            Literal::Null,
+            Literal::Null,
+            Literal::Timestamp {
+                millis: -1,
+                nanos: 1_000,
+            },
+            Literal::Null,
+        ];
+        let mut writer = BinaryRowWriter::new(5);
+        for (pos, (literal, data_type)) in literals.iter().zip(&types).enumerate() {
+            writer.write_literal(pos, literal, data_type).unwrap();
+        }
+        let row = writer.build();
+        // the null non-compact decimal reserves 16 bytes, the null compact one nothing
+        assert_eq!(row.data().len(), 8 + 5 * 8 + 16 + 8);
+        for (pos, (literal, data_type)) in literals.iter().zip(&types).enumerate() {
+            assert_eq!(&row.get_literal(pos, data_type).unwrap(), literal);
+        }
+
+        let mut writer = BinaryRowWriter::new(1);
+        assert!(writer
+            .write_literal(0, &Literal::String("x".to_string()), &types[0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_big_integer_bytes() {
+        assert_eq!(big_integer_bytes(0), vec![0]);
+        assert_eq!(big_integer_bytes(127), vec![0x7F]);
+        assert_eq!(big_integer_bytes(128), vec![0, 0x80]);
+        assert_eq!(big_integer_bytes(-1), vec![0xFF]);
+        assert_eq!(big_integer_bytes(-128), vec![0x80]);
+        assert_eq!(big_integer_bytes(-129), vec![0xFF, 0x7F]);
+    }
+}
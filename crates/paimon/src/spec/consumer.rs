@@ -0,0 +1,56 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use serde::{Deserialize, Serialize};
+
+/// Progress of a streaming consumer of a table: the snapshot it reads next.
+///
+/// The snapshots from the next snapshot of a consumer are not expired.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/consumer/Consumer.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Consumer {
+    next_snapshot: i64,
+}
+
+impl Consumer {
+    pub fn new(next_snapshot: i64) -> Self {
+        Self { next_snapshot }
+    }
+
+    /// Get the id of the snapshot the consumer reads next.
+    #[inline]
+    pub fn next_snapshot(&self) -> i64 {
+        self.next_snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consumer_serialization() {
+        let consumer: Consumer = serde_json::from_str(r#"{"nextSnapshot":5}"#).unwrap();
+        assert_eq!(consumer, Consumer::new(5));
+        assert_eq!(
+            serde_json::to_string(&consumer).unwrap(),
+            r#"{"nextSnapshot":5}"#
+        );
+    }
+}
@@ -0,0 +1,498 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::spec::TableSchema;
+use crate::Error;
+
+/// Typed access to the options of a table, with Paimon's defaults.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/CoreOptions.java>
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoreOptions {
+    options: HashMap<String, String>,
+}
+
+impl CoreOptions {
+    pub const COMPACTION_MAX_FILE_NUM: &'static str = "compaction.max.file-num";
+    pub const NUM_SORTED_RUNS_COMPACTION_TRIGGER: &'static str =
+        "num-sorted-run.compaction-trigger";
+    pub const NUM_LEVELS: &'static str = "num-levels";
+    pub const COMPACTION_MAX_SIZE_AMPLIFICATION_PERCENT: &'static str =
+        "compaction.max-size-amplification-percent";
+    pub const COMPACTION_SIZE_RATIO: &'static str = "compaction.size-ratio";
+    pub const BUCKET: &'static str = "bucket";
+    pub const BUCKET_KEY: &'static str = "bucket-key";
+    pub const SEQUENCE_FIELD: &'static str = "sequence.field";
+    /// Prefix of the options of a field, like `fields.age.default-value`.
+    pub const FIELDS_PREFIX: &'static str = "fields";
+    /// Suffix of the option of the default value of a field.
+    pub const DEFAULT_VALUE_SUFFIX: &'static str = "default-value";
+    pub const FILE_FORMAT: &'static str = "file.format";
+    pub const TARGET_FILE_SIZE: &'static str = "target-file-size";
+
+    pub const DEFAULT_TARGET_FILE_SIZE: u64 = 128 * 1024 * 1024;
+
+    pub const MANIFEST_TARGET_FILE_SIZE: &'static str = "manifest.target-file-size";
+
+    pub const DEFAULT_MANIFEST_TARGET_FILE_SIZE: u64 = 8 * 1024 * 1024;
+
+    pub const SORT_SPILL_BUFFER_SIZE: &'static str = "sort-spill-buffer-size";
+
+    pub const DEFAULT_SORT_SPILL_BUFFER_SIZE: u64 = 64 * 1024 * 1024;
+
+    pub const READ_FILE_CONCURRENCY: &'static str = "read.file-concurrency";
+
+    pub const SCAN_MANIFEST_PARALLELISM: &'static str = "scan.manifest.parallelism";
+
+    pub const SNAPSHOT_NUM_RETAINED_MIN: &'static str = "snapshot.num-retained.min";
+    pub const SNAPSHOT_NUM_RETAINED_MAX: &'static str = "snapshot.num-retained.max";
+
+    pub const COMMIT_USER_PREFIX: &'static str = "commit.user-prefix";
+
+    pub fn new(options: HashMap<String, String>) -> Self {
+        Self { options }
+    }
+
+    /// Get the raw options.
+    #[inline]
+    pub fn options(&self) -> &HashMap<String, String> {
+        &self.options
+    }
+
+    /// Get the raw value of an option.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.options.get(key).map(String::as_str)
+    }
+
+    fn parse<T: FromStr>(&self, key: &str, default: T) -> crate::Result<T> {
+        match self.options.get(key) {
+            None => Ok(default),
+            Some(value) => value.trim().parse().map_err(|_| Error::ConfigInvalid {
+                message: format!("Invalid value '{}' of option '{}'", value, key),
+            }),
+        }
+    }
+
+    /// The maximum number of files to trigger a compaction of an append-only table.
+    pub fn compaction_max_file_num(&self) -> crate::Result<usize> {
+        self.parse(Self::COMPACTION_MAX_FILE_NUM, 50)
+    }
+
+    /// The number of sorted runs to trigger a compaction, including level-0 files, one file one
+    /// sorted run, and the high-level runs, one level one sorted run.
+    pub fn num_sorted_runs_compaction_trigger(&self) -> crate::Result<usize> {
+        self.parse(Self::NUM_SORTED_RUNS_COMPACTION_TRIGGER, 5)
+    }
+
+    /// The total number of levels, one more than the compaction trigger by default.
+    pub fn num_levels(&self) -> crate::Result<usize> {
+        let default = self.num_sorted_runs_compaction_trigger()? + 1;
+        self.parse(Self::NUM_LEVELS, default)
+    }
+
+    /// The size amplification, as a percentage, at which all sorted runs are compacted.
+    pub fn max_size_amplification_percent(&self) -> crate::Result<u64> {
+        self.parse(Self::COMPACTION_MAX_SIZE_AMPLIFICATION_PERCENT, 200)
+    }
+
+    /// The percentage of flexibility when comparing the sizes of sorted runs to pick.
+    pub fn sorted_run_size_ratio(&self) -> crate::Result<u64> {
+        self.parse(Self::COMPACTION_SIZE_RATIO, 1)
+    }
+
+    /// The target size of a data file, at which the writer rolls to a new file.
+    pub fn target_file_size(&self) -> crate::Result<u64> {
+        self.memory_size(Self::TARGET_FILE_SIZE, Self::DEFAULT_TARGET_FILE_SIZE)
+    }
+
+    /// The target size of a manifest file, at which the writer rolls to a new file.
+    pub fn manifest_target_file_size(&self) -> crate::Result<u64> {
+        self.memory_size(
+            Self::MANIFEST_TARGET_FILE_SIZE,
+            Self::DEFAULT_MANIFEST_TARGET_FILE_SIZE,
+        )
+    }
+
+    /// The size in bytes of the records buffered when merging a key, above which they are
+    /// spilled to disk.
+    pub fn sort_spill_buffer_size(&self) -> crate::Result<u64> {
+        self.memory_size(
+            Self::SORT_SPILL_BUFFER_SIZE,
+            Self::DEFAULT_SORT_SPILL_BUFFER_SIZE,
+        )
+    }
+
+    /// The number of data files of a split fetched concurrently when reading it, at least 1.
+    pub fn read_file_concurrency(&self) -> crate::Result<usize> {
+        Ok(self.parse(Self::READ_FILE_CONCURRENCY, 4)?.max(1))
+    }
+
+    /// The number of manifest files read concurrently when planning, at least 1.
+    ///
+    /// This bounds the manifest entries held in memory besides the merged live files.
+    pub fn scan_manifest_parallelism(&self) -> crate::Result<usize> {
+        Ok(self.parse(Self::SCAN_MANIFEST_PARALLELISM, 16)?.max(1))
+    }
+
+    /// The minimum number of completed snapshots to retain, at least 1.
+    pub fn snapshot_num_retained_min(&self) -> crate::Result<usize> {
+        Ok(self.parse(Self::SNAPSHOT_NUM_RETAINED_MIN, 10)?.max(1))
+    }
+
+    /// The maximum number of completed snapshots to retain, at least the minimum.
+    pub fn snapshot_num_retained_max(&self) -> crate::Result<usize> {
+        let max = self.parse(Self::SNAPSHOT_NUM_RETAINED_MAX, i32::MAX as usize)?;
+        Ok(max.max(self.snapshot_num_retained_min()?))
+    }
+
+    fn memory_size(&self, key: &str, default: u64) -> crate::Result<u64> {
+        match self.get(key) {
+            None => Ok(default),
+            Some(value) => parse_memory_size(value).ok_or_else(|| Error::ConfigInvalid {
+                message: format!("Invalid value '{}' of option '{}'", value, key),
+            }),
+        }
+    }
+
+    /// The number of buckets of the table, `-1` for dynamic or unaware bucket mode.
+    pub fn bucket(&self) -> crate::Result<i32> {
+        self.parse(Self::BUCKET, -1)
+    }
+
+    /// The fields to compute the bucket of a row from, overriding the primary keys. Empty if not
+    /// set.
+    pub fn bucket_key(&self) -> Vec<String> {
+        self.field_list(Self::BUCKET_KEY)
+    }
+
+    /// The bucket key fields of a table with `schema`, validated against its fields.
+    ///
+    /// Defaults to the primary keys without the partition keys when `bucket-key` is not set. A
+    /// table with a fixed number of buckets and no primary keys requires the option; otherwise
+    /// the result may be empty.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/schema/TableSchema.java>
+    pub fn bucket_key_fields(&self, schema: &TableSchema) -> crate::Result<Vec<String>> {
+        let bucket_keys = self.bucket_key();
+        if bucket_keys.is_empty() {
+            let primary_keys = schema.trimmed_primary_keys();
+            if primary_keys.is_empty() && self.bucket()? > 0 {
+                return Err(Error::ConfigInvalid {
+                    message: format!(
+                        "A table with a fixed number of buckets requires the '{}' option or primary keys",
+                        Self::BUCKET_KEY
+                    ),
+                });
+            }
+            return Ok(primary_keys);
+        }
+
+        for key in &bucket_keys {
+            if !schema.fields().iter().any(|field| field.name() == key) {
+                return Err(Error::ConfigInvalid {
+                    message: format!("Bucket key '{}' is not a field of the table", key),
+                });
+            }
+            if schema.partition_keys().contains(key) {
+                return Err(Error::ConfigInvalid {
+                    message: format!("Bucket key '{}' should not be a partition key", key),
+                });
+            }
+        }
+        Ok(bucket_keys)
+    }
+
+    /// The fields ordering the records of a primary key, before the sequence number. Empty if
+    /// not set.
+    pub fn sequence_field(&self) -> Vec<String> {
+        self.field_list(Self::SEQUENCE_FIELD)
+    }
+
+    /// The default value of the field named `field_name`, in the string form of
+    /// [`Literal::parse`](crate::spec::Literal::parse), set by `fields.{field_name}.default-value`.
+    pub fn field_default_value(&self, field_name: &str) -> Option<&str> {
+        self.get(&format!(
+            "{}.{}.{}",
+            Self::FIELDS_PREFIX,
+            field_name,
+            Self::DEFAULT_VALUE_SUFFIX
+        ))
+    }
+
+    /// Parse a comma separated list of field names, empty if the option is not set.
+    fn field_list(&self, key: &str) -> Vec<String> {
+        self.get(key)
+            .map(|fields| {
+                fields
+                    .split(',')
+                    .map(|field| field.trim().to_string())
+                    .filter(|field| !field.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The format of the data files, like `parquet`.
+    pub fn file_format(&self) -> String {
+        self.get(Self::FILE_FORMAT).unwrap_or("orc").to_lowercase()
+    }
+
+    /// The prefix of the generated commit users, `None` if not set or blank.
+    pub fn commit_user_prefix(&self) -> Option<&str> {
+        self.get(Self::COMMIT_USER_PREFIX)
+            .map(str::trim)
+            .filter(|prefix| !prefix.is_empty())
+    }
+}
+
+/// Merge dynamic options, given for a single use of a table, over the static options of its
+/// schema: a dynamic option overrides the static option with the same key, the others are kept.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/AbstractFileStoreTable.java>
+pub fn merge_options(
+    static_options: &HashMap<String, String>,
+    dynamic_options: HashMap<String, String>,
+) -> HashMap<String, String> {
+    let mut options = static_options.clone();
+    options.extend(dynamic_options);
+    options
+}
+
+/// Parse a memory size like `128 mb` into bytes, a plain number being a number of bytes.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/options/MemorySize.java#L255>
+fn parse_memory_size(text: &str) -> Option<u64> {
+    let text = text.trim();
+    let unit_start = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(unit_start);
+    let number: u64 = number.parse().ok()?;
+    let multiplier: u64 = match unit.trim().to_lowercase().as_str() {
+        "" | "b" | "bytes" => 1,
+        "k" | "kb" | "kibibytes" => 1 << 10,
+        "m" | "mb" | "mebibytes" => 1 << 20,
+        "g" | "gb" | "gibibytes" => 1 << 30,
+        "t" | "tb" | "tebibytes" => 1 << 40,
+        _ => return None,
+    };
+    number.checked_mul(multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{BigIntType, DataField, DataType, IntType};
+
+    #[test]
+    fn test_compaction_options() {
+        let options = CoreOptions::default();
+        assert_eq!(options.num_sorted_runs_compaction_trigger().unwrap(), 5);
+        assert_eq!(options.num_levels().unwrap(), 6);
+        assert_eq!(options.compaction_max_file_num().unwrap(), 50);
+
+        let options = CoreOptions::new(HashMap::from([
+            (
+                CoreOptions::NUM_SORTED_RUNS_COMPACTION_TRIGGER.to_string(),
+                "3".to_string(),
+            ),
+            (
+                CoreOptions::COMPACTION_SIZE_RATIO.to_string(),
+                "x".to_string(),
+            ),
+        ]));
+        assert_eq!(options.num_levels().unwrap(), 4);
+        assert!(matches!(
+            options.sorted_run_size_ratio(),
+            Err(Error::ConfigInvalid { .. })
+        ));
+    }
+
+    #[test]
+    fn test_write_options() {
+        let options = CoreOptions::default();
+        assert_eq!(options.bucket().unwrap(), -1);
+        assert_eq!(options.file_format(), "orc");
+        assert!(options.bucket_key().is_empty());
+
+        let options = CoreOptions::new(HashMap::from([
+            (CoreOptions::BUCKET.to_string(), "4".to_string()),
+            (CoreOptions::FILE_FORMAT.to_string(), "Parquet".to_string()),
+            (CoreOptions::BUCKET_KEY.to_string(), "a, b".to_string()),
+        ]));
+        assert_eq!(options.bucket().unwrap(), 4);
+        assert_eq!(options.bucket_key(), vec!["a", "b"]);
+        assert_eq!(options.file_format(), "parquet");
+    }
+
+    #[test]
+    fn test_bucket_key_fields() {
+        let fields = vec![
+            DataField::new(0, "pt".to_string(), DataType::Int(IntType::new())),
+            DataField::new(1, "id".to_string(), DataType::BigInt(BigIntType::new())),
+            DataField::new(2, "value".to_string(), DataType::Int(IntType::new())),
+        ];
+        let schema = |primary_keys: Vec<&str>| {
+            TableSchema::new(
+                0,
+                fields.clone(),
+                2,
+                vec!["pt".to_string()],
+                primary_keys.into_iter().map(String::from).collect(),
+                HashMap::new(),
+                None,
+            )
+        };
+        let options = |pairs: &[(&str, &str)]| {
+            CoreOptions::new(
+                pairs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            )
+        };
+
+        // defaults to the primary keys without the partition keys
+        assert_eq!(
+            CoreOptions::default()
+                .bucket_key_fields(&schema(vec!["pt", "id"]))
+                .unwrap(),
+            vec!["id"]
+        );
+        assert!(CoreOptions::default()
+            .bucket_key_fields(&schema(vec![]))
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            options(&[(CoreOptions::BUCKET, "2")]).bucket_key_fields(&schema(vec![])),
+            Err(Error::ConfigInvalid { .. })
+        ));
+
+        assert_eq!(
+            options(&[
+                (CoreOptions::BUCKET, "2"),
+                (CoreOptions::BUCKET_KEY, "value, id")
+            ])
+            .bucket_key_fields(&schema(vec![]))
+            .unwrap(),
+            vec!["value", "id"]
+        );
+        for invalid in ["missing", "id,pt"] {
+            assert!(matches!(
+                options(&[(CoreOptions::BUCKET_KEY, invalid)])
+                    .bucket_key_fields(&schema(vec!["pt", "id"])),
+                Err(Error::ConfigInvalid { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_target_file_size() {
+        assert_eq!(
+            CoreOptions::default().target_file_size().unwrap(),
+            CoreOptions::DEFAULT_TARGET_FILE_SIZE
+        );
+        for (value, expected) in [
+            ("256", 256),
+            ("1 kb", 1024),
+            ("2MB", 2 << 20),
+            ("1g", 1 << 30),
+        ] {
+            let options = CoreOptions::new(HashMap::from([(
+                CoreOptions::TARGET_FILE_SIZE.to_string(),
+                value.to_string(),
+            )]));
+            assert_eq!(options.target_file_size().unwrap(), expected);
+        }
+        let options = CoreOptions::new(HashMap::from([(
+            CoreOptions::TARGET_FILE_SIZE.to_string(),
+            "1 xb".to_string(),
+        )]));
+        assert!(matches!(
+            options.target_file_size(),
+            Err(Error::ConfigInvalid { .. })
+        ));
+
+        assert_eq!(
+            CoreOptions::default().manifest_target_file_size().unwrap(),
+            CoreOptions::DEFAULT_MANIFEST_TARGET_FILE_SIZE
+        );
+        let options = CoreOptions::new(HashMap::from([(
+            CoreOptions::MANIFEST_TARGET_FILE_SIZE.to_string(),
+            "2 kb".to_string(),
+        )]));
+        assert_eq!(options.manifest_target_file_size().unwrap(), 2048);
+    }
+
+    #[test]
+    fn test_sort_spill_buffer_size() {
+        assert_eq!(
+            CoreOptions::default().sort_spill_buffer_size().unwrap(),
+            CoreOptions::DEFAULT_SORT_SPILL_BUFFER_SIZE
+        );
+        let options = CoreOptions::new(HashMap::from([(
+            CoreOptions::SORT_SPILL_BUFFER_SIZE.to_string(),
+            "16 mb".to_string(),
+        )]));
+        assert_eq!(options.sort_spill_buffer_size().unwrap(), 16 << 20);
+    }
+
+    #[test]
+    fn test_merge_options() {
+        let static_options = HashMap::from([
+            (CoreOptions::BUCKET.to_string(), "4".to_string()),
+            (CoreOptions::FILE_FORMAT.to_string(), "orc".to_string()),
+        ]);
+        let merged = merge_options(
+            &static_options,
+            HashMap::from([
+                (CoreOptions::FILE_FORMAT.to_string(), "parquet".to_string()),
+                (
+                    CoreOptions::READ_FILE_CONCURRENCY.to_string(),
+                    "8".to_string(),
+                ),
+            ]),
+        );
+        assert_eq!(
+            merged,
+            HashMap::from([
+                (CoreOptions::BUCKET.to_string(), "4".to_string()),
+                (CoreOptions::FILE_FORMAT.to_string(), "parquet".to_string()),
+                (
+                    CoreOptions::READ_FILE_CONCURRENCY.to_string(),
+                    "8".to_string()
+                ),
+            ])
+        );
+        assert_eq!(static_options[CoreOptions::FILE_FORMAT], "orc");
+    }
+
+    #[test]
+    fn test_read_file_concurrency() {
+        assert_eq!(CoreOptions::default().read_file_concurrency().unwrap(), 4);
+        for (value, expected) in [("8", 8), ("0", 1)] {
+            let options = CoreOptions::new(HashMap::from([(
+                CoreOptions::READ_FILE_CONCURRENCY.to_string(),
+                value.to_string(),
+            )]));
+            assert_eq!(options.read_file_concurrency().unwrap(), expected);
+        }
+    }
+}
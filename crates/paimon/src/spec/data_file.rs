@@ -15,40 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::error::StatsInvalidSnafu;
 use crate::spec::stats::BinaryTableStats;
 use chrono::serde::ts_milliseconds::deserialize as from_millis;
 use chrono::serde::ts_milliseconds::serialize as to_millis;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
-
-pub const EMPTY_BINARY_ROW: BinaryRow = BinaryRow::new(0);
-
-/// An implementation of InternalRow.
-///
-/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/data/BinaryRow.java>
-#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct BinaryRow {
-    arity: i32,
-    null_bits_size_in_bytes: i32,
-}
-
-impl BinaryRow {
-    pub const HEADER_SIZE_IN_BYTES: i32 = 8;
-    pub const fn cal_bit_set_width_in_bytes(arity: i32) -> i32 {
-        ((arity + 63 + Self::HEADER_SIZE_IN_BYTES) / 64) * 8
-    }
-    pub const fn cal_fix_part_size_in_bytes(arity: i32) -> i32 {
-        Self::cal_bit_set_width_in_bytes(arity) + 8 * arity
-    }
-    pub const fn new(arity: i32) -> Self {
-        Self {
-            arity,
-            null_bits_size_in_bytes: (arity + 7) / 8,
-        }
-    }
-}
+use typed_builder::TypedBuilder;
 
 /// The Source of a file.
 /// TODO: move me to the manifest module.
@@ -64,47 +38,84 @@ pub enum FileSource {
 
 /// Metadata of a data file.
 ///
+/// Construct it with [`DataFileMeta::builder`], fields are only readable through accessors:
+///
+/// ```
+/// use chrono::Utc;
+/// use paimon::spec::DataFileMeta;
+///
+/// let file = DataFileMeta::builder()
+///     .file_name("data-0.parquet".to_string())
+///     .file_size(1024)
+///     .row_count(10)
+///     .min_sequence_number(0)
+///     .max_sequence_number(9)
+///     .schema_id(0)
+///     .level(0)
+///     .creation_time(Utc::now())
+///     .build();
+/// assert_eq!(file.file_name(), "data-0.parquet");
+/// assert_eq!(file.extra_files(), &[] as &[String]);
+/// ```
+///
+/// ```compile_fail
+/// use paimon::spec::DataFileMeta;
+///
+/// // fields are private, use the builder instead
+/// fn file_name(file: &DataFileMeta) -> &str {
+///     &file.file_name
+/// }
+/// ```
+///
 /// Impl References: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/io/DataFileMeta.java>
-#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, TypedBuilder)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct DataFileMeta {
     #[serde(rename = "_FILE_NAME")]
-    pub file_name: String,
+    file_name: String,
     #[serde(rename = "_FILE_SIZE")]
-    pub file_size: i64,
+    file_size: i64,
     // row_count tells the total number of rows (including add & delete) in this file.
     #[serde(rename = "_ROW_COUNT")]
-    pub row_count: i64,
+    row_count: i64,
     #[serde(rename = "_MIN_KEY", with = "serde_bytes")]
-    pub min_key: Vec<u8>,
+    #[builder(default)]
+    min_key: Vec<u8>,
     #[serde(rename = "_MAX_KEY", with = "serde_bytes")]
-    pub max_key: Vec<u8>,
+    #[builder(default)]
+    max_key: Vec<u8>,
     #[serde(rename = "_KEY_STATS")]
-    pub key_stats: BinaryTableStats,
+    #[builder(default = BinaryTableStats::new(vec![], vec![], vec![]))]
+    key_stats: BinaryTableStats,
     #[serde(rename = "_VALUE_STATS")]
-    pub value_stats: BinaryTableStats,
+    #[builder(default = BinaryTableStats::new(vec![], vec![], vec![]))]
+    value_stats: BinaryTableStats,
     #[serde(rename = "_MIN_SEQUENCE_NUMBER")]
-    pub min_sequence_number: i64,
+    min_sequence_number: i64,
     #[serde(rename = "_MAX_SEQUENCE_NUMBER")]
-    pub max_sequence_number: i64,
+    max_sequence_number: i64,
     #[serde(rename = "_SCHEMA_ID")]
-    pub schema_id: i64,
+    schema_id: i64,
     #[serde(rename = "_LEVEL")]
-    pub level: i32,
+    level: i32,
     #[serde(rename = "_EXTRA_FILES")]
-    pub extra_files: Vec<String>,
+    #[builder(default)]
+    extra_files: Vec<String>,
     #[serde(
         rename = "_CREATION_TIME",
         serialize_with = "to_millis",
         deserialize_with = "from_millis"
     )]
-    pub creation_time: DateTime<Utc>,
+    creation_time: DateTime<Utc>,
     #[serde(rename = "_DELETE_ROW_COUNT")]
     // rowCount = add_row_count + delete_row_count.
-    pub delete_row_count: Option<i64>,
+    #[builder(default = None)]
+    delete_row_count: Option<i64>,
     // file index filter bytes, if it is small, store in data file meta
     #[serde(rename = "_EMBEDDED_FILE_INDEX", with = "serde_bytes")]
-    pub embedded_index: Option<Vec<u8>>,
+    #[builder(default = None)]
+    embedded_index: Option<Vec<u8>>,
 }
 
 impl Display for DataFileMeta {
@@ -113,5 +124,129 @@ impl Display for DataFileMeta {
     }
 }
 
-#[allow(dead_code)]
-impl DataFileMeta {}
+impl DataFileMeta {
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn file_size(&self) -> i64 {
+        self.file_size
+    }
+
+    /// Get the total number of rows in this file, including the deleted ones.
+    pub fn row_count(&self) -> i64 {
+        self.row_count
+    }
+
+    /// Get the serialized `BinaryRow` of the minimum key.
+    pub fn min_key(&self) -> &[u8] {
+        &self.min_key
+    }
+
+    /// Get the serialized `BinaryRow` of the maximum key.
+    pub fn max_key(&self) -> &[u8] {
+        &self.max_key
+    }
+
+    pub fn key_stats(&self) -> &BinaryTableStats {
+        &self.key_stats
+    }
+
+    pub fn value_stats(&self) -> &BinaryTableStats {
+        &self.value_stats
+    }
+
+    pub fn min_sequence_number(&self) -> i64 {
+        self.min_sequence_number
+    }
+
+    pub fn max_sequence_number(&self) -> i64 {
+        self.max_sequence_number
+    }
+
+    pub fn schema_id(&self) -> i64 {
+        self.schema_id
+    }
+
+    pub fn level(&self) -> i32 {
+        self.level
+    }
+
+    pub fn extra_files(&self) -> &[String] {
+        &self.extra_files
+    }
+
+    pub fn creation_time(&self) -> DateTime<Utc> {
+        self.creation_time
+    }
+
+    /// Get the number of deleted rows, `None` for files written by older versions.
+    pub fn delete_row_count(&self) -> Option<i64> {
+        self.delete_row_count
+    }
+
+    /// Get the number of added rows in this file: its rows but the deleted ones, all of them for
+    /// files written by older versions.
+    pub fn add_row_count(&self) -> i64 {
+        self.row_count - self.delete_row_count.unwrap_or(0)
+    }
+
+    /// Check that the number of deleted rows is between 0 and the number of rows.
+    pub(crate) fn validate_row_counts(&self) -> crate::Result<()> {
+        match self.delete_row_count {
+            Some(delete_row_count) if !(0..=self.row_count).contains(&delete_row_count) => {
+                StatsInvalidSnafu {
+                    message: format!(
+                        "Data file '{}' has {} deleted rows out of {} rows",
+                        self.file_name, delete_row_count, self.row_count
+                    ),
+                }
+                .fail()
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the file index bytes embedded in the meta, if the index is small enough.
+    pub fn embedded_index(&self) -> Option<&[u8]> {
+        self.embedded_index.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::DataSplit;
+    use crate::test_util;
+    use crate::Error;
+
+    fn data_file(row_count: i64, delete_row_count: Option<i64>) -> DataFileMeta {
+        let mut file = test_util::data_file("data-0.parquet", row_count, 100, 0);
+        file.delete_row_count = delete_row_count;
+        file
+    }
+
+    #[test]
+    fn test_add_row_count() {
+        let file = data_file(10, Some(3));
+        assert_eq!(file.add_row_count(), 7);
+        file.validate_row_counts().unwrap();
+
+        for file in [data_file(10, None), data_file(10, Some(0))] {
+            assert_eq!(file.add_row_count(), 10);
+            file.validate_row_counts().unwrap();
+        }
+
+        for delete_row_count in [11, -1] {
+            assert!(matches!(
+                data_file(10, Some(delete_row_count)).validate_row_counts(),
+                Err(Error::StatsInvalid { .. })
+            ));
+        }
+
+        // the rows of a split are estimated without the deleted ones
+        let files = vec![data_file(10, Some(3)), data_file(5, None)];
+        let split = DataSplit::new(1, vec![], 0, "bucket-0".to_string(), 1, files);
+        assert_eq!(split.row_count(), 12);
+    }
+}
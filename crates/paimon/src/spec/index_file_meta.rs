@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use serde::{Deserialize, Serialize};
+
+/// Index type of deletion vectors index files.
+pub const DELETION_VECTORS_INDEX: &str = "DELETION_VECTORS";
+
+/// Index type of dynamic bucket hash index files.
+pub const HASH_INDEX: &str = "HASH";
+
+/// Byte range of the deletion vector of a data file, within a deletion vectors index file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeletionVectorRange {
+    #[serde(rename = "f0")]
+    data_file_name: String,
+
+    #[serde(rename = "f1")]
+    offset: i32,
+
+    #[serde(rename = "f2")]
+    length: i32,
+}
+
+impl DeletionVectorRange {
+    pub fn new(data_file_name: String, offset: i32, length: i32) -> Self {
+        Self {
+            data_file_name,
+            offset,
+            length,
+        }
+    }
+
+    /// Get the name of the data file the deletion vector applies to.
+    pub fn data_file_name(&self) -> &str {
+        &self.data_file_name
+    }
+
+    /// Get the offset of the deletion vector in the index file.
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// Get the length in bytes of the serialized deletion vector.
+    pub fn length(&self) -> i32 {
+        self.length
+    }
+}
+
+/// Metadata of an index file.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/index/IndexFileMeta.java>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IndexFileMeta {
+    #[serde(rename = "_INDEX_TYPE")]
+    index_type: String,
+
+    #[serde(rename = "_FILE_NAME")]
+    file_name: String,
+
+    #[serde(rename = "_FILE_SIZE")]
+    file_size: i64,
+
+    #[serde(rename = "_ROW_COUNT")]
+    row_count: i64,
+
+    /// Ranges of the deletion vectors of each data file, only set for deletion vectors indexes.
+    #[serde(rename = "_DELETIONS_VECTORS_RANGES")]
+    deletion_vectors_ranges: Option<Vec<DeletionVectorRange>>,
+}
+
+impl IndexFileMeta {
+    pub fn new(
+        index_type: String,
+        file_name: String,
+        file_size: i64,
+        row_count: i64,
+        deletion_vectors_ranges: Option<Vec<DeletionVectorRange>>,
+    ) -> Self {
+        Self {
+            index_type,
+            file_name,
+            file_size,
+            row_count,
+            deletion_vectors_ranges,
+        }
+    }
+
+    /// Get the index type, like [`DELETION_VECTORS_INDEX`] or [`HASH_INDEX`].
+    pub fn index_type(&self) -> &str {
+        &self.index_type
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn file_size(&self) -> i64 {
+        self.file_size
+    }
+
+    pub fn row_count(&self) -> i64 {
+        self.row_count
+    }
+
+    pub fn deletion_vectors_ranges(&self) -> Option<&[DeletionVectorRange]> {
+        self.deletion_vectors_ranges.as_deref()
+    }
+}
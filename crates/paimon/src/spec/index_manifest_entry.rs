@@ -0,0 +1,302 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use serde::{Deserialize, Serialize};
+
+use crate::spec::{
+    DeletionVectorRange, FileKind, IndexFileMeta, DELETION_VECTORS_INDEX, HASH_INDEX,
+};
+
+/// Manifest entry of an index file.
+///
+/// The index file meta is stored inline with the entry, mirroring Java's flattened row type.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/manifest/IndexManifestEntry.java>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawIndexManifestEntry", into = "RawIndexManifestEntry")]
+#[non_exhaustive]
+pub struct IndexManifestEntry {
+    kind: FileKind,
+    partition: Vec<u8>,
+    bucket: i32,
+    index_file: IndexFileMeta,
+    version: i32,
+}
+
+impl IndexManifestEntry {
+    /// Serialization version of index manifest entries.
+    pub const VERSION: i32 = 1;
+
+    pub fn new(
+        kind: FileKind,
+        partition: Vec<u8>,
+        bucket: i32,
+        index_file: IndexFileMeta,
+        version: i32,
+    ) -> Self {
+        Self {
+            kind,
+            partition,
+            bucket,
+            index_file,
+            version,
+        }
+    }
+
+    pub fn kind(&self) -> &FileKind {
+        &self.kind
+    }
+
+    /// Get the serialized partition `BinaryRow` of the index file.
+    pub fn partition(&self) -> &Vec<u8> {
+        &self.partition
+    }
+
+    pub fn bucket(&self) -> i32 {
+        self.bucket
+    }
+
+    pub fn index_file(&self) -> &IndexFileMeta {
+        &self.index_file
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+}
+
+/// Select the entries of the index files of the given type, like [`HASH_INDEX`] or
+/// [`DELETION_VECTORS_INDEX`].
+pub fn filter_by_index_type<'a>(
+    entries: &'a [IndexManifestEntry],
+    index_type: &str,
+) -> Vec<&'a IndexManifestEntry> {
+    entries
+        .iter()
+        .filter(|entry| entry.index_file.index_type() == index_type)
+        .collect()
+}
+
+/// Index files of a snapshot, as read from its index manifest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexManifestEntries {
+    entries: Vec<IndexManifestEntry>,
+}
+
+impl IndexManifestEntries {
+    pub fn new(entries: Vec<IndexManifestEntry>) -> Self {
+        Self { entries }
+    }
+
+    pub fn entries(&self) -> &[IndexManifestEntry] {
+        &self.entries
+    }
+
+    /// Get the entries of the index files of the given type.
+    pub fn filter_by_index_type(&self, index_type: &str) -> Vec<&IndexManifestEntry> {
+        filter_by_index_type(&self.entries, index_type)
+    }
+
+    /// Get the entries of the hash index files, mapping keys to buckets for dynamic bucket
+    /// tables.
+    pub fn hash_indexes(&self) -> Vec<&IndexManifestEntry> {
+        self.filter_by_index_type(HASH_INDEX)
+    }
+
+    /// Get the entries of the deletion vectors index files.
+    pub fn deletion_vector_indexes(&self) -> Vec<&IndexManifestEntry> {
+        self.filter_by_index_type(DELETION_VECTORS_INDEX)
+    }
+
+    /// Find the deletion vector of a data file: the deletion vectors index file of the partition
+    /// and bucket covering `data_file`, with the `(offset, length)` of its deletion vector.
+    ///
+    /// Returns `None` if no rows of the data file are deleted.
+    pub fn deletion_vector_for(
+        &self,
+        partition: &[u8],
+        bucket: i32,
+        data_file: &str,
+    ) -> Option<(IndexFileMeta, (i32, i32))> {
+        self.deletion_vector_indexes()
+            .into_iter()
+            .filter(|entry| {
+                entry.kind == FileKind::Add
+                    && entry.bucket == bucket
+                    && entry.partition == partition
+            })
+            .find_map(|entry| {
+                entry
+                    .index_file
+                    .deletion_vectors_ranges()?
+                    .iter()
+                    .find(|range| range.data_file_name() == data_file)
+                    .map(|range| (entry.index_file.clone(), (range.offset(), range.length())))
+            })
+    }
+}
+
+impl From<Vec<IndexManifestEntry>> for IndexManifestEntries {
+    fn from(entries: Vec<IndexManifestEntry>) -> Self {
+        Self::new(entries)
+    }
+}
+
+/// The avro layout of [`IndexManifestEntry`], with the index file meta flattened.
+#[derive(Serialize, Deserialize)]
+struct RawIndexManifestEntry {
+    #[serde(rename = "_KIND")]
+    kind: FileKind,
+
+    #[serde(rename = "_PARTITION", with = "serde_bytes")]
+    partition: Vec<u8>,
+
+    #[serde(rename = "_BUCKET")]
+    bucket: i32,
+
+    #[serde(rename = "_INDEX_TYPE")]
+    index_type: String,
+
+    #[serde(rename = "_FILE_NAME")]
+    file_name: String,
+
+    #[serde(rename = "_FILE_SIZE")]
+    file_size: i64,
+
+    #[serde(rename = "_ROW_COUNT")]
+    row_count: i64,
+
+    #[serde(rename = "_DELETIONS_VECTORS_RANGES")]
+    deletion_vectors_ranges: Option<Vec<DeletionVectorRange>>,
+
+    #[serde(rename = "_VERSION")]
+    version: i32,
+}
+
+impl From<RawIndexManifestEntry> for IndexManifestEntry {
+    fn from(raw: RawIndexManifestEntry) -> Self {
+        IndexManifestEntry::new(
+            raw.kind,
+            raw.partition,
+            raw.bucket,
+            IndexFileMeta::new(
+                raw.index_type,
+                raw.file_name,
+                raw.file_size,
+                raw.row_count,
+                raw.deletion_vectors_ranges,
+            ),
+            raw.version,
+        )
+    }
+}
+
+impl From<IndexManifestEntry> for RawIndexManifestEntry {
+    fn from(entry: IndexManifestEntry) -> Self {
+        let IndexManifestEntry {
+            kind,
+            partition,
+            bucket,
+            index_file,
+            version,
+        } = entry;
+        RawIndexManifestEntry {
+            kind,
+            partition,
+            bucket,
+            index_type: index_file.index_type().to_string(),
+            file_name: index_file.file_name().to_string(),
+            file_size: index_file.file_size(),
+            row_count: index_file.row_count(),
+            deletion_vectors_ranges: index_file.deletion_vectors_ranges().map(<[_]>::to_vec),
+            version,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::spec::IndexManifestFile;
+
+    async fn read_fixture(path: &str) -> IndexManifestEntries {
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        let fixture = std::fs::read(
+            "tests/fixtures/manifest/index-manifest-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-0",
+        )
+        .unwrap();
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(fixture.into())
+            .await
+            .unwrap();
+        IndexManifestEntries::new(IndexManifestFile::new(file_io).read(path).await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_filter_by_index_type() {
+        let entries = read_fixture("memory:/tmp/test_filter_by_index_type/index-manifest").await;
+        let file_names = |entries: Vec<&IndexManifestEntry>| {
+            entries
+                .iter()
+                .map(|entry| entry.index_file().file_name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            file_names(entries.hash_indexes()),
+            vec!["index-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-1"]
+        );
+        assert_eq!(entries.hash_indexes()[0].bucket(), 1);
+        assert_eq!(
+            file_names(entries.deletion_vector_indexes()),
+            vec!["index-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-0"]
+        );
+        assert_eq!(entries.deletion_vector_indexes()[0].bucket(), 0);
+        assert!(filter_by_index_type(entries.entries(), "BLOOM").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_deletion_vector_for() {
+        let entries = read_fixture("memory:/tmp/test_deletion_vector_for/index-manifest").await;
+
+        let partition = [0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+        let data_file = "data-6a5b9d4e-3c1f-4b7a-a2d8-0e9f7c6b5a41-1.orc";
+        let (index_file, range) = entries
+            .deletion_vector_for(&partition, 0, data_file)
+            .unwrap();
+        assert_eq!(
+            index_file.file_name(),
+            "index-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-0"
+        );
+        assert_eq!(range, (31, 22));
+
+        // other bucket, other partition, or a data file without deletions
+        assert!(entries
+            .deletion_vector_for(&partition, 1, data_file)
+            .is_none());
+        let mut other_partition = partition;
+        other_partition[12] = 2;
+        assert!(entries
+            .deletion_vector_for(&other_partition, 0, data_file)
+            .is_none());
+        assert!(entries
+            .deletion_vector_for(&partition, 0, "data-unknown-0.orc")
+            .is_none());
+    }
+}
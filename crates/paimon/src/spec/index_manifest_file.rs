@@ -0,0 +1,184 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::io::FileIO;
+use crate::spec::objects_file::{check_versions, read_objects_file, ObjectsFileKind};
+use crate::spec::IndexManifestEntry;
+
+/// Reader of index manifest files, which record the index files of a snapshot.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/manifest/IndexManifestFile.java>
+pub struct IndexManifestFile {
+    file_io: FileIO,
+}
+
+impl IndexManifestFile {
+    pub fn new(file_io: FileIO) -> Self {
+        Self { file_io }
+    }
+
+    /// Read all entries of the index manifest file at `path`.
+    ///
+    /// Entries written with an unsupported version are rejected.
+    pub async fn read(&self, path: &str) -> crate::Result<Vec<IndexManifestEntry>> {
+        let bytes = self.file_io.new_input(path)?.read().await?;
+        let entries: Vec<IndexManifestEntry> =
+            read_objects_file(path, &bytes, ObjectsFileKind::IndexManifest)?;
+        check_versions(
+            path,
+            entries.iter().map(IndexManifestEntry::version),
+            IndexManifestEntry::VERSION,
+        )?;
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::spec::objects_file::rewrite_versions;
+    use crate::spec::{
+        DeletionVectorRange, FileKind, IndexFileMeta, DELETION_VECTORS_INDEX, HASH_INDEX,
+    };
+    use crate::Error;
+
+    const FIXTURE: &str =
+        "tests/fixtures/manifest/index-manifest-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-0";
+
+    #[tokio::test]
+    async fn test_read_index_manifest_file() {
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        let path = "memory:/tmp/test_read_index_manifest_file/index-manifest";
+        let output = file_io.new_output(path).unwrap();
+        output
+            .write(std::fs::read(FIXTURE).unwrap().into())
+            .await
+            .unwrap();
+
+        let entries = IndexManifestFile::new(file_io).read(path).await.unwrap();
+        let partition = vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(
+            entries,
+            vec![
+                IndexManifestEntry::new(
+                    FileKind::Add,
+                    partition.clone(),
+                    0,
+                    IndexFileMeta::new(
+                        DELETION_VECTORS_INDEX.to_string(),
+                        "index-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-0".to_string(),
+                        61,
+                        2,
+                        Some(vec![
+                            DeletionVectorRange::new(
+                                "data-6a5b9d4e-3c1f-4b7a-a2d8-0e9f7c6b5a41-0.orc".to_string(),
+                                1,
+                                22
+                            ),
+                            DeletionVectorRange::new(
+                                "data-6a5b9d4e-3c1f-4b7a-a2d8-0e9f7c6b5a41-1.orc".to_string(),
+                                31,
+                                22
+                            ),
+                        ]),
+                    ),
+                    1
+                ),
+                IndexManifestEntry::new(
+                    FileKind::Add,
+                    partition,
+                    1,
+                    IndexFileMeta::new(
+                        HASH_INDEX.to_string(),
+                        "index-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-1".to_string(),
+                        16,
+                        4,
+                        None,
+                    ),
+                    1
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_index_manifest_file_with_unsupported_version() {
+        let fixture = std::fs::read(FIXTURE).unwrap();
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        let path = "memory:/tmp/test_read_index_manifest_file_with_unsupported_version/unsupported";
+        let output = file_io.new_output(path).unwrap();
+        output
+            .write(rewrite_versions(&fixture, 2).into())
+            .await
+            .unwrap();
+        let result = IndexManifestFile::new(file_io).read(path).await;
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedManifestVersion { message }) if message.contains("version 2")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_read_other_files_as_index_manifest() {
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        let index_manifest_file = IndexManifestFile::new(file_io.clone());
+        for (fixture, actual) in [
+            (
+                "tests/fixtures/manifest/manifest-8ded1f09-fcda-489e-9167-582ac0f9f846-0",
+                "a manifest file",
+            ),
+            (
+                "tests/fixtures/manifest/manifest-list-5c7399a0-46ae-4a5e-9c13-3ab07212cdb6-0",
+                "a manifest list",
+            ),
+        ] {
+            let path = "memory:/tmp/test_read_other_files_as_index_manifest";
+            file_io
+                .new_output(path)
+                .unwrap()
+                .write(std::fs::read(fixture).unwrap().into())
+                .await
+                .unwrap();
+            let err = index_manifest_file.read(path).await.unwrap_err();
+            assert!(
+                matches!(
+                    &err,
+                    Error::ObjectsFileMismatch { expected, actual: a, .. }
+                        if expected == "an index manifest" && a == actual
+                ),
+                "{err}"
+            );
+        }
+
+        // the manifest file reader does not accept an index manifest either
+        let path = "memory:/tmp/test_read_other_files_as_index_manifest/index-manifest";
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(std::fs::read(FIXTURE).unwrap().into())
+            .await
+            .unwrap();
+        let err = crate::spec::ManifestFile::new(file_io)
+            .read(path)
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("is an index manifest, not a manifest file"));
+    }
+}
@@ -0,0 +1,475 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::error::LiteralInvalidSnafu;
+use crate::spec::{DataType, DEFAULT_PARTITION_NAME};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A typed value of a field, in Paimon's internal representation.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/data/InternalRow.java#L126>
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Null,
+    Boolean(bool),
+    TinyInt(i8),
+    SmallInt(i16),
+    Int(i32),
+    BigInt(i64),
+    Float(f32),
+    Double(f64),
+    Decimal {
+        unscaled: i128,
+        precision: u32,
+        scale: u32,
+    },
+    String(String),
+    Binary(Vec<u8>),
+    /// days since epoch
+    Date(i32),
+    /// milliseconds of the day
+    Time(i32),
+    /// epoch millis and nanos of the millisecond
+    Timestamp {
+        millis: i64,
+        nanos: i32,
+    },
+    /// epoch millis and nanos of the millisecond, in UTC
+    LocalZonedTimestamp {
+        millis: i64,
+        nanos: i32,
+    },
+}
+
+impl Literal {
+    pub fn is_null(&self) -> bool {
+        matches!(self, Literal::Null)
+    }
+
+    /// Whether the literal is a value of the given type, like [`Literal::parse`] produces. Null
+    /// is a value of any type.
+    pub fn is_of_type(&self, data_type: &DataType) -> bool {
+        matches!(
+            (self, data_type),
+            (Literal::Null, _)
+                | (Literal::Boolean(_), DataType::Boolean(_))
+                | (Literal::TinyInt(_), DataType::TinyInt(_))
+                | (Literal::SmallInt(_), DataType::SmallInt(_))
+                | (Literal::Int(_), DataType::Int(_))
+                | (Literal::BigInt(_), DataType::BigInt(_))
+                | (Literal::Float(_), DataType::Float(_))
+                | (Literal::Double(_), DataType::Double(_))
+                | (Literal::Decimal { .. }, DataType::Decimal(_))
+                | (Literal::String(_), DataType::Char(_) | DataType::VarChar(_))
+                | (
+                    Literal::Binary(_),
+                    DataType::Binary(_) | DataType::VarBinary(_)
+                )
+                | (Literal::Date(_), DataType::Date(_))
+                | (Literal::Time(_), DataType::Time(_))
+                | (Literal::Timestamp { .. }, DataType::Timestamp(_))
+                | (
+                    Literal::LocalZonedTimestamp { .. },
+                    DataType::LocalZonedTimestamp(_)
+                )
+        )
+    }
+
+    /// The SQL name of the type of the literal, like `INT` or `STRING`, for error messages.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Literal::Null => "NULL",
+            Literal::Boolean(_) => "BOOLEAN",
+            Literal::TinyInt(_) => "TINYINT",
+            Literal::SmallInt(_) => "SMALLINT",
+            Literal::Int(_) => "INT",
+            Literal::BigInt(_) => "BIGINT",
+            Literal::Float(_) => "FLOAT",
+            Literal::Double(_) => "DOUBLE",
+            Literal::Decimal { .. } => "DECIMAL",
+            Literal::String(_) => "STRING",
+            Literal::Binary(_) => "BYTES",
+            Literal::Date(_) => "DATE",
+            Literal::Time(_) => "TIME",
+            Literal::Timestamp { .. } => "TIMESTAMP",
+            Literal::LocalZonedTimestamp { .. } => "TIMESTAMP WITH LOCAL TIME ZONE",
+        }
+    }
+
+    /// Compare two literals of the same type.
+    ///
+    /// Returns `None` if either is null, if the types differ, or if the values are not comparable
+    /// like `NaN`. Decimals of different scales are compared by their values.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/predicate/CompareUtils.java>
+    pub fn compare(&self, other: &Literal) -> Option<Ordering> {
+        match (self, other) {
+            (Literal::Boolean(a), Literal::Boolean(b)) => a.partial_cmp(b),
+            (Literal::TinyInt(a), Literal::TinyInt(b)) => a.partial_cmp(b),
+            (Literal::SmallInt(a), Literal::SmallInt(b)) => a.partial_cmp(b),
+            (Literal::Int(a), Literal::Int(b)) => a.partial_cmp(b),
+            (Literal::BigInt(a), Literal::BigInt(b)) => a.partial_cmp(b),
+            (Literal::Float(a), Literal::Float(b)) => a.partial_cmp(b),
+            (Literal::Double(a), Literal::Double(b)) => a.partial_cmp(b),
+            (
+                Literal::Decimal {
+                    unscaled: a,
+                    scale: a_scale,
+                    ..
+                },
+                Literal::Decimal {
+                    unscaled: b,
+                    scale: b_scale,
+                    ..
+                },
+            ) => {
+                let scale = (*a_scale).max(*b_scale);
+                let a = a.checked_mul(10i128.checked_pow(scale - a_scale)?)?;
+                let b = b.checked_mul(10i128.checked_pow(scale - b_scale)?)?;
+                a.partial_cmp(&b)
+            }
+            (Literal::String(a), Literal::String(b)) => a.partial_cmp(b),
+            (Literal::Binary(a), Literal::Binary(b)) => a.partial_cmp(b),
+            (Literal::Date(a), Literal::Date(b)) | (Literal::Time(a), Literal::Time(b)) => {
+                a.partial_cmp(b)
+            }
+            (
+                Literal::Timestamp {
+                    millis: a_millis,
+                    nanos: a_nanos,
+                },
+                Literal::Timestamp {
+                    millis: b_millis,
+                    nanos: b_nanos,
+                },
+            )
+            | (
+                Literal::LocalZonedTimestamp {
+                    millis: a_millis,
+                    nanos: a_nanos,
+                },
+                Literal::LocalZonedTimestamp {
+                    millis: b_millis,
+                    nanos: b_nanos,
+                },
+            ) => (a_millis, a_nanos).partial_cmp(&(b_millis, b_nanos)),
+            _ => None,
+        }
+    }
+
+    /// Parse a literal of the given type from its string form, like a partition value of a
+    /// partition spec.
+    ///
+    /// [`DEFAULT_PARTITION_NAME`] is parsed as null. Dates are parsed as `yyyy-MM-dd`, times as
+    /// `HH:mm:ss[.fraction]`, timestamps as `yyyy-MM-dd HH:mm:ss[.fraction]` or with a `T`
+    /// separator, and decimals are rounded half up to the scale of their type.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/utils/TypeUtils.java#L59>
+    pub fn parse(s: &str, data_type: &DataType) -> crate::Result<Literal> {
+        if s == DEFAULT_PARTITION_NAME {
+            return Ok(Literal::Null);
+        }
+        let invalid = || {
+            LiteralInvalidSnafu {
+                message: format!("Cannot parse '{}' as {:?}", s, data_type),
+            }
+            .build()
+        };
+        fn number<T: FromStr>(s: &str) -> Option<T> {
+            s.trim().parse().ok()
+        }
+
+        let literal = match data_type {
+            DataType::Boolean(_) => match s.trim().to_ascii_lowercase().as_str() {
+                "true" => Literal::Boolean(true),
+                "false" => Literal::Boolean(false),
+                _ => return Err(invalid()),
+            },
+            DataType::TinyInt(_) => Literal::TinyInt(number(s).ok_or_else(invalid)?),
+            DataType::SmallInt(_) => Literal::SmallInt(number(s).ok_or_else(invalid)?),
+            DataType::Int(_) => Literal::Int(number(s).ok_or_else(invalid)?),
+            DataType::BigInt(_) => Literal::BigInt(number(s).ok_or_else(invalid)?),
+            DataType::Float(_) => Literal::Float(number(s).ok_or_else(invalid)?),
+            DataType::Double(_) => Literal::Double(number(s).ok_or_else(invalid)?),
+            DataType::Decimal(decimal) => {
+                let unscaled = parse_decimal(s.trim(), decimal.precision(), decimal.scale())
+                    .ok_or_else(invalid)?;
+                Literal::Decimal {
+                    unscaled,
+                    precision: decimal.precision(),
+                    scale: decimal.scale(),
+                }
+            }
+            DataType::Char(_) | DataType::VarChar(_) => Literal::String(s.to_string()),
+            DataType::Binary(_) | DataType::VarBinary(_) => Literal::Binary(s.as_bytes().to_vec()),
+            DataType::Date(_) => {
+                let date =
+                    NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").map_err(|_| invalid())?;
+                let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+                Literal::Date((date - epoch).num_days() as i32)
+            }
+            DataType::Time(_) => {
+                let time =
+                    NaiveTime::parse_from_str(s.trim(), "%H:%M:%S%.f").map_err(|_| invalid())?;
+                Literal::Time(
+                    (time.num_seconds_from_midnight() * 1000 + time.nanosecond() / 1_000_000)
+                        as i32,
+                )
+            }
+            DataType::Timestamp(_) | DataType::LocalZonedTimestamp(_) => {
+                let dt = NaiveDateTime::parse_from_str(s.trim(), "%Y-%m-%d %H:%M:%S%.f")
+                    .or_else(|_| NaiveDateTime::parse_from_str(s.trim(), "%Y-%m-%dT%H:%M:%S%.f"))
+                    .map_err(|_| invalid())?;
+                let millis = dt.and_utc().timestamp_millis();
+                let nanos = (dt.nanosecond() % 1_000_000) as i32;
+                match data_type {
+                    DataType::Timestamp(_) => Literal::Timestamp { millis, nanos },
+                    _ => Literal::LocalZonedTimestamp { millis, nanos },
+                }
+            }
+            DataType::Array(_) | DataType::Map(_) | DataType::Multiset(_) | DataType::Row(_) => {
+                return Err(invalid())
+            }
+        };
+        Ok(literal)
+    }
+
+    /// Convert a date literal to a calendar date.
+    pub(crate) fn date(days: i32) -> Option<NaiveDate> {
+        DateTime::from_timestamp(days as i64 * 86_400, 0).map(|dt| dt.date_naive())
+    }
+
+    /// Convert a time literal to a time of the day.
+    pub(crate) fn time(millis: i32) -> Option<NaiveTime> {
+        NaiveTime::from_num_seconds_from_midnight_opt(
+            (millis / 1000) as u32,
+            (millis % 1000) as u32 * 1_000_000,
+        )
+    }
+
+    /// Convert a timestamp literal to a date time.
+    pub(crate) fn timestamp(millis: i64, nanos: i32) -> Option<NaiveDateTime> {
+        DateTime::from_timestamp_millis(millis)
+            .and_then(|dt| dt.with_nanosecond(dt.nanosecond() + nanos as u32))
+            .map(|dt| dt.naive_utc())
+    }
+}
+
+/// Parse a plain decimal string into its unscaled value at `scale`, rounding half up, `None` if
+/// it is malformed or has more than `precision` digits.
+fn parse_decimal(s: &str, precision: u32, scale: u32) -> Option<i128> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    if int_part.is_empty() && frac_part.is_empty()
+        || !int_part
+            .chars()
+            .chain(frac_part.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let scale = scale as usize;
+    let kept = &frac_part[..frac_part.len().min(scale)];
+    let unscaled_digits = format!("{}{:0<width$}", int_part, kept, width = scale);
+    // `.5` at scale 0 has no digit left before rounding
+    let mut unscaled = if unscaled_digits.is_empty() {
+        0
+    } else {
+        unscaled_digits.parse::<i128>().ok()?
+    };
+    if frac_part.as_bytes().get(scale).is_some_and(|d| *d >= b'5') {
+        unscaled = unscaled.checked_add(1)?;
+    }
+    if unscaled.to_string().len() > precision as usize {
+        return None;
+    }
+    Some(if negative { -unscaled } else { unscaled })
+}
+
+/// Format an unscaled decimal value as a plain string, like Java's `BigDecimal#toPlainString`.
+pub(crate) fn format_decimal(unscaled: i128, scale: u32) -> String {
+    let digits = unscaled.unsigned_abs().to_string();
+    let sign = if unscaled < 0 { "-" } else { "" };
+    let scale = scale as usize;
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, int_part, frac_part)
+}
+
+impl Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Literal::Null => write!(f, "NULL"),
+            Literal::Boolean(v) => write!(f, "{}", v),
+            Literal::TinyInt(v) => write!(f, "{}", v),
+            Literal::SmallInt(v) => write!(f, "{}", v),
+            Literal::Int(v) => write!(f, "{}", v),
+            Literal::BigInt(v) => write!(f, "{}", v),
+            Literal::Float(v) => write!(f, "{:?}", v),
+            Literal::Double(v) => write!(f, "{:?}", v),
+            Literal::Decimal {
+                unscaled, scale, ..
+            } => write!(f, "{}", format_decimal(*unscaled, *scale)),
+            Literal::String(v) => write!(f, "{}", v),
+            Literal::Binary(v) => v.iter().try_for_each(|b| write!(f, "{:02x}", b)),
+            Literal::Date(days) => match Self::date(*days) {
+                Some(date) => write!(f, "{}", date),
+                None => write!(f, "{}", days),
+            },
+            Literal::Time(millis) => match Self::time(*millis) {
+                Some(time) => write!(f, "{}", time),
+                None => write!(f, "{}", millis),
+            },
+            Literal::Timestamp { millis, nanos }
+            | Literal::LocalZonedTimestamp { millis, nanos } => {
+                match Self::timestamp(*millis, *nanos) {
+                    Some(ts) => write!(f, "{}", ts),
+                    None => write!(f, "{}", millis),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Literal::Null.to_string(), "NULL");
+        assert_eq!(Literal::Int(-3).to_string(), "-3");
+        assert_eq!(Literal::Double(1.0).to_string(), "1.0");
+        assert_eq!(Literal::Date(19723).to_string(), "2024-01-01");
+        assert_eq!(Literal::Time(3_723_004).to_string(), "01:02:03.004");
+        assert_eq!(
+            Literal::Timestamp {
+                millis: 1_704_067_200_001,
+                nanos: 5
+            }
+            .to_string(),
+            "2024-01-01 00:00:00.001000005"
+        );
+        assert_eq!(Literal::Binary(vec![0x0a, 0xff]).to_string(), "0aff");
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(
+            Literal::Int(1).compare(&Literal::Int(2)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Literal::String("b".to_string()).compare(&Literal::String("a".to_string())),
+            Some(Ordering::Greater)
+        );
+        let decimal = |unscaled, scale| Literal::Decimal {
+            unscaled,
+            precision: 10,
+            scale,
+        };
+        assert_eq!(
+            decimal(150, 2).compare(&decimal(15, 1)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(decimal(-1, 0).compare(&decimal(1, 3)), Some(Ordering::Less));
+        assert_eq!(Literal::Int(1).compare(&Literal::BigInt(1)), None);
+        assert_eq!(Literal::Null.compare(&Literal::Null), None);
+        assert_eq!(
+            Literal::Double(f64::NAN).compare(&Literal::Double(1.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        use crate::spec::{
+            BooleanType, DateType, DecimalType, IntType, TimestampType, VarCharType,
+        };
+
+        let date = DataType::Date(DateType::new());
+        assert_eq!(
+            Literal::parse("2024-01-01", &date).unwrap(),
+            Literal::Date(19723)
+        );
+        let int = DataType::Int(IntType::new());
+        assert_eq!(Literal::parse("42", &int).unwrap(), Literal::Int(42));
+        assert_eq!(
+            Literal::parse(DEFAULT_PARTITION_NAME, &int).unwrap(),
+            Literal::Null
+        );
+        assert_eq!(
+            Literal::parse("True", &DataType::Boolean(BooleanType::new())).unwrap(),
+            Literal::Boolean(true)
+        );
+        assert_eq!(
+            Literal::parse("a b", &DataType::VarChar(VarCharType::default())).unwrap(),
+            Literal::String("a b".to_string())
+        );
+
+        let decimal = DataType::Decimal(DecimalType::new(5, 2).unwrap());
+        let parse_decimal = |s| match Literal::parse(s, &decimal) {
+            Ok(Literal::Decimal { unscaled, .. }) => Some(unscaled),
+            _ => None,
+        };
+        assert_eq!(parse_decimal("123.45"), Some(12345));
+        assert_eq!(parse_decimal("-1.005"), Some(-101));
+        assert_eq!(parse_decimal("7"), Some(700));
+        assert_eq!(parse_decimal("1234.5"), None);
+        assert_eq!(parse_decimal("1.2.3"), None);
+        // 39 digits overflow the unscaled value instead of parsing as 0
+        assert_eq!(parse_decimal(&"9".repeat(39)), None);
+        let wide = DataType::Decimal(DecimalType::new(38, 0).unwrap());
+        assert!(Literal::parse(&"1".repeat(40), &wide).is_err());
+        assert!(Literal::parse(&"9".repeat(38), &wide).is_ok());
+
+        let timestamp = DataType::Timestamp(TimestampType::new(9).unwrap());
+        let expected = Literal::Timestamp {
+            millis: 1_704_067_200_001,
+            nanos: 5,
+        };
+        assert_eq!(
+            Literal::parse("2024-01-01 00:00:00.001000005", &timestamp).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Literal::parse("2024-01-01T00:00:00.001000005", &timestamp).unwrap(),
+            expected
+        );
+
+        let err = Literal::parse("forty-two", &int).unwrap_err();
+        assert!(matches!(err, crate::Error::LiteralInvalid { .. }));
+        assert!(Literal::parse("2024-13-01", &date).is_err());
+    }
+
+    #[test]
+    fn test_format_decimal() {
+        assert_eq!(format_decimal(12345, 2), "123.45");
+        assert_eq!(format_decimal(-5, 3), "-0.005");
+        assert_eq!(format_decimal(42, 0), "42");
+    }
+}
@@ -15,10 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::spec::DataFileMeta;
+use crate::spec::{partition_path, partition_spec, BinaryRow, DataFileMeta, RowType};
+use crate::table::PathFactory;
+use crate::Error;
 use serde::Deserialize;
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::serde_derive::Serialize;
+use std::collections::HashMap;
 
 /// The same {@link Identifier} indicates that the {@link ManifestEntry} refers to the same data file.
 ///
@@ -33,16 +36,35 @@ pub struct Identifier {
 
 /// Kind of a file.
 /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/manifest/FileKind.java>
-#[derive(PartialEq, Eq, Debug, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 pub enum FileKind {
     Add = 0,
     Delete = 1,
 }
 
+impl FileKind {
+    /// Get the value of the kind stored in manifest files.
+    pub fn to_byte_value(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_byte_value(value: u8) -> crate::Result<Self> {
+        match value {
+            0 => Ok(FileKind::Add),
+            1 => Ok(FileKind::Delete),
+            _ => Err(Error::DataTypeInvalid {
+                message: format!("Unsupported byte value '{}' for file kind", value),
+            }),
+        }
+    }
+}
+
 /// Entry of a manifest file, representing an addition / deletion of a data file.
+///
 /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/manifest/ManifestEntry.java>
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ManifestEntry {
     #[serde(rename = "_KIND")]
     kind: FileKind,
@@ -63,42 +85,46 @@ pub struct ManifestEntry {
     version: i32,
 }
 
-#[allow(dead_code)]
 impl ManifestEntry {
-    fn kind(&self) -> &FileKind {
+    /// Serialization version of manifest entries.
+    pub const VERSION: i32 = 2;
+
+    pub fn kind(&self) -> &FileKind {
         &self.kind
     }
 
-    fn partition(&self) -> &Vec<u8> {
+    /// Get the serialized partition `BinaryRow` of the data file.
+    pub fn partition(&self) -> &Vec<u8> {
         &self.partition
     }
 
-    fn bucket(&self) -> i32 {
+    pub fn bucket(&self) -> i32 {
         self.bucket
     }
 
-    fn level(&self) -> i32 {
-        self.file.level
+    pub fn level(&self) -> i32 {
+        self.file.level()
     }
 
-    fn file_name(&self) -> &str {
-        &self.file.file_name
+    pub fn file_name(&self) -> &str {
+        self.file.file_name()
     }
 
-    fn min_key(&self) -> &Vec<u8> {
-        &self.file.min_key
+    pub fn min_key(&self) -> &[u8] {
+        self.file.min_key()
     }
 
-    fn max_key(&self) -> &Vec<u8> {
-        &self.file.max_key
+    pub fn max_key(&self) -> &[u8] {
+        self.file.max_key()
     }
 
-    fn identifier(&self) -> Identifier {
+    /// Get the identifier of the data file, unique within a table.
+    pub fn identifier(&self) -> Identifier {
         Identifier {
             partition: self.partition.clone(),
             bucket: self.bucket,
-            level: self.file.level,
-            file_name: self.file.file_name.clone(),
+            level: self.file.level(),
+            file_name: self.file.file_name().to_string(),
         }
     }
 
@@ -110,6 +136,46 @@ impl ManifestEntry {
         &self.file
     }
 
+    /// Get the full path of the data file, like `{table}/dt=2024-01-01/bucket-0/{file_name}`.
+    ///
+    /// `partition_type` is the type of the partition keys of the table, and has no field if the
+    /// table is not partitioned.
+    pub fn data_file_path(
+        &self,
+        path_factory: &PathFactory,
+        partition_type: &RowType,
+    ) -> crate::Result<String> {
+        let partition_path = if partition_type.fields().is_empty() {
+            String::new()
+        } else {
+            partition_path(&partition_spec(
+                &BinaryRow::from_bytes(&self.partition)?,
+                partition_type,
+            )?)
+        };
+        Ok(path_factory.data_file_path(&partition_path, self.bucket, self.file.file_name()))
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    /// Merge entries by their [`Identifier`]: a deletion cancels out a previous addition of the same file.
+    ///
+    /// Deletions of files not added by `entries` are kept, the order of first appearance is preserved.
+    /// Adding a file which is already added fails with [`Error::DataInvalid`].
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/manifest/FileEntry.java#L108>
+    pub fn merge_entries(
+        entries: impl IntoIterator<Item = ManifestEntry>,
+    ) -> crate::Result<Vec<ManifestEntry>> {
+        let mut merged = MergedEntries::default();
+        for entry in entries {
+            merged.add(entry)?;
+        }
+        Ok(merged.into_entries())
+    }
+
     pub fn new(
         kind: FileKind,
         partition: Vec<u8>,
@@ -128,3 +194,150 @@ impl ManifestEntry {
         }
     }
 }
+
+/// Entries merged incrementally by their [`Identifier`], see [`ManifestEntry::merge_entries`].
+///
+/// Only the merged entries are held: an addition cancelled out by a later deletion is dropped as
+/// soon as the deletion is added, so entries can be merged while manifest files are read.
+#[derive(Debug, Default)]
+pub struct MergedEntries {
+    /// identifier -> (position of first appearance, merged entry)
+    entries: HashMap<Identifier, (usize, ManifestEntry)>,
+    next_position: usize,
+}
+
+impl MergedEntries {
+    /// Merge the entry into the entries added before it.
+    ///
+    /// Adding a file which is already added fails with [`Error::DataInvalid`].
+    pub fn add(&mut self, entry: ManifestEntry) -> crate::Result<()> {
+        let identifier = entry.identifier();
+        match (entry.kind, self.entries.contains_key(&identifier)) {
+            (FileKind::Add, true) => {
+                return Err(Error::DataInvalid {
+                    message: format!(
+                        "file '{}' of bucket {}",
+                        identifier.file_name, identifier.bucket
+                    ),
+                    source: snafu::FromString::without_source(
+                        "Trying to add a file which is already added".to_string(),
+                    ),
+                });
+            }
+            (FileKind::Delete, true) => {
+                self.entries.remove(&identifier);
+            }
+            (_, false) => {
+                self.entries.insert(identifier, (self.next_position, entry));
+                self.next_position += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the number of merged entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the merged entries in the order of first appearance.
+    pub fn into_entries(self) -> Vec<ManifestEntry> {
+        let mut entries = self.entries.into_values().collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|(position, _)| *position);
+        entries.into_iter().map(|(_, entry)| entry).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{DataField, DataType, IntType};
+    use crate::test_util::{data_file, entry, int_partition};
+
+    #[test]
+    fn test_file_kind_byte_value() {
+        // values of `FileKind` in Paimon, stored as the `_KIND` int of manifest entries
+        for (kind, value) in [(FileKind::Add, 0), (FileKind::Delete, 1)] {
+            assert_eq!(kind.to_byte_value(), value);
+            assert_eq!(FileKind::from_byte_value(value).unwrap(), kind);
+            assert_eq!(serde_json::to_string(&kind).unwrap(), value.to_string());
+            assert_eq!(
+                serde_json::from_str::<FileKind>(&value.to_string()).unwrap(),
+                kind
+            );
+        }
+        assert!(matches!(
+            FileKind::from_byte_value(2),
+            Err(Error::DataTypeInvalid { .. })
+        ));
+        assert!(serde_json::from_str::<FileKind>("2").is_err());
+    }
+
+    #[test]
+    fn test_data_file_path() {
+        let path_factory = PathFactory::new("memory:/tmp/t");
+        let file = data_file("data-0.parquet", 1, 10, 0);
+
+        let partition_type = RowType::new(vec![DataField::new(
+            0,
+            "p".to_string(),
+            DataType::Int(IntType::new()),
+        )]);
+        let entry = ManifestEntry::new(FileKind::Add, int_partition(7), 2, 4, file.clone(), 2);
+        assert_eq!(
+            entry
+                .data_file_path(&path_factory, &partition_type)
+                .unwrap(),
+            "memory:/tmp/t/p=7/bucket-2/data-0.parquet"
+        );
+
+        let entry = ManifestEntry::new(FileKind::Add, vec![0, 0, 0, 0], 0, 1, file, 2);
+        assert_eq!(
+            entry
+                .data_file_path(&path_factory, &RowType::new(vec![]))
+                .unwrap(),
+            "memory:/tmp/t/bucket-0/data-0.parquet"
+        );
+    }
+
+    #[test]
+    fn test_merge_entries() {
+        let add = |name: &str| entry(FileKind::Add, int_partition(1), data_file(name, 1, 10, 0));
+        let delete = |name: &str| {
+            entry(
+                FileKind::Delete,
+                int_partition(1),
+                data_file(name, 1, 10, 0),
+            )
+        };
+
+        let merged = ManifestEntry::merge_entries([
+            add("data-0.parquet"),
+            delete("data-old.parquet"),
+            add("data-1.parquet"),
+            delete("data-0.parquet"),
+            add("data-0.parquet"),
+        ])
+        .unwrap();
+        assert_eq!(
+            merged
+                .iter()
+                .map(|entry| (*entry.kind(), entry.file_name()))
+                .collect::<Vec<_>>(),
+            vec![
+                (FileKind::Delete, "data-old.parquet"),
+                (FileKind::Add, "data-1.parquet"),
+                (FileKind::Add, "data-0.parquet"),
+            ]
+        );
+
+        let err = ManifestEntry::merge_entries([add("data-0.parquet"), add("data-0.parquet")])
+            .unwrap_err();
+        assert!(matches!(err, Error::DataInvalid { .. }));
+        assert!(err.to_string().contains("'data-0.parquet'"), "{err}");
+    }
+}
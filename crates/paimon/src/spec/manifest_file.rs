@@ -0,0 +1,251 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::OnceLock;
+
+use apache_avro::Schema;
+
+use crate::io::FileIO;
+use crate::spec::objects_file::{
+    check_versions, read_objects_file, to_avro_bytes, ObjectsFileKind,
+};
+use crate::spec::stats::BinaryTableStats;
+use crate::spec::{FileKind, ManifestEntry, ManifestFileMeta};
+
+/// Avro schema of manifest files, as written by Paimon.
+const MANIFEST_ENTRY_SCHEMA: &str = r#"["null",{"type":"record","name":"record","namespace":"org.apache.paimon.avro.generated","fields":[{"name":"_VERSION","type":"int"},{"name":"_KIND","type":"int"},{"name":"_PARTITION","type":"bytes"},{"name":"_BUCKET","type":"int"},{"name":"_TOTAL_BUCKETS","type":"int"},{"name":"_FILE","type":["null",{"type":"record","name":"record__FILE","fields":[{"name":"_FILE_NAME","type":"string"},{"name":"_FILE_SIZE","type":"long"},{"name":"_ROW_COUNT","type":"long"},{"name":"_MIN_KEY","type":"bytes"},{"name":"_MAX_KEY","type":"bytes"},{"name":"_KEY_STATS","type":["null",{"type":"record","name":"record__FILE__KEY_STATS","fields":[{"name":"_MIN_VALUES","type":"bytes"},{"name":"_MAX_VALUES","type":"bytes"},{"name":"_NULL_COUNTS","type":["null",{"type":"array","items":["null","long"]}],"default":null}]}],"default":null},{"name":"_VALUE_STATS","type":["null",{"type":"record","name":"record__FILE__VALUE_STATS","fields":[{"name":"_MIN_VALUES","type":"bytes"},{"name":"_MAX_VALUES","type":"bytes"},{"name":"_NULL_COUNTS","type":["null",{"type":"array","items":["null","long"]}],"default":null}]}],"default":null},{"name":"_MIN_SEQUENCE_NUMBER","type":"long"},{"name":"_MAX_SEQUENCE_NUMBER","type":"long"},{"name":"_SCHEMA_ID","type":"long"},{"name":"_LEVEL","type":"int"},{"name":"_EXTRA_FILES","type":{"type":"array","items":"string"}},{"name":"_CREATION_TIME","type":["null",{"type":"long","logicalType":"timestamp-millis"}],"default":null},{"name":"_DELETE_ROW_COUNT","type":["null","long"],"default":null},{"name":"_EMBEDDED_FILE_INDEX","type":["null","bytes"],"default":null}]}],"default":null}]}]"#;
+
+fn manifest_entry_schema() -> &'static Schema {
+    static SCHEMA: OnceLock<Schema> = OnceLock::new();
+    SCHEMA.get_or_init(|| {
+        Schema::parse_str(MANIFEST_ENTRY_SCHEMA).expect("manifest entry schema must be valid")
+    })
+}
+
+/// This file includes several [`ManifestEntry`]s, representing the additional changes since last snapshot.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/manifest/ManifestFile.java>
+pub struct ManifestFile {
+    file_io: FileIO,
+}
+
+impl ManifestFile {
+    pub fn new(file_io: FileIO) -> Self {
+        Self { file_io }
+    }
+
+    /// Read all entries of the manifest file at `path`.
+    ///
+    /// Entries written with an unsupported version, or whose data file has more deleted rows
+    /// than rows, are rejected.
+    pub async fn read(&self, path: &str) -> crate::Result<Vec<ManifestEntry>> {
+        let bytes = self.file_io.new_input(path)?.read().await?;
+        let entries: Vec<ManifestEntry> =
+            read_objects_file(path, &bytes, ObjectsFileKind::Manifest)?;
+        check_versions(
+            path,
+            entries.iter().map(ManifestEntry::version),
+            ManifestEntry::VERSION,
+        )?;
+        for entry in &entries {
+            entry.file().validate_row_counts()?;
+        }
+        Ok(entries)
+    }
+
+    /// Write the entries into a new manifest file at `path`, returning its meta.
+    ///
+    /// Partition stats are not computed yet, the returned meta carries empty stats.
+    pub async fn write(
+        &self,
+        path: &str,
+        entries: &[ManifestEntry],
+        schema_id: i64,
+    ) -> crate::Result<ManifestFileMeta> {
+        let bytes = to_avro_bytes(manifest_entry_schema(), entries)?;
+        self.write_bytes(path, bytes, entries, schema_id).await
+    }
+
+    /// Write the entries into new manifest files of about `target_file_size` bytes each,
+    /// returning their metas in order. `new_path` gives the path of each new file.
+    ///
+    /// The entries are encoded at once, then split evenly into as many files as their encoded
+    /// size requires, keeping their order, so each file is about the target size.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/io/RollingFileWriter.java>
+    pub async fn write_rolling(
+        &self,
+        entries: &[ManifestEntry],
+        schema_id: i64,
+        target_file_size: u64,
+        mut new_path: impl FnMut() -> String,
+    ) -> crate::Result<Vec<ManifestFileMeta>> {
+        let bytes = to_avro_bytes(manifest_entry_schema(), entries)?;
+        let num_files = (bytes.len() as u64)
+            .div_ceil(target_file_size.max(1))
+            .clamp(1, entries.len().max(1) as u64) as usize;
+        if num_files == 1 {
+            let meta = self
+                .write_bytes(&new_path(), bytes, entries, schema_id)
+                .await?;
+            return Ok(vec![meta]);
+        }
+
+        let mut metas = Vec::with_capacity(num_files);
+        for chunk in entries.chunks(entries.len().div_ceil(num_files)) {
+            metas.push(self.write(&new_path(), chunk, schema_id).await?);
+        }
+        Ok(metas)
+    }
+
+    async fn write_bytes(
+        &self,
+        path: &str,
+        bytes: Vec<u8>,
+        entries: &[ManifestEntry],
+        schema_id: i64,
+    ) -> crate::Result<ManifestFileMeta> {
+        let file_size = bytes.len() as i64;
+        self.file_io.new_output(path)?.write(bytes.into()).await?;
+
+        let num_added_files = entries
+            .iter()
+            .filter(|entry| *entry.kind() == FileKind::Add)
+            .count() as i64;
+        let file_name = path.rsplit('/').next().unwrap_or(path).to_string();
+        Ok(ManifestFileMeta::new(
+            file_name,
+            file_size,
+            num_added_files,
+            entries.len() as i64 - num_added_files,
+            BinaryTableStats::new(vec![], vec![], vec![]),
+            schema_id,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::spec::objects_file::rewrite_versions;
+    use crate::spec::EMPTY_BINARY_ROW;
+    use crate::test_util::{data_file, entry};
+    use crate::Error;
+
+    #[tokio::test]
+    async fn test_read_manifest_file_with_unsupported_version() {
+        let fixture = std::fs::read(
+            "tests/fixtures/manifest/manifest-8ded1f09-fcda-489e-9167-582ac0f9f846-0",
+        )
+        .unwrap();
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        let manifest_file = ManifestFile::new(file_io.clone());
+
+        let path = "memory:/tmp/test_read_manifest_file_with_unsupported_version/supported";
+        let output = file_io.new_output(path).unwrap();
+        output.write(fixture.clone().into()).await.unwrap();
+        assert_eq!(manifest_file.read(path).await.unwrap().len(), 2);
+
+        let path = "memory:/tmp/test_read_manifest_file_with_unsupported_version/unsupported";
+        let output = file_io.new_output(path).unwrap();
+        output
+            .write(rewrite_versions(&fixture, 1).into())
+            .await
+            .unwrap();
+        let result = manifest_file.read(path).await;
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedManifestVersion { message }) if message.contains("version 1")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_write_rolling() -> crate::Result<()> {
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let manifest_file = ManifestFile::new(file_io);
+        let entries = (0..200)
+            .map(|i| {
+                entry(
+                    FileKind::Add,
+                    EMPTY_BINARY_ROW.to_bytes(),
+                    data_file(&format!("data-{}.parquet", uuid::Uuid::new_v4()), i, 100, 0),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut count = 0;
+        let mut new_path = || {
+            count += 1;
+            format!("memory:/tmp/test_write_rolling/manifest-{}", count)
+        };
+
+        let metas = manifest_file
+            .write_rolling(&entries, 0, 8 * 1024 * 1024, &mut new_path)
+            .await?;
+        assert_eq!(metas.len(), 1);
+        assert_eq!(metas[0].file_name(), "manifest-1");
+
+        let metas = manifest_file
+            .write_rolling(&entries, 0, 4 * 1024, &mut new_path)
+            .await?;
+        assert!(metas.len() > 1, "{} manifest files", metas.len());
+        assert_eq!(
+            metas.iter().map(|meta| meta.num_added_files()).sum::<i64>(),
+            200
+        );
+        let mut read = Vec::new();
+        for meta in &metas {
+            assert!(meta.file_size() <= 2 * 4 * 1024, "{}", meta.file_size());
+            read.extend(
+                manifest_file
+                    .read(&format!(
+                        "memory:/tmp/test_write_rolling/{}",
+                        meta.file_name()
+                    ))
+                    .await?,
+            );
+        }
+        assert_eq!(read, entries);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_manifest_list_as_manifest_file() {
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        let path = "memory:/tmp/test_read_manifest_list_as_manifest_file";
+        let fixture = std::fs::read(
+            "tests/fixtures/manifest/manifest-list-5c7399a0-46ae-4a5e-9c13-3ab07212cdb6-0",
+        )
+        .unwrap();
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(fixture.into())
+            .await
+            .unwrap();
+
+        let err = ManifestFile::new(file_io).read(path).await.unwrap_err();
+        assert!(matches!(
+            &err,
+            Error::ObjectsFileMismatch { expected, actual, .. }
+                if expected == "a manifest file" && actual == "a manifest list"
+        ));
+        assert!(err
+            .to_string()
+            .contains("is a manifest list, not a manifest file"));
+    }
+}
@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::spec::stats::BinaryTableStats;
+use crate::spec::RowType;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -94,6 +95,9 @@ impl ManifestFileMeta {
         self.version
     }
 
+    /// Serialization version of manifest file metas.
+    pub const VERSION: i32 = 2;
+
     #[inline]
     pub fn new(
         file_name: String,
@@ -104,7 +108,7 @@ impl ManifestFileMeta {
         schema_id: i64,
     ) -> ManifestFileMeta {
         Self {
-            version: 2,
+            version: Self::VERSION,
             file_name,
             file_size,
             num_added_files,
@@ -113,6 +117,20 @@ impl ManifestFileMeta {
             schema_id,
         }
     }
+
+    /// Render this meta like its `Display`, with the partition stats decoded with the partition
+    /// type, see [`BinaryTableStats::debug_with_schema`].
+    pub fn debug_with_partition_type(&self, partition_type: &RowType) -> String {
+        format!(
+            "{{{}, {}, {}, {}, {}, {}}}",
+            self.file_name,
+            self.file_size,
+            self.num_added_files,
+            self.num_deleted_files,
+            self.partition_stats.debug_with_schema(partition_type),
+            self.schema_id
+        )
+    }
 }
 
 impl Display for ManifestFileMeta {
@@ -0,0 +1,144 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::OnceLock;
+
+use apache_avro::Schema;
+
+use crate::io::FileIO;
+use crate::spec::objects_file::{
+    check_versions, read_objects_file, to_avro_bytes, ObjectsFileKind,
+};
+use crate::spec::ManifestFileMeta;
+
+/// Avro schema of manifest lists, as written by Paimon.
+const MANIFEST_FILE_META_SCHEMA: &str = r#"["null",{"type":"record","name":"record","namespace":"org.apache.paimon.avro.generated","fields":[{"name":"_VERSION","type":"int"},{"name":"_FILE_NAME","type":"string"},{"name":"_FILE_SIZE","type":"long"},{"name":"_NUM_ADDED_FILES","type":"long"},{"name":"_NUM_DELETED_FILES","type":"long"},{"name":"_PARTITION_STATS","type":["null",{"type":"record","name":"record__PARTITION_STATS","fields":[{"name":"_MIN_VALUES","type":"bytes"},{"name":"_MAX_VALUES","type":"bytes"},{"name":"_NULL_COUNTS","type":["null",{"type":"array","items":["null","long"]}],"default":null}]}],"default":null},{"name":"_SCHEMA_ID","type":"long"}]}]"#;
+
+fn manifest_file_meta_schema() -> &'static Schema {
+    static SCHEMA: OnceLock<Schema> = OnceLock::new();
+    SCHEMA.get_or_init(|| {
+        Schema::parse_str(MANIFEST_FILE_META_SCHEMA)
+            .expect("manifest file meta schema must be valid")
+    })
+}
+
+/// This file includes several [`ManifestFileMeta`]s, representing all data of the whole table at the corresponding snapshot.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/manifest/ManifestList.java>
+pub struct ManifestList {
+    file_io: FileIO,
+}
+
+impl ManifestList {
+    pub fn new(file_io: FileIO) -> Self {
+        Self { file_io }
+    }
+
+    /// Read all manifest file metas of the manifest list at `path`.
+    ///
+    /// Manifest file metas written with an unsupported version are rejected.
+    pub async fn read(&self, path: &str) -> crate::Result<Vec<ManifestFileMeta>> {
+        let bytes = self.file_io.new_input(path)?.read().await?;
+        let metas: Vec<ManifestFileMeta> =
+            read_objects_file(path, &bytes, ObjectsFileKind::ManifestList)?;
+        check_versions(
+            path,
+            metas.iter().map(ManifestFileMeta::version),
+            ManifestFileMeta::VERSION,
+        )?;
+        Ok(metas)
+    }
+
+    /// Write the manifest file metas into a new manifest list at `path`.
+    pub async fn write(&self, path: &str, metas: &[ManifestFileMeta]) -> crate::Result<()> {
+        let bytes = to_avro_bytes(manifest_file_meta_schema(), metas)?;
+        self.file_io.new_output(path)?.write(bytes.into()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::spec::objects_file::rewrite_versions;
+    use crate::Error;
+
+    async fn write_fixture(path: &str, version: Option<i32>) -> FileIO {
+        let fixture = std::fs::read(
+            "tests/fixtures/manifest/manifest-list-5c7399a0-46ae-4a5e-9c13-3ab07212cdb6-0",
+        )
+        .unwrap();
+        let bytes = match version {
+            Some(version) => rewrite_versions(&fixture, version),
+            None => fixture,
+        };
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(bytes.into())
+            .await
+            .unwrap();
+        file_io
+    }
+
+    #[tokio::test]
+    async fn test_read_manifest_list() {
+        let path = "memory:/tmp/test_read_manifest_list";
+        let file_io = write_fixture(path, None).await;
+        let metas = ManifestList::new(file_io).read(path).await.unwrap();
+        assert_eq!(metas.len(), 2);
+        assert!(metas.iter().all(|meta| meta.version() == 2));
+    }
+
+    #[tokio::test]
+    async fn test_read_manifest_list_with_unsupported_version() {
+        let path = "memory:/tmp/test_read_manifest_list_with_unsupported_version";
+        let file_io = write_fixture(path, Some(3)).await;
+        let result = ManifestList::new(file_io).read(path).await;
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedManifestVersion { message }) if message.contains("version 3")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_read_manifest_file_as_manifest_list() {
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        let path = "memory:/tmp/test_read_manifest_file_as_manifest_list";
+        let fixture = std::fs::read(
+            "tests/fixtures/manifest/manifest-8ded1f09-fcda-489e-9167-582ac0f9f846-0",
+        )
+        .unwrap();
+        file_io
+            .new_output(path)
+            .unwrap()
+            .write(fixture.into())
+            .await
+            .unwrap();
+
+        let err = ManifestList::new(file_io).read(path).await.unwrap_err();
+        assert!(matches!(err, Error::ObjectsFileMismatch { .. }));
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Paimon hitting objects file mismatch: '{}' is a manifest file, not a manifest list",
+                path
+            )
+        );
+    }
+}
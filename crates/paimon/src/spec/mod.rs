@@ -19,9 +19,24 @@
 //!
 //! All paimon specs types are defined here.
 
+mod binary_row;
+pub use binary_row::*;
+
+mod binary_row_writer;
+pub use binary_row_writer::*;
+
+mod consumer;
+pub use consumer::*;
+
+mod core_options;
+pub use core_options::*;
+
 mod data_file;
 pub use data_file::*;
 
+mod row_kind;
+pub use row_kind::*;
+
 mod schema;
 pub use schema::*;
 
@@ -31,12 +46,40 @@ pub use schema_change::*;
 mod snapshot;
 pub use snapshot::*;
 
+mod tag;
+pub use tag::*;
+
+mod index_file_meta;
+pub use index_file_meta::*;
+
+mod index_manifest_entry;
+pub use index_manifest_entry::*;
+
+mod index_manifest_file;
+pub use index_manifest_file::*;
+
 mod manifest_file_meta;
 pub use manifest_file_meta::*;
 
 mod manifest_entry;
+pub use manifest_entry::*;
+
+mod manifest_file;
+pub use manifest_file::*;
+
+mod manifest_list;
+pub use manifest_list::*;
+
+mod literal;
+pub use literal::*;
+
+mod partition;
+pub use partition::*;
+
 mod objects_file;
 mod stats;
+pub use stats::*;
+
 mod types;
 
 pub use types::*;
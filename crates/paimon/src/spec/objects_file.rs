@@ -15,28 +15,189 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::fmt::{Display, Formatter};
+
 use crate::Error;
 use apache_avro::types::Value;
-use apache_avro::{from_value, Reader};
+use apache_avro::{from_value, to_value, Codec, Reader, Schema, Writer};
 use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Deserialize the records of the objects file at `path`, checking first that it is a file of
+/// the expected kind.
+///
+/// Containers compressed with the deflate or snappy codec are supported, an unsupported codec is
+/// reported as [`Error::Unsupported`]. Reading a file of another kind, like a manifest file as a
+/// manifest list, is reported as [`Error::ObjectsFileMismatch`] instead of a deserialization
+/// error. An empty file holds no record to check and is accepted.
+pub fn read_objects_file<T: DeserializeOwned>(
+    path: &str,
+    bytes: &[u8],
+    expected: ObjectsFileKind,
+) -> crate::Result<Vec<T>> {
+    let records = read_records(bytes)?;
+    if let Some(record) = records.first() {
+        if !expected.matches(record) {
+            let actual = ObjectsFileKind::detect(record)
+                .map(|kind| kind.to_string())
+                .unwrap_or_else(|| "an unknown objects file".to_string());
+            return Err(Error::ObjectsFileMismatch {
+                path: path.to_string(),
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+    }
+    from_records(records)
+}
+
+/// Kind of the avro objects files of a table.
+///
+/// All records are named `record` by Paimon, so the kinds are told apart by a field only the
+/// records of one kind have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectsFileKind {
+    ManifestList,
+    Manifest,
+    IndexManifest,
+}
+
+impl ObjectsFileKind {
+    const ALL: [ObjectsFileKind; 3] = [
+        ObjectsFileKind::ManifestList,
+        ObjectsFileKind::Manifest,
+        ObjectsFileKind::IndexManifest,
+    ];
+
+    fn distinct_field(self) -> &'static str {
+        match self {
+            ObjectsFileKind::ManifestList => "_NUM_ADDED_FILES",
+            ObjectsFileKind::Manifest => "_TOTAL_BUCKETS",
+            ObjectsFileKind::IndexManifest => "_INDEX_TYPE",
+        }
+    }
+
+    fn matches(self, record: &Value) -> bool {
+        let fields = match record {
+            Value::Union(_, inner) => inner.as_ref(),
+            record => record,
+        };
+        match fields {
+            Value::Record(fields) => fields.iter().any(|(name, _)| name == self.distinct_field()),
+            _ => false,
+        }
+    }
 
-#[allow(dead_code)]
-pub fn from_avro_bytes<T: DeserializeOwned>(bytes: &[u8]) -> crate::Result<Vec<T>> {
-    let reader = Reader::new(bytes).map_err(Error::from)?;
-    let records = reader
+    /// Detect the kind of file a record is read from.
+    fn detect(record: &Value) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.matches(record))
+    }
+}
+
+impl Display for ObjectsFileKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectsFileKind::ManifestList => write!(f, "a manifest list"),
+            ObjectsFileKind::Manifest => write!(f, "a manifest file"),
+            ObjectsFileKind::IndexManifest => write!(f, "an index manifest"),
+        }
+    }
+}
+
+fn read_records(bytes: &[u8]) -> crate::Result<Vec<Value>> {
+    let reader = Reader::new(bytes).map_err(|source| match source {
+        apache_avro::Error::CodecNotSupported(codec) => Error::Unsupported {
+            message: format!(
+                "Avro codec '{}' is not supported, only null, deflate and snappy are",
+                codec
+            ),
+        },
+        source => Error::from(source),
+    })?;
+    reader
         .collect::<Result<Vec<Value>, _>>()
-        .map_err(Error::from)?;
+        .map_err(Error::from)
+}
+
+fn from_records<T: DeserializeOwned>(records: Vec<Value>) -> crate::Result<Vec<T>> {
     let values = Value::Array(records);
     from_value::<Vec<T>>(&values).map_err(Error::from)
 }
 
+/// Check that all records of the objects file at `path` have the expected serialization version.
+pub fn check_versions(
+    path: &str,
+    versions: impl IntoIterator<Item = i32>,
+    expected: i32,
+) -> crate::Result<()> {
+    match versions.into_iter().find(|version| *version != expected) {
+        None => Ok(()),
+        Some(version) => Err(Error::UnsupportedManifestVersion {
+            message: format!(
+                "'{}' is written with version {}, but only version {} is supported",
+                path, version, expected
+            ),
+        }),
+    }
+}
+
+/// Serialize records into an avro object container with the given writer schema.
+pub fn to_avro_bytes<T: Serialize>(schema: &Schema, records: &[T]) -> crate::Result<Vec<u8>> {
+    let mut writer = Writer::with_codec(schema, Vec::new(), Codec::Snappy);
+    for record in records {
+        let value = to_value(record)?.resolve(schema)?;
+        writer.append(value)?;
+    }
+    Ok(writer.into_inner()?)
+}
+
+/// Rewrite the `_VERSION` field of all records of an avro object container.
+#[cfg(test)]
+pub(crate) fn rewrite_versions(bytes: &[u8], version: i32) -> Vec<u8> {
+    let reader = Reader::new(bytes).unwrap();
+    let schema = reader.writer_schema().clone();
+    let records = reader.collect::<Result<Vec<Value>, _>>().unwrap();
+
+    let mut writer = Writer::new(&schema, Vec::new());
+    for mut record in records {
+        let fields = match &mut record {
+            Value::Union(_, inner) => match inner.as_mut() {
+                Value::Record(fields) => fields,
+                other => panic!("unexpected record {:?}", other),
+            },
+            Value::Record(fields) => fields,
+            other => panic!("unexpected record {:?}", other),
+        };
+        for (name, value) in fields.iter_mut() {
+            if name == "_VERSION" {
+                *value = Value::Int(version);
+            }
+        }
+        writer.append(record).unwrap();
+    }
+    writer.into_inner().unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::spec::manifest_entry::{FileKind, ManifestEntry};
-    use crate::spec::objects_file::from_avro_bytes;
+    use crate::spec::objects_file::{from_records, read_records};
     use crate::spec::stats::BinaryTableStats;
     use crate::spec::{DataFileMeta, ManifestFileMeta};
+    use crate::Error;
     use chrono::{DateTime, Utc};
+    use serde::de::DeserializeOwned;
+
+    /// Deserialize the records of an avro object container, whatever the kind of objects file.
+    fn from_avro_bytes<T: DeserializeOwned>(bytes: &[u8]) -> crate::Result<Vec<T>> {
+        from_records(read_records(bytes)?)
+    }
+
+    fn load_fixture(name: &str) -> Vec<u8> {
+        let workdir =
+            std::env::current_dir().unwrap_or_else(|err| panic!("current_dir must exist: {err}"));
+        std::fs::read(workdir.join("tests/fixtures/manifest").join(name)).unwrap()
+    }
 
     #[tokio::test]
     async fn test_read_manifest_list() {
@@ -92,34 +253,33 @@ mod tests {
                     single_value.clone(),
                     1,
                     10,
-                    DataFileMeta {
-                        file_name: "f1.parquet".to_string(),
-
-                        file_size: 10,
-                        row_count: 100,
-                        min_key: single_value.clone(),
-                        max_key: single_value.clone(),
-                        key_stats: BinaryTableStats::new(
+                    DataFileMeta::builder()
+                        .file_name("f1.parquet".to_string())
+                        .file_size(10)
+                        .row_count(100)
+                        .min_key(single_value.clone())
+                        .max_key(single_value.clone())
+                        .key_stats(BinaryTableStats::new(
                             value_bytes.clone(),
                             value_bytes.clone(),
                             vec![1, 2]
-                        ),
-                        value_stats: BinaryTableStats::new(
+                        ))
+                        .value_stats(BinaryTableStats::new(
                             value_bytes.clone(),
                             value_bytes.clone(),
                             vec![1, 2]
-                        ),
-                        min_sequence_number: 1,
-                        max_sequence_number: 100,
-                        schema_id: 0,
-                        level: 1,
-                        extra_files: vec![],
-                        creation_time: "2024-09-06T07:45:55.039+00:00"
-                            .parse::<DateTime<Utc>>()
-                            .unwrap(),
-                        delete_row_count: Some(0),
-                        embedded_index: None,
-                    },
+                        ))
+                        .min_sequence_number(1)
+                        .max_sequence_number(100)
+                        .schema_id(0)
+                        .level(1)
+                        .creation_time(
+                            "2024-09-06T07:45:55.039+00:00"
+                                .parse::<DateTime<Utc>>()
+                                .unwrap()
+                        )
+                        .delete_row_count(Some(0))
+                        .build(),
                     2
                 ),
                 ManifestEntry::new(
@@ -127,36 +287,67 @@ mod tests {
                     single_value.clone(),
                     2,
                     10,
-                    DataFileMeta {
-                        file_name: "f2.parquet".to_string(),
-                        file_size: 10,
-                        row_count: 100,
-                        min_key: single_value.clone(),
-                        max_key: single_value.clone(),
-                        key_stats: BinaryTableStats::new(
+                    DataFileMeta::builder()
+                        .file_name("f2.parquet".to_string())
+                        .file_size(10)
+                        .row_count(100)
+                        .min_key(single_value.clone())
+                        .max_key(single_value.clone())
+                        .key_stats(BinaryTableStats::new(
                             value_bytes.clone(),
                             value_bytes.clone(),
                             vec![1, 2]
-                        ),
-                        value_stats: BinaryTableStats::new(
+                        ))
+                        .value_stats(BinaryTableStats::new(
                             value_bytes.clone(),
                             value_bytes.clone(),
                             vec![1, 2]
-                        ),
-                        min_sequence_number: 1,
-                        max_sequence_number: 100,
-                        schema_id: 0,
-                        level: 1,
-                        extra_files: vec![],
-                        creation_time: "2024-09-06T07:45:55.039+00:00"
-                            .parse::<DateTime<Utc>>()
-                            .unwrap(),
-                        delete_row_count: Some(1),
-                        embedded_index: None,
-                    },
+                        ))
+                        .min_sequence_number(1)
+                        .max_sequence_number(100)
+                        .schema_id(0)
+                        .level(1)
+                        .creation_time(
+                            "2024-09-06T07:45:55.039+00:00"
+                                .parse::<DateTime<Utc>>()
+                                .unwrap()
+                        )
+                        .delete_row_count(Some(1))
+                        .build(),
                     2
                 ),
             ]
         )
     }
+
+    #[test]
+    fn test_read_deflate_manifest_list() {
+        let snappy = load_fixture("manifest-list-5c7399a0-46ae-4a5e-9c13-3ab07212cdb6-0");
+        let deflate = load_fixture("manifest-list-5c7399a0-46ae-4a5e-9c13-3ab07212cdb6-deflate");
+        assert_ne!(snappy, deflate);
+        let expected = from_avro_bytes::<ManifestFileMeta>(&snappy).unwrap();
+        assert_eq!(expected.len(), 2);
+        assert_eq!(
+            from_avro_bytes::<ManifestFileMeta>(&deflate).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_read_unsupported_codec() {
+        let deflate = load_fixture("manifest-list-5c7399a0-46ae-4a5e-9c13-3ab07212cdb6-deflate");
+        // replace the `deflate` codec name in the header by `xz`, not enabled
+        let codec = b"\x0edeflate";
+        let pos = deflate
+            .windows(codec.len())
+            .position(|window| window == codec)
+            .unwrap();
+        let mut bytes = deflate[..pos].to_vec();
+        bytes.extend_from_slice(b"\x04xz");
+        bytes.extend_from_slice(&deflate[pos + codec.len()..]);
+
+        let err = from_avro_bytes::<ManifestFileMeta>(&bytes).unwrap_err();
+        assert!(matches!(err, Error::Unsupported { .. }));
+        assert!(err.to_string().contains("'xz'"), "{err}");
+    }
 }
@@ -0,0 +1,190 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::spec::literal::format_decimal;
+use crate::spec::{BinaryRow, Literal, RowType};
+use crate::Error;
+use chrono::Timelike;
+
+/// Name of the partition holding null or empty partition values.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/CoreOptions.java#L95>
+pub const DEFAULT_PARTITION_NAME: &str = "__DEFAULT_PARTITION__";
+
+/// Compute the partition spec of a partition row: the partition keys with their values formatted
+/// as strings, in the order of the partition type.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/utils/RowDataPartitionComputer.java>
+pub fn partition_spec(
+    partition: &BinaryRow,
+    partition_type: &RowType,
+) -> crate::Result<Vec<(String, String)>> {
+    let fields = partition_type.fields();
+    if partition.arity() as usize != fields.len() {
+        return Err(Error::BinaryRowInvalid {
+            message: format!(
+                "Partition row has {} fields, but the partition type has {}",
+                partition.arity(),
+                fields.len()
+            ),
+        });
+    }
+
+    fields
+        .iter()
+        .enumerate()
+        .map(|(pos, field)| {
+            let value = partition_value(&partition.get_literal(pos, field.data_type())?)
+                .filter(|v| !v.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_PARTITION_NAME.to_string());
+            Ok((field.name().to_string(), value))
+        })
+        .collect()
+}
+
+/// Format a partition value as Java's `toString` of the internal value does.
+fn partition_value(literal: &Literal) -> Option<String> {
+    let value = match literal {
+        Literal::Null => return None,
+        Literal::Decimal {
+            unscaled, scale, ..
+        } => format_decimal(*unscaled, *scale),
+        // dates and times are formatted as their internal int value
+        Literal::Date(v) | Literal::Time(v) => v.to_string(),
+        Literal::Timestamp { millis, nanos } | Literal::LocalZonedTimestamp { millis, nanos } => {
+            // Java's `LocalDateTime#toString`
+            let ts = Literal::timestamp(*millis, *nanos)?;
+            let mut value = ts.format("%Y-%m-%dT%H:%M").to_string();
+            let nanos = ts.nanosecond();
+            if ts.second() > 0 || nanos > 0 {
+                value.push_str(&format!(":{:02}", ts.second()));
+                if nanos % 1_000_000 == 0 && nanos > 0 {
+                    value.push_str(&format!(".{:03}", nanos / 1_000_000));
+                } else if nanos % 1_000 == 0 && nanos > 0 {
+                    value.push_str(&format!(".{:06}", nanos / 1_000));
+                } else if nanos > 0 {
+                    value.push_str(&format!(".{:09}", nanos));
+                }
+            }
+            value
+        }
+        other => other.to_string(),
+    };
+    Some(value)
+}
+
+/// Generate the relative path of a partition, like `dt=2024-01-01/hr=10/`.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/utils/PartitionPathUtils.java#L70>
+pub fn partition_path(spec: &[(String, String)]) -> String {
+    spec.iter()
+        .map(|(key, value)| format!("{}={}/", escape_path_name(key), escape_path_name(value)))
+        .collect()
+}
+
+/// Escape the characters of a path name which are not allowed in file paths, as `%XX`.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/utils/PartitionPathUtils.java#L100>
+pub fn escape_path_name(path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+    for c in path.chars() {
+        if needs_escaping(c) {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+fn needs_escaping(c: char) -> bool {
+    c.is_ascii_control() && c != '\0' || "\"#%'*/:=?\\{[]^".contains(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{DataField, DataType, DateType, IntType, TimestampType, VarCharType};
+
+    fn partition_type() -> RowType {
+        RowType::new(vec![
+            DataField::new(0, "dt".to_string(), DataType::Date(DateType::new())),
+            DataField::new(1, "hr".to_string(), DataType::Int(IntType::new())),
+            DataField::new(
+                2,
+                "region".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+        ])
+    }
+
+    fn row(null_bits: u64, slots: &[u64]) -> BinaryRow {
+        let mut bytes = (slots.len() as i32).to_be_bytes().to_vec();
+        bytes.extend_from_slice(&null_bits.to_le_bytes());
+        for slot in slots {
+            bytes.extend_from_slice(&slot.to_le_bytes());
+        }
+        BinaryRow::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_partition_path() {
+        // 'a/b' inlined into the slot
+        let region = 0x83 << 56 | u64::from_le_bytes(*b"a/b\0\0\0\0\0");
+        let spec = partition_spec(&row(0, &[19723, 10, region]), &partition_type()).unwrap();
+        assert_eq!(
+            spec,
+            vec![
+                ("dt".to_string(), "19723".to_string()),
+                ("hr".to_string(), "10".to_string()),
+                ("region".to_string(), "a/b".to_string()),
+            ]
+        );
+        assert_eq!(partition_path(&spec), "dt=19723/hr=10/region=a%2Fb/");
+        assert_eq!(partition_path(&[]), "");
+    }
+
+    #[test]
+    fn test_partition_path_with_default_partition() {
+        // empty string and null
+        let empty = 0x80 << 56;
+        let spec = partition_spec(&row(1 << (8 + 1), &[1, 0, empty]), &partition_type()).unwrap();
+        assert_eq!(
+            partition_path(&spec),
+            "dt=1/hr=__DEFAULT_PARTITION__/region=__DEFAULT_PARTITION__/"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_partition_value() {
+        let partition_type = RowType::new(vec![DataField::new(
+            0,
+            "ts".to_string(),
+            DataType::Timestamp(TimestampType::new(3).unwrap()),
+        )]);
+        let spec = partition_spec(&row(0, &[1_704_067_200_000]), &partition_type).unwrap();
+        assert_eq!(spec[0].1, "2024-01-01T00:00");
+        let spec = partition_spec(&row(0, &[1_704_067_201_500]), &partition_type).unwrap();
+        assert_eq!(spec[0].1, "2024-01-01T00:00:01.500");
+    }
+
+    #[test]
+    fn test_escape_path_name() {
+        assert_eq!(escape_path_name("a:b=c%d"), "a%3Ab%3Dc%25d");
+        assert_eq!(escape_path_name("plain-name_1"), "plain-name_1");
+    }
+}
@@ -0,0 +1,75 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::{Display, Formatter};
+
+use crate::Error;
+
+/// Kind of change a row describes in a changelog.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/types/RowKind.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum RowKind {
+    /// Insertion, `+I`.
+    Insert = 0,
+    /// Update with the previous content of the row, `-U`.
+    UpdateBefore = 1,
+    /// Update with the new content of the row, `+U`.
+    UpdateAfter = 2,
+    /// Deletion, `-D`.
+    Delete = 3,
+}
+
+impl RowKind {
+    /// Get the value of the kind stored in data files.
+    pub fn to_byte_value(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_byte_value(value: u8) -> crate::Result<Self> {
+        match value {
+            0 => Ok(RowKind::Insert),
+            1 => Ok(RowKind::UpdateBefore),
+            2 => Ok(RowKind::UpdateAfter),
+            3 => Ok(RowKind::Delete),
+            _ => Err(Error::DataTypeInvalid {
+                message: format!("Unsupported byte value '{}' for row kind", value),
+            }),
+        }
+    }
+
+    /// Whether the row is added, as opposed to retracted by `-U` and `-D`.
+    pub fn is_add(self) -> bool {
+        matches!(self, RowKind::Insert | RowKind::UpdateAfter)
+    }
+
+    pub fn short_string(self) -> &'static str {
+        match self {
+            RowKind::Insert => "+I",
+            RowKind::UpdateBefore => "-U",
+            RowKind::UpdateAfter => "+U",
+            RowKind::Delete => "-D",
+        }
+    }
+}
+
+impl Display for RowKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.short_string())
+    }
+}
@@ -15,13 +15,22 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::spec::types::DataType;
+use crate::arrow::to_arrow_type;
+use crate::error::{
+    ColumnNotExistSnafu, RecordBatchInvalidSnafu, SchemaChangeInvalidSnafu, SchemaInvalidSnafu,
+};
+use crate::spec::types::{DataType, RowType};
+use crate::spec::{ColumnMove, ColumnMoveType, SchemaChange};
+use arrow_array::RecordBatch;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use std::collections::HashMap;
 
 /// The table schema for paimon table.
 ///
+/// Fields of the schema file unknown to this crate, written by a newer Paimon, are preserved
+/// verbatim: they survive a deserialization followed by a serialization, and schema changes.
+///
 /// Impl References: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/schema/TableSchema.java#L47>
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -36,6 +45,599 @@ pub struct TableSchema {
     options: HashMap<String, String>,
     comment: Option<String>,
     time_millis: i64,
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl TableSchema {
+    /// Current version of the schema file.
+    pub const CURRENT_VERSION: i32 = 2;
+
+    pub fn new(
+        id: i64,
+        fields: Vec<DataField>,
+        highest_field_id: i32,
+        partition_keys: Vec<String>,
+        primary_keys: Vec<String>,
+        options: HashMap<String, String>,
+        comment: Option<String>,
+    ) -> Self {
+        Self {
+            version: Self::CURRENT_VERSION,
+            id,
+            fields,
+            highest_field_id,
+            partition_keys,
+            primary_keys,
+            options,
+            comment,
+            time_millis: chrono::Utc::now().timestamp_millis(),
+            extra: HashMap::new(),
+        }
+    }
+
+    /// Get the version of this schema.
+    #[inline]
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    /// Get the id of this schema.
+    #[inline]
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Get the fields of this schema.
+    #[inline]
+    pub fn fields(&self) -> &[DataField] {
+        &self.fields
+    }
+
+    /// Get the highest field id ever assigned in this schema.
+    #[inline]
+    pub fn highest_field_id(&self) -> i32 {
+        self.highest_field_id
+    }
+
+    /// Get the partition keys of this schema.
+    #[inline]
+    pub fn partition_keys(&self) -> &[String] {
+        &self.partition_keys
+    }
+
+    /// Get the row type of the partition keys, in the order of the partition keys.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/schema/TableSchema.java#L245>
+    pub fn logical_partition_type(&self) -> RowType {
+        RowType::new(
+            self.partition_keys
+                .iter()
+                .filter_map(|key| self.fields.iter().find(|field| field.name() == key))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Get the primary keys of this schema.
+    #[inline]
+    pub fn primary_keys(&self) -> &[String] {
+        &self.primary_keys
+    }
+
+    /// Get the primary keys without the partition keys, which identify a row in a bucket.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/schema/TableSchema.java#L163>
+    pub fn trimmed_primary_keys(&self) -> Vec<String> {
+        self.primary_keys
+            .iter()
+            .filter(|key| !self.partition_keys.contains(key))
+            .cloned()
+            .collect()
+    }
+
+    /// Get the row type of the trimmed primary keys, the key type of the data files.
+    pub fn logical_trimmed_primary_keys_type(&self) -> RowType {
+        RowType::new(
+            self.trimmed_primary_keys()
+                .iter()
+                .filter_map(|key| self.fields.iter().find(|field| field.name() == key))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Get the options of this schema.
+    #[inline]
+    pub fn options(&self) -> &HashMap<String, String> {
+        &self.options
+    }
+
+    /// Get the comment of this schema.
+    #[inline]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Get the creation time of this schema.
+    #[inline]
+    pub fn time_millis(&self) -> i64 {
+        self.time_millis
+    }
+
+    /// Copy this schema with other options, keeping its id: the options are not a schema
+    /// change, like dynamic options given for a single use of the table.
+    pub fn copy_with_options(&self, options: HashMap<String, String>) -> TableSchema {
+        TableSchema {
+            options,
+            ..self.clone()
+        }
+    }
+
+    /// Render this schema for humans, one field per line with its id, name, type and comment,
+    /// followed by the partition keys, the primary keys and the options, sorted by key.
+    pub fn to_pretty_string(&self) -> String {
+        let mut pretty = format!("Schema {}\nFields:\n", self.id);
+        for field in &self.fields {
+            pretty.push_str(&format!("  {}: {}\n", field.id(), field.to_sql_string()));
+        }
+        pretty.push_str(&format!(
+            "Partition keys: [{}]\nPrimary keys: [{}]\nOptions:\n",
+            self.partition_keys.join(", "),
+            self.primary_keys.join(", ")
+        ));
+        let mut options = self.options.iter().collect::<Vec<_>>();
+        options.sort_unstable();
+        for (key, value) in options {
+            pretty.push_str(&format!("  {} = {}\n", key, value));
+        }
+        if let Some(comment) = &self.comment {
+            pretty.push_str(&format!("Comment: {}\n", comment));
+        }
+        pretty
+    }
+
+    /// Check that the schema is consistent, to refuse a corrupt schema when loading it.
+    ///
+    /// The highest field id must not be lower than the id of any field, nested ones included, so
+    /// that a new column never reuses an id. It may be higher, as dropping a column keeps it.
+    pub fn validate(&self) -> crate::Result<()> {
+        if let Some(max_field_id) = max_field_id(&self.fields) {
+            if max_field_id > self.highest_field_id {
+                return SchemaInvalidSnafu {
+                    message: format!(
+                        "highest field id {} of schema {} is lower than the field id {}",
+                        self.highest_field_id, self.id, max_field_id
+                    ),
+                }
+                .fail();
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that a batch can be written into a table of this schema, to refuse it before
+    /// producing a corrupt data file.
+    ///
+    /// The batch must have a column for each field, in order, with the name of the field and the
+    /// Arrow type it converts to, and no null in the columns of non-nullable fields.
+    pub fn validate_batch(&self, batch: &RecordBatch) -> crate::Result<()> {
+        if batch.num_columns() != self.fields.len() {
+            return RecordBatchInvalidSnafu {
+                message: format!(
+                    "expected {} columns for the fields of schema {}, but found {}",
+                    self.fields.len(),
+                    self.id,
+                    batch.num_columns()
+                ),
+            }
+            .fail();
+        }
+        let batch_schema = batch.schema();
+        for ((field, arrow_field), column) in self
+            .fields
+            .iter()
+            .zip(batch_schema.fields())
+            .zip(batch.columns())
+        {
+            if arrow_field.name() != field.name() {
+                return RecordBatchInvalidSnafu {
+                    message: format!(
+                        "expected column '{}', but found '{}'",
+                        field.name(),
+                        arrow_field.name()
+                    ),
+                }
+                .fail();
+            }
+            let expected_type = to_arrow_type(field.data_type())?;
+            if arrow_field.data_type() != &expected_type {
+                return RecordBatchInvalidSnafu {
+                    message: format!(
+                        "expected type {} for column '{}', but found {}",
+                        expected_type,
+                        field.name(),
+                        arrow_field.data_type()
+                    ),
+                }
+                .fail();
+            }
+            if !field.data_type().is_nullable() && column.null_count() > 0 {
+                return RecordBatchInvalidSnafu {
+                    message: format!(
+                        "column '{}' is not nullable, but has {} nulls",
+                        field.name(),
+                        column.null_count()
+                    ),
+                }
+                .fail();
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply schema changes in order, producing the next schema.
+    ///
+    /// Partition and primary key columns can not be dropped nor renamed, and the type of a
+    /// partition column can not be updated.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/schema/SchemaManager.java#L181>
+    pub fn apply_changes(&self, changes: &[SchemaChange]) -> crate::Result<TableSchema> {
+        let mut options = self.options.clone();
+        let mut comment = self.comment.clone();
+        let mut fields = self.fields.clone();
+        let mut highest_field_id = self.highest_field_id;
+
+        for change in changes {
+            match change {
+                SchemaChange::SetOption { key, value } => {
+                    options.insert(key.clone(), value.clone());
+                }
+                SchemaChange::RemoveOption { key } => {
+                    options.remove(key);
+                }
+                SchemaChange::UpdateComment {
+                    comment: new_comment,
+                } => {
+                    comment = new_comment.clone();
+                }
+                SchemaChange::AddColumn {
+                    field_name,
+                    data_type,
+                    description,
+                    column_move,
+                } => {
+                    if fields.iter().any(|field| field.name() == field_name) {
+                        return SchemaChangeInvalidSnafu {
+                            message: format!("column {field_name} already exists"),
+                        }
+                        .fail();
+                    }
+                    if !data_type.is_nullable() {
+                        return SchemaChangeInvalidSnafu {
+                            message: format!("column {field_name} can not be added as NOT NULL"),
+                        }
+                        .fail();
+                    }
+                    highest_field_id += 1;
+                    let id = highest_field_id;
+                    let data_type = reassign_field_ids(data_type, &mut highest_field_id);
+                    let field = DataField::new(id, field_name.clone(), data_type)
+                        .with_description(description.clone());
+                    match column_move {
+                        Some(column_move) => {
+                            fields.push(field);
+                            apply_move(&mut fields, column_move)?;
+                        }
+                        None => fields.push(field),
+                    }
+                }
+                SchemaChange::RenameColumn {
+                    field_name,
+                    new_name,
+                } => {
+                    self.validate_not_key(field_name, "rename")?;
+                    if fields.iter().any(|field| field.name() == new_name) {
+                        return SchemaChangeInvalidSnafu {
+                            message: format!("column {new_name} already exists"),
+                        }
+                        .fail();
+                    }
+                    fields = update_field(fields, std::slice::from_ref(field_name), |field| {
+                        Ok(field.with_name(new_name.clone()))
+                    })?;
+                }
+                SchemaChange::DropColumn { field_name } => {
+                    self.validate_not_key(field_name, "drop")?;
+                    let Some(pos) = fields.iter().position(|field| field.name() == field_name)
+                    else {
+                        return ColumnNotExistSnafu {
+                            message: format!("column {field_name} does not exist"),
+                        }
+                        .fail();
+                    };
+                    if fields.len() == 1 {
+                        return SchemaChangeInvalidSnafu {
+                            message: "can not drop all fields of a table".to_string(),
+                        }
+                        .fail();
+                    }
+                    fields.remove(pos);
+                }
+                SchemaChange::UpdateColumnType {
+                    field_name,
+                    data_type,
+                } => {
+                    if self.partition_keys.contains(field_name) {
+                        return SchemaChangeInvalidSnafu {
+                            message: format!("can not update type of partition key {field_name}"),
+                        }
+                        .fail();
+                    }
+                    fields = update_field(fields, std::slice::from_ref(field_name), |field| {
+                        Ok(field.with_data_type(data_type.clone()))
+                    })?;
+                }
+                SchemaChange::UpdateColumnPosition { column_move } => {
+                    apply_move(&mut fields, column_move)?;
+                }
+                SchemaChange::UpdateColumnNullability {
+                    field_name,
+                    nullable,
+                } => {
+                    if *nullable
+                        && field_name.len() == 1
+                        && self.primary_keys.contains(&field_name[0])
+                    {
+                        return SchemaChangeInvalidSnafu {
+                            message: format!("can not make primary key {} nullable", field_name[0]),
+                        }
+                        .fail();
+                    }
+                    fields = update_field(fields, field_name, |field| {
+                        let data_type = field.data_type().copy_with_nullable(*nullable);
+                        Ok(field.with_data_type(data_type))
+                    })?;
+                }
+                SchemaChange::UpdateColumnComment {
+                    field_names,
+                    new_description,
+                } => {
+                    fields = update_field(fields, field_names, |field| {
+                        Ok(field.with_description(Some(new_description.clone())))
+                    })?;
+                }
+            }
+        }
+
+        Ok(TableSchema {
+            extra: self.extra.clone(),
+            ..TableSchema::new(
+                self.id + 1,
+                fields,
+                highest_field_id,
+                self.partition_keys.clone(),
+                self.primary_keys.clone(),
+                options,
+                comment,
+            )
+        })
+    }
+
+    /// Compute the changes turning this schema into `target`, to be applied by
+    /// [`TableSchema::apply_changes`].
+    ///
+    /// Options are compared first, then the comment, then the columns. Columns are matched by
+    /// field id, then by name: unmatched columns of this schema are dropped, matched ones are
+    /// renamed and get their type, nullability and comment updated, and unmatched columns of
+    /// `target` are added at their position. Reordering existing columns and removing a column
+    /// comment are not detected.
+    pub fn diff(&self, target: &TableSchema) -> Vec<SchemaChange> {
+        let mut changes = Vec::new();
+
+        let mut keys = target.options.keys().collect::<Vec<_>>();
+        keys.sort();
+        for key in keys {
+            let value = &target.options[key];
+            if self.options.get(key) != Some(value) {
+                changes.push(SchemaChange::set_option(key.clone(), value.clone()));
+            }
+        }
+        let mut keys = self
+            .options
+            .keys()
+            .filter(|key| !target.options.contains_key(*key))
+            .collect::<Vec<_>>();
+        keys.sort();
+        for key in keys {
+            changes.push(SchemaChange::remove_option(key.clone()));
+        }
+        if self.comment != target.comment {
+            changes.push(SchemaChange::update_comment(target.comment.clone()));
+        }
+
+        // position in this schema of the field matching each field of the target
+        let mut matched = vec![false; self.fields.len()];
+        let matches = target
+            .fields
+            .iter()
+            .map(|field| {
+                let pos = self
+                    .fields
+                    .iter()
+                    .position(|f| f.id() == field.id())
+                    .or_else(|| {
+                        self.fields.iter().enumerate().position(|(pos, f)| {
+                            !matched[pos]
+                                && f.name() == field.name()
+                                && !target.fields.iter().any(|t| t.id() == f.id())
+                        })
+                    });
+                if let Some(pos) = pos {
+                    matched[pos] = true;
+                }
+                pos
+            })
+            .collect::<Vec<_>>();
+
+        for (field, _) in self.fields.iter().zip(&matched).filter(|(_, m)| !**m) {
+            changes.push(SchemaChange::drop_column(field.name().to_string()));
+        }
+        for (field, pos) in target.fields.iter().zip(&matches) {
+            let Some(pos) = pos else {
+                continue;
+            };
+            let old = &self.fields[*pos];
+            let name = field.name().to_string();
+            if old.name() != field.name() {
+                changes.push(SchemaChange::rename_column(
+                    old.name().to_string(),
+                    name.clone(),
+                ));
+            }
+            let (old_type, new_type) = (old.data_type(), field.data_type());
+            if old_type.copy_with_nullable(true) != new_type.copy_with_nullable(true) {
+                changes.push(SchemaChange::update_column_type(
+                    name.clone(),
+                    new_type.clone(),
+                ));
+            } else if old_type.is_nullable() != new_type.is_nullable() {
+                changes.push(SchemaChange::update_column_nullability(
+                    name.clone(),
+                    new_type.is_nullable(),
+                ));
+            }
+            if let Some(description) = field.description() {
+                if old.description() != Some(description) {
+                    changes.push(SchemaChange::update_column_comment(
+                        name,
+                        description.to_string(),
+                    ));
+                }
+            }
+        }
+        for (index, (field, _)) in target
+            .fields
+            .iter()
+            .zip(&matches)
+            .enumerate()
+            .filter(|(_, (_, pos))| pos.is_none())
+        {
+            let column_move = if index + 1 == target.fields.len() {
+                None
+            } else if index == 0 {
+                Some(ColumnMove::move_first(field.name().to_string()))
+            } else {
+                Some(ColumnMove::move_after(
+                    field.name().to_string(),
+                    target.fields[index - 1].name().to_string(),
+                ))
+            };
+            changes.push(SchemaChange::AddColumn {
+                field_name: field.name().to_string(),
+                data_type: field.data_type().clone(),
+                description: field.description().map(str::to_string),
+                column_move,
+            });
+        }
+        changes
+    }
+
+    /// Refuse an operation on a partition or primary key column.
+    fn validate_not_key(&self, field_name: &str, operation: &str) -> crate::Result<()> {
+        let role = if self.partition_keys.iter().any(|key| key == field_name) {
+            "partition key"
+        } else if self.primary_keys.iter().any(|key| key == field_name) {
+            "primary key"
+        } else {
+            return Ok(());
+        };
+        SchemaChangeInvalidSnafu {
+            message: format!("can not {operation} {role} {field_name}"),
+        }
+        .fail()
+    }
+}
+
+/// Update the (nested) field found by `path` in `fields`.
+fn update_field(
+    fields: Vec<DataField>,
+    path: &[String],
+    f: impl FnOnce(DataField) -> crate::Result<DataField>,
+) -> crate::Result<Vec<DataField>> {
+    Ok(RowType::new(fields)
+        .update_field_by_path(path, f)?
+        .fields()
+        .to_vec())
+}
+
+/// Move a field to the first position or after its referenced field.
+fn apply_move(fields: &mut Vec<DataField>, column_move: &ColumnMove) -> crate::Result<()> {
+    let name = column_move.field_name();
+    let Some(pos) = fields.iter().position(|field| field.name() == name) else {
+        return ColumnNotExistSnafu {
+            message: format!("column {name} does not exist"),
+        }
+        .fail();
+    };
+    let field = fields.remove(pos);
+    let pos = resolve_move_position(fields, column_move)?;
+    fields.insert(pos, field);
+    Ok(())
+}
+
+/// Resolve the index at which a moved field is inserted in `fields`, which do not hold it: 0 for
+/// `FIRST`, and right after the referenced field for `AFTER`.
+fn resolve_move_position(fields: &[DataField], column_move: &ColumnMove) -> crate::Result<usize> {
+    match column_move.move_type() {
+        ColumnMoveType::FIRST => Ok(0),
+        ColumnMoveType::AFTER => {
+            let referenced = column_move.referenced_field_name().unwrap_or_default();
+            match fields.iter().position(|field| field.name() == referenced) {
+                Some(pos) => Ok(pos + 1),
+                None => ColumnNotExistSnafu {
+                    message: format!("column {referenced} does not exist"),
+                }
+                .fail(),
+            }
+        }
+    }
+}
+
+/// The highest id of the fields, descending into nested types.
+fn max_field_id(fields: &[DataField]) -> Option<i32> {
+    fn nested(data_type: &DataType) -> Option<i32> {
+        match data_type {
+            DataType::Row(row_type) => max_field_id(row_type.fields()),
+            DataType::Array(v) => nested(v.element_type()),
+            DataType::Multiset(v) => nested(v.element_type()),
+            DataType::Map(v) => nested(v.key_type()).max(nested(v.value_type())),
+            _ => None,
+        }
+    }
+    fields
+        .iter()
+        .map(|field| Some(field.id()).max(nested(field.data_type())))
+        .max()
+        .flatten()
+}
+
+/// Give the fields nested in a row type new ids, after `highest_field_id`.
+fn reassign_field_ids(data_type: &DataType, highest_field_id: &mut i32) -> DataType {
+    let DataType::Row(row_type) = data_type else {
+        return data_type.clone();
+    };
+    let fields = row_type
+        .fields()
+        .iter()
+        .map(|field| {
+            *highest_field_id += 1;
+            let id = *highest_field_id;
+            let nested = reassign_field_ids(field.data_type(), highest_field_id);
+            field.clone().with_id(id).with_data_type(nested)
+        })
+        .collect();
+    DataType::Row(RowType::with_nullable(data_type.is_nullable(), fields))
 }
 
 /// Data field for paimon table.
@@ -78,6 +680,19 @@ impl DataField {
         self.description.as_deref()
     }
 
+    /// Render this field as in SQL, like `"name" VARCHAR(10) 'the name'`.
+    pub fn to_sql_string(&self) -> String {
+        let sql = format!(
+            "\"{}\" {}",
+            escape_identifier(&self.name),
+            self.typ.to_sql_string()
+        );
+        match &self.description {
+            Some(description) => format!("{} '{}'", sql, escape_single_quotes(description)),
+            None => sql,
+        }
+    }
+
     pub fn with_id(mut self, new_id: i32) -> Self {
         self.id = new_id;
         self
@@ -88,6 +703,11 @@ impl DataField {
         self
     }
 
+    pub fn with_data_type(mut self, new_type: DataType) -> Self {
+        self.typ = new_type;
+        self
+    }
+
     pub fn with_description(mut self, new_description: Option<String>) -> Self {
         self.description = new_description;
         self
@@ -104,7 +724,17 @@ pub fn escape_single_quotes(text: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::spec::IntType;
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Int32Array, Int64Array, StringArray};
+    use arrow_schema::Schema;
+
+    use crate::arrow::to_arrow_schema;
+    use crate::spec::{
+        ArrayType, BigIntType, BooleanType, IntType, LocalZonedTimestampType, MapType, RowType,
+        VarBinaryType, VarCharType,
+    };
+    use crate::Error;
 
     use super::*;
 
@@ -159,6 +789,366 @@ mod tests {
         assert_eq!(new_data_field.description(), Some("new description"));
     }
 
+    fn test_schema() -> TableSchema {
+        TableSchema::new(
+            0,
+            vec![
+                DataField::new(
+                    0,
+                    "dt".to_string(),
+                    DataType::VarChar(VarCharType::default()),
+                ),
+                DataField::new(
+                    1,
+                    "id".to_string(),
+                    DataType::Int(IntType::with_nullable(false)),
+                ),
+                DataField::new(
+                    2,
+                    "name".to_string(),
+                    DataType::VarChar(VarCharType::default()),
+                ),
+            ],
+            2,
+            vec!["dt".to_string()],
+            vec!["dt".to_string(), "id".to_string()],
+            HashMap::new(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_to_pretty_string() {
+        let mut schema = test_schema();
+        schema.fields[2] = schema.fields[2]
+            .clone()
+            .with_description(Some("the user's name".to_string()));
+        let schema = schema.copy_with_options(HashMap::from([
+            ("bucket".to_string(), "2".to_string()),
+            ("file.format".to_string(), "orc".to_string()),
+        ]));
+        assert_eq!(
+            schema.to_pretty_string(),
+            "Schema 0\n\
+             Fields:\n  \
+             0: \"dt\" VARCHAR(1)\n  \
+             1: \"id\" INT NOT NULL\n  \
+             2: \"name\" VARCHAR(1) 'the user''s name'\n\
+             Partition keys: [dt]\n\
+             Primary keys: [dt, id]\n\
+             Options:\n  \
+             bucket = 2\n  \
+             file.format = orc\n"
+        );
+    }
+
+    #[test]
+    fn test_data_type_to_sql_string() {
+        let row = DataType::Row(RowType::with_nullable(
+            false,
+            vec![
+                DataField::new(
+                    0,
+                    "k".to_string(),
+                    DataType::Int(IntType::with_nullable(false)),
+                ),
+                DataField::new(
+                    1,
+                    "v".to_string(),
+                    DataType::Map(MapType::new(
+                        DataType::VarChar(VarCharType::new(10).unwrap()),
+                        DataType::Array(ArrayType::new(DataType::Boolean(BooleanType::new()))),
+                    )),
+                )
+                .with_description(Some("values".to_string())),
+            ],
+        ));
+        assert_eq!(
+            row.to_sql_string(),
+            "ROW<\"k\" INT NOT NULL, \"v\" MAP<VARCHAR(10), ARRAY<BOOLEAN>> 'values'> NOT NULL"
+        );
+    }
+
+    #[test]
+    fn test_validate_batch() {
+        let schema = test_schema();
+        let arrow_schema = Arc::new(to_arrow_schema(schema.fields()).unwrap());
+        let columns = |ids: Int32Array| -> Vec<ArrayRef> {
+            vec![
+                Arc::new(StringArray::from(vec!["2024-01-01", "2024-01-02"])),
+                Arc::new(ids),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+            ]
+        };
+        let batch =
+            RecordBatch::try_new(arrow_schema.clone(), columns(Int32Array::from(vec![1, 2])))
+                .unwrap();
+        schema.validate_batch(&batch).unwrap();
+
+        // a null in the non-nullable id column of a batch whose schema allows it
+        let nullable_schema = Schema::new(
+            arrow_schema
+                .fields()
+                .iter()
+                .map(|field| field.as_ref().clone().with_nullable(true))
+                .collect::<Vec<_>>(),
+        );
+        let nulls = RecordBatch::try_new(
+            Arc::new(nullable_schema),
+            columns(Int32Array::from(vec![Some(1), None])),
+        )
+        .unwrap();
+        let err = schema.validate_batch(&nulls).unwrap_err();
+        assert!(matches!(err, Error::RecordBatchInvalid { .. }), "{err}");
+        assert!(err.to_string().contains("not nullable"), "{err}");
+
+        let mismatch = RecordBatch::try_from_iter(vec![
+            (
+                "dt",
+                Arc::new(StringArray::from(vec!["2024-01-01"])) as ArrayRef,
+            ),
+            ("id", Arc::new(Int64Array::from(vec![1])) as ArrayRef),
+            ("name", Arc::new(StringArray::from(vec!["a"])) as ArrayRef),
+        ])
+        .unwrap();
+        let err = schema.validate_batch(&mismatch).unwrap_err();
+        assert!(err.to_string().contains("column 'id'"), "{err}");
+
+        let missing = mismatch.project(&[0]).unwrap();
+        assert!(matches!(
+            schema.validate_batch(&missing),
+            Err(Error::RecordBatchInvalid { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let mut json = serde_json::to_value(test_schema()).unwrap();
+        json["futureProperty"] = serde_json::json!({"enabled": true});
+        let schema: TableSchema = serde_json::from_value(json).unwrap();
+        assert_eq!(schema.fields(), test_schema().fields());
+
+        let schema = schema
+            .apply_changes(&[SchemaChange::drop_column("name".to_string())])
+            .unwrap();
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(json["futureProperty"], serde_json::json!({"enabled": true}));
+        assert_eq!(json["highestFieldId"], 2);
+    }
+
+    #[test]
+    fn test_deserialize_sql_string_types() {
+        let path = std::env::current_dir()
+            .unwrap()
+            .join("tests/fixtures/schema/schema-0");
+        let schema: TableSchema = serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap();
+        let string = |nullable| {
+            DataType::VarChar(
+                VarCharType::with_nullable(nullable, VarCharType::MAX_LENGTH).unwrap(),
+            )
+        };
+
+        let types = schema
+            .fields()
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            vec![
+                DataType::BigInt(BigIntType::with_nullable(false)),
+                string(true),
+                DataType::VarBinary(VarBinaryType::new(VarBinaryType::MAX_LENGTH).unwrap()),
+                DataType::Row(RowType::new(vec![
+                    DataField::new(
+                        4,
+                        "zip".to_string(),
+                        DataType::Int(IntType::with_nullable(false))
+                    ),
+                    DataField::new(5, "city".to_string(), string(false)),
+                    DataField::new(
+                        6,
+                        "updated_at".to_string(),
+                        DataType::LocalZonedTimestamp(LocalZonedTimestampType::new(3).unwrap()),
+                    ),
+                ])),
+                DataType::Array(ArrayType::new(string(true))),
+                string(false),
+            ]
+        );
+        assert_eq!(schema.primary_keys(), &["dt", "id"]);
+    }
+
+    #[test]
+    fn test_validate_highest_field_id() {
+        let schema = test_schema();
+        schema.validate().unwrap();
+        // dropping a column keeps the highest field id
+        let schema = schema
+            .apply_changes(&[SchemaChange::drop_column("name".to_string())])
+            .unwrap();
+        schema.validate().unwrap();
+
+        let nested = DataType::Array(ArrayType::new(DataType::Row(RowType::new(vec![
+            DataField::new(5, "street".to_string(), DataType::Int(IntType::new())),
+        ]))));
+        let schema = TableSchema::new(
+            1,
+            vec![
+                DataField::new(0, "id".to_string(), DataType::Int(IntType::new())),
+                DataField::new(4, "addresses".to_string(), nested),
+            ],
+            4,
+            vec![],
+            vec![],
+            HashMap::new(),
+            None,
+        );
+        let err = schema.validate().unwrap_err();
+        assert!(matches!(err, crate::Error::SchemaInvalid { .. }), "{err:?}");
+        assert!(err.to_string().contains("field id 5"), "{err}");
+    }
+
+    #[test]
+    fn test_apply_changes() {
+        let schema = test_schema()
+            .apply_changes(&[
+                SchemaChange::set_option("bucket".to_string(), "2".to_string()),
+                SchemaChange::drop_column("name".to_string()),
+                SchemaChange::add_column_with_description_and_column_move(
+                    "age".to_string(),
+                    DataType::Int(IntType::new()),
+                    "age of user".to_string(),
+                    ColumnMove::move_after("age".to_string(), "dt".to_string()),
+                ),
+            ])
+            .unwrap();
+        assert_eq!(schema.id(), 1);
+        assert_eq!(schema.highest_field_id(), 3);
+        assert_eq!(schema.options().get("bucket").unwrap(), "2");
+        let names: Vec<_> = schema.fields().iter().map(|field| field.name()).collect();
+        assert_eq!(names, vec!["dt", "age", "id"]);
+        assert_eq!(schema.fields()[1].id(), 3);
+        assert_eq!(schema.fields()[1].description(), Some("age of user"));
+    }
+
+    #[test]
+    fn test_resolve_move_position() {
+        let fields = test_schema().fields().to_vec();
+        let names = fields.iter().map(|field| field.name()).collect::<Vec<_>>();
+
+        let first = ColumnMove::move_first("new".to_string());
+        assert_eq!(resolve_move_position(&fields, &first).unwrap(), 0);
+
+        for (pos, name) in names.iter().enumerate() {
+            let after = ColumnMove::move_after("new".to_string(), name.to_string());
+            assert_eq!(resolve_move_position(&fields, &after).unwrap(), pos + 1);
+        }
+
+        let after_missing = ColumnMove::move_after("new".to_string(), "missing".to_string());
+        assert!(matches!(
+            resolve_move_position(&fields, &after_missing),
+            Err(Error::ColumnNotExist { .. })
+        ));
+    }
+
+    #[test]
+    fn test_drop_partition_key() {
+        let err = test_schema()
+            .apply_changes(&[SchemaChange::drop_column("dt".to_string())])
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::SchemaChangeInvalid { .. }));
+        assert!(err.to_string().contains("partition key dt"), "{err}");
+    }
+
+    #[test]
+    fn test_rename_primary_key() {
+        let err = test_schema()
+            .apply_changes(&[SchemaChange::rename_column(
+                "id".to_string(),
+                "user_id".to_string(),
+            )])
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::SchemaChangeInvalid { .. }));
+        assert!(err.to_string().contains("primary key id"), "{err}");
+
+        let schema = test_schema()
+            .apply_changes(&[SchemaChange::rename_column(
+                "name".to_string(),
+                "user_name".to_string(),
+            )])
+            .unwrap();
+        assert_eq!(schema.fields()[2].name(), "user_name");
+        assert_eq!(schema.fields()[2].id(), 2);
+    }
+
+    #[test]
+    fn test_diff() {
+        let schema = test_schema();
+        assert!(schema.diff(&schema).is_empty());
+
+        let target = TableSchema::new(
+            1,
+            vec![
+                DataField::new(
+                    0,
+                    "dt".to_string(),
+                    DataType::VarChar(VarCharType::default()),
+                ),
+                DataField::new(
+                    1,
+                    "id".to_string(),
+                    DataType::BigInt(BigIntType::with_nullable(false)),
+                ),
+                DataField::new(3, "age".to_string(), DataType::Int(IntType::new())),
+                DataField::new(
+                    2,
+                    "user_name".to_string(),
+                    DataType::VarChar(VarCharType::default()),
+                ),
+            ],
+            3,
+            vec!["dt".to_string()],
+            vec!["dt".to_string(), "id".to_string()],
+            HashMap::from([("bucket".to_string(), "4".to_string())]),
+            None,
+        );
+        let changes = schema.diff(&target);
+        assert_eq!(
+            changes,
+            vec![
+                SchemaChange::set_option("bucket".to_string(), "4".to_string()),
+                SchemaChange::update_column_type(
+                    "id".to_string(),
+                    DataType::BigInt(BigIntType::with_nullable(false))
+                ),
+                SchemaChange::rename_column("name".to_string(), "user_name".to_string()),
+                SchemaChange::AddColumn {
+                    field_name: "age".to_string(),
+                    data_type: DataType::Int(IntType::new()),
+                    description: None,
+                    column_move: Some(ColumnMove::move_after("age".to_string(), "id".to_string())),
+                },
+            ]
+        );
+
+        let applied = schema.apply_changes(&changes).unwrap();
+        assert_eq!(applied.fields(), target.fields());
+        assert_eq!(applied.options(), target.options());
+        assert_eq!(
+            target.diff(&schema),
+            vec![
+                SchemaChange::remove_option("bucket".to_string()),
+                SchemaChange::drop_column("age".to_string()),
+                SchemaChange::update_column_type(
+                    "id".to_string(),
+                    DataType::Int(IntType::with_nullable(false))
+                ),
+                SchemaChange::rename_column("user_name".to_string(), "name".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_escape_identifier() {
         let escaped_identifier = escape_identifier("\"identifier\"");
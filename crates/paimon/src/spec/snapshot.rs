@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use typed_builder::TypedBuilder;
@@ -37,6 +38,9 @@ pub enum CommitKind {
 
 /// Snapshot for paimon.
 ///
+/// Fields of the snapshot file unknown to this crate, written by a newer Paimon, are preserved
+/// verbatim through a deserialization followed by a serialization.
+///
 /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/Snapshot.java#L68>.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, TypedBuilder)]
 #[serde(rename_all = "camelCase")]
@@ -92,6 +96,9 @@ pub struct Snapshot {
     #[builder(default = None)]
     #[serde(skip_serializing_if = "Option::is_none")]
     statistics: Option<String>,
+    #[builder(default, setter(skip))]
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
 }
 
 impl Snapshot {
@@ -192,6 +199,55 @@ impl Snapshot {
     }
 }
 
+/// Assembly of the snapshots committed by a commit user, filling the fields derived from the
+/// previous snapshot and from the commit time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotCommit {
+    commit_user: String,
+    commit_identifier: i64,
+}
+
+impl SnapshotCommit {
+    /// Version of the snapshots built by a commit.
+    pub const SNAPSHOT_VERSION: i32 = 3;
+
+    pub fn new(commit_user: impl ToString, commit_identifier: i64) -> Self {
+        Self {
+            commit_user: commit_user.to_string(),
+            commit_identifier,
+        }
+    }
+
+    /// Build the snapshot following `previous`, or the first snapshot of the table if `None`,
+    /// committed now.
+    ///
+    /// The total record count adds `delta_record_count` to the total of the previous snapshot.
+    pub fn build(
+        &self,
+        previous: Option<&Snapshot>,
+        schema_id: i64,
+        base_manifest_list: String,
+        delta_manifest_list: String,
+        commit_kind: CommitKind,
+        delta_record_count: i64,
+    ) -> Snapshot {
+        let previous_record_count = previous.and_then(Snapshot::total_record_count).unwrap_or(0);
+        Snapshot::builder()
+            .version(Self::SNAPSHOT_VERSION)
+            .id(previous.map_or(1, |previous| previous.id() + 1))
+            .schema_id(schema_id)
+            .base_manifest_list(base_manifest_list)
+            .delta_manifest_list(delta_manifest_list)
+            .commit_user(self.commit_user.clone())
+            .commit_identifier(self.commit_identifier)
+            .commit_kind(commit_kind)
+            .time_millis(Utc::now().timestamp_millis() as u64)
+            .total_record_count(Some(previous_record_count + delta_record_count))
+            .delta_record_count(Some(delta_record_count))
+            .build()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,4 +334,75 @@ mod tests {
             assert_eq!(snapshot, deserialized);
         }
     }
+
+    #[test]
+    fn test_unknown_fields_round_trip() {
+        let content = load_fixture("snapshot-v3-unknown-field");
+        let snapshot: Snapshot = serde_json::from_str(&content).unwrap();
+        assert_eq!(snapshot.id(), 2);
+        assert_eq!(
+            snapshot.log_offsets(),
+            Some(&HashMap::from([(0, 10), (1, 20)]))
+        );
+
+        let serialized = serde_json::to_value(&snapshot).unwrap();
+        assert_eq!(serialized["newField"], 123);
+        assert_eq!(
+            serialized,
+            serde_json::from_str::<serde_json::Value>(&content).unwrap()
+        );
+        assert!(serde_json::to_value(test_cases()[0].1.clone())
+            .unwrap()
+            .get("newField")
+            .is_none());
+    }
+
+    #[test]
+    fn test_snapshot_commit() {
+        let previous = Snapshot::builder()
+            .version(3)
+            .id(41)
+            .schema_id(0)
+            .base_manifest_list("manifest-list-base-0".to_string())
+            .delta_manifest_list("manifest-list-delta-0".to_string())
+            .commit_user("previous".to_string())
+            .commit_identifier(7)
+            .commit_kind(CommitKind::APPEND)
+            .time_millis(0)
+            .total_record_count(Some(100))
+            .build();
+        let commit = SnapshotCommit::new("writer", 8);
+
+        let before = Utc::now().timestamp_millis() as u64;
+        let snapshot = commit.build(
+            Some(&previous),
+            1,
+            "manifest-list-base-1".to_string(),
+            "manifest-list-delta-1".to_string(),
+            CommitKind::COMPACT,
+            20,
+        );
+        let after = Utc::now().timestamp_millis() as u64;
+        assert_eq!(snapshot.id(), 42);
+        assert_eq!(snapshot.version(), SnapshotCommit::SNAPSHOT_VERSION);
+        assert!((before..=after).contains(&snapshot.time_millis()));
+        assert_eq!(snapshot.schema_id(), 1);
+        assert_eq!(snapshot.base_manifest_list(), "manifest-list-base-1");
+        assert_eq!(snapshot.delta_manifest_list(), "manifest-list-delta-1");
+        assert_eq!(snapshot.commit_user(), "writer");
+        assert_eq!(snapshot.commit_identifier(), 8);
+        assert_eq!(snapshot.total_record_count(), Some(120));
+        assert_eq!(snapshot.delta_record_count(), Some(20));
+
+        let first = commit.build(
+            None,
+            0,
+            "base".to_string(),
+            "delta".to_string(),
+            CommitKind::APPEND,
+            5,
+        );
+        assert_eq!(first.id(), 1);
+        assert_eq!(first.total_record_count(), Some(5));
+    }
 }
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::error::StatsInvalidSnafu;
+use crate::spec::{BinaryRow, DataType, RowType};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -68,6 +70,76 @@ impl BinaryTableStats {
             null_counts,
         }
     }
+
+    /// Create statistics of `expected_columns` columns, checking that the minimum and maximum
+    /// values decode to rows of that arity and that there is a null count per column.
+    ///
+    /// Use [`BinaryTableStats::new`] to create statistics without checking them.
+    pub fn try_new(
+        min_values: Vec<u8>,
+        max_values: Vec<u8>,
+        null_counts: Vec<i64>,
+        expected_columns: usize,
+    ) -> crate::Result<BinaryTableStats> {
+        for (name, values) in [("min", &min_values), ("max", &max_values)] {
+            let arity = BinaryRow::from_bytes(values)?.arity() as usize;
+            if arity != expected_columns {
+                return StatsInvalidSnafu {
+                    message: format!(
+                        "{name} values have {arity} columns, but {expected_columns} are expected"
+                    ),
+                }
+                .fail();
+            }
+        }
+        if null_counts.len() != expected_columns {
+            return StatsInvalidSnafu {
+                message: format!(
+                    "found {} null counts, but {expected_columns} columns are expected",
+                    null_counts.len()
+                ),
+            }
+            .fail();
+        }
+        Ok(Self::new(min_values, max_values, null_counts))
+    }
+
+    /// Render the statistics decoded with the row type of their columns, like
+    /// `{id: min=1, max=10, null_count=0}`.
+    ///
+    /// Values which can not be decoded, like the ones of columns without statistics, are
+    /// rendered as `?`.
+    pub fn debug_with_schema(&self, row_type: &RowType) -> String {
+        let min_values = BinaryRow::from_bytes(&self.min_values).ok();
+        let max_values = BinaryRow::from_bytes(&self.max_values).ok();
+        let value = |row: &Option<BinaryRow>, pos: usize, data_type: &DataType| match row {
+            Some(row) if pos < row.arity() as usize => row
+                .get_literal(pos, data_type)
+                .map(|literal| literal.to_string())
+                .unwrap_or_else(|_| "?".to_string()),
+            _ => "?".to_string(),
+        };
+
+        let columns = row_type
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(pos, field)| {
+                let null_count = match self.null_counts.get(pos) {
+                    Some(null_count) => null_count.to_string(),
+                    None => "?".to_string(),
+                };
+                format!(
+                    "{}: min={}, max={}, null_count={}",
+                    field.name(),
+                    value(&min_values, pos, field.data_type()),
+                    value(&max_values, pos, field.data_type()),
+                    null_count
+                )
+            })
+            .collect::<Vec<_>>();
+        format!("{{{}}}", columns.join(", "))
+    }
 }
 
 impl Display for BinaryTableStats {
@@ -75,3 +147,84 @@ impl Display for BinaryTableStats {
         todo!()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{BinaryRowWriter, DataField, IntType, ManifestFileMeta, VarCharType};
+
+    fn row_type() -> RowType {
+        RowType::new(vec![
+            DataField::new(0, "id".to_string(), DataType::Int(IntType::new())),
+            DataField::new(
+                1,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_debug_with_schema() {
+        // the partition stats of the manifest list fixture
+        let value_bytes = vec![
+            0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 129,
+        ];
+        let stats = BinaryTableStats::new(value_bytes.clone(), value_bytes, vec![1, 2]);
+        assert_eq!(
+            stats.debug_with_schema(&row_type()),
+            "{id: min=1, max=1, null_count=1, name: min=1, max=1, null_count=2}"
+        );
+        let meta = ManifestFileMeta::new("manifest-0".to_string(), 10, 1, 0, stats, 0);
+        assert_eq!(
+            meta.debug_with_partition_type(&row_type()),
+            "{manifest-0, 10, 1, 0, {id: min=1, max=1, null_count=1, name: min=1, max=1, null_count=2}, 0}"
+        );
+
+        let mut min = BinaryRowWriter::new(2);
+        min.write_int(0, -5);
+        min.set_null_at(1);
+        let mut max = BinaryRowWriter::new(2);
+        max.write_int(0, 10);
+        max.set_null_at(1);
+        let stats = BinaryTableStats::new(min.build().to_bytes(), max.build().to_bytes(), vec![0]);
+        assert_eq!(
+            stats.debug_with_schema(&row_type()),
+            "{id: min=-5, max=10, null_count=0, name: min=NULL, max=NULL, null_count=?}"
+        );
+
+        let stats = BinaryTableStats::new(vec![], vec![], vec![]);
+        assert_eq!(
+            stats.debug_with_schema(&row_type()),
+            "{id: min=?, max=?, null_count=?, name: min=?, max=?, null_count=?}"
+        );
+    }
+
+    #[test]
+    fn test_try_new() {
+        let mut row = BinaryRowWriter::new(2);
+        row.write_int(0, 1);
+        row.write_string(1, "a");
+        let bytes = row.build().to_bytes();
+
+        let stats = BinaryTableStats::try_new(bytes.clone(), bytes.clone(), vec![0, 1], 2).unwrap();
+        assert_eq!(stats.null_counts(), &vec![0, 1]);
+
+        for (null_counts, expected_columns) in [(vec![0, 1], 3), (vec![0], 2)] {
+            let result = BinaryTableStats::try_new(
+                bytes.clone(),
+                bytes.clone(),
+                null_counts,
+                expected_columns,
+            );
+            assert!(
+                matches!(result, Err(crate::Error::StatsInvalid { .. })),
+                "{result:?}"
+            );
+        }
+        assert!(matches!(
+            BinaryTableStats::try_new(vec![], bytes, vec![0, 1], 2),
+            Err(crate::Error::BinaryRowInvalid { .. })
+        ));
+    }
+}
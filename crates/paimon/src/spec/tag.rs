@@ -0,0 +1,204 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::time::Duration;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::spec::Snapshot;
+
+/// A named snapshot, kept until the tag is deleted or its retention time has passed.
+///
+/// A tag file holds the fields of the tagged snapshot with the creation time and the retention
+/// time of the tag.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/tag/Tag.java>
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tag {
+    #[serde(flatten)]
+    snapshot: Snapshot,
+    /// local time at which the tag is created
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_utils::local_date_time"
+    )]
+    tag_create_time: Option<NaiveDateTime>,
+    /// time for which the tag is retained after its creation
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_utils::duration"
+    )]
+    tag_time_retained: Option<Duration>,
+}
+
+impl Tag {
+    pub fn new(
+        snapshot: Snapshot,
+        tag_create_time: Option<NaiveDateTime>,
+        tag_time_retained: Option<Duration>,
+    ) -> Self {
+        Self {
+            snapshot,
+            tag_create_time,
+            tag_time_retained,
+        }
+    }
+
+    /// Get the tagged snapshot.
+    #[inline]
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+
+    /// Get the local time at which the tag is created.
+    #[inline]
+    pub fn tag_create_time(&self) -> Option<NaiveDateTime> {
+        self.tag_create_time
+    }
+
+    /// Get the time for which the tag is retained after its creation.
+    #[inline]
+    pub fn tag_time_retained(&self) -> Option<Duration> {
+        self.tag_time_retained
+    }
+}
+
+/// Serde of the Java time types as written by Jackson's `JavaTimeModule`.
+mod serde_utils {
+    pub mod local_date_time {
+        use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+        use serde::de::Error;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        /// `[year, month, day, hour, minute, second, nanos]`, without the trailing zero second
+        /// and nanos.
+        pub fn serialize<S: Serializer>(
+            value: &Option<NaiveDateTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let Some(value) = value else {
+                return serializer.serialize_none();
+            };
+            let mut fields = vec![
+                value.year() as u32,
+                value.month(),
+                value.day(),
+                value.hour(),
+                value.minute(),
+            ];
+            if value.second() > 0 || value.nanosecond() > 0 {
+                fields.push(value.second());
+                if value.nanosecond() > 0 {
+                    fields.push(value.nanosecond());
+                }
+            }
+            serializer.collect_seq(fields)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<NaiveDateTime>, D::Error> {
+            let Some(fields) = Option::<Vec<u32>>::deserialize(deserializer)? else {
+                return Ok(None);
+            };
+            if fields.len() < 5 {
+                return Err(D::Error::custom(format!(
+                    "Local date time must have at least 5 fields, but found {:?}",
+                    fields
+                )));
+            }
+            let field = |i: usize| fields.get(i).copied().unwrap_or(0);
+            NaiveDate::from_ymd_opt(field(0) as i32, field(1), field(2))
+                .and_then(|date| date.and_hms_nano_opt(field(3), field(4), field(5), field(6)))
+                .map(Some)
+                .ok_or_else(|| D::Error::custom(format!("Invalid local date time {:?}", fields)))
+        }
+    }
+
+    pub mod duration {
+        use std::time::Duration;
+
+        use serde::de::Error;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        /// Seconds with a fraction of nanos.
+        pub fn serialize<S: Serializer>(
+            value: &Option<Duration>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match value {
+                Some(value) => serializer.serialize_f64(value.as_secs_f64()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Duration>, D::Error> {
+            Option::<f64>::deserialize(deserializer)?
+                .map(|seconds| Duration::try_from_secs_f64(seconds).map_err(D::Error::custom))
+                .transpose()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::CommitKind;
+
+    #[test]
+    fn test_tag_serialization() {
+        let snapshot = Snapshot::builder()
+            .version(3)
+            .id(2)
+            .schema_id(0)
+            .base_manifest_list("manifest-list-0".to_string())
+            .delta_manifest_list("manifest-list-1".to_string())
+            .commit_user("user".to_string())
+            .commit_identifier(1)
+            .commit_kind(CommitKind::APPEND)
+            .time_millis(1724509030368)
+            .build();
+        let create_time = "2024-09-06T07:45:00.039".parse::<NaiveDateTime>().unwrap();
+        let tag = Tag::new(
+            snapshot.clone(),
+            Some(create_time),
+            Some(Duration::from_secs(86400)),
+        );
+
+        let json = serde_json::to_value(&tag).unwrap();
+        assert_eq!(json["id"], 2);
+        assert_eq!(
+            json["tagCreateTime"],
+            serde_json::json!([2024, 9, 6, 7, 45, 0, 39000000])
+        );
+        assert_eq!(json["tagTimeRetained"], 86400.0);
+        assert_eq!(serde_json::from_value::<Tag>(json).unwrap(), tag);
+
+        // a tag written without creation and retention time
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let tag: Tag = serde_json::from_str(&json).unwrap();
+        assert_eq!(tag.snapshot(), &snapshot);
+        assert_eq!(tag.tag_create_time(), None);
+        assert_eq!(tag.tag_time_retained(), None);
+    }
+}
@@ -16,10 +16,9 @@
 // under the License.
 
 use crate::error::*;
-use crate::spec::DataField;
+use crate::spec::{BinaryRow, DataField};
 use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
-use serde_utils::DataTypeName;
 use serde_with::{serde_as, DeserializeFromStr, FromInto, SerializeDisplay};
 use std::{
     fmt::{Debug, Display, Formatter},
@@ -51,7 +50,11 @@ bitflags! {
 /// Data type for paimon table.
 ///
 /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/types/DataType.java#L45>
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Atomic types are serialized as their SQL string, like `INT NOT NULL`, and nested types as JSON
+/// objects. Deserialization accepts the aliases and the omitted parameters of the SQL strings
+/// Paimon writes, like `STRING` or `TIMESTAMP`, at any level of nesting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 #[serde(untagged)]
 pub enum DataType {
     /// Data type of a boolean with a (possibly) three-valued logic of `TRUE`, `FALSE`, `UNKNOWN`.
@@ -103,9 +106,9 @@ pub enum DataType {
     Row(RowType),
 }
 
-#[allow(dead_code)]
 impl DataType {
-    fn is_nullable(&self) -> bool {
+    /// Whether values of this type can be null.
+    pub fn is_nullable(&self) -> bool {
         match self {
             DataType::Boolean(v) => v.nullable,
             DataType::TinyInt(v) => v.nullable,
@@ -129,6 +132,300 @@ impl DataType {
             DataType::Row(v) => v.nullable,
         }
     }
+
+    /// The names of the types, as spelled in serialized schemas, like `INT` or `VARCHAR`.
+    ///
+    /// Parameters, nullability and the keywords around them, like `NOT NULL` or `WITH`, are
+    /// not included, except for `TIMESTAMP WITH LOCAL TIME ZONE` which is spelled as a whole.
+    pub fn supported_type_names() -> &'static [&'static str] {
+        use serde_utils::*;
+        &[
+            BOOLEAN::NAME,
+            TINYINT::NAME,
+            SMALLINT::NAME,
+            INT::NAME,
+            BIGINT::NAME,
+            DECIMAL::NAME,
+            DOUBLE::NAME,
+            FLOAT::NAME,
+            BINARY::NAME,
+            VARBINARY::NAME,
+            CHAR::NAME,
+            VARCHAR::NAME,
+            DATE::NAME,
+            TIME::NAME,
+            TIMESTAMP::NAME,
+            "TIMESTAMP WITH LOCAL TIME ZONE",
+            ARRAY::NAME,
+            MAP::NAME,
+            MULTISET::NAME,
+            ROW::NAME,
+        ]
+    }
+
+    /// Render this type as in SQL, like `INT NOT NULL`, `ARRAY<VARCHAR(10)>` or
+    /// `ROW<"id" INT, "name" VARCHAR(10) 'the name'>`.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/types/DataType.java>
+    pub fn to_sql_string(&self) -> String {
+        use serde_utils::*;
+        let sql = match self {
+            DataType::Boolean(_) => BOOLEAN::NAME.to_string(),
+            DataType::TinyInt(_) => TINYINT::NAME.to_string(),
+            DataType::SmallInt(_) => SMALLINT::NAME.to_string(),
+            DataType::Int(_) => INT::NAME.to_string(),
+            DataType::BigInt(_) => BIGINT::NAME.to_string(),
+            DataType::Float(_) => FLOAT::NAME.to_string(),
+            DataType::Date(_) => DATE::NAME.to_string(),
+            // the other atomic types render their parameters and nullability themselves
+            DataType::Decimal(v) => return v.to_string(),
+            DataType::Double(v) => return v.to_string(),
+            DataType::Binary(v) => return v.to_string(),
+            DataType::VarBinary(v) => return v.to_string(),
+            DataType::Char(v) => return v.to_string(),
+            DataType::VarChar(v) => return v.to_string(),
+            DataType::LocalZonedTimestamp(v) => return v.to_string(),
+            DataType::Time(v) => return v.to_string(),
+            DataType::Timestamp(v) => return v.to_string(),
+            DataType::Array(v) => format!("{}<{}>", ARRAY::NAME, v.element_type().to_sql_string()),
+            DataType::Map(v) => format!(
+                "{}<{}, {}>",
+                MAP::NAME,
+                v.key_type().to_sql_string(),
+                v.value_type().to_sql_string()
+            ),
+            DataType::Multiset(v) => {
+                format!("{}<{}>", MULTISET::NAME, v.element_type().to_sql_string())
+            }
+            DataType::Row(v) => {
+                let fields = v
+                    .fields()
+                    .iter()
+                    .map(DataField::to_sql_string)
+                    .collect::<Vec<_>>();
+                format!("{}<{}>", ROW::NAME, fields.join(", "))
+            }
+        };
+        if self.is_nullable() {
+            sql
+        } else {
+            format!("{} NOT NULL", sql)
+        }
+    }
+
+    /// Copy this type with the given nullability.
+    pub fn copy_with_nullable(&self, nullable: bool) -> DataType {
+        let mut copy = self.clone();
+        match &mut copy {
+            DataType::Boolean(v) => v.nullable = nullable,
+            DataType::TinyInt(v) => v.nullable = nullable,
+            DataType::SmallInt(v) => v.nullable = nullable,
+            DataType::Int(v) => v.nullable = nullable,
+            DataType::BigInt(v) => v.nullable = nullable,
+            DataType::Decimal(v) => v.nullable = nullable,
+            DataType::Double(v) => v.nullable = nullable,
+            DataType::Float(v) => v.nullable = nullable,
+            DataType::Binary(v) => v.nullable = nullable,
+            DataType::VarBinary(v) => v.nullable = nullable,
+            DataType::Char(v) => v.nullable = nullable,
+            DataType::VarChar(v) => v.nullable = nullable,
+            DataType::Date(v) => v.nullable = nullable,
+            DataType::LocalZonedTimestamp(v) => v.nullable = nullable,
+            DataType::Time(v) => v.nullable = nullable,
+            DataType::Timestamp(v) => v.nullable = nullable,
+            DataType::Array(v) => v.nullable = nullable,
+            DataType::Map(v) => v.nullable = nullable,
+            DataType::Multiset(v) => v.nullable = nullable,
+            DataType::Row(v) => v.nullable = nullable,
+        }
+        copy
+    }
+
+    /// Whether values of this type are stored in their 8-byte slot of the fixed-length part of a
+    /// [`BinaryRow`](crate::spec::BinaryRow), rather than in its variable-length part.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/data/BinaryRow.java#L94>
+    pub fn is_fixed_length(&self) -> bool {
+        self.fixed_size_in_bytes().is_some()
+    }
+
+    /// The size in bytes of values of this type in the fixed-length part of a
+    /// [`BinaryRow`](crate::spec::BinaryRow), `None` if they are stored in the variable-length
+    /// part.
+    ///
+    /// Decimals and timestamps are compact, stored as a long, up to a precision of
+    /// [`BinaryRow::MAX_COMPACT_DECIMAL_PRECISION`](crate::spec::BinaryRow::MAX_COMPACT_DECIMAL_PRECISION)
+    /// and [`BinaryRow::MAX_COMPACT_TIMESTAMP_PRECISION`](crate::spec::BinaryRow::MAX_COMPACT_TIMESTAMP_PRECISION).
+    pub fn fixed_size_in_bytes(&self) -> Option<usize> {
+        match self {
+            DataType::Boolean(_) | DataType::TinyInt(_) => Some(1),
+            DataType::SmallInt(_) => Some(2),
+            DataType::Int(_) | DataType::Date(_) | DataType::Time(_) | DataType::Float(_) => {
+                Some(4)
+            }
+            DataType::BigInt(_) | DataType::Double(_) => Some(8),
+            DataType::Decimal(v) => {
+                (v.precision() <= BinaryRow::MAX_COMPACT_DECIMAL_PRECISION).then_some(8)
+            }
+            DataType::Timestamp(v) => {
+                (v.precision() <= BinaryRow::MAX_COMPACT_TIMESTAMP_PRECISION).then_some(8)
+            }
+            DataType::LocalZonedTimestamp(v) => {
+                (v.precision() <= BinaryRow::MAX_COMPACT_TIMESTAMP_PRECISION).then_some(8)
+            }
+            DataType::Binary(_)
+            | DataType::VarBinary(_)
+            | DataType::Char(_)
+            | DataType::VarChar(_)
+            | DataType::Array(_)
+            | DataType::Map(_)
+            | DataType::Multiset(_)
+            | DataType::Row(_) => None,
+        }
+    }
+
+    /// Split the SQL string of a type into its uppercase name with parameters, and whether the
+    /// type is nullable, like `int not null` into `INT` and `false`.
+    fn split_nullability(s: &str) -> (String, bool) {
+        let upper = s.trim().to_ascii_uppercase();
+        match upper.strip_suffix("NOT NULL") {
+            Some(type_str) => (type_str.trim_end().to_string(), false),
+            None => (
+                upper
+                    .strip_suffix(" NULL")
+                    .unwrap_or(&upper)
+                    .trim_end()
+                    .to_string(),
+                true,
+            ),
+        }
+    }
+
+    /// Parse an atomic type from its SQL string, like `INT NOT NULL`, `VARCHAR(10)` or `STRING`.
+    ///
+    /// Names are case-insensitive, and omitted parameters take their default value.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/types/DataTypeJsonParser.java>
+    fn parse_atomic_type_sql_string(s: &str) -> Result<DataType> {
+        let (type_str, nullable) = Self::split_nullability(s);
+        let type_str = type_str.as_str();
+        // `TIMESTAMP(3) WITH LOCAL TIME ZONE` -> `TIMESTAMP WITH LOCAL TIME ZONE` and [3]
+        let (name, params) = match (type_str.find('('), type_str.find(')')) {
+            (Some(open), Some(close)) if open < close => {
+                let params = type_str[open + 1..close]
+                    .split(',')
+                    .map(|param| param.trim().parse::<u32>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| Error::DataTypeInvalid {
+                        message: format!("Invalid parameters of data type '{}'", s),
+                    })?;
+                let suffix = type_str[close + 1..].trim();
+                let name = format!("{} {}", type_str[..open].trim(), suffix);
+                (name.trim_end().to_string(), params)
+            }
+            _ => (type_str.to_string(), vec![]),
+        };
+        let param = |pos: usize, default: u32| params.get(pos).copied().unwrap_or(default);
+
+        let data_type = match name.as_str() {
+            "BOOLEAN" => DataType::Boolean(BooleanType::with_nullable(nullable)),
+            "TINYINT" => DataType::TinyInt(TinyIntType::with_nullable(nullable)),
+            "SMALLINT" => DataType::SmallInt(SmallIntType::with_nullable(nullable)),
+            "INT" | "INTEGER" => DataType::Int(IntType::with_nullable(nullable)),
+            "BIGINT" => DataType::BigInt(BigIntType::with_nullable(nullable)),
+            "FLOAT" => DataType::Float(FloatType::with_nullable(nullable)),
+            "DOUBLE" => DataType::Double(DoubleType::with_nullable(nullable)),
+            "DATE" => DataType::Date(DateType::with_nullable(nullable)),
+            "DECIMAL" | "DEC" | "NUMERIC" => DataType::Decimal(DecimalType::with_nullable(
+                nullable,
+                param(0, DecimalType::DEFAULT_PRECISION),
+                param(1, DecimalType::DEFAULT_SCALE),
+            )?),
+            "CHAR" => DataType::Char(CharType::with_nullable(
+                nullable,
+                param(0, CharType::DEFAULT_LENGTH as u32) as usize,
+            )?),
+            "VARCHAR" => DataType::VarChar(VarCharType::with_nullable(
+                nullable,
+                param(0, VarCharType::DEFAULT_LENGTH),
+            )?),
+            "STRING" => DataType::VarChar(VarCharType::with_nullable(
+                nullable,
+                VarCharType::MAX_LENGTH,
+            )?),
+            "BINARY" => DataType::Binary(BinaryType::with_nullable(
+                nullable,
+                param(0, BinaryType::DEFAULT_LENGTH as u32) as usize,
+            )?),
+            "VARBINARY" => DataType::VarBinary(VarBinaryType::try_new(
+                nullable,
+                param(0, VarBinaryType::DEFAULT_LENGTH),
+            )?),
+            "BYTES" => {
+                DataType::VarBinary(VarBinaryType::try_new(nullable, VarBinaryType::MAX_LENGTH)?)
+            }
+            "TIME" | "TIME WITHOUT TIME ZONE" => DataType::Time(TimeType::with_nullable(
+                nullable,
+                param(0, TimeType::DEFAULT_PRECISION),
+            )?),
+            "TIMESTAMP" | "TIMESTAMP WITHOUT TIME ZONE" => DataType::Timestamp(
+                TimestampType::with_nullable(nullable, param(0, TimestampType::DEFAULT_PRECISION))?,
+            ),
+            "TIMESTAMP WITH LOCAL TIME ZONE" | "TIMESTAMP_LTZ" => {
+                DataType::LocalZonedTimestamp(LocalZonedTimestampType::with_nullable(
+                    nullable,
+                    param(0, LocalZonedTimestampType::DEFAULT_PRECISION),
+                )?)
+            }
+            _ => {
+                return DataTypeInvalidSnafu {
+                    message: format!("Unsupported atomic data type '{}'", s),
+                }
+                .fail()
+            }
+        };
+        Ok(data_type)
+    }
+}
+
+/// Parse the SQL string of an atomic type with [`DataType::parse_atomic_type_sql_string`],
+/// expecting the type `type_name` whose value is taken out by `variant`.
+fn parse_atomic_type<T>(
+    s: &str,
+    type_name: &str,
+    variant: impl FnOnce(DataType) -> Option<T>,
+) -> Result<T> {
+    variant(DataType::parse_atomic_type_sql_string(s)?).ok_or_else(|| Error::DataTypeInvalid {
+        message: format!("Invalid {} type '{}'.", type_name, s),
+    })
+}
+
+impl<'de> Deserialize<'de> for DataType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// Json of a data type: the SQL string of an atomic type, or the object of a nested type.
+        #[derive(Deserialize)]
+        #[serde(untagged, expecting = "a data type string or object")]
+        enum DataTypeJson {
+            Atomic(String),
+            Array(ArrayType),
+            Map(MapType),
+            Multiset(MultisetType),
+            Row(RowType),
+        }
+
+        Ok(match DataTypeJson::deserialize(deserializer)? {
+            DataTypeJson::Atomic(s) => {
+                DataType::parse_atomic_type_sql_string(&s).map_err(serde::de::Error::custom)?
+            }
+            DataTypeJson::Array(v) => DataType::Array(v),
+            DataTypeJson::Map(v) => DataType::Map(v),
+            DataTypeJson::Multiset(v) => DataType::Multiset(v),
+            DataTypeJson::Row(v) => DataType::Row(v),
+        })
+    }
 }
 
 /// ArrayType for paimon.
@@ -161,6 +458,10 @@ impl ArrayType {
     pub fn family(&self) -> DataTypeFamily {
         DataTypeFamily::CONSTRUCTED | DataTypeFamily::COLLECTION
     }
+
+    pub fn element_type(&self) -> &DataType {
+        &self.element_type
+    }
 }
 
 /// BigIntType for paimon.
@@ -231,25 +532,10 @@ impl FromStr for BinaryType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !s.starts_with(serde_utils::BINARY::NAME) {
-            return DataTypeInvalidSnafu {
-                message: "Invalid BINARY type. Expected string to start with 'BINARY'.",
-            }
-            .fail();
-        }
-
-        let (open_bracket, close_bracket) = serde_utils::extract_brackets_pos(s, "BinaryType")?;
-        let length_str = &s[open_bracket + 1..close_bracket];
-        let length = length_str
-            .trim()
-            .parse::<usize>()
-            .map_err(|_| Error::DataTypeInvalid {
-                message: "Invalid BINARY length. Unable to parse length as a usize.".to_string(),
-            })?;
-
-        let nullable = !s[close_bracket..].contains("NOT NULL");
-
-        Ok(BinaryType { nullable, length })
+        parse_atomic_type(s, "BINARY", |data_type| match data_type {
+            DataType::Binary(v) => Some(v),
+            _ => None,
+        })
     }
 }
 
@@ -348,25 +634,10 @@ impl FromStr for CharType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !s.starts_with(serde_utils::CHAR::NAME) {
-            return DataTypeInvalidSnafu {
-                message: "Invalid CHAR type. Expected string to start with 'CHAR'.",
-            }
-            .fail();
-        }
-
-        let (open_bracket, close_bracket) = serde_utils::extract_brackets_pos(s, "CharType")?;
-        let length_str = &s[open_bracket + 1..close_bracket];
-        let length = length_str
-            .trim()
-            .parse::<usize>()
-            .map_err(|_| Error::DataTypeInvalid {
-                message: "Invalid CHAR length. Unable to parse length as a usize.".to_string(),
-            })?;
-
-        let nullable = !s[close_bracket..].contains("NOT NULL");
-
-        Ok(CharType { nullable, length })
+        parse_atomic_type(s, "CHAR", |data_type| match data_type {
+            DataType::Char(v) => Some(v),
+            _ => None,
+        })
     }
 }
 
@@ -467,57 +738,9 @@ impl FromStr for DecimalType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !s.starts_with(serde_utils::DECIMAL::NAME) {
-            return DataTypeInvalidSnafu {
-                message: "Invalid DECIMAL type. Expected string to start with 'DECIMAL'.",
-            }
-            .fail();
-        }
-
-        let (open_bracket, close_bracket) = serde_utils::extract_brackets_pos(s, "DecimalType")?;
-        let precision_scale_str = &s[open_bracket + 1..close_bracket];
-        let (precision, scale) = match precision_scale_str
-            .split(',')
-            .collect::<Vec<&str>>()
-            .as_slice()
-        {
-            [precision_str, scale_str] => {
-                let precision =
-                    precision_str
-                        .trim()
-                        .parse::<u32>()
-                        .map_err(|_| Error::DataTypeInvalid {
-                            message:
-                                "Invalid DECIMAL precision. Unable to parse precision as a u32."
-                                    .to_string(),
-                        })?;
-                let scale =
-                    scale_str
-                        .trim()
-                        .parse::<u32>()
-                        .map_err(|_| Error::DataTypeInvalid {
-                            message: "Invalid DECIMAL scale. Unable to parse scale as a u32."
-                                .to_string(),
-                        })?;
-                (precision, scale)
-            }
-            _ => {
-                let precision = precision_scale_str.trim().parse::<u32>().map_err(|_| {
-                    Error::DataTypeInvalid {
-                        message: "Invalid DECIMAL precision. Unable to parse precision as a u32."
-                            .to_string(),
-                    }
-                })?;
-                (precision, DecimalType::DEFAULT_SCALE)
-            }
-        };
-
-        let nullable = !s[close_bracket..].contains("NOT NULL");
-
-        Ok(DecimalType {
-            nullable,
-            precision,
-            scale,
+        parse_atomic_type(s, "DECIMAL", |data_type| match data_type {
+            DataType::Decimal(v) => Some(v),
+            _ => None,
         })
     }
 }
@@ -722,32 +945,14 @@ impl FromStr for LocalZonedTimestampType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !s.starts_with(serde_utils::LocalZonedTimestamp::NAME) || !s.contains("WITH") {
-            return DataTypeInvalidSnafu {
-                message:
-                    "Invalid LocalZonedTimestamp type. Expected string to start with 'TIMESTAMP'.",
-            }
-            .fail();
-        }
-
-        let (open_bracket, close_bracket) =
-            serde_utils::extract_brackets_pos(s, "LocalZonedTimestampType")?;
-        let precision_str = &s[open_bracket + 1..close_bracket];
-        let precision =
-            precision_str
-                .trim()
-                .parse::<u32>()
-                .map_err(|_| Error::DataTypeInvalid {
-                    message: "Invalid LocalZonedTimestamp length. Unable to parse length as a u32."
-                        .to_string(),
-                })?;
-
-        let nullable = !s[close_bracket..].contains("NOT NULL");
-
-        Ok(LocalZonedTimestampType {
-            nullable,
-            precision,
-        })
+        parse_atomic_type(
+            s,
+            "TIMESTAMP WITH LOCAL TIME ZONE",
+            |data_type| match data_type {
+                DataType::LocalZonedTimestamp(v) => Some(v),
+                _ => None,
+            },
+        )
     }
 }
 
@@ -861,28 +1066,9 @@ impl FromStr for TimeType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !s.starts_with(serde_utils::TIME::NAME) || s.contains("STAMP") {
-            return DataTypeInvalidSnafu {
-                message: "Invalid TIME type. Expected string to start with 'TIME'.",
-            }
-            .fail();
-        }
-
-        let (open_bracket, close_bracket) = serde_utils::extract_brackets_pos(s, "TimeType")?;
-        let precision_str = &s[open_bracket + 1..close_bracket];
-        let precision =
-            precision_str
-                .trim()
-                .parse::<u32>()
-                .map_err(|_| Error::DataTypeInvalid {
-                    message: "Invalid TIME length. Unable to parse length as a u32.".to_string(),
-                })?;
-
-        let nullable = !s[close_bracket..].contains("NOT NULL");
-
-        Ok(TimeType {
-            nullable,
-            precision,
+        parse_atomic_type(s, "TIME", |data_type| match data_type {
+            DataType::Time(v) => Some(v),
+            _ => None,
         })
     }
 }
@@ -957,29 +1143,9 @@ impl FromStr for TimestampType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !s.starts_with(serde_utils::TIMESTAMP::NAME) {
-            return DataTypeInvalidSnafu {
-                message: "Invalid TIMESTAMP type. Expected string to start with 'TIMESTAMP'.",
-            }
-            .fail();
-        }
-
-        let (open_bracket, close_bracket) = serde_utils::extract_brackets_pos(s, "TimestampType")?;
-        let precision_str = &s[open_bracket + 1..close_bracket];
-        let precision =
-            precision_str
-                .trim()
-                .parse::<u32>()
-                .map_err(|_| Error::DataTypeInvalid {
-                    message: "Invalid TIMESTAMP precision. Unable to parse precision as a u32."
-                        .to_string(),
-                })?;
-
-        let nullable = !s[close_bracket..].contains("NOT NULL");
-
-        Ok(TimestampType {
-            nullable,
-            precision,
+        parse_atomic_type(s, "TIMESTAMP", |data_type| match data_type {
+            DataType::Timestamp(v) => Some(v),
+            _ => None,
         })
     }
 }
@@ -1090,25 +1256,10 @@ impl FromStr for VarBinaryType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !s.starts_with(serde_utils::VARBINARY::NAME) {
-            return DataTypeInvalidSnafu {
-                message: "Invalid VARBINARY type. Expected string to start with 'VARBINARY'.",
-            }
-            .fail();
-        }
-
-        let (open_bracket, close_bracket) = serde_utils::extract_brackets_pos(s, "VarBinaryType")?;
-        let length_str = &s[open_bracket + 1..close_bracket];
-        let length = length_str
-            .trim()
-            .parse::<u32>()
-            .map_err(|_| Error::DataTypeInvalid {
-                message: "Invalid VARBINARY length. Unable to parse length as a u32.".to_string(),
-            })?;
-
-        let nullable = !s[close_bracket..].contains("NOT NULL");
-
-        Ok(VarBinaryType { nullable, length })
+        parse_atomic_type(s, "VARBINARY", |data_type| match data_type {
+            DataType::VarBinary(v) => Some(v),
+            _ => None,
+        })
     }
 }
 
@@ -1175,25 +1326,10 @@ impl FromStr for VarCharType {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !s.starts_with(serde_utils::VARCHAR::NAME) {
-            return DataTypeInvalidSnafu {
-                message: "Invalid VARCHAR type. Expected string to start with 'VARCHAR'.",
-            }
-            .fail();
-        }
-
-        let (open_bracket, close_bracket) = serde_utils::extract_brackets_pos(s, "VarCharType")?;
-        let length_str = &s[open_bracket + 1..close_bracket];
-        let length = length_str
-            .trim()
-            .parse::<u32>()
-            .map_err(|_| Error::DataTypeInvalid {
-                message: "Invalid VARCHAR length. Unable to parse length as a u32.".to_string(),
-            })?;
-
-        let nullable = !s[close_bracket..].contains("NOT NULL");
-
-        Ok(VarCharType { nullable, length })
+        parse_atomic_type(s, "VARCHAR", |data_type| match data_type {
+            DataType::VarChar(v) => Some(v),
+            _ => None,
+        })
     }
 }
 
@@ -1265,6 +1401,14 @@ impl MapType {
     pub fn family(&self) -> DataTypeFamily {
         DataTypeFamily::CONSTRUCTED | DataTypeFamily::COLLECTION
     }
+
+    pub fn key_type(&self) -> &DataType {
+        &self.key_type
+    }
+
+    pub fn value_type(&self) -> &DataType {
+        &self.value_type
+    }
 }
 
 /// MultisetType for paimon.
@@ -1298,6 +1442,10 @@ impl MultisetType {
     pub fn family(&self) -> DataTypeFamily {
         DataTypeFamily::CONSTRUCTED | DataTypeFamily::COLLECTION
     }
+
+    pub fn element_type(&self) -> &DataType {
+        &self.element_type
+    }
 }
 
 /// RowType for paimon.
@@ -1326,16 +1474,137 @@ impl RowType {
         Self { nullable, fields }
     }
 
+    pub fn fields(&self) -> &[DataField] {
+        &self.fields
+    }
+
     pub fn family(&self) -> DataTypeFamily {
         DataTypeFamily::CONSTRUCTED
     }
+
+    /// Update the field found by a path of field names, descending into nested rows, with `f`.
+    ///
+    /// Returns an error if a name of the path is not found, or if a field before the last one is
+    /// not of a row type.
+    pub fn update_field_by_path(
+        &self,
+        path: &[String],
+        f: impl FnOnce(DataField) -> Result<DataField>,
+    ) -> Result<RowType> {
+        let Some((name, rest)) = path.split_first() else {
+            return ColumnNotExistSnafu {
+                message: "empty field path".to_string(),
+            }
+            .fail();
+        };
+        let Some(pos) = self.fields.iter().position(|field| field.name() == name) else {
+            return ColumnNotExistSnafu {
+                message: format!("field {name} does not exist"),
+            }
+            .fail();
+        };
+
+        let field = self.fields[pos].clone();
+        let updated = if rest.is_empty() {
+            f(field)?
+        } else {
+            let DataType::Row(row_type) = field.data_type() else {
+                return ColumnNotExistSnafu {
+                    message: format!("field {name} is not a row, can not find {}", rest.join(".")),
+                }
+                .fail();
+            };
+            let nested = row_type.update_field_by_path(rest, f)?;
+            field.with_data_type(DataType::Row(nested))
+        };
+
+        let mut fields = self.fields.clone();
+        fields[pos] = updated;
+        Ok(RowType::with_nullable(self.nullable, fields))
+    }
+
+    /// Create a row type from names and types, assigning sequential field ids starting at 0.
+    pub fn from_named_types(fields: &[(&str, DataType)]) -> Self {
+        fields
+            .iter()
+            .fold(Self::builder(), |builder, (name, data_type)| {
+                builder.field(name, data_type.clone())
+            })
+            .build()
+    }
+
+    /// Create a builder adding fields one by one, with sequential ids starting at 0.
+    pub fn builder() -> RowTypeBuilder {
+        RowTypeBuilder::new()
+    }
+}
+
+/// Builder of a [`RowType`], assigning sequential ids to the fields in the order they are added.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/types/RowType.java#L295>
+#[derive(Debug, Clone)]
+pub struct RowTypeBuilder {
+    nullable: bool,
+    next_id: i32,
+    fields: Vec<DataField>,
+}
+
+impl Default for RowTypeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RowTypeBuilder {
+    pub fn new() -> Self {
+        Self {
+            nullable: true,
+            next_id: 0,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Set the nullability of the row type, nullable by default.
+    pub fn with_nullable(mut self, nullable: bool) -> Self {
+        self.nullable = nullable;
+        self
+    }
+
+    /// Set the id of the next field added, following ones being numbered from it.
+    pub fn with_next_id(mut self, next_id: i32) -> Self {
+        self.next_id = next_id;
+        self
+    }
+
+    /// Add a field with the next id.
+    pub fn field(self, name: &str, data_type: DataType) -> Self {
+        self.field_with_description(name, data_type, None)
+    }
+
+    /// Add a field with the next id and a description.
+    pub fn field_with_description(
+        mut self,
+        name: &str,
+        data_type: DataType,
+        description: Option<String>,
+    ) -> Self {
+        self.fields.push(
+            DataField::new(self.next_id, name.to_string(), data_type).with_description(description),
+        );
+        self.next_id += 1;
+        self
+    }
+
+    pub fn build(self) -> RowType {
+        RowType::with_nullable(self.nullable, self.fields)
+    }
 }
 
 mod serde_utils {
     // We use name like `BOOLEAN` by design to avoid conflict.
     #![allow(clippy::upper_case_acronyms)]
 
-    use crate::Error;
+    use super::DataType;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
     use std::marker::PhantomData;
 
@@ -1418,11 +1687,6 @@ mod serde_utils {
         const NAME: &'static str = "TIMESTAMP";
     }
 
-    pub struct LocalZonedTimestamp;
-    impl DataTypeName for LocalZonedTimestamp {
-        const NAME: &'static str = "TIMESTAMP";
-    }
-
     pub struct TINYINT;
     impl DataTypeName for TINYINT {
         const NAME: &'static str = "TINYINT";
@@ -1475,67 +1739,27 @@ mod serde_utils {
         }
     }
 
-    /// TODO: we should support more edge cases.
     impl<'de, T: DataTypeName> Deserialize<'de> for NullableType<T> {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: Deserializer<'de>,
         {
             let s = String::deserialize(deserializer)?;
-
-            let (name, nullable) = s.split_once(' ').unwrap_or((s.as_str(), ""));
-
-            if name == T::NAME && nullable.is_empty() {
-                Ok(NullableType::from(true))
-            } else if name == T::NAME && nullable.contains("NOT NULL") {
-                Ok(NullableType::from(false))
-            } else {
+            // the name of a nested type, or of an atomic type or one of its aliases
+            let (type_str, nullable) = DataType::split_nullability(&s);
+            let is_type = type_str == T::NAME
+                || DataType::parse_atomic_type_sql_string(&type_str)
+                    .is_ok_and(|data_type| data_type.to_sql_string() == T::NAME);
+            if !is_type {
                 let expect = format!("{} or {} NOT NULL", T::NAME, T::NAME);
-
-                Err(serde::de::Error::invalid_value(
+                return Err(serde::de::Error::invalid_value(
                     serde::de::Unexpected::Str(s.as_str()),
                     &expect.as_str(),
-                ))
+                ));
             }
+            Ok(NullableType::from(nullable))
         }
     }
-
-    pub(crate) fn extract_brackets_pos(
-        s: &str,
-        type_name: &str,
-    ) -> crate::Result<(usize, usize), Error> {
-        let Some(open_bracket) = s.find('(') else {
-            return Err(Error::DataTypeInvalid {
-                message: format!(
-                    "Invalid {} specification. Missing opening bracket.",
-                    type_name
-                )
-                .to_string(),
-            });
-        };
-        let Some(close_bracket) = s.find(')') else {
-            return Err(Error::DataTypeInvalid {
-                message: format!(
-                    "Invalid {} specification. Missing closing bracket.",
-                    type_name
-                )
-                .to_string(),
-            });
-        };
-
-        if open_bracket >= close_bracket {
-            return Err(Error::DataTypeInvalid {
-                message: format!(
-                    "Invalid {} specification. Opening bracket \
-                appears after or at the same position as closing bracket.",
-                    type_name
-                )
-                .to_string(),
-            });
-        }
-
-        Ok((open_bracket, close_bracket))
-    }
 }
 
 #[cfg(test)]
@@ -2080,4 +2304,440 @@ mod tests {
             assert_eq!(actual, expect, "test data type deserialize for {name}")
         }
     }
+
+    fn path(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn nested_row_type() -> RowType {
+        RowType::new(vec![
+            DataField::new(0, "id".to_string(), DataType::Int(IntType::new())),
+            DataField::new(
+                1,
+                "address".to_string(),
+                DataType::Row(RowType::new(vec![
+                    DataField::new(
+                        2,
+                        "city".to_string(),
+                        DataType::VarChar(VarCharType::default()),
+                    ),
+                    DataField::new(3, "zip".to_string(), DataType::Int(IntType::new())),
+                ])),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_update_top_level_field() {
+        let row_type = nested_row_type()
+            .update_field_by_path(&path(&["id"]), |field| {
+                Ok(field.with_name("user_id".to_string()))
+            })
+            .unwrap();
+        assert_eq!(row_type.fields()[0].name(), "user_id");
+        assert_eq!(row_type.fields()[0].id(), 0);
+        assert_eq!(row_type.fields()[1], nested_row_type().fields()[1]);
+    }
+
+    #[test]
+    fn test_update_nested_field_nullability() {
+        let row_type = nested_row_type()
+            .update_field_by_path(&path(&["address", "zip"]), |field| {
+                let data_type = field.data_type().copy_with_nullable(false);
+                Ok(field.with_data_type(data_type))
+            })
+            .unwrap();
+        let DataType::Row(address) = row_type.fields()[1].data_type() else {
+            panic!("address must be a row");
+        };
+        assert!(address.fields()[0].data_type().is_nullable());
+        assert!(!address.fields()[1].data_type().is_nullable());
+        assert_eq!(
+            address.fields()[1].data_type(),
+            &DataType::Int(IntType::with_nullable(false))
+        );
+    }
+
+    #[test]
+    fn test_fixed_length() {
+        let fixed = [
+            (DataType::Boolean(BooleanType::new()), 1),
+            (DataType::TinyInt(TinyIntType::new()), 1),
+            (DataType::SmallInt(SmallIntType::new()), 2),
+            (DataType::Int(IntType::new()), 4),
+            (DataType::Date(DateType::new()), 4),
+            (DataType::Time(TimeType::new(3).unwrap()), 4),
+            (DataType::Float(FloatType::new()), 4),
+            (DataType::BigInt(BigIntType::new()), 8),
+            (DataType::Double(DoubleType::new()), 8),
+            (DataType::Decimal(DecimalType::new(18, 2).unwrap()), 8),
+            (DataType::Timestamp(TimestampType::new(3).unwrap()), 8),
+            (
+                DataType::LocalZonedTimestamp(LocalZonedTimestampType::new(0).unwrap()),
+                8,
+            ),
+        ];
+        for (data_type, size) in fixed {
+            assert!(data_type.is_fixed_length(), "{data_type:?}");
+            assert_eq!(data_type.fixed_size_in_bytes(), Some(size), "{data_type:?}");
+        }
+
+        let variable = [
+            DataType::Decimal(DecimalType::new(19, 2).unwrap()),
+            DataType::Timestamp(TimestampType::new(6).unwrap()),
+            DataType::LocalZonedTimestamp(LocalZonedTimestampType::new(9).unwrap()),
+            DataType::Char(CharType::new(3).unwrap()),
+            DataType::VarChar(VarCharType::default()),
+            DataType::Binary(BinaryType::new(3).unwrap()),
+            DataType::VarBinary(VarBinaryType::new(3).unwrap()),
+            DataType::Array(ArrayType::new(DataType::Int(IntType::new()))),
+            DataType::Row(RowType::new(vec![])),
+        ];
+        for data_type in variable {
+            assert!(!data_type.is_fixed_length(), "{data_type:?}");
+            assert_eq!(data_type.fixed_size_in_bytes(), None, "{data_type:?}");
+        }
+    }
+
+    #[test]
+    fn test_row_type_from_named_types() {
+        let row_type = RowType::from_named_types(&[
+            ("id", DataType::Int(IntType::with_nullable(false))),
+            ("name", DataType::VarChar(VarCharType::default())),
+            ("score", DataType::Double(DoubleType::new())),
+        ]);
+        let fields = row_type
+            .fields()
+            .iter()
+            .map(|field| (field.id(), field.name()))
+            .collect::<Vec<_>>();
+        assert_eq!(fields, vec![(0, "id"), (1, "name"), (2, "score")]);
+        assert!(row_type.nullable);
+        assert_eq!(
+            row_type.fields()[0].data_type(),
+            &DataType::Int(IntType::with_nullable(false))
+        );
+
+        let row_type = RowType::builder()
+            .with_nullable(false)
+            .with_next_id(5)
+            .field("id", DataType::Int(IntType::new()))
+            .field_with_description(
+                "name",
+                DataType::VarChar(VarCharType::default()),
+                Some("user name".to_string()),
+            )
+            .build();
+        assert!(!row_type.nullable);
+        assert_eq!(row_type.fields()[0].id(), 5);
+        assert_eq!(row_type.fields()[1].id(), 6);
+        assert_eq!(row_type.fields()[1].description(), Some("user name"));
+    }
+
+    #[test]
+    fn test_deserialize_invalid_decimal() {
+        for s in ["DECIMAL(5, 9)", "DECIMAL(40, 2)", "DECIMAL(0)"] {
+            assert!(
+                matches!(DecimalType::from_str(s), Err(Error::DataTypeInvalid { .. })),
+                "{s}"
+            );
+            assert!(
+                serde_json::from_str::<DataType>(&format!("\"{s}\"")).is_err(),
+                "{s}"
+            );
+        }
+        let cases = [
+            (
+                "DECIMAL(5, 5) NOT NULL",
+                DecimalType::with_nullable(false, 5, 5),
+            ),
+            ("DECIMAL(12)", DecimalType::with_nullable(true, 12, 0)),
+            (
+                "DECIMAL(12) NOT NULL",
+                DecimalType::with_nullable(false, 12, 0),
+            ),
+            ("DECIMAL", DecimalType::with_nullable(true, 10, 0)),
+            ("DECIMAL NOT NULL", DecimalType::with_nullable(false, 10, 0)),
+        ];
+        for (s, expected) in cases {
+            let expected = expected.unwrap();
+            assert_eq!(DecimalType::from_str(s).unwrap(), expected, "{s}");
+            // serialized in the string form of Paimon
+            let json = serde_json::to_string(&expected).unwrap();
+            assert!(json.starts_with("\"DECIMAL("), "{json}");
+            assert_eq!(
+                serde_json::from_str::<DecimalType>(&json).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_deserialize_sql_string() {
+        let cases = [
+            (
+                "INTEGER NOT NULL",
+                DataType::Int(IntType::with_nullable(false)),
+            ),
+            ("int", DataType::Int(IntType::new())),
+            (
+                "STRING",
+                DataType::VarChar(VarCharType::new(VarCharType::MAX_LENGTH).unwrap()),
+            ),
+            (
+                "BYTES NOT NULL",
+                DataType::VarBinary(
+                    VarBinaryType::try_new(false, VarBinaryType::MAX_LENGTH).unwrap(),
+                ),
+            ),
+            ("VARCHAR", DataType::VarChar(VarCharType::default())),
+            ("DECIMAL", DataType::Decimal(DecimalType::default())),
+            (
+                "NUMERIC(12)",
+                DataType::Decimal(DecimalType::new(12, 0).unwrap()),
+            ),
+            ("TIME", DataType::Time(TimeType::default())),
+            ("TIMESTAMP", DataType::Timestamp(TimestampType::default())),
+            (
+                "TIMESTAMP(3) WITHOUT TIME ZONE NOT NULL",
+                DataType::Timestamp(TimestampType::with_nullable(false, 3).unwrap()),
+            ),
+            (
+                "TIMESTAMP WITH LOCAL TIME ZONE",
+                DataType::LocalZonedTimestamp(LocalZonedTimestampType::default()),
+            ),
+        ];
+        for (s, expected) in cases {
+            let json = format!("\"{s}\"");
+            let actual = serde_json::from_str::<DataType>(&json)
+                .unwrap_or_else(|err| panic!("deserialize failed for {s}: {err}"));
+            assert_eq!(actual, expected, "{s}");
+
+            // the types themselves parse the same strings
+            let actual = match &expected {
+                DataType::Int(_) => DataType::Int(serde_json::from_str(&json).unwrap()),
+                DataType::VarChar(_) => DataType::VarChar(serde_json::from_str(&json).unwrap()),
+                DataType::VarBinary(_) => DataType::VarBinary(serde_json::from_str(&json).unwrap()),
+                DataType::Decimal(_) => DataType::Decimal(serde_json::from_str(&json).unwrap()),
+                DataType::Time(_) => DataType::Time(serde_json::from_str(&json).unwrap()),
+                DataType::Timestamp(_) => DataType::Timestamp(serde_json::from_str(&json).unwrap()),
+                DataType::LocalZonedTimestamp(_) => {
+                    DataType::LocalZonedTimestamp(serde_json::from_str(&json).unwrap())
+                }
+                _ => unreachable!("no case of {s}"),
+            };
+            assert_eq!(actual, expected, "{s}");
+        }
+        assert!(serde_json::from_str::<IntType>("\"BIGINT\"").is_err());
+        assert!(VarCharType::from_str("CHAR(3)").is_err());
+
+        let json = r#"{"type":"MAP NOT NULL","key":"STRING NOT NULL","value":"INTEGER"}"#;
+        assert_eq!(
+            serde_json::from_str::<DataType>(json).unwrap(),
+            DataType::Map(MapType::with_nullable(
+                false,
+                DataType::VarChar(
+                    VarCharType::with_nullable(false, VarCharType::MAX_LENGTH).unwrap()
+                ),
+                DataType::Int(IntType::new()),
+            ))
+        );
+
+        for s in ["GEOMETRY", "VARCHAR(abc)", "TIMESTAMP(20)"] {
+            let err = serde_json::from_str::<DataType>(&format!("\"{s}\"")).unwrap_err();
+            assert!(
+                err.to_string().contains("Paimon data type invalid"),
+                "{s}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_binary_type_from_str() {
+        let cases = [
+            ("BINARY", BinaryType::with_nullable(true, 1)),
+            ("BINARY NOT NULL", BinaryType::with_nullable(false, 1)),
+            ("BINARY(22)", BinaryType::with_nullable(true, 22)),
+            ("BINARY(22) NOT NULL", BinaryType::with_nullable(false, 22)),
+        ];
+        for (s, expected) in cases {
+            let expected = expected.unwrap();
+            assert_eq!(BinaryType::from_str(s).unwrap(), expected, "{s}");
+            let json = serde_json::to_string(&expected).unwrap();
+            assert_eq!(serde_json::from_str::<BinaryType>(&json).unwrap(), expected);
+        }
+        for s in ["BINARY(0)", "BINARY(x)", "VARBINARY(2)"] {
+            assert!(
+                matches!(BinaryType::from_str(s), Err(Error::DataTypeInvalid { .. })),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_var_length_types_from_str() {
+        let cases = [
+            ("VARCHAR", VarCharType::with_nullable(true, 1)),
+            ("VARCHAR NOT NULL", VarCharType::with_nullable(false, 1)),
+            ("VARCHAR(33)", VarCharType::with_nullable(true, 33)),
+            (
+                "VARCHAR(33) NOT NULL",
+                VarCharType::with_nullable(false, 33),
+            ),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(VarCharType::from_str(s).unwrap(), expected.unwrap(), "{s}");
+        }
+        let cases = [
+            ("VARBINARY", VarBinaryType::try_new(true, 1)),
+            ("VARBINARY NOT NULL", VarBinaryType::try_new(false, 1)),
+            ("VARBINARY(233)", VarBinaryType::try_new(true, 233)),
+            (
+                "VARBINARY(233) NOT NULL",
+                VarBinaryType::try_new(false, 233),
+            ),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(
+                VarBinaryType::from_str(s).unwrap(),
+                expected.unwrap(),
+                "{s}"
+            );
+        }
+        for s in ["VARCHAR(0)", "VARCHAR(x)", "CHAR(2)"] {
+            assert!(
+                matches!(VarCharType::from_str(s), Err(Error::DataTypeInvalid { .. })),
+                "{s}"
+            );
+        }
+        for s in ["VARBINARY(0)", "VARBINARY(-1)", "BINARY(2)"] {
+            assert!(
+                matches!(
+                    VarBinaryType::from_str(s),
+                    Err(Error::DataTypeInvalid { .. })
+                ),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_row_type_with_var_length_fields_round_trip() {
+        let row_type = DataType::Row(RowType::new(vec![
+            DataField::new(
+                0,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::with_nullable(false, 20).unwrap()),
+            ),
+            DataField::new(
+                1,
+                "payload".to_string(),
+                DataType::VarBinary(VarBinaryType::default()),
+            ),
+            DataField::new(
+                2,
+                "tags".to_string(),
+                DataType::Map(MapType::new(
+                    DataType::VarChar(VarCharType::default()),
+                    DataType::VarChar(VarCharType::new(VarCharType::MAX_LENGTH).unwrap()),
+                )),
+            ),
+        ]));
+        let json = serde_json::to_string(&row_type).unwrap();
+        assert_eq!(serde_json::from_str::<DataType>(&json).unwrap(), row_type);
+        let DataType::Row(row) = &row_type else {
+            unreachable!()
+        };
+        assert_eq!(serde_json::from_str::<RowType>(&json).unwrap(), *row);
+
+        // each field through the deserializer of its own type
+        for field in row.fields() {
+            let json = serde_json::to_string(field.data_type()).unwrap();
+            let actual = match field.data_type() {
+                DataType::VarChar(_) => {
+                    DataType::VarChar(serde_json::from_str::<VarCharType>(&json).unwrap())
+                }
+                DataType::VarBinary(_) => {
+                    DataType::VarBinary(serde_json::from_str::<VarBinaryType>(&json).unwrap())
+                }
+                DataType::Map(_) => DataType::Map(serde_json::from_str::<MapType>(&json).unwrap()),
+                other => unreachable!("unexpected field type {other:?}"),
+            };
+            assert_eq!(&actual, field.data_type(), "{json}");
+        }
+    }
+
+    #[test]
+    fn test_local_zoned_timestamp_type_from_str() {
+        let cases = [
+            ("TIMESTAMP(3) WITH LOCAL TIME ZONE", true, 3),
+            ("TIMESTAMP WITH LOCAL TIME ZONE(3)", true, 3),
+            ("TIMESTAMP_LTZ(3)", true, 3),
+            ("TIMESTAMP_LTZ(9) NOT NULL", false, 9),
+            ("TIMESTAMP WITH LOCAL TIME ZONE(0) NOT NULL", false, 0),
+            ("TIMESTAMP WITH LOCAL TIME ZONE", true, 6),
+            ("TIMESTAMP_LTZ NOT NULL", false, 6),
+        ];
+        for (s, nullable, precision) in cases {
+            let expected = LocalZonedTimestampType::with_nullable(nullable, precision).unwrap();
+            assert_eq!(
+                LocalZonedTimestampType::from_str(s).unwrap(),
+                expected,
+                "{s}"
+            );
+            let json = serde_json::to_string(&expected).unwrap();
+            assert_eq!(
+                serde_json::from_str::<LocalZonedTimestampType>(&json).unwrap(),
+                expected
+            );
+        }
+        for s in [
+            "TIMESTAMP_LTZ(10)",
+            "TIMESTAMP(10) WITH LOCAL TIME ZONE",
+            "TIMESTAMP(3)",
+            "INT",
+        ] {
+            assert!(
+                matches!(
+                    LocalZonedTimestampType::from_str(s),
+                    Err(Error::DataTypeInvalid { .. })
+                ),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_supported_type_names() {
+        let names = DataType::supported_type_names();
+        for name in ["INT", "BIGINT", "VARCHAR", "TIMESTAMP", "ROW"] {
+            assert!(names.contains(&name), "{name}");
+        }
+        for keyword in ["NOT", "NULL", "WITH", "TO"] {
+            assert!(!names.contains(&keyword), "{keyword}");
+        }
+        // every type of the test cases is spelled with one of the names
+        for (_, data_type) in test_cases() {
+            let spelling = serde_json::to_value(&data_type).unwrap();
+            let spelling = match &spelling {
+                serde_json::Value::String(s) => s.as_str(),
+                value => value["type"].as_str().unwrap(),
+            };
+            assert!(
+                names.iter().any(|name| spelling.starts_with(name)),
+                "{spelling}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_missing_field() {
+        for names in [&["name"][..], &["id", "zip"], &["address", "street"], &[]] {
+            let result = nested_row_type().update_field_by_path(&path(names), Ok);
+            assert!(
+                matches!(result, Err(Error::ColumnNotExist { .. })),
+                "{names:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,228 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bytes::Bytes;
+use chrono::Utc;
+use snafu::ResultExt;
+
+use crate::error::MetadataInvalidSnafu;
+use crate::io::FileIO;
+use crate::spec::Consumer;
+use crate::table::{PathFactory, CONSUMER_PREFIX};
+
+/// Manager for the consumer files of a table, recording the progress of streaming consumers.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/consumer/ConsumerManager.java>
+#[derive(Debug, Clone)]
+pub struct ConsumerManager {
+    file_io: FileIO,
+    path_factory: PathFactory,
+}
+
+impl ConsumerManager {
+    pub fn new(file_io: FileIO, table_path: impl ToString) -> Self {
+        Self {
+            file_io,
+            path_factory: PathFactory::new(table_path),
+        }
+    }
+
+    /// Read the consumer with the given id, `None` if there is no such consumer.
+    pub async fn consumer(&self, consumer_id: &str) -> crate::Result<Option<Consumer>> {
+        let path = self.path_factory.consumer_path(consumer_id);
+        if !self.file_io.exists(&path).await? {
+            return Ok(None);
+        }
+        let json = self.file_io.read_to_string(&path).await?;
+        let consumer = serde_json::from_str(&json).context(MetadataInvalidSnafu {
+            message: format!("Failed to parse consumer '{}'", path),
+        })?;
+        Ok(Some(consumer))
+    }
+
+    /// Move the consumer with the given id to `next_snapshot`, creating it if it does not exist.
+    pub async fn reset_consumer(&self, consumer_id: &str, next_snapshot: i64) -> crate::Result<()> {
+        let path = self.path_factory.consumer_path(consumer_id);
+        let bytes =
+            serde_json::to_vec(&Consumer::new(next_snapshot)).context(MetadataInvalidSnafu {
+                message: format!("Failed to serialize consumer '{}'", path),
+            })?;
+        self.file_io
+            .new_output(&path)?
+            .write(Bytes::from(bytes))
+            .await
+    }
+
+    /// Delete the consumer with the given id, if it exists.
+    pub async fn delete_consumer(&self, consumer_id: &str) -> crate::Result<()> {
+        self.file_io
+            .delete_file(&self.path_factory.consumer_path(consumer_id))
+            .await
+    }
+
+    /// Read all consumers, by id.
+    pub async fn consumers(&self) -> crate::Result<HashMap<String, Consumer>> {
+        let mut consumers = HashMap::new();
+        for consumer_id in self.list_consumer_ids().await? {
+            if let Some(consumer) = self.consumer(&consumer_id).await? {
+                consumers.insert(consumer_id, consumer);
+            }
+        }
+        Ok(consumers)
+    }
+
+    /// Get the smallest next snapshot of the consumers, `None` if there is no consumer.
+    ///
+    /// Snapshots from this one must be retained.
+    pub async fn min_next_snapshot(&self) -> crate::Result<Option<i64>> {
+        Ok(self
+            .consumers()
+            .await?
+            .values()
+            .map(Consumer::next_snapshot)
+            .min())
+    }
+
+    /// Delete the consumers whose file was last modified more than `older_than` ago, returning
+    /// their ids, sorted.
+    ///
+    /// Consumers whose modification time is unknown to the storage are kept, and nothing is
+    /// expired if `older_than` reaches before the earliest representable time.
+    pub async fn expire_consumers(&self, older_than: Duration) -> crate::Result<Vec<String>> {
+        let dir = format!("{}/", self.path_factory.consumer_dir());
+        if !self.file_io.exists(&dir).await? {
+            return Ok(vec![]);
+        }
+        let Some(expire_time) = chrono::Duration::from_std(older_than)
+            .ok()
+            .and_then(|older_than| Utc::now().checked_sub_signed(older_than))
+        else {
+            return Ok(vec![]);
+        };
+        let mut expired = Vec::new();
+        for status in self.file_io.list_status(&dir).await? {
+            let Some(consumer_id) = Self::consumer_id(&status.path) else {
+                continue;
+            };
+            if status.is_dir || !status.last_modified.is_some_and(|time| time < expire_time) {
+                continue;
+            }
+            self.file_io.delete_file(&status.path).await?;
+            expired.push(consumer_id.to_string());
+        }
+        expired.sort_unstable();
+        Ok(expired)
+    }
+
+    /// List the ids of all consumers, sorted.
+    async fn list_consumer_ids(&self) -> crate::Result<Vec<String>> {
+        let dir = format!("{}/", self.path_factory.consumer_dir());
+        if !self.file_io.exists(&dir).await? {
+            return Ok(vec![]);
+        }
+        let mut ids = self
+            .file_io
+            .list_status(&dir)
+            .await?
+            .into_iter()
+            .filter(|status| !status.is_dir)
+            .filter_map(|status| Some(Self::consumer_id(&status.path)?.to_string()))
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        Ok(ids)
+    }
+
+    fn consumer_id(path: &str) -> Option<&str> {
+        path.rsplit('/').next()?.strip_prefix(CONSUMER_PREFIX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use super::*;
+    use crate::io::FileIOBuilder;
+
+    #[tokio::test]
+    async fn test_reset_consumer() -> crate::Result<()> {
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let manager = ConsumerManager::new(file_io, "memory:/tmp/test_reset_consumer");
+        assert_eq!(manager.consumer("c1").await?, None);
+        assert_eq!(manager.min_next_snapshot().await?, None);
+
+        manager.reset_consumer("c1", 5).await?;
+        manager.reset_consumer("c2", 3).await?;
+        assert_eq!(manager.consumer("c1").await?, Some(Consumer::new(5)));
+        assert_eq!(manager.min_next_snapshot().await?, Some(3));
+
+        // reposition a live consumer
+        manager.reset_consumer("c2", 8).await?;
+        assert_eq!(manager.consumer("c2").await?, Some(Consumer::new(8)));
+        assert_eq!(manager.min_next_snapshot().await?, Some(5));
+
+        manager.delete_consumer("c1").await?;
+        let consumers = manager.consumers().await?;
+        assert_eq!(
+            consumers,
+            HashMap::from([("c2".to_string(), Consumer::new(8))])
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_expire_consumers() -> crate::Result<()> {
+        // the local file system reports the modification time of the consumer files
+        let dir =
+            std::env::temp_dir().join(format!("test_expire_consumers-{}", uuid::Uuid::new_v4()));
+        let location = format!("file:{}", dir.display());
+        let file_io = FileIOBuilder::new("file").build()?;
+        let manager = ConsumerManager::new(file_io, &location);
+        assert!(manager
+            .expire_consumers(Duration::from_secs(60))
+            .await?
+            .is_empty());
+
+        manager.reset_consumer("dead", 1).await?;
+        manager.reset_consumer("live", 2).await?;
+        let dead_path = dir.join("consumer").join("consumer-dead");
+        std::fs::File::options()
+            .write(true)
+            .open(&dead_path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+
+        // a duration out of the range of timestamps expires nothing
+        assert!(manager.expire_consumers(Duration::MAX).await?.is_empty());
+        assert!(dead_path.exists());
+
+        let expired = manager.expire_consumers(Duration::from_secs(60)).await?;
+        assert_eq!(expired, vec!["dead".to_string()]);
+        assert!(!dead_path.exists());
+        assert_eq!(
+            manager.consumers().await?,
+            HashMap::from([("live".to_string(), Consumer::new(2))])
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        Ok(())
+    }
+}
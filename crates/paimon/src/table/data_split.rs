@@ -0,0 +1,133 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::spec::{BinaryRow, DataFileMeta, FileKind};
+
+/// Input split of a read: the data files of one bucket of one partition in a snapshot.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/source/DataSplit.java>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSplit {
+    snapshot_id: i64,
+    /// serialized partition `BinaryRow`
+    partition: Vec<u8>,
+    bucket: i32,
+    bucket_path: String,
+    total_buckets: i32,
+    data_files: Vec<DataFileMeta>,
+    /// kind of each data file, all [`FileKind::Add`] unless deletions are included in the scan
+    file_kinds: Vec<FileKind>,
+}
+
+impl DataSplit {
+    pub fn new(
+        snapshot_id: i64,
+        partition: Vec<u8>,
+        bucket: i32,
+        bucket_path: String,
+        total_buckets: i32,
+        data_files: Vec<DataFileMeta>,
+    ) -> Self {
+        let file_kinds = vec![FileKind::Add; data_files.len()];
+        Self {
+            snapshot_id,
+            partition,
+            bucket,
+            bucket_path,
+            total_buckets,
+            data_files,
+            file_kinds,
+        }
+    }
+
+    /// Set the kind of each data file, in the order of the data files.
+    ///
+    /// Panics if there is not one kind per data file, which the scan guarantees.
+    pub(crate) fn with_file_kinds(mut self, file_kinds: Vec<FileKind>) -> Self {
+        assert_eq!(
+            file_kinds.len(),
+            self.data_files.len(),
+            "file kinds must match the data files"
+        );
+        self.file_kinds = file_kinds;
+        self
+    }
+
+    /// Get the id of the snapshot this split is planned from.
+    #[inline]
+    pub fn snapshot_id(&self) -> i64 {
+        self.snapshot_id
+    }
+
+    /// Get the serialized partition `BinaryRow` of this split.
+    #[inline]
+    pub fn partition(&self) -> &[u8] {
+        &self.partition
+    }
+
+    /// Decode the partition of this split, a row of
+    /// [`TableSchema::logical_partition_type`](crate::spec::TableSchema::logical_partition_type).
+    pub fn partition_row(&self) -> crate::Result<BinaryRow> {
+        BinaryRow::from_bytes(&self.partition)
+    }
+
+    #[inline]
+    pub fn bucket(&self) -> i32 {
+        self.bucket
+    }
+
+    /// Get the directory holding the data files of this split.
+    #[inline]
+    pub fn bucket_path(&self) -> &str {
+        &self.bucket_path
+    }
+
+    #[inline]
+    pub fn total_buckets(&self) -> i32 {
+        self.total_buckets
+    }
+
+    #[inline]
+    pub fn data_files(&self) -> &[DataFileMeta] {
+        &self.data_files
+    }
+
+    /// Get the kind of each data file, in the order of the data files.
+    #[inline]
+    pub fn file_kinds(&self) -> &[FileKind] {
+        &self.file_kinds
+    }
+
+    /// Iterate the data files with their kinds.
+    pub fn files_with_kind(&self) -> impl Iterator<Item = (FileKind, &DataFileMeta)> {
+        self.file_kinds.iter().copied().zip(self.data_files.iter())
+    }
+
+    /// Get the full path of a data file of this split.
+    pub fn data_file_path(&self, file: &DataFileMeta) -> String {
+        format!("{}/{}", self.bucket_path, file.file_name())
+    }
+
+    /// Get the total number of added rows of the added data files of this split, an estimation
+    /// of the rows read for tables with primary keys whose files also hold deleted rows.
+    pub fn row_count(&self) -> i64 {
+        self.files_with_kind()
+            .filter(|(kind, _)| *kind == FileKind::Add)
+            .map(|(_, file)| file.add_row_count())
+            .sum()
+    }
+}
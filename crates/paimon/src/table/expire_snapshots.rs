@@ -0,0 +1,196 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use chrono::{DateTime, Utc};
+
+use crate::table::FileStoreTable;
+
+/// Expiration of the old snapshots of a table.
+///
+/// The snapshots beyond `snapshot.num-retained.max` are expired, and so are the ones beyond
+/// `snapshot.num-retained.min` which were committed before the time given to
+/// [`ExpireSnapshots::with_older_than`]. Snapshots are expired from the earliest one and never
+/// from a snapshot pinned by a tag or by the next snapshot of a consumer, so that tags and
+/// streaming readers can still read them.
+///
+/// Only the snapshot files are deleted, the files they reference and no retained snapshot does
+/// are left to the [`OrphanFileScanner`](crate::table::OrphanFileScanner).
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/ExpireSnapshotsImpl.java>
+#[derive(Debug, Clone)]
+pub struct ExpireSnapshots {
+    table: FileStoreTable,
+    older_than: Option<DateTime<Utc>>,
+}
+
+impl ExpireSnapshots {
+    pub fn new(table: FileStoreTable) -> Self {
+        Self {
+            table,
+            older_than: None,
+        }
+    }
+
+    /// Also expire the snapshots beyond the minimum retained number committed before
+    /// `older_than`.
+    pub fn with_older_than(mut self, older_than: DateTime<Utc>) -> Self {
+        self.older_than = Some(older_than);
+        self
+    }
+
+    /// Get the id of the earliest snapshot to retain, `None` if the table has no snapshot.
+    ///
+    /// This is the earliest snapshot kept by the retention policy, lowered to the earliest
+    /// snapshot of a tag and to the earliest next snapshot of a consumer.
+    pub async fn min_retained_snapshot_id(&self) -> crate::Result<Option<i64>> {
+        let snapshot_ids = self.table.snapshot_manager().list_snapshot_ids().await?;
+        let Some(&latest) = snapshot_ids.last() else {
+            return Ok(None);
+        };
+
+        let mut min_retained = self.retention_policy_min(&snapshot_ids, latest).await?;
+        let tag_manager = self.table.tag_manager();
+        for tag_name in tag_manager.list_tag_names().await? {
+            let tagged = tag_manager.tag(&tag_name).await?.snapshot().id();
+            min_retained = min_retained.min(tagged);
+        }
+        if let Some(next_snapshot) = self.table.consumer_manager().min_next_snapshot().await? {
+            min_retained = min_retained.min(next_snapshot);
+        }
+        Ok(Some(min_retained))
+    }
+
+    /// Delete the snapshot files before the earliest snapshot to retain, returning the ids of
+    /// the expired snapshots, sorted.
+    pub async fn expire(&self) -> crate::Result<Vec<i64>> {
+        let Some(min_retained) = self.min_retained_snapshot_id().await? else {
+            return Ok(vec![]);
+        };
+        let snapshot_manager = self.table.snapshot_manager();
+        let expired = snapshot_manager
+            .list_snapshot_ids()
+            .await?
+            .into_iter()
+            .filter(|id| *id < min_retained)
+            .collect::<Vec<_>>();
+        for snapshot_id in &expired {
+            self.table
+                .file_io()
+                .delete_file(&snapshot_manager.snapshot_path(*snapshot_id))
+                .await?;
+        }
+        Ok(expired)
+    }
+
+    /// Get the earliest snapshot kept by the retention policy alone.
+    async fn retention_policy_min(&self, snapshot_ids: &[i64], latest: i64) -> crate::Result<i64> {
+        let options = self.table.options();
+        let retained_min = options.snapshot_num_retained_min()? as i64;
+        let retained_max = options.snapshot_num_retained_max()? as i64;
+        // snapshots from `retained_min` back are always kept, before `retained_max` never
+        let min_end = latest.saturating_sub(retained_min) + 1;
+        let max_end = latest.saturating_sub(retained_max) + 1;
+
+        let snapshot_manager = self.table.snapshot_manager();
+        for &snapshot_id in snapshot_ids {
+            if snapshot_id >= min_end {
+                return Ok(snapshot_id);
+            }
+            if snapshot_id < max_end {
+                continue;
+            }
+            let committed_before = match self.older_than {
+                Some(older_than) => {
+                    let snapshot = snapshot_manager.snapshot(snapshot_id).await?;
+                    (snapshot.time_millis() as i64) < older_than.timestamp_millis()
+                }
+                None => false,
+            };
+            if !committed_before {
+                return Ok(snapshot_id);
+            }
+        }
+        Ok(min_end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::spec::CoreOptions;
+    use crate::test_util::{commit, new_table};
+
+    async fn table_with_snapshots(location: &str, retained_max: &str) -> FileStoreTable {
+        let table = new_table(location, vec![], vec![]).copy(HashMap::from([
+            (
+                CoreOptions::SNAPSHOT_NUM_RETAINED_MIN.to_string(),
+                "1".to_string(),
+            ),
+            (
+                CoreOptions::SNAPSHOT_NUM_RETAINED_MAX.to_string(),
+                retained_max.to_string(),
+            ),
+        ]));
+        for snapshot_id in 1..=5 {
+            commit(&table, snapshot_id, vec![], vec![]).await.unwrap();
+        }
+        table
+    }
+
+    #[tokio::test]
+    async fn test_expire_by_retention_policy() -> crate::Result<()> {
+        let table = table_with_snapshots("memory:/tmp/test_expire_by_retention_policy", "2").await;
+        let expire = ExpireSnapshots::new(table.clone());
+        assert_eq!(expire.min_retained_snapshot_id().await?, Some(4));
+        assert_eq!(expire.expire().await?, vec![1, 2, 3]);
+        assert_eq!(
+            table.snapshot_manager().list_snapshot_ids().await?,
+            vec![4, 5]
+        );
+
+        // snapshots within the maximum are expired by time
+        let expire = expire.with_older_than(Utc::now());
+        assert_eq!(expire.expire().await?, vec![4]);
+        assert!(expire.expire().await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_expire_keeps_pinned_snapshots() -> crate::Result<()> {
+        let table =
+            table_with_snapshots("memory:/tmp/test_expire_keeps_pinned_snapshots", "2").await;
+        table.consumer_manager().reset_consumer("reader", 2).await?;
+        table.create_tag("t3", 3).await?;
+
+        let expire = ExpireSnapshots::new(table.clone());
+        assert_eq!(expire.expire().await?, vec![1]);
+        assert_eq!(
+            table.snapshot_manager().list_snapshot_ids().await?,
+            vec![2, 3, 4, 5]
+        );
+
+        // the tag pins its snapshot once the consumer moves on
+        table.consumer_manager().reset_consumer("reader", 5).await?;
+        assert_eq!(expire.expire().await?, vec![2]);
+        table.consumer_manager().delete_consumer("reader").await?;
+        assert!(expire.expire().await?.is_empty());
+        assert_eq!(expire.min_retained_snapshot_id().await?, Some(3));
+        Ok(())
+    }
+}
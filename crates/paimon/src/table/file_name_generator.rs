@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use uuid::Uuid;
+
+pub const DATA_FILE_PREFIX: &str = "data-";
+pub const MANIFEST_FILE_PREFIX: &str = "manifest-";
+pub const MANIFEST_LIST_PREFIX: &str = "manifest-list-";
+pub const INDEX_FILE_PREFIX: &str = "index-";
+
+/// Generator of the names of the files written by one writer.
+///
+/// All names carry the uuid of the writer and a counter incremented per kind of file:
+///
+/// - data files: `data-{uuid}-{counter}.{format}`
+/// - manifest files: `manifest-{uuid}-{counter}`
+/// - manifest lists: `manifest-list-{uuid}-{counter}`
+/// - index files: `index-{uuid}-{counter}`
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/utils/FileStorePathFactory.java>
+/// and <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/io/DataFilePathFactory.java>
+#[derive(Debug)]
+pub struct FileNameGenerator {
+    uuid: String,
+    data_file_count: AtomicU64,
+    manifest_file_count: AtomicU64,
+    manifest_list_count: AtomicU64,
+    index_file_count: AtomicU64,
+}
+
+impl Default for FileNameGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileNameGenerator {
+    /// Create a generator with a random uuid.
+    pub fn new() -> Self {
+        Self::with_uuid(Uuid::new_v4().to_string())
+    }
+
+    /// Create a generator with the given uuid.
+    pub fn with_uuid(uuid: impl ToString) -> Self {
+        Self {
+            uuid: uuid.to_string(),
+            data_file_count: AtomicU64::new(0),
+            manifest_file_count: AtomicU64::new(0),
+            manifest_list_count: AtomicU64::new(0),
+            index_file_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the uuid of the writer.
+    #[inline]
+    pub fn uuid(&self) -> &str {
+        &self.uuid
+    }
+
+    fn next_name(&self, prefix: &str, counter: &AtomicU64) -> String {
+        let count = counter.fetch_add(1, Ordering::Relaxed);
+        format!("{}{}-{}", prefix, self.uuid, count)
+    }
+
+    /// Generate the name of a new data file of the given format, like `parquet`.
+    pub fn new_data_file_name(&self, format: &str) -> String {
+        format!(
+            "{}.{}",
+            self.next_name(DATA_FILE_PREFIX, &self.data_file_count),
+            format
+        )
+    }
+
+    /// Generate the name of a new manifest file.
+    pub fn new_manifest_file_name(&self) -> String {
+        self.next_name(MANIFEST_FILE_PREFIX, &self.manifest_file_count)
+    }
+
+    /// Generate the name of a new manifest list.
+    pub fn new_manifest_list_name(&self) -> String {
+        self.next_name(MANIFEST_LIST_PREFIX, &self.manifest_list_count)
+    }
+
+    /// Generate the name of a new index file.
+    pub fn new_index_file_name(&self) -> String {
+        self.next_name(INDEX_FILE_PREFIX, &self.index_file_count)
+    }
+}
+
+/// Get the format identifier of a data file from its name, like `parquet`.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/io/DataFilePathFactory.java#L96>
+pub fn format_identifier(file_name: &str) -> Option<&str> {
+    file_name.rsplit_once('.').map(|(_, format)| format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_names() {
+        let generator = FileNameGenerator::with_uuid("abc");
+        assert_eq!(
+            generator.new_data_file_name("parquet"),
+            "data-abc-0.parquet"
+        );
+        assert_eq!(generator.new_data_file_name("orc"), "data-abc-1.orc");
+        assert_eq!(generator.new_manifest_file_name(), "manifest-abc-0");
+        assert_eq!(generator.new_manifest_list_name(), "manifest-list-abc-0");
+        assert_eq!(generator.new_manifest_list_name(), "manifest-list-abc-1");
+        assert_eq!(generator.new_index_file_name(), "index-abc-0");
+        assert_eq!(
+            generator.new_data_file_name("parquet"),
+            "data-abc-2.parquet"
+        );
+    }
+
+    #[test]
+    fn test_random_uuid() {
+        let generator = FileNameGenerator::new();
+        let uuid = Uuid::parse_str(generator.uuid()).unwrap();
+        assert_eq!(
+            generator.new_manifest_file_name(),
+            format!("manifest-{}-0", uuid)
+        );
+        assert_ne!(FileNameGenerator::new().uuid(), generator.uuid());
+    }
+
+    #[test]
+    fn test_format_identifier() {
+        assert_eq!(format_identifier("data-abc-0.parquet"), Some("parquet"));
+        assert_eq!(format_identifier("manifest-abc-0"), None);
+    }
+}
@@ -0,0 +1,812 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures::{StreamExt, TryStreamExt};
+
+use crate::io::FileIO;
+use crate::predicate::Predicate;
+use crate::spec::{
+    merge_options, BinaryRow, CoreOptions, FileKind, IndexManifestEntry, IndexManifestFile,
+    ManifestEntry, ManifestFile, ManifestList, MergedEntries, Snapshot, TableSchema,
+};
+use crate::table::sink::BatchWriteBuilder;
+use crate::table::{
+    ConsumerManager, ExpireSnapshots, PathFactory, SnapshotManager, TableRead, TableScan,
+    TagManager,
+};
+use crate::trace;
+use crate::Error;
+
+/// Aggregated statistics of a partition.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/manifest/PartitionEntry.java>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionEntry {
+    /// serialized partition `BinaryRow`
+    partition: Vec<u8>,
+    record_count: i64,
+    file_size_in_bytes: i64,
+    file_count: i64,
+    /// creation time of the latest file in milliseconds
+    last_file_creation_time: i64,
+}
+
+impl PartitionEntry {
+    /// Get the serialized partition `BinaryRow` of this partition.
+    #[inline]
+    pub fn partition(&self) -> &[u8] {
+        &self.partition
+    }
+
+    /// Get the number of records in this partition.
+    #[inline]
+    pub fn record_count(&self) -> i64 {
+        self.record_count
+    }
+
+    /// Get the total size of the data files in this partition.
+    #[inline]
+    pub fn file_size_in_bytes(&self) -> i64 {
+        self.file_size_in_bytes
+    }
+
+    /// Get the number of data files in this partition.
+    #[inline]
+    pub fn file_count(&self) -> i64 {
+        self.file_count
+    }
+
+    /// Get the creation time of the latest data file in this partition, in milliseconds.
+    #[inline]
+    pub fn last_file_creation_time(&self) -> i64 {
+        self.last_file_creation_time
+    }
+
+    fn from_entry(entry: &ManifestEntry) -> Self {
+        Self {
+            partition: entry.partition().clone(),
+            record_count: entry.file().row_count(),
+            file_size_in_bytes: entry.file().file_size(),
+            file_count: 1,
+            last_file_creation_time: entry.file().creation_time().timestamp_millis(),
+        }
+    }
+
+    fn merge(&mut self, other: PartitionEntry) {
+        self.record_count += other.record_count;
+        self.file_size_in_bytes += other.file_size_in_bytes;
+        self.file_count += other.file_count;
+        self.last_file_creation_time = self
+            .last_file_creation_time
+            .max(other.last_file_creation_time);
+    }
+}
+
+/// Aggregated statistics of the live data files of a snapshot of a table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableSummary {
+    snapshot_id: Option<i64>,
+    file_count: i64,
+    file_size_in_bytes: i64,
+    record_count: i64,
+    partitions: Vec<PartitionEntry>,
+}
+
+impl TableSummary {
+    fn new(snapshot_id: i64, partitions: Vec<PartitionEntry>) -> Self {
+        Self {
+            snapshot_id: Some(snapshot_id),
+            file_count: partitions.iter().map(|p| p.file_count).sum(),
+            file_size_in_bytes: partitions.iter().map(|p| p.file_size_in_bytes).sum(),
+            record_count: partitions.iter().map(|p| p.record_count).sum(),
+            partitions,
+        }
+    }
+
+    /// Get the id of the summarized snapshot, `None` if the table has no snapshot yet.
+    #[inline]
+    pub fn snapshot_id(&self) -> Option<i64> {
+        self.snapshot_id
+    }
+
+    /// Get the number of live data files.
+    #[inline]
+    pub fn file_count(&self) -> i64 {
+        self.file_count
+    }
+
+    /// Get the total size of the live data files.
+    #[inline]
+    pub fn file_size_in_bytes(&self) -> i64 {
+        self.file_size_in_bytes
+    }
+
+    /// Get the number of records of the live data files.
+    #[inline]
+    pub fn record_count(&self) -> i64 {
+        self.record_count
+    }
+
+    /// Get the statistics of each partition, see [`FileStoreTable::list_partitions`].
+    #[inline]
+    pub fn partitions(&self) -> &[PartitionEntry] {
+        &self.partitions
+    }
+}
+
+/// A table backed by the file store: snapshots, manifests and data files under the table location.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/AbstractFileStoreTable.java>
+#[derive(Debug, Clone)]
+pub struct FileStoreTable {
+    file_io: FileIO,
+    location: String,
+    schema: TableSchema,
+}
+
+impl FileStoreTable {
+    pub fn new(file_io: FileIO, location: impl ToString, schema: TableSchema) -> Self {
+        Self {
+            file_io,
+            location: location.to_string().trim_end_matches('/').to_string(),
+            schema,
+        }
+    }
+
+    /// Get the file io of this table.
+    #[inline]
+    pub fn file_io(&self) -> &FileIO {
+        &self.file_io
+    }
+
+    /// Get the root location of this table.
+    #[inline]
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Get the schema of this table.
+    #[inline]
+    pub fn schema(&self) -> &TableSchema {
+        &self.schema
+    }
+
+    /// Get the effective options of this table.
+    ///
+    /// They are the options of its schema file, overridden by the table options of the catalog
+    /// it is got from, overridden by the dynamic options of [`FileStoreTable::copy`]: dynamic >
+    /// catalog > schema. The merged options are also those of [`FileStoreTable::schema`].
+    pub fn options(&self) -> CoreOptions {
+        CoreOptions::new(self.schema.options().clone())
+    }
+
+    /// Copy this table with dynamic options merged over the options of its schema, see
+    /// [`merge_options`]: a dynamic option wins over the schema option with the same key.
+    ///
+    /// The schema files are not changed, the options only apply to the copy.
+    pub fn copy(&self, dynamic_options: HashMap<String, String>) -> Self {
+        let options = merge_options(self.schema.options(), dynamic_options);
+        Self {
+            schema: self.schema.copy_with_options(options),
+            ..self.clone()
+        }
+    }
+
+    /// Get the factory of the paths of the files of this table.
+    pub fn path_factory(&self) -> PathFactory {
+        PathFactory::new(&self.location)
+    }
+
+    pub fn snapshot_manager(&self) -> SnapshotManager {
+        SnapshotManager::new(self.file_io.clone(), &self.location)
+    }
+
+    pub fn tag_manager(&self) -> TagManager {
+        TagManager::new(self.file_io.clone(), &self.location)
+    }
+
+    pub fn consumer_manager(&self) -> ConsumerManager {
+        ConsumerManager::new(self.file_io.clone(), &self.location)
+    }
+
+    /// Create an expiration of the old snapshots of this table.
+    pub fn new_expire_snapshots(&self) -> ExpireSnapshots {
+        ExpireSnapshots::new(self.clone())
+    }
+
+    /// Create a tag of the snapshot with the given id.
+    pub async fn create_tag(&self, tag_name: &str, snapshot_id: i64) -> crate::Result<()> {
+        let snapshot = self.existing_snapshot(snapshot_id).await?;
+        self.tag_manager()
+            .create_tag(&snapshot, tag_name, None)
+            .await
+    }
+
+    /// Create a tag of the snapshot with the given id, retained for `time_retained`.
+    pub async fn create_tag_with_retention(
+        &self,
+        tag_name: &str,
+        snapshot_id: i64,
+        time_retained: Duration,
+    ) -> crate::Result<()> {
+        let snapshot = self.existing_snapshot(snapshot_id).await?;
+        self.tag_manager()
+            .create_tag(&snapshot, tag_name, Some(time_retained))
+            .await
+    }
+
+    /// Create a tag of the latest snapshot.
+    ///
+    /// Fails with [`Error::SnapshotNotExist`] if the table has no snapshot yet.
+    pub async fn create_tag_from_latest(&self, tag_name: &str) -> crate::Result<()> {
+        let snapshot = self.latest_snapshot_for_tag(tag_name).await?;
+        self.tag_manager()
+            .create_tag(&snapshot, tag_name, None)
+            .await
+    }
+
+    /// Create a tag of the latest snapshot, retained for `time_retained`.
+    ///
+    /// Fails with [`Error::SnapshotNotExist`] if the table has no snapshot yet.
+    pub async fn create_tag_from_latest_with_retention(
+        &self,
+        tag_name: &str,
+        time_retained: Duration,
+    ) -> crate::Result<()> {
+        let snapshot = self.latest_snapshot_for_tag(tag_name).await?;
+        self.tag_manager()
+            .create_tag(&snapshot, tag_name, Some(time_retained))
+            .await
+    }
+
+    async fn existing_snapshot(&self, snapshot_id: i64) -> crate::Result<Snapshot> {
+        let snapshot_manager = self.snapshot_manager();
+        if !self
+            .file_io
+            .exists(&snapshot_manager.snapshot_path(snapshot_id))
+            .await?
+        {
+            return Err(Error::SnapshotNotExist {
+                message: format!("Snapshot {} does not exist", snapshot_id),
+            });
+        }
+        snapshot_manager.snapshot(snapshot_id).await
+    }
+
+    async fn latest_snapshot_for_tag(&self, tag_name: &str) -> crate::Result<Snapshot> {
+        self.snapshot_manager()
+            .latest_snapshot()
+            .await?
+            .ok_or_else(|| Error::SnapshotNotExist {
+                message: format!(
+                    "Can not create tag '{}' because the table has no snapshot",
+                    tag_name
+                ),
+            })
+    }
+
+    /// Create a scan of the latest snapshot of this table.
+    pub fn new_scan(&self) -> TableScan {
+        TableScan::new(self.clone())
+    }
+
+    /// Create a read of the splits planned by a scan of this table.
+    pub fn new_read(&self) -> crate::Result<TableRead> {
+        TableRead::new(self.clone())
+    }
+
+    /// Create a builder of a batch write of this table.
+    pub fn new_batch_write_builder(&self) -> BatchWriteBuilder {
+        BatchWriteBuilder::new(self.clone())
+    }
+
+    /// List the partitions of the latest snapshot with their aggregated statistics.
+    ///
+    /// An unpartitioned table with data has a single partition with an empty partition row.
+    pub async fn list_partitions(&self) -> crate::Result<Vec<PartitionEntry>> {
+        let Some(snapshot) = self.snapshot_manager().latest_snapshot().await? else {
+            return Ok(vec![]);
+        };
+        Ok(Self::partition_entries(self.live_entries(&snapshot).await?))
+    }
+
+    /// Compute the statistics of the live data files of the latest snapshot, in total and per
+    /// partition.
+    ///
+    /// Nothing is cached, the manifests are read on every call.
+    pub async fn compute_statistics(&self) -> crate::Result<TableSummary> {
+        let Some(snapshot) = self.snapshot_manager().latest_snapshot().await? else {
+            return Ok(TableSummary::default());
+        };
+        let partitions = Self::partition_entries(self.live_entries(&snapshot).await?);
+        Ok(TableSummary::new(snapshot.id(), partitions))
+    }
+
+    /// Find the ids of the retained snapshots whose live data files include the file named
+    /// `file_name`, in ascending order, to tell why the file is not cleaned up yet.
+    ///
+    /// The manifests of every snapshot are read, so this is meant for debugging. Snapshots
+    /// expired while they are walked are skipped.
+    pub async fn snapshots_referencing(&self, file_name: &str) -> crate::Result<Vec<i64>> {
+        let snapshot_manager = self.snapshot_manager();
+        let mut ids = Vec::new();
+        for id in snapshot_manager.list_snapshot_ids().await? {
+            let snapshot = match snapshot_manager.snapshot(id).await {
+                Ok(snapshot) => snapshot,
+                Err(err) if err.is_not_found() => continue,
+                Err(err) => return Err(err),
+            };
+            let entries = self.live_entries(&snapshot).await?;
+            if entries.iter().any(|entry| entry.file_name() == file_name) {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Aggregate the statistics of data files by partition, in the order of first appearance.
+    fn partition_entries(entries: Vec<ManifestEntry>) -> Vec<PartitionEntry> {
+        let mut partitions: Vec<PartitionEntry> = Vec::new();
+        let mut positions: HashMap<Vec<u8>, usize> = HashMap::new();
+        for entry in entries {
+            let partition_entry = PartitionEntry::from_entry(&entry);
+            match positions.get(entry.partition()) {
+                Some(pos) => partitions[*pos].merge(partition_entry),
+                None => {
+                    positions.insert(entry.partition().clone(), partitions.len());
+                    partitions.push(partition_entry);
+                }
+            }
+        }
+        partitions
+    }
+
+    /// Read the data files alive in the given snapshot.
+    pub(crate) async fn live_entries(
+        &self,
+        snapshot: &Snapshot,
+    ) -> crate::Result<Vec<ManifestEntry>> {
+        self.filtered_live_entries(snapshot, None).await
+    }
+
+    /// Read the data files alive in the given snapshot, skipping the partitions which cannot
+    /// match `partition_filter`, in the order of first appearance.
+    ///
+    /// The entries are merged while the manifest files are read, so the deleted files are not
+    /// held in memory, unlike merging the result of [`FileStoreTable::read_manifest_entries`].
+    /// The live entries are still all returned at once: a file of a partition and bucket may be
+    /// deleted by any later manifest file, so no split is complete before the last one is read.
+    pub(crate) async fn filtered_live_entries(
+        &self,
+        snapshot: &Snapshot,
+        partition_filter: Option<&Predicate>,
+    ) -> crate::Result<Vec<ManifestEntry>> {
+        let mut merged = MergedEntries::default();
+        self.for_each_entry(snapshot, partition_filter, |entry| merged.add(entry))
+            .await?;
+        Ok(merged
+            .into_entries()
+            .into_iter()
+            .filter(|entry| *entry.kind() == FileKind::Add)
+            .collect())
+    }
+
+    /// Read the index files recorded by the index manifest of the given snapshot.
+    pub(crate) async fn index_entries(
+        &self,
+        snapshot: &Snapshot,
+    ) -> crate::Result<Vec<IndexManifestEntry>> {
+        match snapshot.index_manifest() {
+            None => Ok(vec![]),
+            Some(index_manifest) => {
+                IndexManifestFile::new(self.file_io.clone())
+                    .read(&self.path_factory().index_manifest_path(index_manifest))
+                    .await
+            }
+        }
+    }
+
+    /// Read all manifest entries of the given snapshot in commit order, without merging the
+    /// deletions into the additions: the entries of the base manifest list, then of the delta
+    /// manifest list.
+    ///
+    /// The manifest files are read concurrently, up to
+    /// [`CoreOptions::scan_manifest_parallelism`] at a time.
+    pub async fn read_manifest_entries(
+        &self,
+        snapshot: &Snapshot,
+    ) -> crate::Result<Vec<ManifestEntry>> {
+        self.filtered_entries(snapshot, None).await
+    }
+
+    /// Read the manifest entries of the given snapshot in commit order, skipping the manifest
+    /// files and the entries whose partitions cannot match `partition_filter`.
+    pub(crate) async fn filtered_entries(
+        &self,
+        snapshot: &Snapshot,
+        partition_filter: Option<&Predicate>,
+    ) -> crate::Result<Vec<ManifestEntry>> {
+        let mut entries = Vec::new();
+        self.for_each_entry(snapshot, partition_filter, |entry| {
+            entries.push(entry);
+            Ok(())
+        })
+        .await?;
+        Ok(entries)
+    }
+
+    /// Visit the manifest entries of the given snapshot in commit order, skipping the manifest
+    /// files and the entries whose partitions cannot match `partition_filter`.
+    ///
+    /// Only the entries of the manifest files being read are held in memory.
+    async fn for_each_entry(
+        &self,
+        snapshot: &Snapshot,
+        partition_filter: Option<&Predicate>,
+        mut f: impl FnMut(ManifestEntry) -> crate::Result<()>,
+    ) -> crate::Result<()> {
+        let path_factory = self.path_factory();
+        let manifest_list = ManifestList::new(self.file_io.clone());
+        let manifest_file = ManifestFile::new(self.file_io.clone());
+        let partition_type = self.schema.logical_partition_type();
+
+        let base_path = path_factory.manifest_list_path(snapshot.base_manifest_list());
+        let delta_path = path_factory.manifest_list_path(snapshot.delta_manifest_list());
+        let (base, delta) = futures::try_join!(
+            manifest_list.read(&base_path),
+            manifest_list.read(&delta_path)
+        )?;
+        let mut metas = Vec::with_capacity(base.len() + delta.len());
+        for meta in base.into_iter().chain(delta) {
+            if let Some(filter) = partition_filter {
+                let stats = meta.partition_stats();
+                let file_count = meta.num_added_files() + meta.num_deleted_files();
+                if !stats.min_values().is_empty()
+                    && !filter.test_stats(
+                        &partition_type,
+                        file_count,
+                        &BinaryRow::from_bytes(stats.min_values())?,
+                        &BinaryRow::from_bytes(stats.max_values())?,
+                        stats.null_counts(),
+                    )?
+                {
+                    continue;
+                }
+            }
+            metas.push(meta);
+        }
+
+        let paths = metas
+            .iter()
+            .map(|meta| path_factory.manifest_path(meta.file_name()))
+            .collect::<Vec<_>>();
+        trace::record("manifest_files", paths.len() as u64);
+        // The buffered stream keeps the order of the manifest files.
+        let manifest_file = &manifest_file;
+        let mut manifests = futures::stream::iter(paths)
+            .map(|path| async move { manifest_file.read(&path).await })
+            .buffered(self.options().scan_manifest_parallelism()?);
+        let mut entry_count = 0;
+        while let Some(entries) = manifests.try_next().await? {
+            entry_count += entries.len() as u64;
+            for entry in entries {
+                if let Some(filter) = partition_filter {
+                    let partition = BinaryRow::from_bytes(entry.partition())?;
+                    let null_counts = (0..partition.arity() as usize)
+                        .map(|pos| partition.is_null_at(pos) as i64)
+                        .collect::<Vec<_>>();
+                    if !filter.test_stats(
+                        &partition_type,
+                        1,
+                        &partition,
+                        &partition,
+                        &null_counts,
+                    )? {
+                        continue;
+                    }
+                }
+                f(entry)?;
+            }
+        }
+        trace::record("entries", entry_count);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use crate::spec::{CoreOptions, FileKind};
+    use crate::table::TableSummary;
+    use crate::test_util::{commit, data_file, entry, int_partition, new_table};
+    use crate::Error;
+
+    #[test]
+    fn test_copy_with_dynamic_options() {
+        let table = new_table("memory:/tmp/test_copy_with_dynamic_options", vec![], vec![]);
+        let table = table.copy(HashMap::from([(
+            CoreOptions::BUCKET.to_string(),
+            "2".to_string(),
+        )]));
+        let copy = table.copy(HashMap::from([
+            (CoreOptions::BUCKET.to_string(), "4".to_string()),
+            (CoreOptions::FILE_FORMAT.to_string(), "parquet".to_string()),
+        ]));
+        assert_eq!(copy.options().bucket().unwrap(), 4);
+        assert_eq!(copy.options().file_format(), "parquet");
+        assert_eq!(copy.schema().id(), table.schema().id());
+        assert_eq!(table.options().bucket().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_partitions() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_list_partitions",
+            vec![],
+            vec!["p".to_string()],
+        );
+        assert!(table.list_partitions().await?.is_empty());
+
+        let base = vec![
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f1", 10, 100, 1000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f2", 20, 200, 3000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(2),
+                data_file("f3", 30, 300, 2000),
+            ),
+        ];
+        let delta = vec![
+            entry(
+                FileKind::Delete,
+                int_partition(2),
+                data_file("f3", 30, 300, 2000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(2),
+                data_file("f4", 5, 50, 4000),
+            ),
+        ];
+        commit(&table, 1, base, delta).await?;
+
+        let partitions = table.list_partitions().await?;
+        assert_eq!(partitions.len(), 2);
+
+        assert_eq!(partitions[0].partition(), int_partition(1));
+        assert_eq!(partitions[0].record_count(), 30);
+        assert_eq!(partitions[0].file_count(), 2);
+        assert_eq!(partitions[0].file_size_in_bytes(), 300);
+        assert_eq!(partitions[0].last_file_creation_time(), 3000);
+
+        assert_eq!(partitions[1].partition(), int_partition(2));
+        assert_eq!(partitions[1].record_count(), 5);
+        assert_eq!(partitions[1].file_count(), 1);
+        assert_eq!(partitions[1].file_size_in_bytes(), 50);
+        assert_eq!(partitions[1].last_file_creation_time(), 4000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_manifest_entries() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_read_manifest_entries",
+            vec![],
+            vec!["p".to_string()],
+        );
+        let base = vec![
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f1", 10, 100, 1000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(2),
+                data_file("f2", 20, 200, 2000),
+            ),
+        ];
+        let delta = vec![entry(
+            FileKind::Delete,
+            int_partition(1),
+            data_file("f1", 10, 100, 1000),
+        )];
+        commit(&table, 1, base.clone(), delta.clone()).await?;
+
+        let snapshot = table.snapshot_manager().snapshot(1).await?;
+        let entries = table.read_manifest_entries(&snapshot).await?;
+        assert_eq!(entries.len(), base.len() + delta.len());
+        assert_eq!(entries, [base, delta].concat());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compute_statistics() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_compute_statistics",
+            vec![],
+            vec!["p".to_string()],
+        );
+        assert_eq!(table.compute_statistics().await?, TableSummary::default());
+
+        let base = vec![
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f1", 10, 100, 1000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(2),
+                data_file("f2", 20, 200, 2000),
+            ),
+        ];
+        let delta = vec![
+            entry(
+                FileKind::Delete,
+                int_partition(1),
+                data_file("f1", 10, 100, 1000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f3", 15, 150, 3000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(2),
+                data_file("f4", 5, 50, 4000),
+            ),
+        ];
+        commit(&table, 1, base, delta).await?;
+
+        let summary = table.compute_statistics().await?;
+        let snapshot = table.snapshot_manager().snapshot(1).await?;
+        let live_files = table.live_entries(&snapshot).await?;
+        assert_eq!(summary.snapshot_id(), Some(1));
+        assert_eq!(summary.file_count(), live_files.len() as i64);
+        assert_eq!(summary.file_count(), 3);
+        assert_eq!(
+            summary.record_count(),
+            live_files.iter().map(|e| e.file().row_count()).sum::<i64>()
+        );
+        assert_eq!(summary.record_count(), 40);
+        assert_eq!(
+            summary.file_size_in_bytes(),
+            live_files.iter().map(|e| e.file().file_size()).sum::<i64>()
+        );
+        assert_eq!(summary.file_size_in_bytes(), 400);
+        assert_eq!(summary.partitions(), table.list_partitions().await?);
+        // partition 2 appears first among the live files
+        assert_eq!(summary.partitions()[0].partition(), int_partition(2));
+        assert_eq!(summary.partitions()[0].record_count(), 25);
+        assert_eq!(summary.partitions()[1].partition(), int_partition(1));
+        assert_eq!(summary.partitions()[1].record_count(), 15);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_partitions_unpartitioned() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_list_partitions_unpartitioned",
+            vec![],
+            vec![],
+        );
+        let empty_partition = vec![0, 0, 0, 0];
+        let base = vec![
+            entry(
+                FileKind::Add,
+                empty_partition.clone(),
+                data_file("f1", 10, 100, 1000),
+            ),
+            entry(
+                FileKind::Add,
+                empty_partition.clone(),
+                data_file("f2", 20, 200, 2000),
+            ),
+        ];
+        commit(&table, 1, base, vec![]).await?;
+
+        let partitions = table.list_partitions().await?;
+        assert_eq!(partitions.len(), 1);
+        assert_eq!(partitions[0].partition(), empty_partition);
+        assert_eq!(partitions[0].record_count(), 30);
+        assert_eq!(partitions[0].file_count(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshots_referencing() -> crate::Result<()> {
+        let table = new_table("memory:/tmp/test_snapshots_referencing", vec![], vec![]);
+        let file = |kind, name| entry(kind, vec![0, 0, 0, 0], data_file(name, 1, 1, 0));
+        assert!(table.snapshots_referencing("f1").await?.is_empty());
+
+        commit(&table, 1, vec![], vec![file(FileKind::Add, "f1")]).await?;
+        commit(
+            &table,
+            2,
+            vec![file(FileKind::Add, "f1")],
+            vec![file(FileKind::Add, "f2")],
+        )
+        .await?;
+        commit(
+            &table,
+            3,
+            vec![file(FileKind::Add, "f1"), file(FileKind::Add, "f2")],
+            vec![file(FileKind::Delete, "f1")],
+        )
+        .await?;
+
+        assert_eq!(table.snapshots_referencing("f1").await?, vec![1, 2]);
+        assert_eq!(table.snapshots_referencing("f2").await?, vec![2, 3]);
+        assert!(table.snapshots_referencing("missing").await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_tag_from_latest() -> crate::Result<()> {
+        let table = new_table("memory:/tmp/test_create_tag_from_latest", vec![], vec![]);
+        let result = table.create_tag_from_latest("empty").await;
+        assert!(matches!(result, Err(Error::SnapshotNotExist { .. })));
+        assert!(!table.tag_manager().tag_exists("empty").await?);
+
+        let file = |name| entry(FileKind::Add, vec![0, 0, 0, 0], data_file(name, 1, 1, 0));
+        commit(&table, 1, vec![], vec![file("f1")]).await?;
+        commit(&table, 2, vec![file("f1")], vec![file("f2")]).await?;
+
+        table.create_tag_from_latest("latest").await?;
+        let tag = table.tag_manager().tag("latest").await?;
+        assert_eq!(tag.snapshot().id(), 2);
+        assert!(tag.tag_create_time().is_some());
+        assert_eq!(tag.tag_time_retained(), None);
+
+        let retention = Duration::from_secs(3600);
+        table
+            .create_tag_from_latest_with_retention("retained", retention)
+            .await?;
+        let tag = table.tag_manager().tag("retained").await?;
+        assert_eq!(tag.snapshot().id(), 2);
+        assert_eq!(tag.tag_time_retained(), Some(retention));
+
+        table.create_tag("first", 1).await?;
+        assert_eq!(table.tag_manager().tag("first").await?.snapshot().id(), 1);
+        assert!(matches!(
+            table.create_tag("missing", 3).await,
+            Err(Error::SnapshotNotExist { .. })
+        ));
+        assert!(matches!(
+            table.create_tag_from_latest("latest").await,
+            Err(Error::TagInvalid { .. })
+        ));
+        Ok(())
+    }
+}
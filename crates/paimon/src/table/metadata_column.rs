@@ -0,0 +1,58 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::spec::{BigIntType, DataField, DataType, VarCharType};
+
+/// System column which can be appended to the rows read by a [`crate::table::TableRead`].
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-common/src/main/java/org/apache/paimon/table/SpecialFields.java>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetadataColumn {
+    /// The sequence number of the row, `BIGINT NOT NULL`.
+    SequenceNumber,
+    /// The kind of change of the row, like `+I`, `STRING NOT NULL`.
+    RowKind,
+}
+
+impl MetadataColumn {
+    /// Get the name of the column.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MetadataColumn::SequenceNumber => "_SEQUENCE_NUMBER",
+            MetadataColumn::RowKind => "_ROW_KIND",
+        }
+    }
+
+    /// Get the field of the column, with an id reserved at the end of the id range so that it
+    /// never conflicts with a field of the table.
+    pub fn field(&self) -> DataField {
+        match self {
+            MetadataColumn::SequenceNumber => DataField::new(
+                i32::MAX - 1,
+                self.name().to_string(),
+                DataType::BigInt(BigIntType::with_nullable(false)),
+            ),
+            MetadataColumn::RowKind => DataField::new(
+                i32::MAX - 2,
+                self.name().to_string(),
+                DataType::VarChar(
+                    VarCharType::with_nullable(false, VarCharType::MAX_LENGTH).unwrap(),
+                ),
+            ),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Table module for paimon.
+//!
+//! Read-side access to the snapshots and data files of a paimon table.
+
+mod consumer_manager;
+pub use consumer_manager::*;
+
+mod data_split;
+pub use data_split::*;
+
+mod expire_snapshots;
+pub use expire_snapshots::*;
+
+mod file_name_generator;
+pub use file_name_generator::*;
+
+mod file_store_table;
+pub use file_store_table::*;
+
+mod metadata_column;
+pub use metadata_column::*;
+
+mod orphan_file_scanner;
+pub use orphan_file_scanner::*;
+
+mod path_factory;
+pub use path_factory::*;
+
+mod schema_cache;
+pub use schema_cache::*;
+
+mod snapshot_manager;
+pub use snapshot_manager::*;
+
+pub mod sink;
+
+pub mod system;
+
+mod table_read;
+pub use table_read::*;
+
+mod table_scan;
+pub use table_scan::*;
+
+mod tag_manager;
+pub use tag_manager::*;
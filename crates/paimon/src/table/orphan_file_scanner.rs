@@ -0,0 +1,265 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+
+use crate::io::FileStatus;
+use crate::spec::{ManifestFile, ManifestList, Snapshot};
+use crate::table::FileStoreTable;
+
+/// Directories of a table holding metadata which is never referenced by a snapshot.
+const METADATA_DIRS: [&str; 4] = ["schema", "snapshot", "tag", "consumer"];
+
+/// Scanner of the orphan files of a table: the files under the table directory which are not
+/// referenced by any retained snapshot, the snapshots of the table and the snapshots of its tags.
+///
+/// Manifest lists, manifest files, index manifests, index files and data files are checked,
+/// the schema, snapshot, tag and consumer directories are skipped. Files are matched by name,
+/// which is unique within a table.
+///
+/// A file written by an in-progress commit is not referenced yet, use
+/// [`OrphanFileScanner::with_older_than`] to only report files older than any running commit.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/operation/OrphanFilesClean.java>
+#[derive(Debug, Clone)]
+pub struct OrphanFileScanner {
+    table: FileStoreTable,
+    older_than: Option<DateTime<Utc>>,
+}
+
+impl OrphanFileScanner {
+    pub fn new(table: FileStoreTable) -> Self {
+        Self {
+            table,
+            older_than: None,
+        }
+    }
+
+    /// Only report files last modified before `older_than`.
+    ///
+    /// Files whose modification time is unknown to the storage are never reported then.
+    pub fn with_older_than(mut self, older_than: DateTime<Utc>) -> Self {
+        self.older_than = Some(older_than);
+        self
+    }
+
+    /// List the orphan files of the table, sorted by path.
+    pub async fn scan(&self) -> crate::Result<Vec<FileStatus>> {
+        let referenced = self.referenced_files().await?;
+        let mut orphans = self
+            .list_files()
+            .await?
+            .into_iter()
+            .filter(|status| {
+                let name = status.path.rsplit('/').next().unwrap_or_default();
+                !referenced.contains(name)
+            })
+            .filter(|status| match (self.older_than, status.last_modified) {
+                (None, _) => true,
+                (Some(older_than), Some(last_modified)) => last_modified < older_than,
+                (Some(_), None) => false,
+            })
+            .collect::<Vec<_>>();
+        orphans.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(orphans)
+    }
+
+    /// Collect the names of the files referenced by the snapshots and the tags of the table.
+    async fn referenced_files(&self) -> crate::Result<HashSet<String>> {
+        let snapshot_manager = self.table.snapshot_manager();
+        let tag_manager = self.table.tag_manager();
+        let mut snapshots = HashMap::new();
+        for snapshot_id in snapshot_manager.list_snapshot_ids().await? {
+            snapshots.insert(snapshot_id, snapshot_manager.snapshot(snapshot_id).await?);
+        }
+        for tag_name in tag_manager.list_tag_names().await? {
+            let snapshot = tag_manager.tag(&tag_name).await?.snapshot().clone();
+            snapshots.entry(snapshot.id()).or_insert(snapshot);
+        }
+
+        let mut referenced = HashSet::new();
+        for snapshot in snapshots.values() {
+            self.add_snapshot_files(snapshot, &mut referenced).await?;
+        }
+        Ok(referenced)
+    }
+
+    /// Add the names of the files referenced by a snapshot, including the data files of its
+    /// deleted entries, which may still be read by an older snapshot.
+    async fn add_snapshot_files(
+        &self,
+        snapshot: &Snapshot,
+        referenced: &mut HashSet<String>,
+    ) -> crate::Result<()> {
+        let path_factory = self.table.path_factory();
+        let manifest_list = ManifestList::new(self.table.file_io().clone());
+        let manifest_file = ManifestFile::new(self.table.file_io().clone());
+
+        let lists = [
+            Some(snapshot.base_manifest_list()),
+            Some(snapshot.delta_manifest_list()),
+            snapshot.changelog_manifest_list(),
+        ];
+        for list in lists.into_iter().flatten() {
+            referenced.insert(list.to_string());
+            let metas = manifest_list
+                .read(&path_factory.manifest_list_path(list))
+                .await?;
+            for meta in metas {
+                let entries = manifest_file
+                    .read(&path_factory.manifest_path(meta.file_name()))
+                    .await?;
+                referenced.insert(meta.file_name().to_string());
+                for entry in entries {
+                    referenced.insert(entry.file_name().to_string());
+                    referenced.extend(entry.file().extra_files().iter().cloned());
+                }
+            }
+        }
+
+        if let Some(statistics) = snapshot.statistics() {
+            referenced.insert(statistics.to_string());
+        }
+        if let Some(index_manifest) = snapshot.index_manifest() {
+            referenced.insert(index_manifest.to_string());
+            for entry in self.table.index_entries(snapshot).await? {
+                referenced.insert(entry.index_file().file_name().to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// List the files under the table directory, outside of the metadata directories.
+    async fn list_files(&self) -> crate::Result<Vec<FileStatus>> {
+        let file_io = self.table.file_io();
+        let root = format!("{}/", self.table.location());
+        if !file_io.exists(&root).await? {
+            return Ok(vec![]);
+        }
+
+        let mut files = Vec::new();
+        let mut dirs = vec![root.clone()];
+        while let Some(dir) = dirs.pop() {
+            for status in file_io.list_status(&dir).await? {
+                if !status.is_dir {
+                    files.push(status);
+                    continue;
+                }
+                let path = format!("{}/", status.path.trim_end_matches('/'));
+                let name = path[..path.len() - 1]
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or_default();
+                if dir == root && METADATA_DIRS.contains(&name) {
+                    continue;
+                }
+                dirs.push(path);
+            }
+        }
+        Ok(files)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::spec::{DataField, DataType, FileKind, IntType, EMPTY_BINARY_ROW};
+    use crate::test_util::{commit, data_file, entry, new_table};
+
+    #[tokio::test]
+    async fn test_scan_orphan_files() -> crate::Result<()> {
+        let fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        let table = new_table("memory:/tmp/test_scan_orphan_files", fields, vec![]);
+        let path_factory = table.path_factory();
+        let file_io = table.file_io();
+        for name in ["data-a.parquet", "data-b.parquet", "data-orphan.parquet"] {
+            file_io
+                .new_output(&path_factory.data_file_path("", 0, name))?
+                .write(Bytes::from_static(b"data"))
+                .await?;
+        }
+        let orphan_manifest = path_factory.manifest_path("manifest-orphan-0");
+        let orphan_statistics = path_factory.statistics_path("stat-orphan-0");
+        for path in [
+            orphan_manifest.clone(),
+            orphan_statistics.clone(),
+            path_factory.statistics_path("stat-2"),
+        ] {
+            file_io
+                .new_output(&path)?
+                .write(Bytes::from_static(b"metadata"))
+                .await?;
+        }
+
+        let add = |name: &str| {
+            entry(
+                FileKind::Add,
+                EMPTY_BINARY_ROW.to_bytes(),
+                data_file(name, 1, 4, 0),
+            )
+        };
+        commit(&table, 1, vec![], vec![add("data-a.parquet")]).await?;
+        // the second snapshot rewrites the base without data-a.parquet
+        commit(&table, 2, vec![add("data-b.parquet")], vec![]).await?;
+        // the second snapshot references its statistics file
+        let snapshot_path = table.snapshot_manager().snapshot_path(2);
+        let mut snapshot: serde_json::Value =
+            serde_json::from_str(&file_io.read_to_string(&snapshot_path).await?).unwrap();
+        snapshot["statistics"] = "stat-2".into();
+        file_io
+            .new_output(&snapshot_path)?
+            .write(Bytes::from(snapshot.to_string()))
+            .await?;
+        // snapshot 1 is expired but retained by a tag
+        table.create_tag("t1", 1).await?;
+        file_io
+            .delete_file(&table.snapshot_manager().snapshot_path(1))
+            .await?;
+
+        let orphans = OrphanFileScanner::new(table.clone()).scan().await?;
+        let mut paths = orphans
+            .iter()
+            .map(|status| status.path.as_str())
+            .collect::<Vec<_>>();
+        paths.sort();
+        let orphan_data = path_factory.data_file_path("", 0, "data-orphan.parquet");
+        assert_eq!(
+            paths,
+            vec![
+                orphan_data.as_str(),
+                orphan_manifest.as_str(),
+                orphan_statistics.as_str()
+            ]
+        );
+
+        // the modification time of memory files is unknown, they are never old enough
+        let orphans = OrphanFileScanner::new(table)
+            .with_older_than(Utc::now())
+            .scan()
+            .await?;
+        assert!(orphans.is_empty());
+        Ok(())
+    }
+}
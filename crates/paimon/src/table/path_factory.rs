@@ -0,0 +1,207 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+pub const SCHEMA_PREFIX: &str = "schema-";
+pub const SNAPSHOT_PREFIX: &str = "snapshot-";
+pub const BUCKET_PATH_PREFIX: &str = "bucket-";
+pub const TAG_PREFIX: &str = "tag-";
+pub const CONSUMER_PREFIX: &str = "consumer-";
+
+/// Factory of the paths of the files of a table, relative to the table root:
+///
+/// ```text
+/// {root}/schema/schema-{id}
+/// {root}/snapshot/snapshot-{id}
+/// {root}/tag/tag-{name}
+/// {root}/consumer/consumer-{id}
+/// {root}/manifest/{manifest file or manifest list}
+/// {root}/index/{index file}
+/// {root}/statistics/{statistics file}
+/// {root}/{partition path}bucket-{bucket}/{data file}
+/// ```
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/utils/FileStorePathFactory.java>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathFactory {
+    root: String,
+}
+
+impl PathFactory {
+    pub fn new(root: impl ToString) -> Self {
+        Self {
+            root: root.to_string().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Get the root of the table.
+    #[inline]
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    pub fn schema_dir(&self) -> String {
+        format!("{}/schema", self.root)
+    }
+
+    pub fn schema_path(&self, schema_id: i64) -> String {
+        format!("{}/{}{}", self.schema_dir(), SCHEMA_PREFIX, schema_id)
+    }
+
+    pub fn snapshot_dir(&self) -> String {
+        format!("{}/snapshot", self.root)
+    }
+
+    pub fn snapshot_path(&self, snapshot_id: i64) -> String {
+        format!("{}/{}{}", self.snapshot_dir(), SNAPSHOT_PREFIX, snapshot_id)
+    }
+
+    pub fn tag_dir(&self) -> String {
+        format!("{}/tag", self.root)
+    }
+
+    pub fn tag_path(&self, tag_name: &str) -> String {
+        format!("{}/{}{}", self.tag_dir(), TAG_PREFIX, tag_name)
+    }
+
+    pub fn consumer_dir(&self) -> String {
+        format!("{}/consumer", self.root)
+    }
+
+    pub fn consumer_path(&self, consumer_id: &str) -> String {
+        format!("{}/{}{}", self.consumer_dir(), CONSUMER_PREFIX, consumer_id)
+    }
+
+    pub fn manifest_dir(&self) -> String {
+        format!("{}/manifest", self.root)
+    }
+
+    pub fn manifest_path(&self, file_name: &str) -> String {
+        format!("{}/{}", self.manifest_dir(), file_name)
+    }
+
+    /// Manifest lists live in the manifest directory, next to the manifest files.
+    pub fn manifest_list_path(&self, file_name: &str) -> String {
+        self.manifest_path(file_name)
+    }
+
+    /// Index manifests live in the manifest directory, next to the manifest files.
+    pub fn index_manifest_path(&self, file_name: &str) -> String {
+        self.manifest_path(file_name)
+    }
+
+    pub fn index_dir(&self) -> String {
+        format!("{}/index", self.root)
+    }
+
+    pub fn index_path(&self, file_name: &str) -> String {
+        format!("{}/{}", self.index_dir(), file_name)
+    }
+
+    pub fn statistics_dir(&self) -> String {
+        format!("{}/statistics", self.root)
+    }
+
+    pub fn statistics_path(&self, file_name: &str) -> String {
+        format!("{}/{}", self.statistics_dir(), file_name)
+    }
+
+    /// Get the directory of a bucket.
+    ///
+    /// `partition_path` is the relative path of the partition, like `dt=2024-01-01/hr=10/`, and
+    /// is empty for an unpartitioned table. The files of a table in unaware bucket mode,
+    /// `bucket = -1`, are in bucket 0.
+    pub fn bucket_path(&self, partition_path: &str, bucket: i32) -> String {
+        let partition_path = partition_path.trim_matches('/');
+        if partition_path.is_empty() {
+            format!("{}/{}{}", self.root, BUCKET_PATH_PREFIX, bucket)
+        } else {
+            format!(
+                "{}/{}/{}{}",
+                self.root, partition_path, BUCKET_PATH_PREFIX, bucket
+            )
+        }
+    }
+
+    pub fn data_file_path(&self, partition_path: &str, bucket: i32, file_name: &str) -> String {
+        format!("{}/{}", self.bucket_path(partition_path, bucket), file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_paths() {
+        let factory = PathFactory::new("s3://bucket/warehouse/db.db/t/");
+        assert_eq!(factory.root(), "s3://bucket/warehouse/db.db/t");
+        assert_eq!(
+            factory.schema_path(1),
+            "s3://bucket/warehouse/db.db/t/schema/schema-1"
+        );
+        assert_eq!(
+            factory.snapshot_dir(),
+            "s3://bucket/warehouse/db.db/t/snapshot"
+        );
+        assert_eq!(
+            factory.snapshot_path(3),
+            "s3://bucket/warehouse/db.db/t/snapshot/snapshot-3"
+        );
+        assert_eq!(
+            factory.tag_path("daily"),
+            "s3://bucket/warehouse/db.db/t/tag/tag-daily"
+        );
+        assert_eq!(
+            factory.manifest_path("manifest-abc-0"),
+            "s3://bucket/warehouse/db.db/t/manifest/manifest-abc-0"
+        );
+        assert_eq!(
+            factory.manifest_list_path("manifest-list-abc-0"),
+            "s3://bucket/warehouse/db.db/t/manifest/manifest-list-abc-0"
+        );
+        assert_eq!(
+            factory.index_manifest_path("index-manifest-abc-0"),
+            "s3://bucket/warehouse/db.db/t/manifest/index-manifest-abc-0"
+        );
+        assert_eq!(
+            factory.index_path("index-abc-0"),
+            "s3://bucket/warehouse/db.db/t/index/index-abc-0"
+        );
+    }
+
+    #[test]
+    fn test_bucket_paths() {
+        let factory = PathFactory::new("file:/tmp/t");
+        assert_eq!(factory.bucket_path("", 0), "file:/tmp/t/bucket-0");
+        assert_eq!(
+            factory.bucket_path("dt=2024-01-01/hr=10/", 2),
+            "file:/tmp/t/dt=2024-01-01/hr=10/bucket-2"
+        );
+        assert_eq!(
+            factory.bucket_path("dt=2024-01-01", 2),
+            "file:/tmp/t/dt=2024-01-01/bucket-2"
+        );
+        assert_eq!(
+            factory.data_file_path("", 1, "data-abc-0.parquet"),
+            "file:/tmp/t/bucket-1/data-abc-0.parquet"
+        );
+        assert_eq!(
+            factory.data_file_path("p=1/", 1, "data-abc-0.parquet"),
+            "file:/tmp/t/p=1/bucket-1/data-abc-0.parquet"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use snafu::ResultExt;
+
+use crate::error::MetadataInvalidSnafu;
+use crate::io::FileIO;
+use crate::spec::TableSchema;
+use crate::table::{PathFactory, SCHEMA_PREFIX};
+
+/// Cache of the schemas of a table by id, read from the schema files and validated on first use.
+///
+/// Clones share the same cache.
+#[derive(Debug, Clone)]
+pub struct SchemaCache {
+    file_io: FileIO,
+    path_factory: PathFactory,
+    schemas: Arc<Mutex<HashMap<i64, Arc<TableSchema>>>>,
+}
+
+impl SchemaCache {
+    pub fn new(file_io: FileIO, table_path: impl ToString) -> Self {
+        Self {
+            file_io,
+            path_factory: PathFactory::new(table_path),
+            schemas: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Put a schema already known into the cache, to avoid reading its file.
+    pub fn with_schema(self, schema: TableSchema) -> Self {
+        self.schemas
+            .lock()
+            .unwrap()
+            .insert(schema.id(), Arc::new(schema));
+        self
+    }
+
+    /// Get the schema with the given id, reading its file if not cached yet.
+    pub async fn schema(&self, schema_id: i64) -> crate::Result<Arc<TableSchema>> {
+        if let Some(schema) = self.schemas.lock().unwrap().get(&schema_id) {
+            return Ok(schema.clone());
+        }
+
+        let path = self.path_factory.schema_path(schema_id);
+        let json = self.file_io.read_to_string(&path).await?;
+        let schema: TableSchema = serde_json::from_str(&json).context(MetadataInvalidSnafu {
+            message: format!("Failed to parse schema '{}'", path),
+        })?;
+        schema.validate()?;
+        let schema = Arc::new(schema);
+        self.schemas
+            .lock()
+            .unwrap()
+            .insert(schema_id, schema.clone());
+        Ok(schema)
+    }
+
+    /// Get the schema with the highest id, `None` if there is no schema file, like for a
+    /// directory which is not a table.
+    pub async fn latest_schema(&self) -> crate::Result<Option<Arc<TableSchema>>> {
+        let dir = format!("{}/", self.path_factory.schema_dir());
+        if !self.file_io.exists(&dir).await? {
+            return Ok(None);
+        }
+        let latest = self
+            .file_io
+            .list_status(&dir)
+            .await?
+            .into_iter()
+            .filter(|status| !status.is_dir)
+            .filter_map(|status| {
+                let name = status.path.rsplit('/').next()?;
+                name.strip_prefix(SCHEMA_PREFIX)?.parse::<i64>().ok()
+            })
+            .max();
+        match latest {
+            Some(schema_id) => Ok(Some(self.schema(schema_id).await?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::io::FileIOBuilder;
+    use crate::spec::{DataField, DataType, IntType};
+
+    #[tokio::test]
+    async fn test_schema_cache() -> crate::Result<()> {
+        let file_io = FileIOBuilder::new("memory").build()?;
+        let location = "memory:/tmp/test_schema_cache";
+        let fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        let schema = TableSchema::new(1, fields, 0, vec![], vec![], HashMap::new(), None);
+        let path = PathFactory::new(location).schema_path(1);
+        file_io
+            .new_output(&path)?
+            .write(Bytes::from(serde_json::to_vec(&schema).unwrap()))
+            .await?;
+
+        let cache = SchemaCache::new(file_io.clone(), location);
+        assert_eq!(cache.schema(1).await?.as_ref(), &schema);
+        // served from the cache once read
+        file_io.delete_file(&path).await?;
+        assert_eq!(cache.clone().schema(1).await?.as_ref(), &schema);
+        assert!(cache.schema(2).await.unwrap_err().is_not_found());
+        Ok(())
+    }
+}
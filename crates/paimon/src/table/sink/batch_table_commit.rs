@@ -0,0 +1,443 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Bytes;
+use snafu::ResultExt;
+
+use crate::error::MetadataInvalidSnafu;
+use crate::spec::{
+    CommitKind, FileKind, ManifestEntry, ManifestFile, ManifestFileMeta, ManifestList, Snapshot,
+    SnapshotCommit,
+};
+use crate::table::sink::{BatchWriteBuilder, CommitConflictChecker, CommitMessage};
+use crate::table::{FileNameGenerator, FileStoreTable};
+use crate::Error;
+
+/// Commit of the files written by a [`BatchTableWrite`](crate::table::sink::BatchTableWrite) as a
+/// new snapshot of the table.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/operation/FileStoreCommitImpl.java>
+#[derive(Debug)]
+pub struct BatchTableCommit {
+    table: FileStoreTable,
+    commit_user: String,
+    file_name_generator: FileNameGenerator,
+}
+
+impl BatchTableCommit {
+    pub fn new(table: FileStoreTable, commit_user: impl ToString) -> Self {
+        Self {
+            table,
+            commit_user: commit_user.to_string(),
+            file_name_generator: FileNameGenerator::new(),
+        }
+    }
+
+    /// Get the user recorded in the committed snapshots.
+    #[inline]
+    pub fn commit_user(&self) -> &str {
+        &self.commit_user
+    }
+
+    /// Commit the files of the messages as a new `APPEND` snapshot.
+    ///
+    /// Nothing is committed if the messages hold no file. If another snapshot is committed
+    /// concurrently, the commit is retried on top of it when they touch disjoint buckets, see
+    /// [`CommitConflictChecker`], and fails with [`Error::CommitConflict`] otherwise.
+    pub async fn commit(&self, messages: Vec<CommitMessage>) -> crate::Result<()> {
+        let total_buckets = self.table.options().bucket()?;
+        let entries: Vec<ManifestEntry> = messages
+            .iter()
+            .flat_map(|message| {
+                message.new_files().iter().map(|file| {
+                    ManifestEntry::new(
+                        FileKind::Add,
+                        message.partition().to_vec(),
+                        message.bucket(),
+                        total_buckets,
+                        file.clone(),
+                        ManifestEntry::VERSION,
+                    )
+                })
+            })
+            .collect();
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let latest = self.table.snapshot_manager().latest_snapshot().await?;
+        self.commit_entries(&entries, latest).await
+    }
+
+    /// Commit the entries on top of `latest`, retrying on top of the snapshots committed
+    /// concurrently which don't conflict with them.
+    async fn commit_entries(
+        &self,
+        entries: &[ManifestEntry],
+        mut latest: Option<Snapshot>,
+    ) -> crate::Result<()> {
+        let file_io = self.table.file_io();
+        let path_factory = self.table.path_factory();
+        let snapshot_manager = self.table.snapshot_manager();
+        let manifest_file = ManifestFile::new(file_io.clone());
+        let manifest_list = ManifestList::new(file_io.clone());
+        let schema_id = self.table.schema().id();
+
+        let manifests = manifest_file
+            .write_rolling(
+                entries,
+                schema_id,
+                self.table.options().manifest_target_file_size()?,
+                || path_factory.manifest_path(&self.file_name_generator.new_manifest_file_name()),
+            )
+            .await?;
+        let delta_manifest_list = self.file_name_generator.new_manifest_list_name();
+        manifest_list
+            .write(
+                &path_factory.manifest_list_path(&delta_manifest_list),
+                &manifests,
+            )
+            .await?;
+        let delta_record_count: i64 = entries.iter().map(|entry| entry.file().row_count()).sum();
+
+        loop {
+            // the base of the new snapshot holds all manifests of the latest snapshot
+            let mut base_manifests: Vec<ManifestFileMeta> = Vec::new();
+            if let Some(latest) = &latest {
+                for list in [latest.base_manifest_list(), latest.delta_manifest_list()] {
+                    base_manifests.extend(
+                        manifest_list
+                            .read(&path_factory.manifest_list_path(list))
+                            .await?,
+                    );
+                }
+            }
+            let base_manifest_list = self.file_name_generator.new_manifest_list_name();
+            let base_manifest_list_path = path_factory.manifest_list_path(&base_manifest_list);
+            manifest_list
+                .write(&base_manifest_list_path, &base_manifests)
+                .await?;
+
+            let snapshot =
+                SnapshotCommit::new(&self.commit_user, BatchWriteBuilder::COMMIT_IDENTIFIER).build(
+                    latest.as_ref(),
+                    schema_id,
+                    base_manifest_list,
+                    delta_manifest_list.clone(),
+                    CommitKind::APPEND,
+                    delta_record_count,
+                );
+            let snapshot_id = snapshot.id();
+
+            // Not atomic: a concurrent commit between the check and the write is not detected.
+            let snapshot_path = path_factory.snapshot_path(snapshot_id);
+            if !file_io.exists(&snapshot_path).await? {
+                let bytes = serde_json::to_vec(&snapshot).context(MetadataInvalidSnafu {
+                    message: format!("Failed to serialize snapshot {}", snapshot_id),
+                })?;
+                file_io
+                    .new_output(&snapshot_path)?
+                    .write(Bytes::from(bytes))
+                    .await?;
+                return snapshot_manager.commit_latest_hint(snapshot_id).await;
+            }
+
+            // another commit took the snapshot id, retry on top of it if it changed other buckets
+            file_io.delete_file(&base_manifest_list_path).await?;
+            let winner = snapshot_manager.snapshot(snapshot_id).await?;
+            if CommitConflictChecker::conflicts(entries, &self.delta_entries(&winner).await?) {
+                return Err(Error::CommitConflict {
+                    message: format!(
+                        "Snapshot {} is already committed with changes to the same buckets",
+                        snapshot_id
+                    ),
+                });
+            }
+            latest = Some(winner);
+        }
+    }
+
+    /// Read the entries of the delta manifests of a snapshot, the changes of its commit.
+    async fn delta_entries(&self, snapshot: &Snapshot) -> crate::Result<Vec<ManifestEntry>> {
+        let path_factory = self.table.path_factory();
+        let manifest_file = ManifestFile::new(self.table.file_io().clone());
+        let metas = ManifestList::new(self.table.file_io().clone())
+            .read(&path_factory.manifest_list_path(snapshot.delta_manifest_list()))
+            .await?;
+        let mut entries = Vec::new();
+        for meta in metas {
+            entries.extend(
+                manifest_file
+                    .read(&path_factory.manifest_path(meta.file_name()))
+                    .await?,
+            );
+        }
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::{Int32Array, RecordBatch, StringArray};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use crate::io::FileIOBuilder;
+    use crate::spec::{
+        CoreOptions, DataField, DataType, FileKind, IntType, TableSchema, VarCharType,
+        EMPTY_BINARY_ROW,
+    };
+    use crate::table::sink::BucketKeyExtractor;
+    use crate::table::FileStoreTable;
+    use crate::test_util::{bucket_entry, data_file};
+    use crate::Error;
+
+    fn new_table(location: &str, options: HashMap<String, String>) -> FileStoreTable {
+        let fields = vec![
+            DataField::new(0, "id".to_string(), DataType::Int(IntType::new())),
+            DataField::new(
+                1,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+        ];
+        let schema = TableSchema::new(0, fields, 1, vec![], vec![], options, None);
+        let file_io = FileIOBuilder::new("memory").build().unwrap();
+        FileStoreTable::new(file_io, location, schema)
+    }
+
+    fn write_options() -> HashMap<String, String> {
+        HashMap::from([
+            (CoreOptions::BUCKET.to_string(), "1".to_string()),
+            (CoreOptions::FILE_FORMAT.to_string(), "parquet".to_string()),
+        ])
+    }
+
+    fn batch(write: &crate::table::sink::BatchTableWrite, ids: Vec<i32>) -> RecordBatch {
+        let names: Vec<String> = ids.iter().map(|id| format!("name-{}", id)).collect();
+        RecordBatch::try_new(
+            write.schema().clone(),
+            vec![
+                Arc::new(Int32Array::from(ids)),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_write_and_scan() -> crate::Result<()> {
+        let table = new_table("memory:/tmp/test_write_and_scan", write_options());
+
+        // first commit with two batches
+        let builder = table.new_batch_write_builder();
+        let mut write = builder.new_write()?;
+        write.write(&batch(&write, vec![1, 2, 3])).await?;
+        write.write(&batch(&write, vec![4, 5])).await?;
+        let messages = write.prepare_commit().await?;
+        assert_eq!(messages.len(), 1);
+        builder.new_commit().commit(messages).await?;
+
+        // second commit continues the sequence numbers of the bucket
+        let builder = table.new_batch_write_builder();
+        let mut write = builder.new_write()?;
+        write.write(&batch(&write, vec![6])).await?;
+        builder
+            .new_commit()
+            .commit(write.prepare_commit().await?)
+            .await?;
+
+        let snapshot = table.snapshot_manager().latest_snapshot().await?.unwrap();
+        assert_eq!(snapshot.id(), 2);
+        assert_eq!(snapshot.total_record_count(), Some(6));
+        assert_eq!(snapshot.delta_record_count(), Some(1));
+
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(plan.snapshot_id(), Some(2));
+        assert_eq!(plan.splits().len(), 1);
+        let split = &plan.splits()[0];
+        assert_eq!(split.bucket(), 0);
+        assert_eq!(split.row_count(), 6);
+        // the data files are ordered by their random names
+        let mut sequence_numbers: Vec<(i64, i64)> = split
+            .data_files()
+            .iter()
+            .map(|file| (file.min_sequence_number(), file.max_sequence_number()))
+            .collect();
+        sequence_numbers.sort_unstable();
+        assert_eq!(sequence_numbers, vec![(0, 4), (5, 5)]);
+
+        let mut ids = Vec::new();
+        for file in split.data_files() {
+            let bytes = table
+                .file_io()
+                .new_input(&split.data_file_path(file))?
+                .read()
+                .await?;
+            for batch in ParquetRecordBatchReaderBuilder::try_new(bytes)?.build()? {
+                let batch = batch?;
+                let column = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap();
+                ids.extend(column.values().iter().copied());
+            }
+        }
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5, 6]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_fixed_buckets() -> crate::Result<()> {
+        let mut options = write_options();
+        options.insert(CoreOptions::BUCKET.to_string(), "3".to_string());
+        options.insert(CoreOptions::BUCKET_KEY.to_string(), "id".to_string());
+        let table = new_table("memory:/tmp/test_write_fixed_buckets", options);
+
+        let builder = table.new_batch_write_builder();
+        let mut write = builder.new_write()?;
+        let batch = batch(&write, (0..20).collect());
+        write.write(&batch).await?;
+        builder
+            .new_commit()
+            .commit(write.prepare_commit().await?)
+            .await?;
+
+        let extractor = BucketKeyExtractor::new(table.schema(), &table.options())?;
+        let mut expected_buckets = (0..batch.num_rows())
+            .map(|row| {
+                Ok(BucketKeyExtractor::bucket(
+                    &extractor.extract(&batch, row)?,
+                    3,
+                ))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        expected_buckets.sort_unstable();
+        expected_buckets.dedup();
+
+        let plan = table.new_scan().plan().await?;
+        let mut buckets: Vec<i32> = plan.splits().iter().map(|split| split.bucket()).collect();
+        buckets.sort_unstable();
+        assert_eq!(buckets, expected_buckets);
+        assert!(buckets.len() > 1);
+        assert!(plan.splits().iter().all(|split| split.total_buckets() == 3));
+        let rows: i64 = plan.splits().iter().map(|split| split.row_count()).sum();
+        assert_eq!(rows, 20);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_unaware_bucket() -> crate::Result<()> {
+        let mut options = write_options();
+        options.insert(CoreOptions::BUCKET.to_string(), "-1".to_string());
+        let location = "memory:/tmp/test_write_unaware_bucket";
+        let table = new_table(location, options);
+
+        for ids in [vec![1, 2, 3], vec![4, 5]] {
+            let builder = table.new_batch_write_builder();
+            let mut write = builder.new_write()?;
+            write.write(&batch(&write, ids)).await?;
+            builder
+                .new_commit()
+                .commit(write.prepare_commit().await?)
+                .await?;
+        }
+
+        // a single logical bucket, in the bucket-0 directory
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(plan.splits().len(), 1);
+        let split = &plan.splits()[0];
+        assert_eq!(split.bucket(), 0);
+        assert_eq!(split.total_buckets(), -1);
+        assert_eq!(split.row_count(), 5);
+        assert_eq!(split.bucket_path(), format!("{}/bucket-0", location));
+        for file in split.data_files() {
+            let path = split.data_file_path(file);
+            assert_eq!(path, format!("{}/bucket-0/{}", location, file.file_name()));
+            assert!(table.file_io().exists(&path).await?);
+        }
+
+        let mut options = write_options();
+        options.insert(CoreOptions::BUCKET.to_string(), "0".to_string());
+        let table = new_table("memory:/tmp/test_write_bucket_0", options);
+        assert!(matches!(
+            table.new_batch_write_builder().new_write(),
+            Err(Error::Unsupported { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_commit_nothing() -> crate::Result<()> {
+        let table = new_table("memory:/tmp/test_commit_nothing", write_options());
+        let builder = table.new_batch_write_builder();
+        let mut write = builder.new_write()?;
+        builder
+            .new_commit()
+            .commit(write.prepare_commit().await?)
+            .await?;
+        assert!(table.snapshot_manager().latest_snapshot().await?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_concurrent_commit() -> crate::Result<()> {
+        let mut options = write_options();
+        options.insert(CoreOptions::BUCKET.to_string(), "2".to_string());
+        let table = new_table("memory:/tmp/test_retry_concurrent_commit", options);
+        let add = |bucket, name: &str| {
+            let file = data_file(name, 1, 1, 0);
+            bucket_entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), bucket, 2, file)
+        };
+        let commit = table.new_batch_write_builder().new_commit();
+        commit.commit_entries(&[add(0, "data-0")], None).await?;
+
+        // committed without seeing snapshot 1, which changed another bucket
+        commit.commit_entries(&[add(1, "data-1")], None).await?;
+        let snapshot = table.snapshot_manager().latest_snapshot().await?.unwrap();
+        assert_eq!(snapshot.id(), 2);
+        assert_eq!(snapshot.total_record_count(), Some(2));
+        let plan = table.new_scan().plan().await?;
+        let mut buckets = plan.splits().iter().map(|s| s.bucket()).collect::<Vec<_>>();
+        buckets.sort_unstable();
+        assert_eq!(buckets, vec![0, 1]);
+
+        // committed without seeing snapshot 2, which changed the same bucket
+        let latest = table.snapshot_manager().snapshot(1).await?;
+        let result = commit
+            .commit_entries(&[add(1, "data-2")], Some(latest))
+            .await;
+        assert!(matches!(result, Err(Error::CommitConflict { .. })));
+        assert_eq!(
+            table.snapshot_manager().latest_snapshot_id().await?,
+            Some(2)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_table() {
+        let table = new_table("memory:/tmp/test_unsupported_table", HashMap::new());
+        assert!(matches!(
+            table.new_batch_write_builder().new_write(),
+            Err(Error::Unsupported { .. })
+        ));
+    }
+}
@@ -0,0 +1,202 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, UInt32Array};
+use arrow_schema::SchemaRef;
+use arrow_select::take::take_record_batch;
+
+use crate::append::AppendOnlyWriter;
+use crate::arrow::to_arrow_schema;
+use crate::spec::EMPTY_BINARY_ROW;
+use crate::table::sink::{BucketKeyExtractor, CommitMessage};
+use crate::table::{FileNameGenerator, FileStoreTable};
+use crate::Error;
+
+/// The `bucket` option of append-only tables in unaware bucket mode.
+const UNAWARE_BUCKET: i32 = -1;
+
+/// Write of batches into the data files of a table.
+///
+/// Only unpartitioned append-only tables can be written for now, and only into parquet files.
+/// With a fixed number of buckets, the rows are distributed to the buckets by their bucket key.
+/// With `bucket = -1`, the unaware bucket mode, the table has a single logical bucket and all
+/// rows are written to bucket 0, whose directory is `bucket-0` as in Paimon.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/sink/TableWriteImpl.java>
+pub struct BatchTableWrite {
+    table: FileStoreTable,
+    schema: SchemaRef,
+    file_format: String,
+    target_file_size: u64,
+    num_buckets: i32,
+    /// extractor of the bucket keys, `None` if the table has a single bucket
+    bucket_key_extractor: Option<BucketKeyExtractor>,
+    file_name_generator: Arc<FileNameGenerator>,
+    /// writers by serialized partition and bucket
+    writers: BTreeMap<(Vec<u8>, i32), AppendOnlyWriter>,
+}
+
+impl BatchTableWrite {
+    pub fn new(table: FileStoreTable) -> crate::Result<Self> {
+        let schema = table.schema();
+        if !schema.primary_keys().is_empty() {
+            return Err(Error::Unsupported {
+                message: "Writing tables with primary keys is not supported yet".to_string(),
+            });
+        }
+        if !schema.partition_keys().is_empty() {
+            return Err(Error::Unsupported {
+                message: "Writing partitioned tables is not supported yet".to_string(),
+            });
+        }
+        let options = table.options();
+        let num_buckets = options.bucket()?;
+        if num_buckets < 1 && num_buckets != UNAWARE_BUCKET {
+            return Err(Error::Unsupported {
+                message: format!(
+                    "Writing tables with bucket {} is not supported, only a fixed number of buckets or {} is supported",
+                    num_buckets, UNAWARE_BUCKET
+                ),
+            });
+        }
+        let bucket_key_extractor = if num_buckets > 1 {
+            Some(BucketKeyExtractor::new(schema, &options)?)
+        } else {
+            None
+        };
+        let file_format = options.file_format();
+        if file_format != "parquet" {
+            return Err(Error::Unsupported {
+                message: format!(
+                    "Writing {} files is not supported yet, only parquet is supported",
+                    file_format
+                ),
+            });
+        }
+
+        let target_file_size = options.target_file_size()?;
+
+        Ok(Self {
+            schema: Arc::new(to_arrow_schema(schema.fields())?),
+            table,
+            file_format,
+            target_file_size,
+            num_buckets,
+            bucket_key_extractor,
+            file_name_generator: Arc::new(FileNameGenerator::new()),
+            writers: BTreeMap::new(),
+        })
+    }
+
+    /// Get the Arrow schema of the batches to write.
+    #[inline]
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Write a batch into the table.
+    ///
+    /// The columns of the batch are matched with the fields of the table by position, and must
+    /// have the names and the Arrow types of the fields, see
+    /// [`TableSchema::validate_batch`](crate::spec::TableSchema::validate_batch).
+    pub async fn write(&mut self, batch: &RecordBatch) -> crate::Result<()> {
+        self.table.schema().validate_batch(batch)?;
+        let batch = RecordBatch::try_new(self.schema.clone(), batch.columns().to_vec())?;
+        let partition = EMPTY_BINARY_ROW.to_bytes();
+        for (bucket, batch) in self.split_by_bucket(batch)? {
+            self.writer(partition.clone(), bucket)
+                .await?
+                .write(&batch)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Split a batch into the rows of each bucket, in the order of the buckets.
+    fn split_by_bucket(&self, batch: RecordBatch) -> crate::Result<Vec<(i32, RecordBatch)>> {
+        let Some(extractor) = &self.bucket_key_extractor else {
+            return Ok(vec![(0, batch)]);
+        };
+
+        let mut rows_by_bucket: BTreeMap<i32, Vec<u32>> = BTreeMap::new();
+        for row in 0..batch.num_rows() {
+            let bucket_key = extractor.extract(&batch, row)?;
+            let bucket = BucketKeyExtractor::bucket(&bucket_key, self.num_buckets);
+            rows_by_bucket.entry(bucket).or_default().push(row as u32);
+        }
+        rows_by_bucket
+            .into_iter()
+            .map(|(bucket, rows)| {
+                let indices = UInt32Array::from(rows);
+                Ok((bucket, take_record_batch(&batch, &indices)?))
+            })
+            .collect()
+    }
+
+    async fn writer(
+        &mut self,
+        partition: Vec<u8>,
+        bucket: i32,
+    ) -> crate::Result<&mut AppendOnlyWriter> {
+        let key = (partition, bucket);
+        if !self.writers.contains_key(&key) {
+            let writer = self.new_writer(&key.0, key.1).await?;
+            self.writers.insert(key.clone(), writer);
+        }
+        Ok(self.writers.get_mut(&key).expect("writer must exist"))
+    }
+
+    /// Create the writer of a bucket, continuing after the sequence numbers of its files in
+    /// the latest snapshot.
+    async fn new_writer(&self, partition: &[u8], bucket: i32) -> crate::Result<AppendOnlyWriter> {
+        let plan = self.table.new_scan().plan().await?;
+        let max_sequence_number = plan
+            .splits()
+            .iter()
+            .filter(|split| split.partition() == partition && split.bucket() == bucket)
+            .flat_map(|split| split.data_files())
+            .map(|file| file.max_sequence_number())
+            .max()
+            .unwrap_or(-1);
+        Ok(AppendOnlyWriter::new(
+            self.table.file_io().clone(),
+            self.table.path_factory().bucket_path("", bucket),
+            self.schema.clone(),
+            self.table.schema().id(),
+            &self.file_format,
+            self.file_name_generator.clone(),
+            max_sequence_number,
+        )
+        .with_target_file_size(self.target_file_size))
+    }
+
+    /// Close the data files in progress and return the files written since the last call, one
+    /// message per written bucket.
+    pub async fn prepare_commit(&mut self) -> crate::Result<Vec<CommitMessage>> {
+        let mut messages = Vec::new();
+        for ((partition, bucket), writer) in self.writers.iter_mut() {
+            let new_files = writer.prepare_commit().await?;
+            if !new_files.is_empty() {
+                messages.push(CommitMessage::new(partition.clone(), *bucket, new_files));
+            }
+        }
+        Ok(messages)
+    }
+}
@@ -0,0 +1,72 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::table::sink::{BatchTableCommit, BatchTableWrite, CommitUser};
+use crate::table::FileStoreTable;
+
+/// Builder of the write and the commit of a batch write of a table.
+///
+/// The write and the commit of a builder share the same commit user.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/sink/BatchWriteBuilder.java>
+#[derive(Debug, Clone)]
+pub struct BatchWriteBuilder {
+    table: FileStoreTable,
+    commit_user: CommitUser,
+}
+
+impl BatchWriteBuilder {
+    /// Commit identifier of all batch commits.
+    pub const COMMIT_IDENTIFIER: i64 = i64::MAX;
+
+    pub fn new(table: FileStoreTable) -> Self {
+        Self {
+            commit_user: CommitUser::generate(&table.options()),
+            table,
+        }
+    }
+
+    /// Use the given commit user rather than a generated one.
+    pub fn with_commit_user(mut self, commit_user: CommitUser) -> Self {
+        self.commit_user = commit_user;
+        self
+    }
+
+    /// Get the table to write.
+    #[inline]
+    pub fn table(&self) -> &FileStoreTable {
+        &self.table
+    }
+
+    /// Get the user of the commits, generated by [`CommitUser::generate`] unless given.
+    #[inline]
+    pub fn commit_user(&self) -> &str {
+        self.commit_user.as_str()
+    }
+
+    /// Create a write of the table.
+    ///
+    /// Fails if the table can not be written yet.
+    pub fn new_write(&self) -> crate::Result<BatchTableWrite> {
+        BatchTableWrite::new(self.table.clone())
+    }
+
+    /// Create a commit of the table.
+    pub fn new_commit(&self) -> BatchTableCommit {
+        BatchTableCommit::new(self.table.clone(), self.commit_user.as_str())
+    }
+}
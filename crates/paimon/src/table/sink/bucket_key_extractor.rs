@@ -0,0 +1,177 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_array::RecordBatch;
+
+use crate::arrow::to_literal;
+use crate::spec::{BinaryRow, BinaryRowWriter, CoreOptions, DataType, TableSchema};
+use crate::Error;
+
+/// Extractor of the bucket key of the rows to write into a table with a fixed number of buckets.
+///
+/// The bucket key is made of the fields of the `bucket-key` option if set, otherwise of the
+/// primary keys without the partition keys.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/sink/FixedBucketRowKeyExtractor.java>
+#[derive(Debug, Clone)]
+pub struct BucketKeyExtractor {
+    /// positions of the bucket key fields in the table fields
+    positions: Vec<usize>,
+    types: Vec<DataType>,
+}
+
+impl BucketKeyExtractor {
+    pub fn new(schema: &TableSchema, options: &CoreOptions) -> crate::Result<Self> {
+        let bucket_keys = options.bucket_key_fields(schema)?;
+        if bucket_keys.is_empty() {
+            return Err(Error::ConfigInvalid {
+                message: format!(
+                    "A table with a fixed number of buckets requires the '{}' option or primary keys",
+                    CoreOptions::BUCKET_KEY
+                ),
+            });
+        }
+
+        let mut positions = Vec::with_capacity(bucket_keys.len());
+        let mut types = Vec::with_capacity(bucket_keys.len());
+        for key in &bucket_keys {
+            let pos = schema
+                .fields()
+                .iter()
+                .position(|field| field.name() == key)
+                .ok_or_else(|| Error::ConfigInvalid {
+                    message: format!("Bucket key '{}' is not a field of the table", key),
+                })?;
+            positions.push(pos);
+            types.push(schema.fields()[pos].data_type().clone());
+        }
+        Ok(Self { positions, types })
+    }
+
+    /// Extract the bucket key of a row of a batch with the fields of the table.
+    pub fn extract(&self, batch: &RecordBatch, row: usize) -> crate::Result<BinaryRow> {
+        let mut writer = BinaryRowWriter::new(self.positions.len() as i32);
+        for (i, (pos, data_type)) in self.positions.iter().zip(&self.types).enumerate() {
+            let literal = to_literal(batch.column(*pos).as_ref(), row)?;
+            writer.write_literal(i, &literal, data_type)?;
+        }
+        Ok(writer.build())
+    }
+
+    /// Compute the bucket of a bucket key.
+    ///
+    /// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/sink/KeyAndBucketExtractor.java#L56>
+    pub fn bucket(bucket_key: &BinaryRow, num_buckets: i32) -> i32 {
+        (bucket_key.hash_code() % num_buckets).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::{Int64Array, StringArray};
+
+    use super::*;
+    use crate::arrow::to_arrow_schema;
+    use crate::spec::{BigIntType, DataField, IntType, Literal, VarCharType};
+
+    fn schema(primary_keys: Vec<String>) -> TableSchema {
+        let fields = vec![
+            DataField::new(0, "id".to_string(), DataType::BigInt(BigIntType::new())),
+            DataField::new(
+                1,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+            DataField::new(2, "value".to_string(), DataType::Int(IntType::new())),
+        ];
+        TableSchema::new(0, fields, 2, vec![], primary_keys, HashMap::new(), None)
+    }
+
+    fn batch(schema: &TableSchema) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::new(to_arrow_schema(schema.fields()).unwrap()),
+            vec![
+                Arc::new(Int64Array::from(vec![1, -2])),
+                Arc::new(StringArray::from(vec![Some("a-long-name"), None])),
+                Arc::new(arrow_array::Int32Array::from(vec![10, 20])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_extract_primary_keys() {
+        let schema = schema(vec!["id".to_string(), "name".to_string()]);
+        let extractor = BucketKeyExtractor::new(&schema, &CoreOptions::default()).unwrap();
+        let batch = batch(&schema);
+
+        let key = extractor.extract(&batch, 0).unwrap();
+        let key = BinaryRow::from_bytes(&key.to_bytes()).unwrap();
+        assert_eq!(key.arity(), 2);
+        assert_eq!(key.get_long(0), 1);
+        assert_eq!(key.get_string(1).unwrap(), "a-long-name");
+
+        let key = extractor.extract(&batch, 1).unwrap();
+        let key = BinaryRow::from_bytes(&key.to_bytes()).unwrap();
+        let types = [
+            DataType::BigInt(BigIntType::new()),
+            DataType::VarChar(VarCharType::default()),
+        ];
+        assert_eq!(key.get_literal(0, &types[0]).unwrap(), Literal::BigInt(-2));
+        assert_eq!(key.get_literal(1, &types[1]).unwrap(), Literal::Null);
+
+        let bucket = BucketKeyExtractor::bucket(&key, 3);
+        assert!((0..3).contains(&bucket));
+        assert_eq!(bucket, BucketKeyExtractor::bucket(&key.clone(), 3));
+    }
+
+    #[test]
+    fn test_bucket_key_option_overrides_primary_keys() {
+        let schema = schema(vec!["id".to_string()]);
+        let options = CoreOptions::new(HashMap::from([(
+            CoreOptions::BUCKET_KEY.to_string(),
+            "value".to_string(),
+        )]));
+        let extractor = BucketKeyExtractor::new(&schema, &options).unwrap();
+        let key = extractor.extract(&batch(&schema), 1).unwrap();
+        assert_eq!(key.arity(), 1);
+        assert_eq!(key.get_int(0), 20);
+
+        let options = CoreOptions::new(HashMap::from([(
+            CoreOptions::BUCKET_KEY.to_string(),
+            "missing".to_string(),
+        )]));
+        assert!(matches!(
+            BucketKeyExtractor::new(&schema, &options),
+            Err(Error::ConfigInvalid { .. })
+        ));
+        assert!(matches!(
+            BucketKeyExtractor::new(
+                &self::schema(vec!["missing".to_string()]),
+                &CoreOptions::default()
+            ),
+            Err(Error::ConfigInvalid { .. })
+        ));
+        assert!(matches!(
+            BucketKeyExtractor::new(&self::schema(vec![]), &CoreOptions::default()),
+            Err(Error::ConfigInvalid { .. })
+        ));
+    }
+}
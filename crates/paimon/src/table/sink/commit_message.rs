@@ -0,0 +1,57 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::spec::DataFileMeta;
+
+/// The files written into a bucket, to be committed by a
+/// [`BatchTableCommit`](crate::table::sink::BatchTableCommit).
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/sink/CommitMessageImpl.java>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitMessage {
+    /// serialized partition `BinaryRow`
+    partition: Vec<u8>,
+    bucket: i32,
+    new_files: Vec<DataFileMeta>,
+}
+
+impl CommitMessage {
+    pub fn new(partition: Vec<u8>, bucket: i32, new_files: Vec<DataFileMeta>) -> Self {
+        Self {
+            partition,
+            bucket,
+            new_files,
+        }
+    }
+
+    /// Get the serialized partition `BinaryRow` of the written bucket.
+    #[inline]
+    pub fn partition(&self) -> &[u8] {
+        &self.partition
+    }
+
+    #[inline]
+    pub fn bucket(&self) -> i32 {
+        self.bucket
+    }
+
+    /// Get the data files newly written into the bucket.
+    #[inline]
+    pub fn new_files(&self) -> &[DataFileMeta] {
+        &self.new_files
+    }
+}
@@ -0,0 +1,101 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::{Display, Formatter};
+
+use uuid::Uuid;
+
+use crate::spec::CoreOptions;
+
+/// User recording the commits of a write session in their snapshots, to deduplicate the commits
+/// of the session and to tell who wrote a snapshot.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/AbstractFileStoreTable.java>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommitUser(String);
+
+impl CommitUser {
+    /// Use the given user as is.
+    pub fn new(user: impl ToString) -> Self {
+        Self(user.to_string())
+    }
+
+    /// Generate a new user, a random uuid prefixed by `commit.user-prefix` and a `-` if set.
+    pub fn generate(options: &CoreOptions) -> Self {
+        let uuid = Uuid::new_v4();
+        match options.commit_user_prefix() {
+            Some(prefix) => Self(format!("{}-{}", prefix, uuid)),
+            None => Self(uuid.to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for CommitUser {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::test_util::new_table;
+
+    #[test]
+    fn test_generate() {
+        let user = CommitUser::generate(&CoreOptions::default());
+        assert!(Uuid::parse_str(user.as_str()).is_ok());
+        assert_ne!(user, CommitUser::generate(&CoreOptions::default()));
+
+        let options = CoreOptions::new(HashMap::from([(
+            CoreOptions::COMMIT_USER_PREFIX.to_string(),
+            "etl".to_string(),
+        )]));
+        let user = CommitUser::generate(&options);
+        let uuid = user.as_str().strip_prefix("etl-").unwrap();
+        assert!(Uuid::parse_str(uuid).is_ok());
+    }
+
+    #[test]
+    fn test_commit_user_of_session() {
+        let table = new_table("memory:/tmp/test_commit_user_of_session", vec![], vec![]).copy(
+            HashMap::from([(
+                CoreOptions::COMMIT_USER_PREFIX.to_string(),
+                "job".to_string(),
+            )]),
+        );
+        let builder = table.new_batch_write_builder();
+        assert!(builder.commit_user().starts_with("job-"));
+        assert_eq!(
+            builder.new_commit().commit_user(),
+            builder.clone().new_commit().commit_user()
+        );
+        assert_ne!(
+            builder.commit_user(),
+            table.new_batch_write_builder().commit_user()
+        );
+
+        let builder = builder.with_commit_user(CommitUser::new("backfill"));
+        assert_eq!(builder.new_commit().commit_user(), "backfill");
+    }
+}
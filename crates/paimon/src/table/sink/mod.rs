@@ -0,0 +1,48 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Write-side access to a paimon table: write batches into data files, then commit them as a
+//! new snapshot.
+//!
+//! ```ignore
+//! let builder = table.new_batch_write_builder();
+//! let mut write = builder.new_write()?;
+//! write.write(&batch).await?;
+//! let messages = write.prepare_commit().await?;
+//! builder.new_commit().commit(messages).await?;
+//! ```
+
+mod batch_table_commit;
+pub use batch_table_commit::*;
+
+mod batch_table_write;
+pub use batch_table_write::*;
+
+mod batch_write_builder;
+pub use batch_write_builder::*;
+
+mod bucket_key_extractor;
+pub use bucket_key_extractor::*;
+
+mod commit_conflict_checker;
+pub use commit_conflict_checker::*;
+
+mod commit_message;
+pub use commit_message::*;
+
+mod commit_user;
+pub use commit_user::*;
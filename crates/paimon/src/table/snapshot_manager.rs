@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use snafu::ResultExt;
+
+use crate::error::MetadataInvalidSnafu;
+use crate::io::FileIO;
+use crate::spec::Snapshot;
+use crate::table::{PathFactory, SNAPSHOT_PREFIX};
+use crate::Error;
+
+const LATEST: &str = "LATEST";
+
+/// Manager for snapshot files of a table.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/utils/SnapshotManager.java>
+#[derive(Debug, Clone)]
+pub struct SnapshotManager {
+    file_io: FileIO,
+    path_factory: PathFactory,
+}
+
+impl SnapshotManager {
+    pub fn new(file_io: FileIO, table_path: impl ToString) -> Self {
+        Self {
+            file_io,
+            path_factory: PathFactory::new(table_path),
+        }
+    }
+
+    /// Get the directory holding all snapshot files.
+    pub fn snapshot_dir(&self) -> String {
+        self.path_factory.snapshot_dir()
+    }
+
+    /// Get the path of the snapshot file with the given id.
+    pub fn snapshot_path(&self, snapshot_id: i64) -> String {
+        self.path_factory.snapshot_path(snapshot_id)
+    }
+
+    /// Read the snapshot with the given id.
+    pub async fn snapshot(&self, snapshot_id: i64) -> crate::Result<Snapshot> {
+        let path = self.snapshot_path(snapshot_id);
+        let json = self.file_io.read_to_string(&path).await?;
+        serde_json::from_str(&json).context(MetadataInvalidSnafu {
+            message: format!("Failed to parse snapshot '{}'", path),
+        })
+    }
+
+    /// Get the id of the latest snapshot, `None` if the table has no snapshot yet.
+    ///
+    /// The `LATEST` hint file is used when it is up to date, otherwise all snapshot files are listed.
+    pub async fn latest_snapshot_id(&self) -> crate::Result<Option<i64>> {
+        if let Some(hint) = self.read_hint(LATEST).await? {
+            if !self.file_io.exists(&self.snapshot_path(hint + 1)).await? {
+                return Ok(Some(hint));
+            }
+        }
+        Ok(self.list_snapshot_ids().await?.into_iter().max())
+    }
+
+    /// Read the latest snapshot, `None` if the table has no snapshot yet.
+    pub async fn latest_snapshot(&self) -> crate::Result<Option<Snapshot>> {
+        match self.latest_snapshot_id().await? {
+            Some(snapshot_id) => Ok(Some(self.snapshot(snapshot_id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// List the ids of all snapshot files.
+    pub async fn list_snapshot_ids(&self) -> crate::Result<Vec<i64>> {
+        let dir = format!("{}/", self.snapshot_dir());
+        if !self.file_io.exists(&dir).await? {
+            return Ok(vec![]);
+        }
+        let mut ids = self
+            .file_io
+            .list_status(&dir)
+            .await?
+            .into_iter()
+            .filter(|status| !status.is_dir)
+            .filter_map(|status| {
+                let name = status.path.rsplit('/').next()?.to_string();
+                name.strip_prefix(SNAPSHOT_PREFIX)?.parse::<i64>().ok()
+            })
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        Ok(ids)
+    }
+
+    /// Record the given snapshot as the latest one in the `LATEST` hint file.
+    pub async fn commit_latest_hint(&self, snapshot_id: i64) -> crate::Result<()> {
+        let path = format!("{}/{}", self.snapshot_dir(), LATEST);
+        self.file_io
+            .new_output(&path)?
+            .write(snapshot_id.to_string().into())
+            .await
+    }
+
+    async fn read_hint(&self, file_name: &str) -> crate::Result<Option<i64>> {
+        let path = format!("{}/{}", self.snapshot_dir(), file_name);
+        if !self.file_io.exists(&path).await? {
+            return Ok(None);
+        }
+        // A broken hint is not fatal, the caller falls back to listing snapshot files.
+        match self.file_io.read_to_string(&path).await {
+            Ok(hint) => Ok(hint.trim().parse::<i64>().ok()),
+            Err(Error::Utf8Invalid { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef, Int32Array, Int64Array, RecordBatch, StringArray, TimestampMillisecondArray,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use async_trait::async_trait;
+
+use crate::table::system::{format_partition, format_row, SystemTable};
+use crate::table::{format_identifier, FileStoreTable};
+
+/// Name of the files system table.
+pub const FILES: &str = "files";
+
+/// A system table listing the live data files of the latest snapshot of a table.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/system/FilesTable.java>
+#[derive(Debug, Clone)]
+pub struct FilesTable {
+    table: FileStoreTable,
+}
+
+impl FilesTable {
+    pub fn new(table: FileStoreTable) -> Self {
+        Self { table }
+    }
+}
+
+#[async_trait(?Send)]
+impl SystemTable for FilesTable {
+    fn name(&self) -> &str {
+        FILES
+    }
+
+    fn arrow_schema(&self) -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("partition", DataType::Utf8, false),
+            Field::new("bucket", DataType::Int32, false),
+            Field::new("file_path", DataType::Utf8, false),
+            Field::new("file_format", DataType::Utf8, false),
+            Field::new("record_count", DataType::Int64, false),
+            Field::new("file_size_in_bytes", DataType::Int64, false),
+            Field::new("min_key", DataType::Utf8, false),
+            Field::new("max_key", DataType::Utf8, false),
+            Field::new(
+                "creation_time",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                false,
+            ),
+            Field::new("level", DataType::Int32, false),
+        ]))
+    }
+
+    async fn read(&self) -> crate::Result<RecordBatch> {
+        let schema = self.table.schema();
+        let partition_type = schema.logical_partition_type();
+        let key_type = schema.logical_trimmed_primary_keys_type();
+
+        let mut partitions = Vec::new();
+        let mut buckets = Vec::new();
+        let mut file_paths = Vec::new();
+        let mut file_formats = Vec::new();
+        let mut record_counts = Vec::new();
+        let mut file_sizes = Vec::new();
+        let mut min_keys = Vec::new();
+        let mut max_keys = Vec::new();
+        let mut creation_times = Vec::new();
+        let mut levels = Vec::new();
+        for split in self.table.new_scan().plan().await?.splits() {
+            let partition = format_partition(split.partition(), &partition_type)?;
+            for file in split.data_files() {
+                partitions.push(partition.clone());
+                buckets.push(split.bucket());
+                file_paths.push(split.data_file_path(file));
+                file_formats.push(
+                    format_identifier(file.file_name())
+                        .unwrap_or_default()
+                        .to_string(),
+                );
+                record_counts.push(file.row_count());
+                file_sizes.push(file.file_size());
+                min_keys.push(format_row(file.min_key(), &key_type)?);
+                max_keys.push(format_row(file.max_key(), &key_type)?);
+                creation_times.push(file.creation_time().timestamp_millis());
+                levels.push(file.level());
+            }
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(partitions)),
+            Arc::new(Int32Array::from(buckets)),
+            Arc::new(StringArray::from(file_paths)),
+            Arc::new(StringArray::from(file_formats)),
+            Arc::new(Int64Array::from(record_counts)),
+            Arc::new(Int64Array::from(file_sizes)),
+            Arc::new(StringArray::from(min_keys)),
+            Arc::new(StringArray::from(max_keys)),
+            Arc::new(TimestampMillisecondArray::from(creation_times)),
+            Arc::new(Int32Array::from(levels)),
+        ];
+        Ok(RecordBatch::try_new(self.arrow_schema(), columns)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{DataField, FileKind, IntType};
+    use crate::table::system::load_system_table;
+    use crate::test_util::{commit, data_file, entry, int_partition, new_table};
+
+    #[tokio::test]
+    async fn test_read_files_table() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_read_files_table",
+            vec![DataField::new(
+                0,
+                "p".to_string(),
+                crate::spec::DataType::Int(IntType::new()),
+            )],
+            vec!["p".to_string()],
+        );
+        let base = vec![
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("data-1.parquet", 10, 100, 1000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(2),
+                data_file("data-2.orc", 20, 200, 2000),
+            ),
+        ];
+        let delta = vec![
+            entry(
+                FileKind::Delete,
+                int_partition(1),
+                data_file("data-1.parquet", 10, 100, 1000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("data-3.parquet", 30, 300, 3000),
+            ),
+        ];
+        commit(&table, 1, base, delta).await?;
+
+        let system_table = load_system_table(FILES, table).expect("files system table");
+        let batch = system_table.read().await?;
+        assert_eq!(batch.schema(), system_table.arrow_schema());
+        assert_eq!(batch.num_rows(), 2);
+
+        let strings = |i: usize| {
+            let array = batch.column(i).as_any().downcast_ref::<StringArray>();
+            array
+                .unwrap()
+                .iter()
+                .map(Option::unwrap)
+                .collect::<Vec<_>>()
+        };
+        let longs = |i: usize| {
+            let array = batch.column(i).as_any().downcast_ref::<Int64Array>();
+            array.unwrap().values().to_vec()
+        };
+        assert_eq!(strings(0), vec!["p=1", "p=2"]);
+        assert_eq!(
+            strings(2),
+            vec![
+                "memory:/tmp/test_read_files_table/p=1/bucket-0/data-3.parquet",
+                "memory:/tmp/test_read_files_table/p=2/bucket-0/data-2.orc",
+            ]
+        );
+        assert_eq!(strings(3), vec!["parquet", "orc"]);
+        assert_eq!(longs(4), vec![30, 20]);
+        assert_eq!(longs(5), vec![300, 200]);
+        assert_eq!(strings(6), vec!["[]", "[]"]);
+
+        let creation_times = batch
+            .column(8)
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(creation_times.values(), &[3000, 2000]);
+        let buckets = batch.column(1).as_any().downcast_ref::<Int32Array>();
+        assert_eq!(buckets.unwrap().values(), &[0, 0]);
+
+        Ok(())
+    }
+}
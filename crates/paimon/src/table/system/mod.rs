@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! System tables exposing the metadata of a table, like `my_table$partitions`.
+
+mod files_table;
+pub use files_table::*;
+
+mod partitions_table;
+pub use partitions_table::*;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+use async_trait::async_trait;
+
+use crate::spec::{partition_path, partition_spec, BinaryRow, RowType};
+use crate::table::FileStoreTable;
+
+/// A read-only table computed from the metadata of a [`FileStoreTable`].
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/system/SystemTableLoader.java>
+#[async_trait(?Send)]
+pub trait SystemTable: Send + Sync {
+    /// Get the name of this system table, like `partitions`.
+    fn name(&self) -> &str;
+
+    /// Get the arrow schema of the rows of this system table.
+    fn arrow_schema(&self) -> SchemaRef;
+
+    /// Read all rows of this system table.
+    async fn read(&self) -> crate::Result<RecordBatch>;
+}
+
+/// Load the system table with the given name, as returned by
+/// [`Identifier::system_table_name`](crate::catalog::Identifier::system_table_name).
+///
+/// Returns `None` if there is no such system table.
+pub fn load_system_table(name: &str, table: FileStoreTable) -> Option<Box<dyn SystemTable>> {
+    match name.to_lowercase().as_str() {
+        FILES => Some(Box::new(FilesTable::new(table))),
+        PARTITIONS => Some(Box::new(PartitionsTable::new(table))),
+        _ => None,
+    }
+}
+
+/// Format a serialized partition row as its partition path without the trailing `/`,
+/// like `dt=2024-01-01/hr=10`.
+fn format_partition(partition: &[u8], partition_type: &RowType) -> crate::Result<String> {
+    let spec = partition_spec(&BinaryRow::from_bytes(partition)?, partition_type)?;
+    Ok(partition_path(&spec).trim_end_matches('/').to_string())
+}
+
+/// Format a serialized row as its field values, like `[1, a]`.
+fn format_row(row: &[u8], row_type: &RowType) -> crate::Result<String> {
+    if row.is_empty() {
+        return Ok("[]".to_string());
+    }
+    let row = BinaryRow::from_bytes(row)?;
+    let values = row_type
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(pos, field)| Ok(row.get_literal(pos, field.data_type())?.to_string()))
+        .collect::<crate::Result<Vec<_>>>()?;
+    Ok(format!("[{}]", values.join(", ")))
+}
@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray, TimestampMillisecondArray};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use async_trait::async_trait;
+
+use crate::table::system::{format_partition, SystemTable};
+use crate::table::FileStoreTable;
+
+/// Name of the partitions system table.
+pub const PARTITIONS: &str = "partitions";
+
+/// A system table listing the partitions of a table with their statistics.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/system/PartitionsTable.java>
+#[derive(Debug, Clone)]
+pub struct PartitionsTable {
+    table: FileStoreTable,
+}
+
+impl PartitionsTable {
+    pub fn new(table: FileStoreTable) -> Self {
+        Self { table }
+    }
+}
+
+#[async_trait(?Send)]
+impl SystemTable for PartitionsTable {
+    fn name(&self) -> &str {
+        PARTITIONS
+    }
+
+    fn arrow_schema(&self) -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("partition", DataType::Utf8, false),
+            Field::new("record_count", DataType::Int64, false),
+            Field::new("file_count", DataType::Int64, false),
+            Field::new("file_size_in_bytes", DataType::Int64, false),
+            Field::new(
+                "last_update_time",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                false,
+            ),
+        ]))
+    }
+
+    async fn read(&self) -> crate::Result<RecordBatch> {
+        let partition_type = self.table.schema().logical_partition_type();
+        let mut rows = Vec::new();
+        for entry in self.table.list_partitions().await? {
+            rows.push((format_partition(entry.partition(), &partition_type)?, entry));
+        }
+        rows.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(rows.iter().map(|r| &r.0))),
+            Arc::new(Int64Array::from_iter_values(
+                rows.iter().map(|r| r.1.record_count()),
+            )),
+            Arc::new(Int64Array::from_iter_values(
+                rows.iter().map(|r| r.1.file_count()),
+            )),
+            Arc::new(Int64Array::from_iter_values(
+                rows.iter().map(|r| r.1.file_size_in_bytes()),
+            )),
+            Arc::new(TimestampMillisecondArray::from_iter_values(
+                rows.iter().map(|r| r.1.last_file_creation_time()),
+            )),
+        ];
+        Ok(RecordBatch::try_new(self.arrow_schema(), columns)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::Identifier;
+    use crate::spec::{DataField, FileKind, IntType};
+    use crate::table::system::load_system_table;
+    use crate::test_util::{commit, data_file, entry, int_partition, new_table};
+    use arrow_array::Array;
+
+    #[tokio::test]
+    async fn test_read_partitions_table() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_read_partitions_table",
+            vec![
+                DataField::new(
+                    0,
+                    "p".to_string(),
+                    crate::spec::DataType::Int(IntType::new()),
+                ),
+                DataField::new(
+                    1,
+                    "v".to_string(),
+                    crate::spec::DataType::Int(IntType::new()),
+                ),
+            ],
+            vec!["p".to_string()],
+        );
+        let base = vec![
+            entry(
+                FileKind::Add,
+                int_partition(2),
+                data_file("f1", 5, 50, 4000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f2", 10, 100, 1000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f3", 20, 200, 3000),
+            ),
+        ];
+        commit(&table, 1, base, vec![]).await?;
+
+        let identifier = Identifier::from_string("default.t$partitions")?;
+        let system_table = load_system_table(identifier.system_table_name().unwrap(), table)
+            .expect("partitions system table");
+        assert_eq!(system_table.name(), PARTITIONS);
+
+        let batch = system_table.read().await?;
+        assert_eq!(batch.schema(), system_table.arrow_schema());
+        assert_eq!(batch.num_rows(), 2);
+
+        let column = |i: usize| batch.column(i).as_any();
+        let partitions = column(0).downcast_ref::<StringArray>().unwrap();
+        assert_eq!(partitions.value(0), "p=1");
+        assert_eq!(partitions.value(1), "p=2");
+        let record_counts = column(1).downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(record_counts.values(), &[30, 5]);
+        let file_counts = column(2).downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(file_counts.values(), &[2, 1]);
+        let file_sizes = column(3).downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(file_sizes.values(), &[300, 50]);
+        let update_times = column(4)
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(update_times.values(), &[3000, 4000]);
+        assert_eq!(update_times.null_count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_unknown_system_table() {
+        let table = new_table("memory:/tmp/test_load_unknown_system_table", vec![], vec![]);
+        assert!(load_system_table("unknown", table).is_none());
+    }
+}
@@ -0,0 +1,1169 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow_array::{
+    new_null_array, ArrayRef, Int64Array, RecordBatch, RecordBatchReader, StringArray, UInt32Array,
+};
+use arrow_schema::SchemaRef;
+use arrow_select::filter::filter_record_batch;
+use futures::{StreamExt, TryStreamExt};
+use parquet::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+use parquet::arrow::ProjectionMask;
+use parquet::file::reader::ChunkReader;
+
+use crate::arrow::{literal_array, to_arrow_schema, AvroReader};
+use crate::error::{ColumnNotExistSnafu, UnsupportedSnafu};
+use crate::predicate::Predicate;
+use crate::spec::{DataField, FileKind, Literal, RowKind};
+use crate::table::{DataSplit, FileStoreTable, MetadataColumn, SchemaCache};
+use crate::trace;
+
+/// Read of the data files of the splits planned by a [`crate::table::TableScan`].
+///
+/// Each data file is decoded with the schema it was written with, found by its schema id, and its
+/// columns are mapped by field id to the schema of the table: renamed columns keep their values,
+/// columns added after the file was written hold the default value of their field, set by
+/// [`CoreOptions::field_default_value`](crate::spec::CoreOptions::field_default_value), or null,
+/// and columns whose type was updated are cast.
+/// Only the column chunks of the projected columns are decoded.
+///
+/// The files of a split are fetched concurrently, up to
+/// [`CoreOptions::read_file_concurrency`](crate::spec::CoreOptions::read_file_concurrency) at a
+/// time, and decoded in the order of the split.
+///
+/// System columns requested with [`TableRead::with_metadata_columns`] are appended after the
+/// read fields, and the rows are filtered by the predicate set with [`TableRead::with_filter`].
+///
+/// Only parquet and avro files of tables without primary keys are supported, the format of a
+/// file is given by its extension.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/operation/AppendOnlyFileStoreRead.java>
+#[derive(Debug, Clone)]
+pub struct TableRead {
+    table: FileStoreTable,
+    schema_cache: SchemaCache,
+    read_fields: Vec<DataField>,
+    /// default value of each read field, for the files written before the field was added
+    default_values: Vec<Option<Literal>>,
+    metadata_columns: Vec<MetadataColumn>,
+    read_schema: SchemaRef,
+    filter: Option<Predicate>,
+    limit: Option<usize>,
+    file_concurrency: usize,
+}
+
+impl TableRead {
+    pub fn new(table: FileStoreTable) -> crate::Result<Self> {
+        let schema_cache = SchemaCache::new(table.file_io().clone(), table.location())
+            .with_schema(table.schema().clone());
+        let read_fields = table.schema().fields().to_vec();
+        let default_values = Self::default_values(&table, &read_fields)?;
+        let read_schema = Arc::new(to_arrow_schema(&read_fields)?);
+        let file_concurrency = table.options().read_file_concurrency()?;
+        Ok(Self {
+            table,
+            schema_cache,
+            read_fields,
+            default_values,
+            metadata_columns: Vec::new(),
+            read_schema,
+            filter: None,
+            limit: None,
+            file_concurrency,
+        })
+    }
+
+    /// Get the arrow schema of the batches read, the schema of the table or its projection,
+    /// followed by the metadata columns.
+    #[inline]
+    pub fn read_schema(&self) -> &SchemaRef {
+        &self.read_schema
+    }
+
+    /// Read only the columns with the given field ids, in this order.
+    ///
+    /// Field ids are stable across renames, so a projection holds for files written with any
+    /// schema of the table.
+    pub fn with_projection(mut self, field_ids: &[i32]) -> crate::Result<Self> {
+        let fields = self.table.schema().fields();
+        let read_fields = field_ids
+            .iter()
+            .map(|id| match fields.iter().find(|field| field.id() == *id) {
+                Some(field) => Ok(field.clone()),
+                None => ColumnNotExistSnafu {
+                    message: format!("field id {id} does not exist"),
+                }
+                .fail(),
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        self.default_values = Self::default_values(&self.table, &read_fields)?;
+        self.read_fields = read_fields;
+        self.update_read_schema()?;
+        Ok(self)
+    }
+
+    /// Append the given system columns to the batches read, in this order.
+    ///
+    /// Only tables without primary keys are supported, their data files hold no key-value
+    /// columns, so the metadata columns are derived: rows are all insertions, and their sequence
+    /// numbers are assigned in order from the minimum sequence number of their data file, as
+    /// Paimon writes them. Reading a file whose sequence number range does not match its row
+    /// count fails with [`crate::Error::Unsupported`].
+    ///
+    /// Fails with [`crate::Error::Unsupported`] for tables with primary keys.
+    pub fn with_metadata_columns(mut self, columns: &[MetadataColumn]) -> crate::Result<Self> {
+        if !columns.is_empty() && !self.table.schema().primary_keys().is_empty() {
+            return UnsupportedSnafu {
+                message: "Metadata columns of tables with primary keys are not supported yet"
+                    .to_string(),
+            }
+            .fail();
+        }
+        self.metadata_columns.clear();
+        for column in columns {
+            if !self.metadata_columns.contains(column) {
+                self.metadata_columns.push(*column);
+            }
+        }
+        self.update_read_schema()?;
+        Ok(self)
+    }
+
+    /// Parse the default value of each field from the options of the table.
+    fn default_values(
+        table: &FileStoreTable,
+        fields: &[DataField],
+    ) -> crate::Result<Vec<Option<Literal>>> {
+        let options = table.options();
+        fields
+            .iter()
+            .map(|field| {
+                options
+                    .field_default_value(field.name())
+                    .map(|value| Literal::parse(value, field.data_type()))
+                    .transpose()
+            })
+            .collect()
+    }
+
+    /// Rebuild the read schema from the read fields and the metadata columns.
+    fn update_read_schema(&mut self) -> crate::Result<()> {
+        let fields = self
+            .read_fields
+            .iter()
+            .cloned()
+            .chain(self.metadata_columns.iter().map(|column| column.field()))
+            .collect::<Vec<_>>();
+        self.read_schema = Arc::new(to_arrow_schema(&fields)?);
+        Ok(())
+    }
+
+    /// Only keep the rows matching the predicate, usually the filter of the scan which planned
+    /// the splits, since the scan only skips the files which can't match.
+    ///
+    /// The predicate is evaluated on the batches read, see [`Predicate::test_batch`], so its
+    /// fields must be read: a field left out by [`TableRead::with_projection`] fails the read
+    /// with [`crate::Error::PredicateInvalid`].
+    pub fn with_filter(mut self, predicate: Predicate) -> Self {
+        self.filter = Some(predicate);
+        self
+    }
+
+    /// Stop reading once `limit` rows are read, for previews.
+    ///
+    /// The limit applies to each call of [`TableRead::read`] or [`TableRead::read_splits`].
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Read the added data files of a split.
+    pub async fn read(&self, split: &DataSplit) -> crate::Result<Vec<RecordBatch>> {
+        self.read_split(split, self.limit).await
+    }
+
+    /// Read the added data files of splits in order.
+    pub async fn read_splits(&self, splits: &[DataSplit]) -> crate::Result<Vec<RecordBatch>> {
+        let mut batches = Vec::new();
+        let mut remaining = self.limit;
+        for split in splits {
+            if remaining == Some(0) {
+                break;
+            }
+            let split_batches = self.read_split(split, remaining).await?;
+            if let Some(remaining) = remaining.as_mut() {
+                *remaining -= split_batches.iter().map(|b| b.num_rows()).sum::<usize>();
+            }
+            batches.extend(split_batches);
+        }
+        Ok(batches)
+    }
+
+    /// Read the added data files of a split, up to `limit` rows.
+    async fn read_split(
+        &self,
+        split: &DataSplit,
+        limit: Option<usize>,
+    ) -> crate::Result<Vec<RecordBatch>> {
+        let read = async {
+            let batches = self.read_split_files(split, limit).await?;
+            trace::record("rows", batches.iter().map(|b| b.num_rows() as u64).sum());
+            Ok(batches)
+        };
+        #[cfg(feature = "tracing")]
+        let read = tracing::Instrument::instrument(
+            read,
+            tracing::info_span!(
+                "paimon.read",
+                table = self.table.location(),
+                snapshot_id = split.snapshot_id(),
+                bucket = split.bucket(),
+                files = tracing::field::Empty,
+                rows = tracing::field::Empty,
+                bytes = tracing::field::Empty,
+            ),
+        );
+        read.await
+    }
+
+    async fn read_split_files(
+        &self,
+        split: &DataSplit,
+        mut limit: Option<usize>,
+    ) -> crate::Result<Vec<RecordBatch>> {
+        if !self.table.schema().primary_keys().is_empty() {
+            return UnsupportedSnafu {
+                message: "Reading tables with primary keys is not supported yet".to_string(),
+            }
+            .fail();
+        }
+
+        let mut batches = Vec::new();
+        if limit == Some(0) {
+            return Ok(batches);
+        }
+        let files = split
+            .files_with_kind()
+            .filter(|(kind, _)| *kind == FileKind::Add)
+            .map(|(_, file)| file)
+            .collect::<Vec<_>>();
+        if let Some(file) = files.iter().find(|f| file_format(f.file_name()).is_none()) {
+            return UnsupportedSnafu {
+                message: format!("Reading data file '{}' is not supported", file.file_name()),
+            }
+            .fail();
+        }
+        trace::record("files", files.len() as u64);
+
+        // Fetch up to `file_concurrency` files at once, the buffered stream keeps the split order.
+        let mut fetches = futures::stream::iter(files)
+            .map(|file| async move {
+                let file_schema = self.schema_cache.schema(file.schema_id()).await?;
+                let path = split.data_file_path(file);
+                let bytes = self.table.file_io().new_input(&path)?.read().await?;
+                Ok::<_, crate::Error>((file, file_schema, bytes))
+            })
+            .buffered(self.file_concurrency);
+        let mut bytes_read = 0;
+        while let Some((file, file_schema, bytes)) = fetches.try_next().await? {
+            bytes_read += bytes.len() as u64;
+            trace::record("bytes", bytes_read);
+            let reader: Box<dyn RecordBatchReader> = match file_format(file.file_name()) {
+                Some(FileFormat::Avro) => Box::new(AvroReader::new(
+                    &bytes,
+                    self.projected_file_fields(file_schema.fields()),
+                )?),
+                Some(FileFormat::Parquet) | None => {
+                    Box::new(self.parquet_reader(bytes, file_schema.fields())?)
+                }
+            };
+            if self
+                .metadata_columns
+                .contains(&MetadataColumn::SequenceNumber)
+                && file.max_sequence_number() - file.min_sequence_number() + 1 != file.row_count()
+            {
+                return UnsupportedSnafu {
+                    message: format!(
+                        "Sequence numbers of the rows of data file '{}' can't be derived from \
+                         its range [{}, {}]",
+                        file.file_name(),
+                        file.min_sequence_number(),
+                        file.max_sequence_number()
+                    ),
+                }
+                .fail();
+            }
+            let mut sequence_number = file.min_sequence_number();
+            for batch in reader {
+                let batch = batch?;
+                let num_rows = batch.num_rows();
+                let mut batch = self.evolve(&batch, file_schema.fields(), sequence_number)?;
+                sequence_number += num_rows as i64;
+                if let Some(filter) = &self.filter {
+                    batch = filter_record_batch(&batch, &filter.test_batch(&batch)?)?;
+                }
+                if let Some(remaining) = limit.as_mut() {
+                    if batch.num_rows() >= *remaining {
+                        batch = batch.slice(0, *remaining);
+                    }
+                    *remaining -= batch.num_rows();
+                }
+                batches.push(batch);
+                if limit == Some(0) {
+                    return Ok(batches);
+                }
+            }
+        }
+        Ok(batches)
+    }
+
+    /// Create a reader of a parquet file written with `file_fields`, decoding only the columns
+    /// of the read fields.
+    fn parquet_reader<T: ChunkReader + 'static>(
+        &self,
+        input: T,
+        file_fields: &[DataField],
+    ) -> crate::Result<ParquetRecordBatchReader> {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(input)?;
+        let projected = self.projected_file_fields(file_fields);
+        let names = projected
+            .iter()
+            .map(|field| field.name())
+            .collect::<Vec<_>>();
+        let roots = builder
+            .parquet_schema()
+            .root_schema()
+            .get_fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| names.contains(&column.name()))
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        let mask = ProjectionMask::roots(builder.parquet_schema(), roots);
+        Ok(builder.with_projection(mask).build()?)
+    }
+
+    /// Get the fields of a file matching the read fields by id, in the order of the read fields.
+    fn projected_file_fields(&self, file_fields: &[DataField]) -> Vec<DataField> {
+        self.read_fields
+            .iter()
+            .filter_map(|field| file_fields.iter().find(|f| f.id() == field.id()))
+            .cloned()
+            .collect()
+    }
+
+    /// Map a batch written with `file_fields` to the read schema, the first row of the batch has
+    /// the sequence number `sequence_number`.
+    fn evolve(
+        &self,
+        batch: &RecordBatch,
+        file_fields: &[DataField],
+        sequence_number: i64,
+    ) -> crate::Result<RecordBatch> {
+        let num_rows = batch.num_rows();
+        let mut columns = self
+            .read_fields
+            .iter()
+            .zip(&self.default_values)
+            .zip(self.read_schema.fields())
+            .map(|((field, default_value), arrow_field)| {
+                let column = file_fields
+                    .iter()
+                    .find(|file_field| file_field.id() == field.id())
+                    .and_then(|file_field| batch.column_by_name(file_field.name()));
+                let column: ArrayRef = match (column, default_value) {
+                    (Some(column), _) if column.data_type() == arrow_field.data_type() => {
+                        column.clone()
+                    }
+                    (Some(column), _) => arrow_cast::cast(column, arrow_field.data_type())?,
+                    (None, Some(default_value)) => {
+                        let value =
+                            literal_array(default_value, arrow_field.data_type(), field.name())?;
+                        let indices = UInt32Array::from(vec![0; num_rows]);
+                        arrow_select::take::take(&value, &indices, None)?
+                    }
+                    (None, None) => new_null_array(arrow_field.data_type(), num_rows),
+                };
+                Ok(column)
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        for column in &self.metadata_columns {
+            let column: ArrayRef = match column {
+                MetadataColumn::SequenceNumber => Arc::new(Int64Array::from_iter_values(
+                    sequence_number..sequence_number + num_rows as i64,
+                )),
+                MetadataColumn::RowKind => Arc::new(StringArray::from(vec![
+                    RowKind::Insert
+                        .short_string();
+                    num_rows
+                ])),
+            };
+            columns.push(column);
+        }
+        Ok(RecordBatch::try_new(self.read_schema.clone(), columns)?)
+    }
+}
+
+/// Formats of the data files which can be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileFormat {
+    Parquet,
+    Avro,
+}
+
+/// Get the format of a data file from the extension of its name.
+fn file_format(file_name: &str) -> Option<FileFormat> {
+    match file_name.rsplit_once('.')?.1 {
+        "parquet" => Some(FileFormat::Parquet),
+        "avro" => Some(FileFormat::Avro),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use arrow_array::{Array, Int32Array, Int64Array, StringArray};
+    use bytes::{Buf, Bytes};
+    use parquet::file::reader::Length;
+    use rand::distributions::{Alphanumeric, DistString};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::io::DataFileWriter;
+    use crate::predicate::PredicateBuilder;
+    use crate::spec::{
+        BigIntType, BinaryRowWriter, CoreOptions, DataFileMeta, DataType, IntType, Literal,
+        SchemaChange, TableSchema, VarCharType, EMPTY_BINARY_ROW,
+    };
+    use crate::table::Plan;
+    use crate::test_util::{commit, data_file, entry, int_partition, new_table};
+
+    /// Write the batch into a parquet data file at `bucket_path`, returning its meta.
+    async fn write_data_file(
+        table: &FileStoreTable,
+        bucket_path: &str,
+        file_name: &str,
+        batch: &RecordBatch,
+    ) -> crate::Result<DataFileMeta> {
+        let path = format!("{}/{}", bucket_path, file_name);
+        let mut writer =
+            DataFileWriter::new(table.file_io().new_output(&path)?, batch.schema(), 0, 0)?;
+        writer.write(batch)?;
+        writer.close().await
+    }
+
+    /// Read the int column at `pos` of all splits of the plan.
+    async fn read_ints(table: &FileStoreTable, plan: &Plan, pos: usize) -> crate::Result<Vec<i32>> {
+        let read = table.new_read()?;
+        let mut values = Vec::new();
+        for split in plan.splits() {
+            for batch in read.read(split).await? {
+                let column = batch
+                    .column(pos)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap();
+                values.extend(column.values().iter().copied());
+            }
+        }
+        Ok(values)
+    }
+
+    #[tokio::test]
+    async fn test_read_partitioned_table() -> crate::Result<()> {
+        let location = "memory:/tmp/test_read_partitioned_table";
+        let fields = vec![
+            DataField::new(0, "p".to_string(), DataType::Int(IntType::new())),
+            DataField::new(1, "id".to_string(), DataType::Int(IntType::new())),
+        ];
+        let table = new_table(location, fields.clone(), vec!["p".to_string()]);
+        let arrow_schema = Arc::new(to_arrow_schema(&fields)?);
+        let mut delta = Vec::new();
+        for (p, ids) in [(1, vec![1, 2]), (2, vec![3])] {
+            let batch = RecordBatch::try_new(
+                arrow_schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(vec![p; ids.len()])),
+                    Arc::new(Int32Array::from(ids)),
+                ],
+            )?;
+            let bucket_path = format!("{}/p={}/bucket-0", location, p);
+            let file_name = format!("data-{}.parquet", p);
+            let file = write_data_file(&table, &bucket_path, &file_name, &batch).await?;
+            delta.push(entry(FileKind::Add, int_partition(p), file));
+        }
+        commit(&table, 1, vec![], delta).await?;
+
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(plan.splits().len(), 2);
+        let split = &plan.splits()[1];
+        assert_eq!(split.bucket_path(), format!("{}/p=2/bucket-0", location));
+        assert_eq!(split.partition_row()?.get_int(0), 2);
+        assert_eq!(read_ints(&table, &plan, 1).await?, vec![1, 2, 3]);
+
+        let filter = PredicateBuilder::new(&fields).equal(0, Literal::Int(2));
+        let plan = table.new_scan().with_filter(filter).plan().await?;
+        assert_eq!(plan.splits().len(), 1);
+        assert_eq!(read_ints(&table, &plan, 0).await?, vec![2]);
+        assert_eq!(read_ints(&table, &plan, 1).await?, vec![3]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_multi_key_partitioned_table() -> crate::Result<()> {
+        let location = "memory:/tmp/test_read_multi_key_partitioned_table";
+        let fields = vec![
+            DataField::new(
+                0,
+                "dt".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+            DataField::new(1, "hr".to_string(), DataType::Int(IntType::new())),
+            DataField::new(2, "id".to_string(), DataType::Int(IntType::new())),
+        ];
+        let table = new_table(
+            location,
+            fields.clone(),
+            vec!["dt".to_string(), "hr".to_string()],
+        );
+        let arrow_schema = Arc::new(to_arrow_schema(&fields)?);
+        let partitions = [
+            ("2024-01-01", 10, vec![1, 2]),
+            ("2024-01-01", 11, vec![3]),
+            ("2024-01-02", 10, vec![4, 5]),
+        ];
+        let mut delta = Vec::new();
+        for (i, (dt, hr, ids)) in partitions.into_iter().enumerate() {
+            let batch = RecordBatch::try_new(
+                arrow_schema.clone(),
+                vec![
+                    Arc::new(StringArray::from(vec![dt; ids.len()])),
+                    Arc::new(Int32Array::from(vec![hr; ids.len()])),
+                    Arc::new(Int32Array::from(ids)),
+                ],
+            )?;
+            let bucket_path = format!("{}/dt={}/hr={}/bucket-0", location, dt, hr);
+            let file_name = format!("data-{}.parquet", i);
+            let file = write_data_file(&table, &bucket_path, &file_name, &batch).await?;
+            let mut partition = BinaryRowWriter::new(2);
+            partition.write_string(0, dt);
+            partition.write_int(1, hr);
+            delta.push(entry(FileKind::Add, partition.build().to_bytes(), file));
+        }
+        commit(&table, 1, vec![], delta).await?;
+
+        let plan = table.new_scan().plan().await?;
+        let bucket_paths = plan
+            .splits()
+            .iter()
+            .map(|split| split.bucket_path().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bucket_paths,
+            vec![
+                format!("{}/dt=2024-01-01/hr=10/bucket-0", location),
+                format!("{}/dt=2024-01-01/hr=11/bucket-0", location),
+                format!("{}/dt=2024-01-02/hr=10/bucket-0", location),
+            ]
+        );
+        let partition = plan.splits()[1].partition_row()?;
+        assert_eq!(partition.get_string(0)?, "2024-01-01");
+        assert_eq!(partition.get_int(1), 11);
+        assert_eq!(read_ints(&table, &plan, 2).await?, vec![1, 2, 3, 4, 5]);
+
+        let builder = PredicateBuilder::new(&fields);
+        let filter = PredicateBuilder::and(vec![
+            builder.equal(0, Literal::String("2024-01-01".to_string())),
+            builder.equal(1, Literal::Int(10)),
+        ]);
+        let plan = table.new_scan().with_filter(filter).plan().await?;
+        assert_eq!(plan.splits().len(), 1);
+        assert_eq!(read_ints(&table, &plan, 2).await?, vec![1, 2]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_avro_file() -> crate::Result<()> {
+        let fields = vec![
+            DataField::new(
+                0,
+                "id".to_string(),
+                DataType::Int(IntType::with_nullable(false)),
+            ),
+            DataField::new(
+                1,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+        ];
+        let table = new_table("memory:/tmp/test_read_avro_file", fields, vec![]);
+        let file_name = "data-0f7a8a0e-4c1b-4d4e-9b1e-3d2a6c5f8b91-0.avro";
+        let bytes = std::fs::read(format!(
+            "{}/tests/fixtures/data/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            file_name
+        ))
+        .unwrap();
+        let path = format!("{}/{}", table.path_factory().bucket_path("", 0), file_name);
+        let file = data_file(file_name, 3, bytes.len() as i64, 0);
+        table
+            .file_io()
+            .new_output(&path)?
+            .write(Bytes::from(bytes))
+            .await?;
+        let delta = vec![entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), file)];
+        commit(&table, 1, vec![], delta).await?;
+
+        let plan = table.new_scan().plan().await?;
+        let read = table.new_read()?.with_projection(&[1])?;
+        let batches = read.read(&plan.splits()[0]).await?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].schema(), *read.read_schema());
+        let names = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            vec![Some("apple"), None, Some("cherry")]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_file_of_older_schema() -> crate::Result<()> {
+        let location = "memory:/tmp/test_read_file_of_older_schema";
+        let fields = vec![
+            DataField::new(0, "id".to_string(), DataType::Int(IntType::new())),
+            DataField::new(
+                1,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+        ];
+        let table = new_table(location, fields.clone(), vec![]);
+        let file_io = table.file_io().clone();
+        let path_factory = table.path_factory();
+        file_io
+            .new_output(&path_factory.schema_path(0))?
+            .write(Bytes::from(serde_json::to_vec(table.schema()).unwrap()))
+            .await?;
+
+        let arrow_schema = Arc::new(to_arrow_schema(&fields)?);
+        let batch = RecordBatch::try_new(
+            arrow_schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+            ],
+        )?;
+        let path = format!("{}/data-0.parquet", path_factory.bucket_path("", 0));
+        let mut writer = DataFileWriter::new(file_io.new_output(&path)?, arrow_schema, 0, 0)?;
+        writer.write(&batch)?;
+        let file = writer.close().await?;
+
+        let schema = table.schema().apply_changes(&[
+            SchemaChange::rename_column("name".to_string(), "user_name".to_string()),
+            SchemaChange::update_column_type("id".to_string(), DataType::BigInt(BigIntType::new())),
+            SchemaChange::add_column("age".to_string(), DataType::Int(IntType::new())),
+        ])?;
+        let table = FileStoreTable::new(file_io, location, schema);
+        let delta = vec![entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), file)];
+        commit(&table, 1, vec![], delta).await?;
+
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(plan.splits().len(), 1);
+        let read = table.new_read()?;
+        let batches = read.read(&plan.splits()[0]).await?;
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.schema(), *read.read_schema());
+
+        let ids = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(ids.values().to_vec(), vec![1, 2]);
+        let names = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(names.iter().collect::<Vec<_>>(), vec![Some("a"), None]);
+        assert_eq!(batch.column(2).null_count(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_added_column_default_value() -> crate::Result<()> {
+        let location = "memory:/tmp/test_read_added_column_default_value";
+        let fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        let table = new_table(location, fields.clone(), vec![]);
+        table
+            .file_io()
+            .new_output(&table.path_factory().schema_path(0))?
+            .write(Bytes::from(serde_json::to_vec(table.schema()).unwrap()))
+            .await?;
+        let batch = RecordBatch::try_new(
+            Arc::new(to_arrow_schema(&fields)?),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        let bucket_path = table.path_factory().bucket_path("", 0);
+        let file = write_data_file(&table, &bucket_path, "data-0.parquet", &batch).await?;
+
+        let schema = table.schema().apply_changes(&[
+            SchemaChange::add_column("age".to_string(), DataType::Int(IntType::new())),
+            SchemaChange::add_column(
+                "city".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+            SchemaChange::set_option("fields.age.default-value".to_string(), "18".to_string()),
+        ])?;
+        let table = FileStoreTable::new(table.file_io().clone(), location, schema);
+        let delta = vec![entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), file)];
+        commit(&table, 1, vec![], delta).await?;
+
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(read_ints(&table, &plan, 0).await?, vec![1, 2, 3]);
+        assert_eq!(read_ints(&table, &plan, 1).await?, vec![18, 18, 18]);
+        let batches = table.new_read()?.read(&plan.splits()[0]).await?;
+        assert_eq!(batches[0].column(1).null_count(), 0);
+        assert_eq!(batches[0].column(2).null_count(), 3);
+
+        // the default of a projected field
+        let read = table.new_read()?.with_projection(&[1])?;
+        let batches = read.read(&plan.splits()[0]).await?;
+        let ages = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(ages.values().to_vec(), vec![18, 18, 18]);
+
+        // an invalid default fails the read
+        let table = table.copy(HashMap::from([(
+            "fields.age.default-value".to_string(),
+            "eighteen".to_string(),
+        )]));
+        assert!(matches!(
+            table.new_read(),
+            Err(crate::Error::LiteralInvalid { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_files_concurrently() -> crate::Result<()> {
+        let location = "memory:/tmp/test_read_files_concurrently";
+        let fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        let table = new_table(location, fields.clone(), vec![]);
+        let schema = table.schema().apply_changes(&[SchemaChange::set_option(
+            CoreOptions::READ_FILE_CONCURRENCY.to_string(),
+            "2".to_string(),
+        )])?;
+        let table = FileStoreTable::new(table.file_io().clone(), location, schema);
+        let arrow_schema = Arc::new(to_arrow_schema(&fields)?);
+        let mut delta = Vec::new();
+        for i in 0..5 {
+            let batch = RecordBatch::try_new(
+                arrow_schema.clone(),
+                vec![Arc::new(Int32Array::from_iter_values(i * 10..i * 10 + 10))],
+            )?;
+            let path = format!(
+                "{}/data-{}.parquet",
+                table.path_factory().bucket_path("", 0),
+                i
+            );
+            let output = table.file_io().new_output(&path)?;
+            let mut writer = DataFileWriter::new(output, arrow_schema.clone(), 1, 0)?;
+            writer.write(&batch)?;
+            let file = writer.close().await?;
+            delta.push(entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), file));
+        }
+        commit(&table, 1, vec![], delta).await?;
+
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(plan.splits().len(), 1);
+        assert_eq!(plan.splits()[0].data_files().len(), 5);
+        let read = table.new_read()?;
+        assert_eq!(read.file_concurrency, 2);
+        let batches = read.read(&plan.splits()[0]).await?;
+        let ids = batches
+            .iter()
+            .flat_map(|batch| {
+                let ids = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap();
+                ids.values().to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ids, (0..50).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    /// Chunk reader counting the bytes read from it.
+    struct CountingReader {
+        bytes: Bytes,
+        read: Arc<AtomicUsize>,
+    }
+
+    impl Length for CountingReader {
+        fn len(&self) -> u64 {
+            self.bytes.len() as u64
+        }
+    }
+
+    impl ChunkReader for CountingReader {
+        type T = bytes::buf::Reader<Bytes>;
+
+        fn get_read(&self, start: u64) -> parquet::errors::Result<Self::T> {
+            let bytes = self.bytes.slice(start as usize..);
+            self.read.fetch_add(bytes.len(), Ordering::SeqCst);
+            Ok(bytes.reader())
+        }
+
+        fn get_bytes(&self, start: u64, length: usize) -> parquet::errors::Result<Bytes> {
+            self.read.fetch_add(length, Ordering::SeqCst);
+            Ok(self.bytes.slice(start as usize..start as usize + length))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_with_projection() -> crate::Result<()> {
+        let mut fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        for id in 1..5 {
+            fields.push(DataField::new(
+                id,
+                format!("text{id}"),
+                DataType::VarChar(VarCharType::default()),
+            ));
+        }
+        let table = new_table(
+            "memory:/tmp/test_read_with_projection",
+            fields.clone(),
+            vec![],
+        );
+        let arrow_schema = Arc::new(to_arrow_schema(&fields)?);
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut columns: Vec<ArrayRef> = vec![Arc::new(Int32Array::from_iter_values(0..100))];
+        for _ in 1..5 {
+            let texts = (0..100)
+                .map(|_| Alphanumeric.sample_string(&mut rng, 100))
+                .collect::<Vec<_>>();
+            columns.push(Arc::new(StringArray::from(texts)));
+        }
+        let batch = RecordBatch::try_new(arrow_schema.clone(), columns)?;
+        let path = format!("{}/data-0.parquet", table.path_factory().bucket_path("", 0));
+        let output = table.file_io().new_output(&path)?;
+        let mut writer = DataFileWriter::new(output, arrow_schema, 0, 0)?;
+        writer.write(&batch)?;
+        let file = writer.close().await?;
+        let delta = vec![entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), file)];
+        commit(&table, 1, vec![], delta).await?;
+
+        let read = table.new_read()?.with_projection(&[3, 0])?;
+        let names = read
+            .read_schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["text3", "id"]);
+        let plan = table.new_scan().plan().await?;
+        let batches = read.read(&plan.splits()[0]).await?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].column(0).as_ref(), batch.column(3).as_ref());
+        assert_eq!(batches[0].column(1).as_ref(), batch.column(0).as_ref());
+
+        // the projected read does not fetch the chunks of the other text columns
+        let bytes = table.file_io().new_input(&path)?.read().await?;
+        let bytes_read = |read: &TableRead| -> crate::Result<usize> {
+            let counter = Arc::new(AtomicUsize::new(0));
+            let input = CountingReader {
+                bytes: bytes.clone(),
+                read: counter.clone(),
+            };
+            for batch in read.parquet_reader(input, &fields)? {
+                batch?;
+            }
+            Ok(counter.load(Ordering::SeqCst))
+        };
+        let projected = bytes_read(&read)?;
+        let full = bytes_read(&table.new_read()?)?;
+        assert!(projected * 2 < full, "read {projected} of {full} bytes");
+
+        assert!(matches!(
+            table.new_read()?.with_projection(&[5]),
+            Err(crate::Error::ColumnNotExist { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_with_metadata_columns() -> crate::Result<()> {
+        let fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        let table = new_table(
+            "memory:/tmp/test_read_with_metadata_columns",
+            fields.clone(),
+            vec![],
+        );
+        let arrow_schema = Arc::new(to_arrow_schema(&fields)?);
+        let mut delta = Vec::new();
+        for (i, min_sequence_number) in [10, 20].into_iter().enumerate() {
+            let batch = RecordBatch::try_new(
+                arrow_schema.clone(),
+                vec![Arc::new(Int32Array::from_iter_values(0..3))],
+            )?;
+            let path = format!(
+                "{}/data-{}.parquet",
+                table.path_factory().bucket_path("", 0),
+                i
+            );
+            let output = table.file_io().new_output(&path)?;
+            let mut writer =
+                DataFileWriter::new(output, arrow_schema.clone(), 0, min_sequence_number)?;
+            writer.write(&batch)?;
+            let file = writer.close().await?;
+            delta.push(entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), file));
+        }
+        commit(&table, 1, vec![], delta).await?;
+
+        let read = table
+            .new_read()?
+            .with_metadata_columns(&[MetadataColumn::SequenceNumber, MetadataColumn::RowKind])?;
+        let names = read
+            .read_schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["id", "_SEQUENCE_NUMBER", "_ROW_KIND"]);
+        let plan = table.new_scan().plan().await?;
+        let batches = read.read(&plan.splits()[0]).await?;
+        let mut sequence_numbers = Vec::new();
+        for batch in &batches {
+            assert_eq!(batch.schema(), *read.read_schema());
+            let column = batch
+                .column(1)
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap();
+            sequence_numbers.extend(column.values().iter().copied());
+            let kinds = batch
+                .column(2)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            assert!(kinds.iter().all(|kind| kind == Some("+I")));
+        }
+        assert_eq!(sequence_numbers, vec![10, 11, 12, 20, 21, 22]);
+
+        // metadata columns follow the projected fields
+        let read = table
+            .new_read()?
+            .with_metadata_columns(&[MetadataColumn::SequenceNumber])?
+            .with_projection(&[0])?;
+        assert_eq!(read.read_schema().fields().len(), 2);
+
+        // the sequence numbers of a file with a range not matching its rows can't be derived
+        let path = plan.splits()[0].data_file_path(&plan.splits()[0].data_files()[0]);
+        let file_name = path.rsplit('/').next().unwrap();
+        let file = data_file(file_name, 3, 0, 0);
+        commit(
+            &table,
+            2,
+            vec![entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), file)],
+            vec![],
+        )
+        .await?;
+        let plan = table.new_scan().plan().await?;
+        assert!(matches!(
+            read.read(&plan.splits()[0]).await,
+            Err(crate::Error::Unsupported { .. })
+        ));
+        assert!(table.new_read()?.read(&plan.splits()[0]).await.is_ok());
+
+        let schema = TableSchema::new(
+            0,
+            fields,
+            0,
+            vec![],
+            vec!["id".to_string()],
+            HashMap::new(),
+            None,
+        );
+        let table = FileStoreTable::new(
+            table.file_io().clone(),
+            "memory:/tmp/test_read_with_metadata_columns_pk",
+            schema,
+        );
+        assert!(matches!(
+            table
+                .new_read()?
+                .with_metadata_columns(&[MetadataColumn::RowKind]),
+            Err(crate::Error::Unsupported { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_with_limit() -> crate::Result<()> {
+        let fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        let table = new_table("memory:/tmp/test_read_with_limit", fields.clone(), vec![]);
+        let arrow_schema = Arc::new(to_arrow_schema(&fields)?);
+        let mut delta = Vec::new();
+        for i in 0..3 {
+            let batch = RecordBatch::try_new(
+                arrow_schema.clone(),
+                vec![Arc::new(Int32Array::from(vec![i * 2, i * 2 + 1]))],
+            )?;
+            let path = format!(
+                "{}/data-{}.parquet",
+                table.path_factory().bucket_path("", 0),
+                i
+            );
+            let output = table.file_io().new_output(&path)?;
+            let mut writer = DataFileWriter::new(output, arrow_schema.clone(), 0, 0)?;
+            writer.write(&batch)?;
+            let file = writer.close().await?;
+            delta.push(entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), file));
+        }
+        commit(&table, 1, vec![], delta).await?;
+
+        // the first two files hold the 3 first rows
+        let plan = table.new_scan().with_limit(3).plan().await?;
+        let splits = plan.splits();
+        assert_eq!(
+            splits.iter().map(|s| s.data_files().len()).sum::<usize>(),
+            2
+        );
+
+        let batches = table.new_read()?.with_limit(3).read_splits(splits).await?;
+        let ids = batches
+            .iter()
+            .flat_map(|batch| {
+                let ids = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap();
+                ids.values().to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![0, 1, 2]);
+
+        let plan = table.new_scan().plan().await?;
+        let batches = table.new_read()?.read_splits(plan.splits()).await?;
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+        let batches = table
+            .new_read()?
+            .with_limit(0)
+            .read(&plan.splits()[0])
+            .await?;
+        assert!(batches.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_with_filter() -> crate::Result<()> {
+        let fields = vec![
+            DataField::new(0, "id".to_string(), DataType::Int(IntType::new())),
+            DataField::new(1, "v".to_string(), DataType::Int(IntType::new())),
+        ];
+        let table = new_table("memory:/tmp/test_read_with_filter", fields.clone(), vec![]);
+        let batch = RecordBatch::try_new(
+            Arc::new(to_arrow_schema(&fields)?),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5])),
+                Arc::new(Int32Array::from(vec![
+                    Some(10),
+                    None,
+                    Some(30),
+                    Some(40),
+                    None,
+                ])),
+            ],
+        )?;
+        let bucket_path = table.path_factory().bucket_path("", 0);
+        let file = write_data_file(&table, &bucket_path, "data-0.parquet", &batch).await?;
+        commit(
+            &table,
+            1,
+            vec![],
+            vec![entry(FileKind::Add, EMPTY_BINARY_ROW.to_bytes(), file)],
+        )
+        .await?;
+
+        // the rows with a null value are unknown, so filtered out
+        let builder = PredicateBuilder::new(&fields);
+        let filter = builder.not_equal(1, Literal::Int(30));
+        let plan = table.new_scan().with_filter(filter.clone()).plan().await?;
+        let read = table.new_read()?.with_filter(filter.clone());
+        let batches = read.read_splits(plan.splits()).await?;
+        let ids = batches
+            .iter()
+            .flat_map(|batch| {
+                let ids = batch.column(0).as_any().downcast_ref::<Int32Array>();
+                ids.unwrap().values().to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![1, 4]);
+
+        // the limit counts the rows kept
+        let batches = read.with_limit(1).read_splits(plan.splits()).await?;
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+
+        let result = table
+            .new_read()?
+            .with_projection(&[0])?
+            .with_filter(filter)
+            .read_splits(plan.splits())
+            .await;
+        assert!(matches!(result, Err(crate::Error::PredicateInvalid { .. })));
+        Ok(())
+    }
+}
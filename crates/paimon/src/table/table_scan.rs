@@ -0,0 +1,934 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use crate::predicate::Predicate;
+use crate::spec::{
+    partition_path, partition_spec, BinaryRow, DataFileMeta, FileKind, IndexManifestEntries,
+    RowType,
+};
+use crate::table::{DataSplit, FileStoreTable};
+use crate::trace;
+use crate::Error;
+
+/// Serialized partition and bucket of a split.
+type SplitKey = (Vec<u8>, i32);
+
+/// Result of planning a scan: the splits to read.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/source/TableScan.java#L41>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    snapshot_id: Option<i64>,
+    splits: Vec<DataSplit>,
+}
+
+impl Plan {
+    /// Get the id of the planned snapshot, `None` if the table has no snapshot yet.
+    #[inline]
+    pub fn snapshot_id(&self) -> Option<i64> {
+        self.snapshot_id
+    }
+
+    #[inline]
+    pub fn splits(&self) -> &[DataSplit] {
+        &self.splits
+    }
+}
+
+/// Scan of the latest snapshot of a table, producing one split per partition and bucket.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/table/source/snapshot/SnapshotReaderImpl.java>
+#[derive(Debug, Clone)]
+pub struct TableScan {
+    table: FileStoreTable,
+    include_deletes: bool,
+    filter: Option<Predicate>,
+    limit: Option<usize>,
+    validate_paths: bool,
+    skip_missing_paths: bool,
+    /// only plan the data files created after these epoch milliseconds
+    created_after_millis: Option<i64>,
+}
+
+impl TableScan {
+    pub fn new(table: FileStoreTable) -> Self {
+        Self {
+            table,
+            include_deletes: false,
+            filter: None,
+            limit: None,
+            validate_paths: false,
+            skip_missing_paths: false,
+            created_after_millis: None,
+        }
+    }
+
+    /// Skip the partitions and data files which cannot match the predicate.
+    ///
+    /// The conjuncts on partition fields only are tested against the partitions, the others
+    /// against the value stats of the data files. Value stats are only used for tables without
+    /// primary keys, whose files hold the final rows, and embedded file indexes are not used yet.
+    /// Files are skipped as a whole, so the planned files may still hold rows not matching.
+    pub fn with_filter(mut self, predicate: Predicate) -> Self {
+        self.filter = Some(predicate);
+        self
+    }
+
+    /// Whether to plan all added and deleted files of the manifests instead of the live files.
+    ///
+    /// With deletions included, the add/delete history is not merged, and each split holds its
+    /// files in commit order instead of by file name, with their [`FileKind`]. This is meant for
+    /// compaction and debugging.
+    pub fn with_include_deletes(mut self, include_deletes: bool) -> Self {
+        self.include_deletes = include_deletes;
+        self
+    }
+
+    /// Stop planning data files once the planned ones hold at least `limit` rows, for previews.
+    ///
+    /// The limit is applied at the granularity of files, so the planned files may hold more than
+    /// `limit` rows, see [`crate::table::TableRead::with_limit`] to read exactly `limit` rows. It
+    /// is ignored for tables with primary keys, whose files overlap, when deletions are included,
+    /// and when the filter has conditions on non-partition fields, which may not match all rows
+    /// of a file.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Whether to check that the bucket path of each split exists while planning, failing with
+    /// [`crate::Error::PathNotExist`] otherwise.
+    ///
+    /// This costs one request to the storage per split, and helps to diagnose tables whose files
+    /// were partially deleted.
+    pub fn with_validate_paths(mut self, validate_paths: bool) -> Self {
+        self.validate_paths = validate_paths;
+        self
+    }
+
+    /// Whether to drop the splits whose bucket path does not exist instead of failing, when paths
+    /// are validated, see [`TableScan::with_validate_paths`].
+    pub fn with_skip_missing_paths(mut self, skip_missing_paths: bool) -> Self {
+        self.skip_missing_paths = skip_missing_paths;
+        self
+    }
+
+    /// Plan the splits of the data files of the latest snapshot.
+    ///
+    /// Splits are ordered by partition path and bucket, and the data files of each split by file
+    /// name, so that planning the same snapshot twice gives the same plan.
+    pub async fn plan(&self) -> crate::Result<Plan> {
+        let plan = self.plan_latest();
+        #[cfg(feature = "tracing")]
+        let plan = tracing::Instrument::instrument(
+            plan,
+            tracing::info_span!(
+                "paimon.scan.plan",
+                table = self.table.location(),
+                snapshot_id = tracing::field::Empty,
+                manifest_files = tracing::field::Empty,
+                entries = tracing::field::Empty,
+                files = tracing::field::Empty,
+            ),
+        );
+        plan.await
+    }
+
+    async fn plan_latest(&self) -> crate::Result<Plan> {
+        let Some(snapshot) = self.table.snapshot_manager().latest_snapshot().await? else {
+            return Ok(Plan {
+                snapshot_id: None,
+                splits: vec![],
+            });
+        };
+        trace::record("snapshot_id", snapshot.id() as u64);
+
+        let (partition_filter, data_filter) = match &self.filter {
+            Some(filter) => filter.split_by_fields(self.table.schema().partition_keys()),
+            None => (None, None),
+        };
+        let entries = if self.include_deletes {
+            self.table
+                .filtered_entries(&snapshot, partition_filter.as_ref())
+                .await?
+        } else {
+            self.table
+                .filtered_live_entries(&snapshot, partition_filter.as_ref())
+                .await?
+        };
+        // the rows of a filtered file may not match, so they can't count towards the limit
+        let limit = match self.limit {
+            Some(limit)
+                if !self.include_deletes
+                    && data_filter.is_none()
+                    && self.table.schema().primary_keys().is_empty() =>
+            {
+                Some(limit as i64)
+            }
+            _ => None,
+        };
+        let mut filtered = Vec::with_capacity(entries.len());
+        let mut row_count = 0;
+        for entry in entries {
+            if matches!(limit, Some(limit) if row_count >= limit) {
+                break;
+            }
+            if matches!(
+                self.created_after_millis,
+                Some(millis) if entry.file().creation_time().timestamp_millis() <= millis
+            ) {
+                continue;
+            }
+            if let Some(filter) = &data_filter {
+                if !self.test_data_file(filter, entry.file())? {
+                    continue;
+                }
+            }
+            row_count += entry.file().row_count();
+            filtered.push(entry);
+        }
+        let entries = filtered;
+        trace::record("files", entries.len() as u64);
+
+        // (partition, bucket) -> (total buckets, data files, file kinds)
+        let mut groups: Vec<(SplitKey, i32, Vec<DataFileMeta>, Vec<FileKind>)> = Vec::new();
+        let mut positions: HashMap<SplitKey, usize> = HashMap::new();
+        for entry in entries {
+            let key = (entry.partition().clone(), entry.bucket());
+            let pos = *positions.entry(key.clone()).or_insert_with(|| {
+                groups.push((key, entry.total_buckets(), vec![], vec![]));
+                groups.len() - 1
+            });
+            groups[pos].2.push(entry.file().clone());
+            groups[pos].3.push(*entry.kind());
+        }
+
+        let mut splits = groups
+            .into_iter()
+            .map(
+                |((partition, bucket), total_buckets, mut data_files, file_kinds)| {
+                    let partition_path = self.partition_path(&partition)?;
+                    let bucket_path = self
+                        .table
+                        .path_factory()
+                        .bucket_path(&partition_path, bucket);
+                    if !self.include_deletes {
+                        data_files.sort_by(|a, b| a.file_name().cmp(b.file_name()));
+                    }
+                    let split = DataSplit::new(
+                        snapshot.id(),
+                        partition,
+                        bucket,
+                        bucket_path,
+                        total_buckets,
+                        data_files,
+                    )
+                    .with_file_kinds(file_kinds);
+                    Ok((partition_path, split))
+                },
+            )
+            .collect::<crate::Result<Vec<_>>>()?;
+        splits.sort_by(|(a_path, a), (b_path, b)| (a_path, a.bucket()).cmp(&(b_path, b.bucket())));
+        let splits = splits.into_iter().map(|(_, split)| split).collect();
+        let splits = if self.validate_paths {
+            self.validate_paths(splits).await?
+        } else {
+            splits
+        };
+        Ok(Plan {
+            snapshot_id: Some(snapshot.id()),
+            splits,
+        })
+    }
+
+    /// Plan the splits of the data files of the latest snapshot created after `millis`, the epoch
+    /// milliseconds of a previous export, for incremental exports without diffing snapshots.
+    ///
+    /// Only the files whose creation time is strictly after `millis` are planned, and the
+    /// partitions and buckets without such file are skipped. The files of a table with primary
+    /// keys are planned without the older files they would be merged with.
+    pub async fn plan_created_after(&self, millis: u64) -> crate::Result<Plan> {
+        let mut scan = self.clone();
+        scan.created_after_millis = Some(i64::try_from(millis).unwrap_or(i64::MAX));
+        scan.plan().await
+    }
+
+    /// Plan the splits of the data files of the latest snapshot, grouped by the path of their
+    /// partition, like `dt=2024-01-01/hr=10`.
+    ///
+    /// The splits of an unpartitioned table are grouped under the empty path.
+    pub async fn plan_by_partition(&self) -> crate::Result<HashMap<String, Vec<DataSplit>>> {
+        let mut splits_by_partition: HashMap<String, Vec<DataSplit>> = HashMap::new();
+        for split in self.plan().await?.splits {
+            let partition_path = self.partition_path(split.partition())?;
+            splits_by_partition
+                .entry(partition_path.trim_end_matches('/').to_string())
+                .or_default()
+                .push(split);
+        }
+        Ok(splits_by_partition)
+    }
+
+    /// Check that the bucket paths of the splits exist, dropping the splits whose path is missing
+    /// if configured.
+    async fn validate_paths(&self, splits: Vec<DataSplit>) -> crate::Result<Vec<DataSplit>> {
+        let mut validated = Vec::with_capacity(splits.len());
+        for split in splits {
+            let dir = format!("{}/", split.bucket_path());
+            if self.table.file_io().exists(&dir).await? {
+                validated.push(split);
+            } else if !self.skip_missing_paths {
+                return Err(Error::PathNotExist {
+                    message: format!(
+                        "Bucket path '{}' of bucket {} planned with {} data files does not exist",
+                        split.bucket_path(),
+                        split.bucket(),
+                        split.data_files().len()
+                    ),
+                });
+            }
+        }
+        Ok(validated)
+    }
+
+    /// Whether the data file may hold rows matching the filter, according to its value stats.
+    fn test_data_file(&self, filter: &Predicate, file: &DataFileMeta) -> crate::Result<bool> {
+        let schema = self.table.schema();
+        let stats = file.value_stats();
+        // stats of files written with another schema have other fields
+        if !schema.primary_keys().is_empty()
+            || file.schema_id() != schema.id()
+            || stats.min_values().is_empty()
+        {
+            return Ok(true);
+        }
+        filter.test_stats(
+            &RowType::new(schema.fields().to_vec()),
+            file.row_count(),
+            &BinaryRow::from_bytes(stats.min_values())?,
+            &BinaryRow::from_bytes(stats.max_values())?,
+            stats.null_counts(),
+        )
+    }
+
+    /// Read the index files of the latest snapshot, like deletion vectors and hash indexes.
+    ///
+    /// Returns no entries if the table has no snapshot or the snapshot has no index manifest.
+    pub async fn index_entries(&self) -> crate::Result<IndexManifestEntries> {
+        match self.table.snapshot_manager().latest_snapshot().await? {
+            None => Ok(IndexManifestEntries::default()),
+            Some(snapshot) => Ok(self.table.index_entries(&snapshot).await?.into()),
+        }
+    }
+
+    /// Get the path of a serialized partition with a trailing `/`, empty if the table is not
+    /// partitioned.
+    fn partition_path(&self, partition: &[u8]) -> crate::Result<String> {
+        let partition_type = self.table.schema().logical_partition_type();
+        if partition_type.fields().is_empty() {
+            return Ok(String::new());
+        }
+        Ok(partition_path(&partition_spec(
+            &BinaryRow::from_bytes(partition)?,
+            &partition_type,
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::DateTime;
+
+    use std::collections::HashMap;
+
+    use super::Plan;
+    use crate::predicate::PredicateBuilder;
+    use crate::spec::{
+        BinaryRowWriter, BinaryTableStats, CoreOptions, DataField, DataFileMeta, DataType,
+        FileKind, IntType, Literal, ManifestEntry,
+    };
+    use crate::test_util::{
+        commit, commit_with_index_manifest, data_file, entry, int_partition, new_table,
+    };
+    use crate::Error;
+
+    #[tokio::test]
+    async fn test_plan() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_table_scan_plan",
+            vec![DataField::new(
+                0,
+                "p".to_string(),
+                DataType::Int(IntType::new()),
+            )],
+            vec!["p".to_string()],
+        );
+        assert_eq!(table.new_scan().plan().await?.snapshot_id(), None);
+
+        let base = vec![
+            entry(FileKind::Add, int_partition(1), data_file("f1", 10, 100, 0)),
+            entry(FileKind::Add, int_partition(2), data_file("f2", 20, 200, 0)),
+            entry(FileKind::Add, int_partition(1), data_file("f3", 30, 300, 0)),
+        ];
+        let delta = vec![entry(
+            FileKind::Delete,
+            int_partition(1),
+            data_file("f1", 10, 100, 0),
+        )];
+        commit(&table, 1, base, delta).await?;
+
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(plan.snapshot_id(), Some(1));
+        let splits = plan.splits();
+        assert_eq!(splits.len(), 2);
+
+        // f1 is deleted
+        assert_eq!(splits[0].partition(), int_partition(1));
+        assert_eq!(splits[0].bucket(), 0);
+        assert_eq!(
+            splits[0].bucket_path(),
+            "memory:/tmp/test_table_scan_plan/p=1/bucket-0"
+        );
+        assert_eq!(splits[0].data_files().len(), 1);
+        assert_eq!(splits[0].data_files()[0].file_name(), "f3");
+        assert_eq!(splits[0].row_count(), 30);
+
+        assert_eq!(splits[1].partition(), int_partition(2));
+        assert_eq!(
+            splits[1].data_file_path(&splits[1].data_files()[0]),
+            "memory:/tmp/test_table_scan_plan/p=2/bucket-0/f2"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_plan_merges_entries_incrementally() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_plan_merges_entries_incrementally",
+            vec![DataField::new(
+                0,
+                "p".to_string(),
+                DataType::Int(IntType::new()),
+            )],
+            vec!["p".to_string()],
+        );
+        let bucket_entry = |kind, partition, bucket, name: &str| {
+            ManifestEntry::new(
+                kind,
+                int_partition(partition),
+                bucket,
+                2,
+                data_file(name, 10, 100, 0),
+                2,
+            )
+        };
+        let base = vec![
+            bucket_entry(FileKind::Add, 1, 0, "f1"),
+            bucket_entry(FileKind::Add, 1, 1, "f2"),
+            bucket_entry(FileKind::Add, 2, 0, "f3"),
+            bucket_entry(FileKind::Add, 1, 0, "f4"),
+            bucket_entry(FileKind::Add, 2, 1, "f5"),
+        ];
+        let delta = vec![
+            bucket_entry(FileKind::Delete, 1, 0, "f1"),
+            bucket_entry(FileKind::Delete, 2, 1, "f5"),
+            bucket_entry(FileKind::Add, 2, 1, "f6"),
+            bucket_entry(FileKind::Add, 1, 0, "f1"),
+        ];
+        commit(&table, 1, base, delta).await?;
+
+        // the bounded manifest reads plan the same splits as the default ones
+        let bounded = table.copy(HashMap::from([(
+            CoreOptions::SCAN_MANIFEST_PARALLELISM.to_string(),
+            "1".to_string(),
+        )]));
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(bounded.new_scan().plan().await?.splits(), plan.splits());
+
+        // splits ordered by partition, bucket and file name, without the deleted files
+        let splits = plan
+            .splits()
+            .iter()
+            .map(|split| {
+                let names = split
+                    .data_files()
+                    .iter()
+                    .map(|file| file.file_name().to_string())
+                    .collect::<Vec<_>>();
+                ((split.partition().to_vec(), split.bucket()), names)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            splits,
+            vec![
+                (
+                    (int_partition(1), 0),
+                    vec!["f1".to_string(), "f4".to_string()],
+                ),
+                ((int_partition(1), 1), vec!["f2".to_string()]),
+                ((int_partition(2), 0), vec!["f3".to_string()]),
+                ((int_partition(2), 1), vec!["f6".to_string()]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_plan_is_deterministic() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_plan_is_deterministic",
+            vec![DataField::new(
+                0,
+                "p".to_string(),
+                DataType::Int(IntType::new()),
+            )],
+            vec!["p".to_string()],
+        );
+        let bucket_entry = |partition, bucket, name: &str| {
+            ManifestEntry::new(
+                FileKind::Add,
+                int_partition(partition),
+                bucket,
+                4,
+                data_file(name, 10, 100, 0),
+                2,
+            )
+        };
+        let base = vec![
+            bucket_entry(3, 2, "f1"),
+            bucket_entry(1, 3, "f2"),
+            bucket_entry(3, 0, "f3"),
+            bucket_entry(1, 0, "f5"),
+            bucket_entry(2, 1, "f6"),
+            bucket_entry(1, 0, "f4"),
+        ];
+        commit(&table, 1, base, vec![]).await?;
+
+        let layout = |plan: &Plan| {
+            plan.splits()
+                .iter()
+                .map(|split| {
+                    let names = split
+                        .data_files()
+                        .iter()
+                        .map(|file| file.file_name().to_string())
+                        .collect::<Vec<_>>();
+                    (split.bucket_path().to_string(), names)
+                })
+                .collect::<Vec<_>>()
+        };
+        let first = layout(&table.new_scan().plan().await?);
+        let second = layout(&table.new_scan().plan().await?);
+        assert_eq!(first, second);
+        let root = "memory:/tmp/test_plan_is_deterministic";
+        assert_eq!(
+            first,
+            vec![
+                (
+                    format!("{}/p=1/bucket-0", root),
+                    vec!["f4".to_string(), "f5".to_string()]
+                ),
+                (format!("{}/p=1/bucket-3", root), vec!["f2".to_string()]),
+                (format!("{}/p=2/bucket-1", root), vec!["f6".to_string()]),
+                (format!("{}/p=3/bucket-0", root), vec!["f3".to_string()]),
+                (format!("{}/p=3/bucket-2", root), vec!["f1".to_string()]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_plan_by_partition() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_plan_by_partition",
+            vec![DataField::new(
+                0,
+                "p".to_string(),
+                DataType::Int(IntType::new()),
+            )],
+            vec!["p".to_string()],
+        );
+        assert!(table.new_scan().plan_by_partition().await?.is_empty());
+
+        let base = vec![
+            entry(FileKind::Add, int_partition(1), data_file("f1", 10, 100, 0)),
+            entry(FileKind::Add, int_partition(2), data_file("f2", 20, 200, 0)),
+            entry(FileKind::Add, int_partition(1), data_file("f3", 30, 300, 0)),
+        ];
+        commit(&table, 1, base, vec![]).await?;
+
+        let splits = table.new_scan().plan_by_partition().await?;
+        let mut partitions = splits.keys().cloned().collect::<Vec<_>>();
+        partitions.sort();
+        assert_eq!(partitions, vec!["p=1", "p=2"]);
+        assert_eq!(splits["p=1"].len(), 1);
+        assert_eq!(splits["p=1"][0].partition(), int_partition(1));
+        assert_eq!(splits["p=1"][0].row_count(), 40);
+        assert_eq!(splits["p=2"][0].data_files()[0].file_name(), "f2");
+
+        let table = new_table(
+            "memory:/tmp/test_plan_by_partition_unpartitioned",
+            vec![],
+            vec![],
+        );
+        let base = vec![entry(
+            FileKind::Add,
+            vec![0, 0, 0, 0],
+            data_file("f1", 10, 100, 0),
+        )];
+        commit(&table, 1, base, vec![]).await?;
+        let splits = table.new_scan().plan_by_partition().await?;
+        assert_eq!(splits.len(), 1);
+        assert_eq!(splits[""][0].row_count(), 10);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_plan_created_after() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_plan_created_after",
+            vec![DataField::new(
+                0,
+                "p".to_string(),
+                DataType::Int(IntType::new()),
+            )],
+            vec!["p".to_string()],
+        );
+        assert_eq!(
+            table.new_scan().plan_created_after(0).await?.snapshot_id(),
+            None
+        );
+
+        let base = vec![
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f1", 10, 100, 1000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(2),
+                data_file("f2", 20, 200, 2000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f3", 30, 300, 3000),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                data_file("f4", 40, 400, 4000),
+            ),
+        ];
+        let delta = vec![entry(
+            FileKind::Delete,
+            int_partition(1),
+            data_file("f4", 40, 400, 4000),
+        )];
+        commit(&table, 1, base, delta).await?;
+
+        let file_names = |plan: &Plan| {
+            plan.splits()
+                .iter()
+                .flat_map(|split| split.data_files().iter().map(|f| f.file_name().to_string()))
+                .collect::<Vec<_>>()
+        };
+        let plan = table.new_scan().plan_created_after(0).await?;
+        assert_eq!(file_names(&plan), vec!["f1", "f3", "f2"]);
+
+        // the threshold is exclusive, and deleted files are never planned
+        let plan = table.new_scan().plan_created_after(2000).await?;
+        assert_eq!(plan.snapshot_id(), Some(1));
+        assert_eq!(plan.splits().len(), 1);
+        assert_eq!(plan.splits()[0].partition(), int_partition(1));
+        assert_eq!(file_names(&plan), vec!["f3"]);
+
+        assert!(table
+            .new_scan()
+            .plan_created_after(5000)
+            .await?
+            .splits()
+            .is_empty());
+        // the scan itself still plans all files
+        assert_eq!(table.new_scan().plan().await?.splits().len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_plan_include_deletes() -> crate::Result<()> {
+        let table = new_table("memory:/tmp/test_plan_include_deletes", vec![], vec![]);
+        let partition = vec![0, 0, 0, 0];
+        let base = vec![
+            entry(
+                FileKind::Add,
+                partition.clone(),
+                data_file("f1", 10, 100, 0),
+            ),
+            entry(
+                FileKind::Add,
+                partition.clone(),
+                data_file("f2", 20, 200, 0),
+            ),
+        ];
+        let delta = vec![entry(
+            FileKind::Delete,
+            partition.clone(),
+            data_file("f1", 10, 100, 0),
+        )];
+        commit(&table, 1, base, delta).await?;
+
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(plan.splits().len(), 1);
+        let files = plan.splits()[0]
+            .files_with_kind()
+            .map(|(kind, file)| (kind, file.file_name()))
+            .collect::<Vec<_>>();
+        assert_eq!(files, vec![(FileKind::Add, "f2")]);
+
+        let plan = table.new_scan().with_include_deletes(true).plan().await?;
+        assert_eq!(plan.splits().len(), 1);
+        let split = &plan.splits()[0];
+        assert_eq!(
+            split.bucket_path(),
+            "memory:/tmp/test_plan_include_deletes/bucket-0"
+        );
+        let files = split
+            .files_with_kind()
+            .map(|(kind, file)| (kind, file.file_name()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            files,
+            vec![
+                (FileKind::Add, "f1"),
+                (FileKind::Add, "f2"),
+                (FileKind::Delete, "f1"),
+            ]
+        );
+        assert_eq!(split.row_count(), 30);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_index_entries() -> crate::Result<()> {
+        let table = new_table("memory:/tmp/test_table_scan_index_entries", vec![], vec![]);
+        assert!(table.new_scan().index_entries().await?.entries().is_empty());
+
+        commit(&table, 1, vec![], vec![]).await?;
+        assert!(table.new_scan().index_entries().await?.entries().is_empty());
+
+        let index_manifest = "index-manifest-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-0";
+        let fixture = std::fs::read(format!("tests/fixtures/manifest/{}", index_manifest)).unwrap();
+        table
+            .file_io()
+            .new_output(&table.path_factory().index_manifest_path(index_manifest))?
+            .write(fixture.into())
+            .await?;
+        commit_with_index_manifest(&table, 2, vec![], vec![], Some(index_manifest.to_string()))
+            .await?;
+
+        let entries = table.new_scan().index_entries().await?;
+        let index_files = entries
+            .entries()
+            .iter()
+            .map(|entry| {
+                (
+                    entry.bucket(),
+                    entry.index_file().index_type(),
+                    entry.index_file().file_name(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            index_files,
+            vec![
+                (
+                    0,
+                    "DELETION_VECTORS",
+                    "index-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-0"
+                ),
+                (1, "HASH", "index-55b4e4d5-7ea4-4a3f-9e55-8a6a4b62d3f1-1"),
+            ]
+        );
+        assert!(entries
+            .entries()
+            .iter()
+            .all(|entry| entry.partition() == &int_partition(1)));
+
+        Ok(())
+    }
+
+    fn file_with_id_range(name: &str, partition: i32, min_id: i32, max_id: i32) -> DataFileMeta {
+        let stats_row = |id: i32| {
+            let mut writer = BinaryRowWriter::new(2);
+            writer.write_int(0, partition);
+            writer.write_int(1, id);
+            writer.build().to_bytes()
+        };
+        DataFileMeta::builder()
+            .file_name(name.to_string())
+            .file_size(100)
+            .row_count(10)
+            .value_stats(BinaryTableStats::new(
+                stats_row(min_id),
+                stats_row(max_id),
+                vec![0, 0],
+            ))
+            .min_sequence_number(0)
+            .max_sequence_number(9)
+            .schema_id(0)
+            .level(0)
+            .creation_time(DateTime::from_timestamp_millis(0).unwrap())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_plan_with_filter() -> crate::Result<()> {
+        let fields = vec![
+            DataField::new(0, "p".to_string(), DataType::Int(IntType::new())),
+            DataField::new(1, "id".to_string(), DataType::Int(IntType::new())),
+        ];
+        let table = new_table(
+            "memory:/tmp/test_table_scan_plan_with_filter",
+            fields.clone(),
+            vec!["p".to_string()],
+        );
+        let base = vec![
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                file_with_id_range("f1", 1, 1, 10),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(1),
+                file_with_id_range("f2", 1, 20, 30),
+            ),
+            entry(
+                FileKind::Add,
+                int_partition(2),
+                file_with_id_range("f3", 2, 20, 30),
+            ),
+        ];
+        commit(&table, 1, base, vec![]).await?;
+
+        let planned_files = |plan: Plan| {
+            plan.splits()
+                .iter()
+                .flat_map(|split| split.data_files())
+                .map(|file| file.file_name().to_string())
+                .collect::<Vec<_>>()
+        };
+        let builder = PredicateBuilder::new(&fields);
+
+        // f1 is skipped by its stats
+        let plan = table
+            .new_scan()
+            .with_filter(builder.equal(1, Literal::Int(25)))
+            .plan()
+            .await?;
+        assert_eq!(planned_files(plan), vec!["f2", "f3"]);
+
+        // the limit is not pushed down with a data filter: f2 may hold no matching row
+        let plan = table
+            .new_scan()
+            .with_filter(builder.equal(1, Literal::Int(25)))
+            .with_limit(1)
+            .plan()
+            .await?;
+        assert_eq!(planned_files(plan), vec!["f2", "f3"]);
+        let plan = table
+            .new_scan()
+            .with_filter(builder.equal(0, Literal::Int(1)))
+            .with_limit(1)
+            .plan()
+            .await?;
+        assert_eq!(planned_files(plan).len(), 1);
+
+        // partition 2 is skipped by the partition filter
+        let plan = table
+            .new_scan()
+            .with_filter(PredicateBuilder::and(vec![
+                builder.equal(0, Literal::Int(1)),
+                builder.greater_than(1, Literal::Int(5)),
+            ]))
+            .plan()
+            .await?;
+        assert_eq!(planned_files(plan), vec!["f1", "f2"]);
+
+        let plan = table
+            .new_scan()
+            .with_filter(builder.is_null(1))
+            .plan()
+            .await?;
+        assert!(plan.splits().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_plan_with_validate_paths() -> crate::Result<()> {
+        let table = new_table(
+            "memory:/tmp/test_table_scan_validate_paths",
+            vec![DataField::new(
+                0,
+                "p".to_string(),
+                DataType::Int(IntType::new()),
+            )],
+            vec!["p".to_string()],
+        );
+        let base = vec![
+            entry(FileKind::Add, int_partition(1), data_file("f1", 10, 100, 0)),
+            entry(FileKind::Add, int_partition(2), data_file("f2", 20, 200, 0)),
+        ];
+        commit(&table, 1, base, vec![]).await?;
+        for split in table.new_scan().plan().await?.splits() {
+            for file in split.data_files() {
+                table
+                    .file_io()
+                    .new_output(&split.data_file_path(file))?
+                    .write(Bytes::from("data"))
+                    .await?;
+            }
+        }
+        let bucket_path = table.path_factory().bucket_path("p=2/", 0);
+        table
+            .file_io()
+            .delete_dir(&format!("{}/", bucket_path))
+            .await?;
+
+        assert_eq!(table.new_scan().plan().await?.splits().len(), 2);
+        let scan = table.new_scan().with_validate_paths(true);
+        let err = scan.plan().await.unwrap_err();
+        assert!(matches!(err, Error::PathNotExist { .. }));
+        assert!(err.is_not_found());
+        assert!(err.to_string().contains(&bucket_path), "{err}");
+
+        let plan = scan.with_skip_missing_paths(true).plan().await?;
+        assert_eq!(plan.splits().len(), 1);
+        assert_eq!(plan.splits()[0].partition(), int_partition(1));
+        Ok(())
+    }
+}
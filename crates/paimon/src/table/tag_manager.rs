@@ -0,0 +1,120 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::time::Duration;
+
+use bytes::Bytes;
+use snafu::ResultExt;
+
+use crate::error::MetadataInvalidSnafu;
+use crate::io::FileIO;
+use crate::spec::{Snapshot, Tag};
+use crate::table::{PathFactory, TAG_PREFIX};
+use crate::Error;
+
+/// Manager for tag files of a table.
+///
+/// Impl Reference: <https://github.com/apache/paimon/blob/release-0.8.2/paimon-core/src/main/java/org/apache/paimon/utils/TagManager.java>
+#[derive(Debug, Clone)]
+pub struct TagManager {
+    file_io: FileIO,
+    path_factory: PathFactory,
+}
+
+impl TagManager {
+    pub fn new(file_io: FileIO, table_path: impl ToString) -> Self {
+        Self {
+            file_io,
+            path_factory: PathFactory::new(table_path),
+        }
+    }
+
+    /// Get the path of the tag file with the given name.
+    pub fn tag_path(&self, tag_name: &str) -> String {
+        self.path_factory.tag_path(tag_name)
+    }
+
+    /// Whether the tag with the given name exists.
+    pub async fn tag_exists(&self, tag_name: &str) -> crate::Result<bool> {
+        self.file_io.exists(&self.tag_path(tag_name)).await
+    }
+
+    /// Read the tag with the given name.
+    pub async fn tag(&self, tag_name: &str) -> crate::Result<Tag> {
+        let path = self.tag_path(tag_name);
+        let json = self.file_io.read_to_string(&path).await?;
+        serde_json::from_str(&json).context(MetadataInvalidSnafu {
+            message: format!("Failed to parse tag '{}'", path),
+        })
+    }
+
+    /// List the names of all tags, sorted.
+    pub async fn list_tag_names(&self) -> crate::Result<Vec<String>> {
+        let dir = format!("{}/", self.path_factory.tag_dir());
+        if !self.file_io.exists(&dir).await? {
+            return Ok(vec![]);
+        }
+        let mut names = self
+            .file_io
+            .list_status(&dir)
+            .await?
+            .into_iter()
+            .filter(|status| !status.is_dir)
+            .filter_map(|status| {
+                let name = status.path.rsplit('/').next()?;
+                Some(name.strip_prefix(TAG_PREFIX)?.to_string())
+            })
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        Ok(names)
+    }
+
+    /// Create a tag of the snapshot, retained for `time_retained` if given.
+    ///
+    /// Fails if a tag with the same name already exists.
+    pub async fn create_tag(
+        &self,
+        snapshot: &Snapshot,
+        tag_name: &str,
+        time_retained: Option<Duration>,
+    ) -> crate::Result<()> {
+        if tag_name.trim().is_empty() {
+            return Err(Error::TagInvalid {
+                message: "Tag name must not be blank".to_string(),
+            });
+        }
+        if self.tag_exists(tag_name).await? {
+            return Err(Error::TagInvalid {
+                message: format!("Tag '{}' already exists", tag_name),
+            });
+        }
+
+        let tag = Tag::new(
+            snapshot.clone(),
+            Some(chrono::Local::now().naive_local()),
+            time_retained,
+        );
+        let path = self.tag_path(tag_name);
+        let bytes = serde_json::to_vec(&tag).context(MetadataInvalidSnafu {
+            message: format!("Failed to serialize tag '{}'", path),
+        })?;
+        self.file_io
+            .new_output(&path)?
+            .write(Bytes::from(bytes))
+            .await
+    }
+}
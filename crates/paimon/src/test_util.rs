@@ -0,0 +1,325 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers to build tables in tests, with committed data files, enabled by the `test-util`
+//! feature.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use bytes::{BufMut, Bytes, BytesMut};
+use chrono::{DateTime, Utc};
+use snafu::ResultExt;
+
+use crate::arrow::to_arrow_schema;
+use crate::error::MetadataInvalidSnafu;
+use crate::io::{DataFileWriter, FileIOBuilder};
+use crate::spec::{
+    CommitKind, DataField, DataFileMeta, FileKind, ManifestEntry, ManifestFile, ManifestList,
+    Snapshot, TableSchema, EMPTY_BINARY_ROW,
+};
+use crate::table::{FileNameGenerator, FileStoreTable};
+
+/// Serialize a `BinaryRow` with a single int field as Paimon does.
+pub fn int_partition(value: i32) -> Vec<u8> {
+    let mut buffer = BytesMut::new();
+    buffer.put_i32(1);
+    buffer.put_u64_le(0);
+    buffer.put_i64_le(value as i64);
+    buffer.to_vec()
+}
+
+/// A data file of bucket 0 written with schema 0 at level 0, whose sequence numbers are all 0.
+pub fn data_file(
+    file_name: &str,
+    row_count: i64,
+    file_size: i64,
+    creation_millis: i64,
+) -> DataFileMeta {
+    DataFileMeta::builder()
+        .file_name(file_name.to_string())
+        .file_size(file_size)
+        .row_count(row_count)
+        .min_sequence_number(0)
+        .max_sequence_number(0)
+        .schema_id(0)
+        .level(0)
+        .creation_time(DateTime::<Utc>::from_timestamp_millis(creation_millis).unwrap())
+        .build()
+}
+
+/// A manifest entry of `file` in bucket 0 of a table with one bucket.
+pub fn entry(kind: FileKind, partition: Vec<u8>, file: DataFileMeta) -> ManifestEntry {
+    bucket_entry(kind, partition, 0, 1, file)
+}
+
+/// A manifest entry of `file` in `bucket` of a table with `total_buckets` buckets.
+pub fn bucket_entry(
+    kind: FileKind,
+    partition: Vec<u8>,
+    bucket: i32,
+    total_buckets: i32,
+    file: DataFileMeta,
+) -> ManifestEntry {
+    ManifestEntry::new(kind, partition, bucket, total_buckets, file, 2)
+}
+
+/// Commit snapshot `snapshot_id` of schema 0 whose base and delta manifests hold the given
+/// entries.
+pub async fn commit(
+    table: &FileStoreTable,
+    snapshot_id: i64,
+    base: Vec<ManifestEntry>,
+    delta: Vec<ManifestEntry>,
+) -> crate::Result<()> {
+    commit_with_index_manifest(table, snapshot_id, base, delta, None).await
+}
+
+/// Commit a snapshot referencing an index manifest already written to the manifest directory.
+pub async fn commit_with_index_manifest(
+    table: &FileStoreTable,
+    snapshot_id: i64,
+    base: Vec<ManifestEntry>,
+    delta: Vec<ManifestEntry>,
+    index_manifest: Option<String>,
+) -> crate::Result<()> {
+    let row_count = |entries: &[ManifestEntry]| -> i64 {
+        entries
+            .iter()
+            .map(|entry| match entry.kind() {
+                FileKind::Add => entry.file().row_count(),
+                FileKind::Delete => -entry.file().row_count(),
+            })
+            .sum()
+    };
+    let delta_record_count = row_count(&delta);
+    let total_record_count = row_count(&base) + delta_record_count;
+    let path_factory = table.path_factory();
+    let manifest_file = ManifestFile::new(table.file_io().clone());
+    let manifest_list = ManifestList::new(table.file_io().clone());
+    let mut lists = Vec::new();
+    for (i, entries) in [base, delta].iter().enumerate() {
+        let manifest = format!("manifest-{}-{}", snapshot_id, i);
+        let meta = manifest_file
+            .write(&path_factory.manifest_path(&manifest), entries, 0)
+            .await?;
+        let list = format!("manifest-list-{}-{}", snapshot_id, i);
+        manifest_list
+            .write(&path_factory.manifest_list_path(&list), &[meta])
+            .await?;
+        lists.push(list);
+    }
+
+    let snapshot = Snapshot::builder()
+        .version(3)
+        .id(snapshot_id)
+        .schema_id(0)
+        .base_manifest_list(lists[0].clone())
+        .delta_manifest_list(lists[1].clone())
+        .index_manifest(index_manifest)
+        .commit_user("test".to_string())
+        .commit_identifier(snapshot_id)
+        .commit_kind(CommitKind::APPEND)
+        .time_millis(0)
+        .total_record_count(Some(total_record_count))
+        .delta_record_count(Some(delta_record_count))
+        .build();
+    let path = path_factory.snapshot_path(snapshot_id);
+    table
+        .file_io()
+        .new_output(&path)?
+        .write(Bytes::from(serde_json::to_vec(&snapshot).unwrap()))
+        .await
+}
+
+/// A table with schema 0 of `fields` on memory storage, without primary keys. The schema file
+/// is not written.
+pub fn new_table(
+    location: &str,
+    fields: Vec<DataField>,
+    partition_keys: Vec<String>,
+) -> FileStoreTable {
+    new_table_with_options(location, fields, partition_keys, HashMap::new())
+}
+
+fn new_table_with_options(
+    location: &str,
+    fields: Vec<DataField>,
+    partition_keys: Vec<String>,
+    options: HashMap<String, String>,
+) -> FileStoreTable {
+    let file_io = FileIOBuilder::new("memory").build().unwrap();
+    let highest_field_id = fields.iter().map(|f| f.id()).max().unwrap_or(-1);
+    let schema = TableSchema::new(
+        0,
+        fields,
+        highest_field_id,
+        partition_keys,
+        vec![],
+        options,
+        None,
+    );
+    FileStoreTable::new(file_io, location, schema)
+}
+
+/// Builder of a minimal unpartitioned append-only table on memory storage, ready to be scanned
+/// and read.
+///
+/// [`TestTableBuilder::build`] writes schema 0 and commits snapshot 1 with [`commit`]. The
+/// snapshot adds one parquet data file in bucket 0 holding the batch set with
+/// [`TestTableBuilder::with_batch`], and no file if no batch is set.
+///
+/// ```ignore
+/// let table = TestTableBuilder::new("memory:/tmp/t", fields)
+///     .with_batch(batch)
+///     .build()
+///     .await?;
+/// let plan = table.new_scan().plan().await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct TestTableBuilder {
+    location: String,
+    fields: Vec<DataField>,
+    options: HashMap<String, String>,
+    batch: Option<RecordBatch>,
+}
+
+impl TestTableBuilder {
+    /// Commit user of the snapshot of the built table.
+    pub const COMMIT_USER: &'static str = "test";
+
+    pub fn new(location: impl ToString, fields: Vec<DataField>) -> Self {
+        Self {
+            location: location.to_string(),
+            fields,
+            options: HashMap::new(),
+            batch: None,
+        }
+    }
+
+    /// Set an option of the table schema.
+    pub fn with_option(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.options.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Set the rows of the data file, see
+    /// [`TableSchema::validate_batch`](crate::spec::TableSchema::validate_batch) for the columns.
+    pub fn with_batch(mut self, batch: RecordBatch) -> Self {
+        self.batch = Some(batch);
+        self
+    }
+
+    /// Write the files of the table and return it.
+    pub async fn build(self) -> crate::Result<FileStoreTable> {
+        let table = new_table_with_options(&self.location, self.fields, vec![], self.options);
+        let file_io = table.file_io();
+        let path_factory = table.path_factory();
+        let schema_path = path_factory.schema_path(0);
+        let schema_bytes = serde_json::to_vec(table.schema()).context(MetadataInvalidSnafu {
+            message: format!("Failed to serialize schema '{}'", schema_path),
+        })?;
+        file_io
+            .new_output(&schema_path)?
+            .write(Bytes::from(schema_bytes))
+            .await?;
+
+        let mut delta = Vec::new();
+        if let Some(batch) = self.batch {
+            table.schema().validate_batch(&batch)?;
+            let arrow_schema = Arc::new(to_arrow_schema(table.schema().fields())?);
+            let batch = RecordBatch::try_new(arrow_schema.clone(), batch.columns().to_vec())?;
+            let file_name = FileNameGenerator::new().new_data_file_name("parquet");
+            let path = path_factory.data_file_path("", 0, &file_name);
+            let mut writer = DataFileWriter::new(file_io.new_output(&path)?, arrow_schema, 0, 0)?;
+            writer.write(&batch)?;
+            delta.push(entry(
+                FileKind::Add,
+                EMPTY_BINARY_ROW.to_bytes(),
+                writer.close().await?,
+            ));
+        }
+        commit(&table, 1, vec![], delta).await?;
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Int32Array, StringArray};
+
+    use super::*;
+    use crate::spec::{DataType, IntType, VarCharType};
+
+    #[tokio::test]
+    async fn test_build_and_scan() -> crate::Result<()> {
+        let fields = vec![
+            DataField::new(0, "id".to_string(), DataType::Int(IntType::new())),
+            DataField::new(
+                1,
+                "name".to_string(),
+                DataType::VarChar(VarCharType::default()),
+            ),
+        ];
+        let arrow_schema = Arc::new(to_arrow_schema(&fields)?);
+        let batch = RecordBatch::try_new(
+            arrow_schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+            ],
+        )?;
+        let table = TestTableBuilder::new("memory:/tmp/test_build_and_scan", fields)
+            .with_option("bucket", "1")
+            .with_batch(batch.clone())
+            .build()
+            .await?;
+        assert_eq!(table.schema().options().get("bucket").unwrap(), "1");
+
+        let snapshot = table.snapshot_manager().latest_snapshot().await?.unwrap();
+        assert_eq!(snapshot.id(), 1);
+        assert_eq!(snapshot.commit_user(), TestTableBuilder::COMMIT_USER);
+        assert_eq!(snapshot.total_record_count(), Some(3));
+
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(plan.snapshot_id(), Some(1));
+        assert_eq!(plan.splits().len(), 1);
+        assert_eq!(plan.splits()[0].row_count(), 3);
+        let batches = table.new_read()?.read(&plan.splits()[0]).await?;
+        assert_eq!(batches, vec![batch]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_build_empty_table() -> crate::Result<()> {
+        let fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        let table = TestTableBuilder::new("memory:/tmp/test_build_empty_table", fields)
+            .build()
+            .await?;
+        let snapshot = table.snapshot_manager().latest_snapshot().await?.unwrap();
+        assert_eq!(snapshot.total_record_count(), Some(0));
+        let plan = table.new_scan().plan().await?;
+        assert_eq!(plan.snapshot_id(), Some(1));
+        assert!(plan.splits().is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Instrumentation of the scans and the reads with [tracing](https://docs.rs/tracing) spans,
+//! enabled by the `tracing` feature:
+//!
+//! - `paimon.scan.plan` around [`TableScan::plan`](crate::table::TableScan::plan), with the
+//!   fields `table`, `snapshot_id`, `manifest_files`, `entries` and `files`,
+//! - `paimon.read` around the read of each split by [`TableRead`](crate::table::TableRead), with
+//!   the fields `table`, `snapshot_id`, `bucket`, `files`, `rows` and `bytes`.
+//!
+//! Without the feature, no span is created and recording a field is a no-op.
+//!
+//! The feature also logs warnings, like the names of a file index header decoded lossily by
+//! [`FileIndexFormatReader::get_file_index_with_strict_utf8`](crate::file_index::FileIndexFormatReader::get_file_index_with_strict_utf8).
+
+/// Record the value of a field of the current span, if the span has such a field.
+#[inline]
+pub(crate) fn record(field: &'static str, value: u64) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record(field, value);
+    #[cfg(not(feature = "tracing"))]
+    let _ = (field, value);
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+
+    use arrow_array::{Int32Array, RecordBatch};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+    use tracing_core::span::Current;
+
+    use crate::arrow::to_arrow_schema;
+    use crate::spec::{DataField, DataType, IntType};
+    use crate::test_util::TestTableBuilder;
+
+    type Fields = HashMap<&'static str, String>;
+
+    /// Subscriber recording the names and the fields of the spans.
+    #[derive(Default, Clone)]
+    struct Recorder {
+        spans: Arc<Mutex<Vec<(&'static Metadata<'static>, Fields)>>>,
+        entered: Arc<Mutex<Vec<Id>>>,
+    }
+
+    impl Recorder {
+        fn spans(&self, name: &str) -> Vec<Fields> {
+            self.spans
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(metadata, _)| metadata.name() == name)
+                .map(|(_, fields)| fields.clone())
+                .collect()
+        }
+    }
+
+    struct Visitor<'a>(&'a mut Fields);
+
+    impl Visit for Visitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.insert(field.name(), format!("{:?}", value));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+            let mut fields = Fields::new();
+            attributes.record(&mut Visitor(&mut fields));
+            let mut spans = self.spans.lock().unwrap();
+            spans.push((attributes.metadata(), fields));
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            let (_, fields) = &mut spans[span.into_u64() as usize - 1];
+            values.record(&mut Visitor(fields));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, span: &Id) {
+            self.entered.lock().unwrap().push(span.clone());
+        }
+
+        fn exit(&self, _: &Id) {
+            self.entered.lock().unwrap().pop();
+        }
+
+        fn current_span(&self) -> Current {
+            match self.entered.lock().unwrap().last() {
+                Some(id) => {
+                    let metadata = self.spans.lock().unwrap()[id.into_u64() as usize - 1].0;
+                    Current::new(id.clone(), metadata)
+                }
+                None => Current::none(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_and_read_spans() -> crate::Result<()> {
+        let fields = vec![DataField::new(
+            0,
+            "id".to_string(),
+            DataType::Int(IntType::new()),
+        )];
+        let batch = RecordBatch::try_new(
+            Arc::new(to_arrow_schema(&fields)?),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        let location = "memory:/tmp/test_scan_and_read_spans";
+        let table = TestTableBuilder::new(location, fields)
+            .with_batch(batch)
+            .build()
+            .await?;
+
+        let recorder = Recorder::default();
+        let guard = tracing::subscriber::set_default(recorder.clone());
+        let plan = table.new_scan().plan().await?;
+        let batches = table.new_read()?.read(&plan.splits()[0]).await?;
+        drop(guard);
+        assert_eq!(batches[0].num_rows(), 3);
+
+        let file_size = plan.splits()[0].data_files()[0].file_size();
+        let expected = |pairs: &[(&'static str, String)]| pairs.iter().cloned().collect::<Fields>();
+        assert_eq!(
+            recorder.spans("paimon.scan.plan"),
+            vec![expected(&[
+                ("table", format!("{:?}", location)),
+                ("snapshot_id", "1".to_string()),
+                // the base and the delta manifest of the snapshot
+                ("manifest_files", "2".to_string()),
+                ("entries", "1".to_string()),
+                ("files", "1".to_string()),
+            ])]
+        );
+        assert_eq!(
+            recorder.spans("paimon.read"),
+            vec![expected(&[
+                ("table", format!("{:?}", location)),
+                ("snapshot_id", "1".to_string()),
+                ("bucket", "0".to_string()),
+                ("files", "1".to_string()),
+                ("rows", "3".to_string()),
+                ("bytes", file_size.to_string()),
+            ])]
+        );
+        Ok(())
+    }
+}